@@ -0,0 +1,174 @@
+// src/ake/mod.rs
+
+pub mod mqv;
+mod seal;
+pub mod sigma;
+pub mod sts;
+pub mod transcript;
+pub mod transport;
+mod wire;
+
+pub use mqv::{Mqv, MqvVariant};
+pub use sigma::Sigma;
+pub use sts::Sts;
+pub use transcript::Transcript;
+pub use transport::{MemoryTransport, StreamTransport, Transport};
+
+use crate::algebra::traits::{ElementEncoding, Group, Membership};
+use crate::diffie_hellman::{DiffieHellman, DiffieHellmanError, PublicKey};
+use crate::hash::{Hmac, Sha256};
+use crate::kdf::{hkdf, KdfError};
+use std::fmt;
+
+/// Length in bytes of session keys and MAC keys.
+const KEY_LENGTH: usize = 32;
+
+/// Errors raised while running an authenticated key exchange.
+#[derive(Debug)]
+pub enum AkeError {
+    Transport(String),
+    Io(std::io::Error),
+    UnexpectedMessage { expected: u8, found: u8 },
+    MalformedMessage,
+    InvalidKey(DiffieHellmanError),
+    InvalidSignature,
+    KeyConfirmationFailed,
+    DecryptionFailed,
+    UnknownPeer,
+    KeyDerivation(KdfError),
+}
+
+impl fmt::Display for AkeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AkeError::Transport(reason) => write!(f, "Transport failure: {}.", reason),
+            AkeError::Io(err) => write!(f, "I/O error: {}.", err),
+            AkeError::UnexpectedMessage { expected, found } => write!(
+                f,
+                "Expected protocol message {} but received message {}.",
+                expected, found
+            ),
+            AkeError::MalformedMessage => write!(f, "The protocol message is malformed."),
+            AkeError::InvalidKey(err) => write!(f, "{}", err),
+            AkeError::InvalidSignature => write!(f, "The peer's signature is invalid."),
+            AkeError::KeyConfirmationFailed => {
+                write!(f, "The peer's key confirmation tag is invalid.")
+            }
+            AkeError::DecryptionFailed => {
+                write!(f, "The encrypted payload failed authentication.")
+            }
+            AkeError::UnknownPeer => write!(f, "The peer's identity is not trusted."),
+            AkeError::KeyDerivation(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for AkeError {}
+
+impl From<std::io::Error> for AkeError {
+    fn from(err: std::io::Error) -> Self {
+        AkeError::Io(err)
+    }
+}
+
+impl From<DiffieHellmanError> for AkeError {
+    fn from(err: DiffieHellmanError) -> Self {
+        AkeError::InvalidKey(err)
+    }
+}
+
+impl From<KdfError> for AkeError {
+    fn from(err: KdfError) -> Self {
+        AkeError::KeyDerivation(err)
+    }
+}
+
+/// The outcome of a successful protocol run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EstablishedSession {
+    pub key: Vec<u8>,             // Shared session key
+    pub peer_identity: Vec<u8>,   // Encoded long-term public key of the peer
+    pub transcript_hash: Vec<u8>, // Hash of the full transcript
+}
+
+/// Keys derived from a shared secret: one for sealing handshake payloads, one for MACs and
+/// key confirmation, and the session key handed to the application.
+struct KeySchedule {
+    sealing: Vec<u8>,
+    mac: Vec<u8>,
+    session: Vec<u8>,
+}
+
+impl KeySchedule {
+    /// Derives the keys with HKDF-SHA256, salted with the transcript hash.
+    fn derive(protocol: &str, z: &[u8], transcript: &Transcript) -> Result<Self, AkeError> {
+        let salt = transcript.hash();
+        let derive = |label: &str, length: usize| {
+            hkdf::<Sha256>(
+                &salt,
+                z,
+                format!("{} {}", protocol, label).as_bytes(),
+                length,
+            )
+        };
+        Ok(KeySchedule {
+            sealing: derive("sealing", 2 * KEY_LENGTH)?,
+            mac: derive("mac", KEY_LENGTH)?,
+            session: derive("session", KEY_LENGTH)?,
+        })
+    }
+
+    /// Computes a MAC over a role label and a transcript hash.
+    fn tag(&self, label: &str, transcript_hash: &[u8]) -> Vec<u8> {
+        let mut hmac = Hmac::<Sha256>::new(&self.mac);
+        hmac.update(label.as_bytes());
+        hmac.update(transcript_hash);
+        hmac.finalize()
+    }
+
+    /// Checks a MAC produced by `tag` in constant time.
+    fn verify_tag(&self, label: &str, transcript_hash: &[u8], tag: &[u8]) -> bool {
+        constant_time_eq(&self.tag(label, transcript_hash), tag)
+    }
+}
+
+/// Compares two byte strings without an early exit on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// The message to be signed by one role: a role label followed by the transcript hash.
+fn signed_message(protocol: &str, role: &str, transcript_hash: &[u8]) -> Vec<u8> {
+    let mut message = format!("{} {}", protocol, role).into_bytes();
+    message.extend_from_slice(transcript_hash);
+    message
+}
+
+/// Decodes and validates a public key received from the peer.
+fn decode_public_key<G>(
+    dh: &DiffieHellman<G>,
+    bytes: &[u8],
+) -> Result<PublicKey<G::Element>, AkeError>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+{
+    let element = dh
+        .group
+        .decode_element(bytes)
+        .ok_or(AkeError::MalformedMessage)?;
+    let public_key = PublicKey { element };
+    dh.validate_public_key(&public_key)?;
+    Ok(public_key)
+}
+
+/// Encodes a public key for the wire.
+fn encode_public_key<G>(dh: &DiffieHellman<G>, public_key: &PublicKey<G::Element>) -> Vec<u8>
+where
+    G: Group + ElementEncoding,
+{
+    dh.group.encode_element(&public_key.element)
+}
+
+#[cfg(test)]
+mod tests;
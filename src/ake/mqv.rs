@@ -0,0 +1,316 @@
+// src/ake/mqv.rs
+
+use super::{
+    decode_public_key, encode_public_key, wire, AkeError, EstablishedSession, KeySchedule,
+    Transcript, Transport,
+};
+use crate::algebra::traits::{ElementEncoding, Group, Membership};
+use crate::diffie_hellman::{DiffieHellman, DiffieHellmanError, PrivateKey, PublicKey};
+use crate::hash::Sha256;
+use crate::kdf::one_step_kdf;
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Which exponents are mixed into the implicit signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MqvVariant {
+    /// MQV (SP 800-56A, section 5.7.2.1): d and e come from the associate value function
+    /// applied to the ephemeral public keys.
+    Mqv,
+    /// HMQV (Krawczyk, 2005): d and e hash each ephemeral key together with the peer's
+    /// identity.
+    Hmqv,
+}
+
+impl MqvVariant {
+    /// Returns the protocol name bound into transcripts and key derivation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MqvVariant::Mqv => "MQV",
+            MqvVariant::Hmqv => "HMQV",
+        }
+    }
+}
+
+/// First MQV message, initiator to responder: the ephemeral public key g^x.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqvMessage1 {
+    pub ephemeral: Vec<u8>,
+}
+
+/// Second MQV message, responder to initiator: g^y and the responder's key confirmation tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqvMessage2 {
+    pub ephemeral: Vec<u8>,
+    pub confirmation: Vec<u8>,
+}
+
+/// Third MQV message, initiator to responder: the initiator's key confirmation tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqvMessage3 {
+    pub confirmation: Vec<u8>,
+}
+
+impl MqvMessage1 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(1, &[&self.ephemeral])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 1, 1)?;
+        Ok(MqvMessage1 {
+            ephemeral: fields.remove(0),
+        })
+    }
+}
+
+impl MqvMessage2 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(2, &[&self.ephemeral, &self.confirmation])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 2, 2)?;
+        Ok(MqvMessage2 {
+            ephemeral: fields.remove(0),
+            confirmation: fields.remove(0),
+        })
+    }
+}
+
+impl MqvMessage3 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(3, &[&self.confirmation])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 3, 1)?;
+        Ok(MqvMessage3 {
+            confirmation: fields.remove(0),
+        })
+    }
+}
+
+/// MQV and HMQV: Diffie-Hellman authenticated implicitly by static key pairs.
+///
+/// Each party combines its ephemeral and static exponents into s = x + d * a mod q and raises
+/// the peer's combined key Y * B^e to s. Only the holders of the two static private keys can
+/// compute the result, so no signatures are needed; the MACs of messages 2 and 3 confirm that
+/// both sides derived the same key.
+///
+/// Both parties know each other's static public key in advance, and it serves as the identity.
+pub struct Mqv<G: Group> {
+    pub dh: DiffieHellman<G>, // Group for static and ephemeral keys
+    pub variant: MqvVariant,  // MQV or HMQV
+    pub static_key: PrivateKey<G::Element>, // Our static key pair
+    pub peer_static: PublicKey<G::Element>, // The peer's static public key
+}
+
+/// Initiator state after sending message 1, waiting for message 2.
+pub struct MqvInitiator<'a, G: Group> {
+    mqv: &'a Mqv<G>,
+    ephemeral: PrivateKey<G::Element>,
+    transcript: Transcript,
+}
+
+/// Responder state after sending message 2, waiting for message 3.
+pub struct MqvResponder<'a, G: Group> {
+    mqv: &'a Mqv<G>,
+    keys: KeySchedule,
+    transcript: Transcript,
+}
+
+impl<G> Mqv<G>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+{
+    /// Starts a run as the initiator.
+    pub fn initiate(&self) -> Result<(MqvInitiator<'_, G>, MqvMessage1), AkeError> {
+        let mut transcript = self.start_transcript(true)?;
+        let ephemeral = self.dh.generate_private_key();
+        let x = encode_public_key(&self.dh, ephemeral.public_key());
+        transcript.append("X", &x);
+        let state = MqvInitiator {
+            mqv: self,
+            ephemeral,
+            transcript,
+        };
+        Ok((state, MqvMessage1 { ephemeral: x }))
+    }
+
+    /// Answers message 1 as the responder.
+    pub fn respond(
+        &self,
+        message: &MqvMessage1,
+    ) -> Result<(MqvResponder<'_, G>, MqvMessage2), AkeError> {
+        let mut transcript = self.start_transcript(false)?;
+        let peer_ephemeral = decode_public_key(&self.dh, &message.ephemeral)?;
+        let ephemeral = self.dh.generate_private_key();
+        let y = encode_public_key(&self.dh, ephemeral.public_key());
+        transcript.append("X", &message.ephemeral);
+        transcript.append("Y", &y);
+
+        let z = self.shared_secret(&ephemeral, &peer_ephemeral)?;
+        let keys = KeySchedule::derive(self.variant.name(), &z, &transcript)?;
+        let confirmation = keys.tag("responder", &transcript.hash());
+        transcript.append("responder", &confirmation);
+
+        let state = MqvResponder {
+            mqv: self,
+            keys,
+            transcript,
+        };
+        Ok((
+            state,
+            MqvMessage2 {
+                ephemeral: y,
+                confirmation,
+            },
+        ))
+    }
+
+    /// Runs the whole protocol as the initiator over `transport`.
+    pub fn run_initiator(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let (state, message1) = self.initiate()?;
+        transport.send(&message1.to_bytes())?;
+        let message2 = MqvMessage2::from_bytes(&transport.receive()?)?;
+        let (session, message3) = state.finish(&message2)?;
+        transport.send(&message3.to_bytes())?;
+        Ok(session)
+    }
+
+    /// Runs the whole protocol as the responder over `transport`.
+    pub fn run_responder(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let message1 = MqvMessage1::from_bytes(&transport.receive()?)?;
+        let (state, message2) = self.respond(&message1)?;
+        transport.send(&message2.to_bytes())?;
+        let message3 = MqvMessage3::from_bytes(&transport.receive()?)?;
+        state.finish(&message3)
+    }
+
+    /// Validates the peer's static key and starts a transcript with both identities, the
+    /// initiator's first.
+    fn start_transcript(&self, initiator: bool) -> Result<Transcript, AkeError> {
+        self.dh.validate_public_key(&self.peer_static)?;
+        let ours = encode_public_key(&self.dh, &self.static_key.public_key);
+        let theirs = encode_public_key(&self.dh, &self.peer_static);
+        let (a, b) = if initiator {
+            (ours, theirs)
+        } else {
+            (theirs, ours)
+        };
+        let mut transcript = Transcript::new(self.variant.name());
+        transcript.append("A", &a);
+        transcript.append("B", &b);
+        Ok(transcript)
+    }
+
+    /// Computes the encoded shared secret (Y * B^e)^(x + d * a) from our side, where d is the
+    /// coefficient of our ephemeral key and e that of the peer's.
+    fn shared_secret(
+        &self,
+        ephemeral: &PrivateKey<G::Element>,
+        peer_ephemeral: &PublicKey<G::Element>,
+    ) -> Result<Vec<u8>, AkeError> {
+        let group = &self.dh.group;
+        let ours = encode_public_key(&self.dh, &self.static_key.public_key);
+        let theirs = encode_public_key(&self.dh, &self.peer_static);
+        // In HMQV, d hashes X with the responder's identity and e hashes Y with the
+        // initiator's, so each ephemeral key is hashed with the identity of the other party.
+        let d = self.coefficient(ephemeral.public_key(), &theirs);
+        let e = self.coefficient(peer_ephemeral, &ours);
+
+        let s = (ephemeral.exponent() + d * self.static_key.exponent()) % &self.dh.q;
+        let base = group.combine(
+            &peer_ephemeral.element,
+            &group.pow(&self.peer_static.element, &e),
+        );
+        let shared = group.pow(&base, &s);
+        if self.dh.is_identity(&shared) {
+            return Err(DiffieHellmanError::TrivialSharedSecret.into());
+        }
+        Ok(group.encode_element(&shared))
+    }
+
+    /// Computes the coefficient of an ephemeral key, an integer of h = ceil(|q| / 2) bits.
+    ///
+    /// MQV takes the associate value 2^h + (X mod 2^h) of the encoded key; HMQV hashes the
+    /// encoded key with `identity` and keeps h bits of the output.
+    fn coefficient(&self, ephemeral: &PublicKey<G::Element>, identity: &[u8]) -> BigUint {
+        let h = self.dh.q.bits().div_ceil(2);
+        let encoded = encode_public_key(&self.dh, ephemeral);
+        let mask = (BigUint::one() << h) - 1u32;
+        match self.variant {
+            MqvVariant::Mqv => (BigUint::from_bytes_be(&encoded) & mask) | (BigUint::one() << h),
+            MqvVariant::Hmqv => {
+                let mut input = encoded;
+                input.extend_from_slice(identity);
+                let digest = one_step_kdf::<Sha256>(&input, b"HMQV", h.div_ceil(8) as usize)
+                    .expect("h bits are far below the one-step KDF limit");
+                BigUint::from_bytes_be(&digest) & mask
+            }
+        }
+    }
+}
+
+impl<G> MqvInitiator<'_, G>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+{
+    /// Processes message 2: derives the key, checks the responder's confirmation tag, and
+    /// returns the session together with message 3.
+    pub fn finish(
+        mut self,
+        message: &MqvMessage2,
+    ) -> Result<(EstablishedSession, MqvMessage3), AkeError> {
+        let mqv = self.mqv;
+        let peer_ephemeral = decode_public_key(&mqv.dh, &message.ephemeral)?;
+        self.transcript.append("Y", &message.ephemeral);
+        let z = mqv.shared_secret(&self.ephemeral, &peer_ephemeral)?;
+        let keys = KeySchedule::derive(mqv.variant.name(), &z, &self.transcript)?;
+
+        if !keys.verify_tag("responder", &self.transcript.hash(), &message.confirmation) {
+            return Err(AkeError::KeyConfirmationFailed);
+        }
+        self.transcript.append("responder", &message.confirmation);
+        let confirmation = keys.tag("initiator", &self.transcript.hash());
+        self.transcript.append("initiator", &confirmation);
+
+        let session = EstablishedSession {
+            key: keys.session,
+            peer_identity: encode_public_key(&mqv.dh, &mqv.peer_static),
+            transcript_hash: self.transcript.hash(),
+        };
+        Ok((session, MqvMessage3 { confirmation }))
+    }
+}
+
+impl<G> MqvResponder<'_, G>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+{
+    /// Processes message 3: checks the initiator's confirmation tag.
+    pub fn finish(mut self, message: &MqvMessage3) -> Result<EstablishedSession, AkeError> {
+        if !self
+            .keys
+            .verify_tag("initiator", &self.transcript.hash(), &message.confirmation)
+        {
+            return Err(AkeError::KeyConfirmationFailed);
+        }
+        self.transcript.append("initiator", &message.confirmation);
+        Ok(EstablishedSession {
+            key: self.keys.session,
+            peer_identity: encode_public_key(&self.mqv.dh, &self.mqv.peer_static),
+            transcript_hash: self.transcript.hash(),
+        })
+    }
+}
@@ -0,0 +1,69 @@
+// src/ake/seal.rs
+
+use super::{constant_time_eq, AkeError, KEY_LENGTH};
+use crate::hash::{Hmac, Sha256};
+
+/// Encrypt-then-MAC for handshake payloads, built from HMAC-SHA256 alone: the keystream is
+/// HMAC(K_enc, label || counter) and the tag is HMAC(K_mac, label || ciphertext).
+///
+/// Each sealing key protects at most one payload per label, so no nonce is needed. This only
+/// hides identities and signatures inside the handshake; it is not a general-purpose cipher.
+pub(super) struct SealingKey<'a> {
+    encryption: &'a [u8],
+    authentication: &'a [u8],
+}
+
+impl<'a> SealingKey<'a> {
+    /// Splits 64 bytes of key material into an encryption and an authentication key.
+    pub(super) fn new(key: &'a [u8]) -> Self {
+        let (encryption, authentication) = key.split_at(KEY_LENGTH);
+        SealingKey {
+            encryption,
+            authentication,
+        }
+    }
+
+    /// Encrypts and authenticates `plaintext` under `label`.
+    pub(super) fn seal(&self, label: &str, plaintext: &[u8]) -> Vec<u8> {
+        let mut sealed = self.apply_keystream(label, plaintext);
+        let tag = self.tag(label, &sealed);
+        sealed.extend(tag);
+        sealed
+    }
+
+    /// Checks the tag and decrypts a payload produced by `seal` with the same label.
+    pub(super) fn open(&self, label: &str, sealed: &[u8]) -> Result<Vec<u8>, AkeError> {
+        if sealed.len() < KEY_LENGTH {
+            return Err(AkeError::DecryptionFailed);
+        }
+        let (ciphertext, tag) = sealed.split_at(sealed.len() - KEY_LENGTH);
+        if !constant_time_eq(&self.tag(label, ciphertext), tag) {
+            return Err(AkeError::DecryptionFailed);
+        }
+        Ok(self.apply_keystream(label, ciphertext))
+    }
+
+    fn apply_keystream(&self, label: &str, data: &[u8]) -> Vec<u8> {
+        data.chunks(KEY_LENGTH)
+            .enumerate()
+            .flat_map(|(counter, chunk)| {
+                let mut hmac = Hmac::<Sha256>::new(self.encryption);
+                hmac.update(label.as_bytes());
+                hmac.update(&(counter as u64).to_be_bytes());
+                let block = hmac.finalize();
+                chunk
+                    .iter()
+                    .zip(block)
+                    .map(|(byte, key)| byte ^ key)
+                    .collect::<Vec<u8>>()
+            })
+            .collect()
+    }
+
+    fn tag(&self, label: &str, ciphertext: &[u8]) -> Vec<u8> {
+        let mut hmac = Hmac::<Sha256>::new(self.authentication);
+        hmac.update(label.as_bytes());
+        hmac.update(ciphertext);
+        hmac.finalize()
+    }
+}
@@ -0,0 +1,283 @@
+// src/ake/sigma.rs
+
+use super::seal::SealingKey;
+use super::{
+    decode_public_key, encode_public_key, signed_message, wire, AkeError, EstablishedSession,
+    KeySchedule, Transcript, Transport,
+};
+use crate::algebra::traits::{ElementEncoding, Group, Membership};
+use crate::diffie_hellman::{DiffieHellman, PrivateKey};
+use crate::signature::{SigningKey, VerifyingKey};
+
+const PROTOCOL: &str = "SIGMA-I";
+
+/// First SIGMA-I message, initiator to responder: the ephemeral public key g^x.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigmaMessage1 {
+    pub ephemeral: Vec<u8>,
+}
+
+/// Second SIGMA-I message, responder to initiator: g^y and the encrypted identity, signature
+/// and MAC of the responder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigmaMessage2 {
+    pub ephemeral: Vec<u8>,
+    pub sealed_payload: Vec<u8>,
+}
+
+/// Third SIGMA-I message, initiator to responder: the initiator's encrypted identity,
+/// signature and MAC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigmaMessage3 {
+    pub sealed_payload: Vec<u8>,
+}
+
+impl SigmaMessage1 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(1, &[&self.ephemeral])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 1, 1)?;
+        Ok(SigmaMessage1 {
+            ephemeral: fields.remove(0),
+        })
+    }
+}
+
+impl SigmaMessage2 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(2, &[&self.ephemeral, &self.sealed_payload])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 2, 2)?;
+        Ok(SigmaMessage2 {
+            ephemeral: fields.remove(0),
+            sealed_payload: fields.remove(0),
+        })
+    }
+}
+
+impl SigmaMessage3 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(3, &[&self.sealed_payload])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 3, 1)?;
+        Ok(SigmaMessage3 {
+            sealed_payload: fields.remove(0),
+        })
+    }
+}
+
+/// SIGMA-I (Krawczyk, 2003), the identity-protecting variant of "SIGn-and-MAc" that underlies
+/// IKEv2 and TLS 1.3: each party sends its identity, a signature on the transcript and a MAC
+/// over its identity, all encrypted under the new key.
+///
+/// The signature proves possession of the long-term key and the MAC binds the identity to
+/// the session key. Identities are not known in advance: the peer's identity is looked up in
+/// `trusted_peers`, and the responder reveals its identity only to a party that knows the key.
+pub struct Sigma<G: Group, S: SigningKey> {
+    pub dh: DiffieHellman<G>,                // Group for the ephemeral keys
+    pub signing_key: S,                      // Our long-term signing key
+    pub trusted_peers: Vec<S::VerifyingKey>, // Keys we accept as the peer's identity
+}
+
+/// Initiator state after sending message 1, waiting for message 2.
+pub struct SigmaInitiator<'a, G: Group, S: SigningKey> {
+    sigma: &'a Sigma<G, S>,
+    ephemeral: PrivateKey<G::Element>,
+    transcript: Transcript,
+}
+
+/// Responder state after sending message 2, waiting for message 3.
+pub struct SigmaResponder<'a, G: Group, S: SigningKey> {
+    sigma: &'a Sigma<G, S>,
+    keys: KeySchedule,
+    transcript: Transcript,
+}
+
+impl<G, S> Sigma<G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Starts a run as the initiator.
+    pub fn initiate(&self) -> (SigmaInitiator<'_, G, S>, SigmaMessage1) {
+        let ephemeral = self.dh.generate_private_key();
+        let x = encode_public_key(&self.dh, ephemeral.public_key());
+        let mut transcript = Transcript::new(PROTOCOL);
+        transcript.append("X", &x);
+        let state = SigmaInitiator {
+            sigma: self,
+            ephemeral,
+            transcript,
+        };
+        (state, SigmaMessage1 { ephemeral: x })
+    }
+
+    /// Answers message 1 as the responder.
+    pub fn respond(
+        &self,
+        message: &SigmaMessage1,
+    ) -> Result<(SigmaResponder<'_, G, S>, SigmaMessage2), AkeError> {
+        let peer_ephemeral = decode_public_key(&self.dh, &message.ephemeral)?;
+        let ephemeral = self.dh.generate_private_key();
+        let y = encode_public_key(&self.dh, ephemeral.public_key());
+
+        let mut transcript = Transcript::new(PROTOCOL);
+        transcript.append("X", &message.ephemeral);
+        transcript.append("Y", &y);
+        let z = self
+            .dh
+            .compute_shared_secret_bytes(&peer_ephemeral, &ephemeral)?;
+        let keys = KeySchedule::derive(PROTOCOL, &z, &transcript)?;
+        let sealed_payload = self.seal_payload(&keys, "responder", &mut transcript);
+
+        let state = SigmaResponder {
+            sigma: self,
+            keys,
+            transcript,
+        };
+        Ok((
+            state,
+            SigmaMessage2 {
+                ephemeral: y,
+                sealed_payload,
+            },
+        ))
+    }
+
+    /// Runs the whole protocol as the initiator over `transport`.
+    pub fn run_initiator(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let (state, message1) = self.initiate();
+        transport.send(&message1.to_bytes())?;
+        let message2 = SigmaMessage2::from_bytes(&transport.receive()?)?;
+        let (session, message3) = state.finish(&message2)?;
+        transport.send(&message3.to_bytes())?;
+        Ok(session)
+    }
+
+    /// Runs the whole protocol as the responder over `transport`.
+    pub fn run_responder(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let message1 = SigmaMessage1::from_bytes(&transport.receive()?)?;
+        let (state, message2) = self.respond(&message1)?;
+        transport.send(&message2.to_bytes())?;
+        let message3 = SigmaMessage3::from_bytes(&transport.receive()?)?;
+        state.finish(&message3)
+    }
+
+    /// Builds and encrypts our identity, signature and MAC for `role`, and appends them to the
+    /// transcript.
+    fn seal_payload(&self, keys: &KeySchedule, role: &str, transcript: &mut Transcript) -> Vec<u8> {
+        let identity = self.signing_key.verifying_key().to_bytes();
+        transcript.append(&format!("{} identity", role), &identity);
+        let hash = transcript.hash();
+        let signature = self
+            .signing_key
+            .sign(&signed_message(PROTOCOL, role, &hash));
+        let mac = keys.tag(role, &hash);
+        let payload = wire::encode_fields(&[&identity, &signature, &mac]);
+        let sealed = SealingKey::new(&keys.sealing).seal(role, &payload);
+        transcript.append(role, &sealed);
+        sealed
+    }
+
+    /// Decrypts the peer's payload, looks up its identity and checks its signature and MAC.
+    /// On success the payload is appended to the transcript and the identity is returned.
+    fn open_payload(
+        &self,
+        keys: &KeySchedule,
+        role: &str,
+        sealed: &[u8],
+        transcript: &mut Transcript,
+    ) -> Result<Vec<u8>, AkeError> {
+        let payload = SealingKey::new(&keys.sealing).open(role, sealed)?;
+        let mut fields = wire::decode_exact(&payload, 3)?;
+        let (identity, signature, mac) = (fields.remove(0), fields.remove(0), fields.remove(0));
+        let peer_key = self
+            .trusted_peers
+            .iter()
+            .find(|key| key.to_bytes() == identity)
+            .ok_or(AkeError::UnknownPeer)?;
+
+        transcript.append(&format!("{} identity", role), &identity);
+        let hash = transcript.hash();
+        if !peer_key.verify(&signed_message(PROTOCOL, role, &hash), &signature) {
+            return Err(AkeError::InvalidSignature);
+        }
+        if !keys.verify_tag(role, &hash, &mac) {
+            return Err(AkeError::KeyConfirmationFailed);
+        }
+        transcript.append(role, sealed);
+        Ok(identity)
+    }
+}
+
+impl<G, S> SigmaInitiator<'_, G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Processes message 2: derives the key, authenticates the responder, and returns the
+    /// session together with message 3.
+    pub fn finish(
+        mut self,
+        message: &SigmaMessage2,
+    ) -> Result<(EstablishedSession, SigmaMessage3), AkeError> {
+        let sigma = self.sigma;
+        let peer_ephemeral = decode_public_key(&sigma.dh, &message.ephemeral)?;
+        self.transcript.append("Y", &message.ephemeral);
+        let z = sigma
+            .dh
+            .compute_shared_secret_bytes(&peer_ephemeral, &self.ephemeral)?;
+        let keys = KeySchedule::derive(PROTOCOL, &z, &self.transcript)?;
+
+        let peer_identity = sigma.open_payload(
+            &keys,
+            "responder",
+            &message.sealed_payload,
+            &mut self.transcript,
+        )?;
+        let sealed_payload = sigma.seal_payload(&keys, "initiator", &mut self.transcript);
+
+        let session = EstablishedSession {
+            key: keys.session,
+            peer_identity,
+            transcript_hash: self.transcript.hash(),
+        };
+        Ok((session, SigmaMessage3 { sealed_payload }))
+    }
+}
+
+impl<G, S> SigmaResponder<'_, G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Processes message 3: authenticates the initiator.
+    pub fn finish(mut self, message: &SigmaMessage3) -> Result<EstablishedSession, AkeError> {
+        let peer_identity = self.sigma.open_payload(
+            &self.keys,
+            "initiator",
+            &message.sealed_payload,
+            &mut self.transcript,
+        )?;
+        Ok(EstablishedSession {
+            key: self.keys.session,
+            peer_identity,
+            transcript_hash: self.transcript.hash(),
+        })
+    }
+}
@@ -0,0 +1,251 @@
+// src/ake/sts.rs
+
+use super::seal::SealingKey;
+use super::{
+    decode_public_key, encode_public_key, signed_message, wire, AkeError, EstablishedSession,
+    KeySchedule, Transcript, Transport,
+};
+use crate::algebra::traits::{ElementEncoding, Group, Membership};
+use crate::diffie_hellman::{DiffieHellman, PrivateKey, PublicKey};
+use crate::signature::{SigningKey, VerifyingKey};
+
+const PROTOCOL: &str = "STS";
+
+/// First STS message, initiator to responder: the ephemeral public key g^x.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StsMessage1 {
+    pub ephemeral: Vec<u8>,
+}
+
+/// Second STS message, responder to initiator: g^y and the responder's signature on the
+/// transcript, encrypted under the new key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StsMessage2 {
+    pub ephemeral: Vec<u8>,
+    pub sealed_signature: Vec<u8>,
+}
+
+/// Third STS message, initiator to responder: the initiator's encrypted signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StsMessage3 {
+    pub sealed_signature: Vec<u8>,
+}
+
+impl StsMessage1 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(1, &[&self.ephemeral])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 1, 1)?;
+        Ok(StsMessage1 {
+            ephemeral: fields.remove(0),
+        })
+    }
+}
+
+impl StsMessage2 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(2, &[&self.ephemeral, &self.sealed_signature])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 2, 2)?;
+        Ok(StsMessage2 {
+            ephemeral: fields.remove(0),
+            sealed_signature: fields.remove(0),
+        })
+    }
+}
+
+impl StsMessage3 {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        wire::encode(3, &[&self.sealed_signature])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AkeError> {
+        let mut fields = wire::decode(bytes, 3, 1)?;
+        Ok(StsMessage3 {
+            sealed_signature: fields.remove(0),
+        })
+    }
+}
+
+/// The Station-to-Station protocol (Diffie, van Oorschot and Wiener): ephemeral
+/// Diffie-Hellman where each party signs the transcript and encrypts the signature under the
+/// new key. Decrypting and verifying the peer's signature authenticates the peer and confirms
+/// that it holds the same key.
+///
+/// Both parties know each other's long-term verifying key in advance.
+pub struct Sts<G: Group, S: SigningKey> {
+    pub dh: DiffieHellman<G>,      // Group for the ephemeral keys
+    pub signing_key: S,            // Our long-term signing key
+    pub peer_key: S::VerifyingKey, // The peer's long-term verifying key
+}
+
+/// Initiator state after sending message 1, waiting for message 2.
+pub struct StsInitiator<'a, G: Group, S: SigningKey> {
+    sts: &'a Sts<G, S>,
+    ephemeral: PrivateKey<G::Element>,
+    transcript: Transcript,
+}
+
+/// Responder state after sending message 2, waiting for message 3.
+pub struct StsResponder<'a, G: Group, S: SigningKey> {
+    sts: &'a Sts<G, S>,
+    keys: KeySchedule,
+    transcript: Transcript,
+}
+
+impl<G, S> Sts<G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Starts a run as the initiator.
+    pub fn initiate(&self) -> (StsInitiator<'_, G, S>, StsMessage1) {
+        let ephemeral = self.dh.generate_private_key();
+        let x = encode_public_key(&self.dh, ephemeral.public_key());
+        let mut transcript = Transcript::new(PROTOCOL);
+        transcript.append("X", &x);
+        let state = StsInitiator {
+            sts: self,
+            ephemeral,
+            transcript,
+        };
+        (state, StsMessage1 { ephemeral: x })
+    }
+
+    /// Answers message 1 as the responder.
+    pub fn respond(
+        &self,
+        message: &StsMessage1,
+    ) -> Result<(StsResponder<'_, G, S>, StsMessage2), AkeError> {
+        let peer_ephemeral = decode_public_key(&self.dh, &message.ephemeral)?;
+        let ephemeral = self.dh.generate_private_key();
+        let y = encode_public_key(&self.dh, ephemeral.public_key());
+
+        let mut transcript = Transcript::new(PROTOCOL);
+        transcript.append("X", &message.ephemeral);
+        transcript.append("Y", &y);
+        let z = self
+            .dh
+            .compute_shared_secret_bytes(&peer_ephemeral, &ephemeral)?;
+        let keys = KeySchedule::derive(PROTOCOL, &z, &transcript)?;
+
+        let signature =
+            self.signing_key
+                .sign(&signed_message(PROTOCOL, "responder", &transcript.hash()));
+        let sealed_signature = SealingKey::new(&keys.sealing).seal("responder", &signature);
+        transcript.append("responder", &sealed_signature);
+
+        let state = StsResponder {
+            sts: self,
+            keys,
+            transcript,
+        };
+        Ok((
+            state,
+            StsMessage2 {
+                ephemeral: y,
+                sealed_signature,
+            },
+        ))
+    }
+
+    /// Runs the whole protocol as the initiator over `transport`.
+    pub fn run_initiator(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let (state, message1) = self.initiate();
+        transport.send(&message1.to_bytes())?;
+        let message2 = StsMessage2::from_bytes(&transport.receive()?)?;
+        let (session, message3) = state.finish(&message2)?;
+        transport.send(&message3.to_bytes())?;
+        Ok(session)
+    }
+
+    /// Runs the whole protocol as the responder over `transport`.
+    pub fn run_responder(
+        &self,
+        transport: &mut impl Transport,
+    ) -> Result<EstablishedSession, AkeError> {
+        let message1 = StsMessage1::from_bytes(&transport.receive()?)?;
+        let (state, message2) = self.respond(&message1)?;
+        transport.send(&message2.to_bytes())?;
+        let message3 = StsMessage3::from_bytes(&transport.receive()?)?;
+        state.finish(&message3)
+    }
+}
+
+impl<G, S> StsInitiator<'_, G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Processes message 2: derives the key, decrypts and verifies the responder's signature,
+    /// and returns the session together with message 3.
+    pub fn finish(
+        mut self,
+        message: &StsMessage2,
+    ) -> Result<(EstablishedSession, StsMessage3), AkeError> {
+        let sts = self.sts;
+        let peer_ephemeral: PublicKey<G::Element> = decode_public_key(&sts.dh, &message.ephemeral)?;
+        self.transcript.append("Y", &message.ephemeral);
+        let z = sts
+            .dh
+            .compute_shared_secret_bytes(&peer_ephemeral, &self.ephemeral)?;
+        let keys = KeySchedule::derive(PROTOCOL, &z, &self.transcript)?;
+        let sealing = SealingKey::new(&keys.sealing);
+
+        let signature = sealing.open("responder", &message.sealed_signature)?;
+        let expected = signed_message(PROTOCOL, "responder", &self.transcript.hash());
+        if !sts.peer_key.verify(&expected, &signature) {
+            return Err(AkeError::InvalidSignature);
+        }
+        self.transcript
+            .append("responder", &message.sealed_signature);
+
+        let signature = sts.signing_key.sign(&signed_message(
+            PROTOCOL,
+            "initiator",
+            &self.transcript.hash(),
+        ));
+        let sealed_signature = sealing.seal("initiator", &signature);
+        self.transcript.append("initiator", &sealed_signature);
+
+        let session = EstablishedSession {
+            key: keys.session,
+            peer_identity: sts.peer_key.to_bytes(),
+            transcript_hash: self.transcript.hash(),
+        };
+        Ok((session, StsMessage3 { sealed_signature }))
+    }
+}
+
+impl<G, S> StsResponder<'_, G, S>
+where
+    G: Group + ElementEncoding + Membership,
+    G::Element: Clone,
+    S: SigningKey,
+{
+    /// Processes message 3: decrypts and verifies the initiator's signature.
+    pub fn finish(mut self, message: &StsMessage3) -> Result<EstablishedSession, AkeError> {
+        let sealing = SealingKey::new(&self.keys.sealing);
+        let signature = sealing.open("initiator", &message.sealed_signature)?;
+        let expected = signed_message(PROTOCOL, "initiator", &self.transcript.hash());
+        if !self.sts.peer_key.verify(&expected, &signature) {
+            return Err(AkeError::InvalidSignature);
+        }
+        self.transcript
+            .append("initiator", &message.sealed_signature);
+        Ok(EstablishedSession {
+            key: self.keys.session,
+            peer_identity: self.sts.peer_key.to_bytes(),
+            transcript_hash: self.transcript.hash(),
+        })
+    }
+}
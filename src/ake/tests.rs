@@ -0,0 +1,371 @@
+// src/ake/tests.rs
+
+// Runs both parties of each protocol on separate threads over a `MemoryTransport` pair, and
+// over a `StreamTransport` on in-memory pipes as the CLI pipe mode does.
+
+use super::transport::MAX_FRAME_LENGTH;
+use super::{
+    AkeError, EstablishedSession, MemoryTransport, Mqv, MqvVariant, Sigma, StreamTransport, Sts,
+    Transport,
+};
+use crate::diffie_hellman::{DiffieHellman, NamedGroup};
+use crate::eddsa::Ed25519SigningKey;
+use crate::integer_mod_p::IntegerModP;
+use crate::signature::{SigningKey, VerifyingKey};
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+
+type Outcome = Result<EstablishedSession, AkeError>;
+
+/// A transport that flips the last byte of the `index`-th message it sends (counting from 0).
+struct TamperingTransport {
+    inner: MemoryTransport,
+    index: usize,
+    sent: usize,
+}
+
+impl TamperingTransport {
+    fn new(inner: MemoryTransport, index: usize) -> Self {
+        TamperingTransport {
+            inner,
+            index,
+            sent: 0,
+        }
+    }
+}
+
+impl Transport for TamperingTransport {
+    fn send(&mut self, message: &[u8]) -> Result<(), AkeError> {
+        let mut message = message.to_vec();
+        if self.sent == self.index {
+            *message.last_mut().expect("messages are not empty") ^= 0x01;
+        }
+        self.sent += 1;
+        self.inner.send(&message)
+    }
+
+    fn receive(&mut self) -> Result<Vec<u8>, AkeError> {
+        self.inner.receive()
+    }
+}
+
+/// The write end of an in-memory pipe; every write is passed on as one chunk.
+struct PipeWriter(Sender<Vec<u8>>);
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The read end of an in-memory pipe. Reads block until data arrives and return 0 once the
+/// writer is gone.
+struct PipeReader {
+    chunks: Receiver<Vec<u8>>,
+    buffer: VecDeque<u8>,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.buffer.is_empty() {
+            match self.chunks.recv() {
+                Ok(chunk) => self.buffer.extend(chunk),
+                Err(_) => return Ok(0),
+            }
+        }
+        let count = buf.len().min(self.buffer.len());
+        for (slot, byte) in buf.iter_mut().zip(self.buffer.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+}
+
+fn pipe() -> (PipeReader, PipeWriter) {
+    let (sender, receiver) = channel();
+    let reader = PipeReader {
+        chunks: receiver,
+        buffer: VecDeque::new(),
+    };
+    (reader, PipeWriter(sender))
+}
+
+type PipeTransport = StreamTransport<PipeReader, PipeWriter>;
+
+/// Two stream transports joined by a pipe in each direction.
+fn stream_pair() -> (PipeTransport, PipeTransport) {
+    let (a_reader, b_writer) = pipe();
+    let (b_reader, a_writer) = pipe();
+    (
+        StreamTransport::new(a_reader, a_writer),
+        StreamTransport::new(b_reader, b_writer),
+    )
+}
+
+fn dh() -> DiffieHellman<IntegerModP> {
+    DiffieHellman::named(NamedGroup::Ffdhe2048)
+}
+
+/// Runs the initiator on this thread and the responder on another and returns both outcomes.
+/// Each closure owns its transport, so a party that aborts hangs up on the other.
+fn run<A, B>(initiator: A, responder: B) -> (Outcome, Outcome)
+where
+    A: FnOnce() -> Outcome,
+    B: FnOnce() -> Outcome + Send,
+{
+    thread::scope(|scope| {
+        let responder = scope.spawn(responder);
+        let initiator = initiator();
+        (initiator, responder.join().unwrap())
+    })
+}
+
+fn sts(
+    signing_key: &Ed25519SigningKey,
+    peer: &Ed25519SigningKey,
+) -> Sts<IntegerModP, Ed25519SigningKey> {
+    Sts {
+        dh: dh(),
+        signing_key: signing_key.clone(),
+        peer_key: peer.verifying_key(),
+    }
+}
+
+fn sigma(
+    signing_key: &Ed25519SigningKey,
+    peers: &[&Ed25519SigningKey],
+) -> Sigma<IntegerModP, Ed25519SigningKey> {
+    Sigma {
+        dh: dh(),
+        signing_key: signing_key.clone(),
+        trusted_peers: peers.iter().map(|peer| peer.verifying_key()).collect(),
+    }
+}
+
+fn mqv_pair(variant: MqvVariant) -> (Mqv<IntegerModP>, Mqv<IntegerModP>) {
+    let group = dh();
+    let alice = group.generate_private_key();
+    let bob = group.generate_private_key();
+    let a = Mqv {
+        dh: dh(),
+        variant,
+        static_key: alice.clone(),
+        peer_static: bob.public_key().clone(),
+    };
+    let b = Mqv {
+        dh: group,
+        variant,
+        static_key: bob,
+        peer_static: alice.public_key().clone(),
+    };
+    (a, b)
+}
+
+fn assert_agree(initiator: &Outcome, responder: &Outcome) {
+    let (initiator, responder) = (initiator.as_ref().unwrap(), responder.as_ref().unwrap());
+    assert_eq!(initiator.key, responder.key);
+    assert_eq!(initiator.transcript_hash, responder.transcript_hash);
+    assert_eq!(initiator.key.len(), 32);
+}
+
+#[test]
+fn sts_handshake_agrees_on_a_key() {
+    let (alice, bob) = (Ed25519SigningKey::generate(), Ed25519SigningKey::generate());
+    let (a, b) = (sts(&alice, &bob), sts(&bob, &alice));
+    let (ta, tb) = MemoryTransport::pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert_agree(&initiator, &responder);
+    assert_eq!(
+        initiator.unwrap().peer_identity,
+        bob.verifying_key().to_bytes()
+    );
+    assert_eq!(
+        responder.unwrap().peer_identity,
+        alice.verifying_key().to_bytes()
+    );
+}
+
+#[test]
+fn mqv_and_hmqv_handshakes_agree_on_a_key() {
+    for variant in [MqvVariant::Mqv, MqvVariant::Hmqv] {
+        let (a, b) = mqv_pair(variant);
+        let (ta, tb) = MemoryTransport::pair();
+        let (initiator, responder) = run(
+            || a.run_initiator(&mut { ta }),
+            || b.run_responder(&mut { tb }),
+        );
+        assert_agree(&initiator, &responder);
+    }
+}
+
+#[test]
+fn sigma_handshake_agrees_on_a_key() {
+    let (alice, bob, carol) = (
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+    );
+    let (a, b) = (sigma(&alice, &[&carol, &bob]), sigma(&bob, &[&alice]));
+    let (ta, tb) = MemoryTransport::pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert_agree(&initiator, &responder);
+    assert_eq!(
+        initiator.unwrap().peer_identity,
+        bob.verifying_key().to_bytes()
+    );
+    assert_eq!(
+        responder.unwrap().peer_identity,
+        alice.verifying_key().to_bytes()
+    );
+}
+
+#[test]
+fn sts_rejects_a_signature_by_another_key() {
+    let (alice, bob, mallory) = (
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+    );
+    let (a, m) = (sts(&alice, &bob), sts(&mallory, &alice));
+    let (ta, tm) = MemoryTransport::pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || m.run_responder(&mut { tm }),
+    );
+    assert!(matches!(initiator, Err(AkeError::InvalidSignature)));
+    assert!(matches!(responder, Err(AkeError::Transport(_))));
+}
+
+#[test]
+fn sts_rejects_a_tampered_signature() {
+    let (alice, bob) = (Ed25519SigningKey::generate(), Ed25519SigningKey::generate());
+    let (a, b) = (sts(&alice, &bob), sts(&bob, &alice));
+    let (ta, tb) = MemoryTransport::pair();
+    let tb = TamperingTransport::new(tb, 0);
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert!(matches!(initiator, Err(AkeError::DecryptionFailed)));
+    assert!(matches!(responder, Err(AkeError::Transport(_))));
+}
+
+#[test]
+fn mqv_rejects_a_tampered_mac() {
+    let (a, b) = mqv_pair(MqvVariant::Hmqv);
+    let (ta, tb) = MemoryTransport::pair();
+    let tb = TamperingTransport::new(tb, 0);
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert!(matches!(initiator, Err(AkeError::KeyConfirmationFailed)));
+    assert!(matches!(responder, Err(AkeError::Transport(_))));
+
+    let (a, b) = mqv_pair(MqvVariant::Mqv);
+    let (ta, tb) = MemoryTransport::pair();
+    let ta = TamperingTransport::new(ta, 1);
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert!(initiator.is_ok());
+    assert!(matches!(responder, Err(AkeError::KeyConfirmationFailed)));
+}
+
+#[test]
+fn sigma_rejects_tampered_payloads_and_unknown_peers() {
+    let (alice, bob, mallory) = (
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+        Ed25519SigningKey::generate(),
+    );
+    let (a, b) = (sigma(&alice, &[&bob]), sigma(&bob, &[&alice]));
+    let (ta, tb) = MemoryTransport::pair();
+    let ta = TamperingTransport::new(ta, 1);
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert!(initiator.is_ok());
+    assert!(matches!(responder, Err(AkeError::DecryptionFailed)));
+
+    let m = sigma(&mallory, &[&alice]);
+    let (ta, tm) = MemoryTransport::pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || m.run_responder(&mut { tm }),
+    );
+    assert!(matches!(initiator, Err(AkeError::UnknownPeer)));
+    assert!(matches!(responder, Err(AkeError::Transport(_))));
+}
+
+#[test]
+fn handshakes_run_over_stream_transports() {
+    let (alice, bob) = (Ed25519SigningKey::generate(), Ed25519SigningKey::generate());
+    let (a, b) = (sts(&alice, &bob), sts(&bob, &alice));
+    let (ta, tb) = stream_pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert_agree(&initiator, &responder);
+
+    let (a, b) = mqv_pair(MqvVariant::Hmqv);
+    let (ta, tb) = stream_pair();
+    let (initiator, responder) = run(
+        || a.run_initiator(&mut { ta }),
+        || b.run_responder(&mut { tb }),
+    );
+    assert_agree(&initiator, &responder);
+}
+
+#[test]
+fn stream_transport_frames_messages() {
+    let (mut a, mut b) = stream_pair();
+    for message in [&b""[..], b"x", &[0xab; 1000]] {
+        a.send(message).unwrap();
+        assert_eq!(b.receive().unwrap(), message);
+    }
+
+    // The length prefix is 4 bytes, big-endian.
+    let (reader, mut writer) = pipe();
+    let mut transport = StreamTransport::new(reader, io::sink());
+    writer.write_all(&[0, 0, 0, 3, 1, 2, 3]).unwrap();
+    assert_eq!(transport.receive().unwrap(), [1, 2, 3]);
+
+    // A stream that ends inside a frame is an I/O error.
+    writer.write_all(&[0, 0, 0, 5, 1, 2]).unwrap();
+    drop(writer);
+    assert!(matches!(transport.receive(), Err(AkeError::Io(_))));
+}
+
+#[test]
+fn stream_transport_rejects_oversized_frames() {
+    let (reader, mut writer) = pipe();
+    let mut transport = StreamTransport::new(reader, io::sink());
+    let too_long = (MAX_FRAME_LENGTH as u32 + 1).to_be_bytes();
+    writer.write_all(&too_long).unwrap();
+    assert!(matches!(transport.receive(), Err(AkeError::Transport(_))));
+
+    // A frame of exactly the maximum length is still accepted.
+    let mut frame = (MAX_FRAME_LENGTH as u32).to_be_bytes().to_vec();
+    frame.resize(4 + MAX_FRAME_LENGTH, 7);
+    writer.write_all(&frame).unwrap();
+    assert_eq!(transport.receive().unwrap().len(), MAX_FRAME_LENGTH);
+}
@@ -0,0 +1,37 @@
+// src/ake/transcript.rs
+
+use crate::hash::{HashFunction, Sha256};
+
+/// A running hash of everything exchanged in a protocol run.
+///
+/// Each entry is a label and a value, both length-prefixed, so that different sequences of
+/// entries never hash alike. Signatures, MACs and key derivation all bind to the transcript
+/// hash, which ties them to this particular run.
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    /// Starts a transcript for the protocol named `protocol`.
+    pub fn new(protocol: &str) -> Self {
+        let mut transcript = Transcript {
+            hasher: Sha256::new(),
+        };
+        transcript.append("protocol", protocol.as_bytes());
+        transcript
+    }
+
+    /// Appends a labelled value.
+    pub fn append(&mut self, label: &str, value: &[u8]) {
+        for part in [label.as_bytes(), value] {
+            self.hasher.update(&(part.len() as u64).to_be_bytes());
+            self.hasher.update(part);
+        }
+    }
+
+    /// Returns the hash of the entries so far; the transcript can keep growing.
+    pub fn hash(&self) -> Vec<u8> {
+        self.hasher.clone().finalize()
+    }
+}
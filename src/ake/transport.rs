@@ -0,0 +1,92 @@
+// src/ake/transport.rs
+
+use super::AkeError;
+use std::io::{Read, Write};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// Largest frame a stream transport accepts, to bound allocations on malformed input.
+pub(super) const MAX_FRAME_LENGTH: usize = 1 << 20;
+
+/// A bidirectional, message-oriented channel between two protocol parties.
+pub trait Transport {
+    /// Sends one message.
+    fn send(&mut self, message: &[u8]) -> Result<(), AkeError>;
+
+    /// Blocks until the next message arrives.
+    fn receive(&mut self) -> Result<Vec<u8>, AkeError>;
+}
+
+/// One end of an in-memory transport, for running both parties in one process.
+pub struct MemoryTransport {
+    outgoing: Sender<Vec<u8>>,
+    incoming: Receiver<Vec<u8>>,
+}
+
+impl MemoryTransport {
+    /// Creates two connected ends: whatever one sends, the other receives.
+    pub fn pair() -> (MemoryTransport, MemoryTransport) {
+        let (a_to_b, b_from_a) = channel();
+        let (b_to_a, a_from_b) = channel();
+        (
+            MemoryTransport {
+                outgoing: a_to_b,
+                incoming: a_from_b,
+            },
+            MemoryTransport {
+                outgoing: b_to_a,
+                incoming: b_from_a,
+            },
+        )
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&mut self, message: &[u8]) -> Result<(), AkeError> {
+        self.outgoing
+            .send(message.to_vec())
+            .map_err(|_| AkeError::Transport("the peer has hung up".to_string()))
+    }
+
+    fn receive(&mut self) -> Result<Vec<u8>, AkeError> {
+        self.incoming
+            .recv()
+            .map_err(|_| AkeError::Transport("the peer has hung up".to_string()))
+    }
+}
+
+/// A transport over a byte stream pair, such as stdin and stdout. Every message is framed
+/// with a 4-byte big-endian length.
+pub struct StreamTransport<R: Read, W: Write> {
+    reader: R,
+    writer: W,
+}
+
+impl<R: Read, W: Write> StreamTransport<R, W> {
+    /// Wraps a reader for incoming and a writer for outgoing messages.
+    pub fn new(reader: R, writer: W) -> Self {
+        StreamTransport { reader, writer }
+    }
+}
+
+impl<R: Read, W: Write> Transport for StreamTransport<R, W> {
+    fn send(&mut self, message: &[u8]) -> Result<(), AkeError> {
+        let length = u32::try_from(message.len())
+            .map_err(|_| AkeError::Transport("message too long".to_string()))?;
+        self.writer.write_all(&length.to_be_bytes())?;
+        self.writer.write_all(message)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn receive(&mut self) -> Result<Vec<u8>, AkeError> {
+        let mut length = [0u8; 4];
+        self.reader.read_exact(&mut length)?;
+        let length = u32::from_be_bytes(length) as usize;
+        if length > MAX_FRAME_LENGTH {
+            return Err(AkeError::Transport("frame too long".to_string()));
+        }
+        let mut message = vec![0u8; length];
+        self.reader.read_exact(&mut message)?;
+        Ok(message)
+    }
+}
@@ -0,0 +1,62 @@
+// src/ake/wire.rs
+
+use super::AkeError;
+
+/// Encodes a message as a one-byte message number followed by length-prefixed fields.
+pub(super) fn encode(number: u8, fields: &[&[u8]]) -> Vec<u8> {
+    let mut out = vec![number];
+    for field in fields {
+        out.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        out.extend_from_slice(field);
+    }
+    out
+}
+
+/// Decodes a message produced by `encode`, checking its number and field count.
+pub(super) fn decode(bytes: &[u8], number: u8, count: usize) -> Result<Vec<Vec<u8>>, AkeError> {
+    let (&found, mut rest) = bytes.split_first().ok_or(AkeError::MalformedMessage)?;
+    if found != number {
+        return Err(AkeError::UnexpectedMessage {
+            expected: number,
+            found,
+        });
+    }
+    let fields = decode_fields(&mut rest, count)?;
+    if !rest.is_empty() {
+        return Err(AkeError::MalformedMessage);
+    }
+    Ok(fields)
+}
+
+/// Encodes fields without a message number, for payloads nested inside sealed boxes.
+pub(super) fn encode_fields(fields: &[&[u8]]) -> Vec<u8> {
+    encode(0, fields)[1..].to_vec()
+}
+
+/// Decodes exactly `count` fields produced by `encode_fields`.
+pub(super) fn decode_exact(bytes: &[u8], count: usize) -> Result<Vec<Vec<u8>>, AkeError> {
+    let mut rest = bytes;
+    let fields = decode_fields(&mut rest, count)?;
+    if !rest.is_empty() {
+        return Err(AkeError::MalformedMessage);
+    }
+    Ok(fields)
+}
+
+fn decode_fields(rest: &mut &[u8], count: usize) -> Result<Vec<Vec<u8>>, AkeError> {
+    let mut fields = Vec::with_capacity(count);
+    for _ in 0..count {
+        if rest.len() < 4 {
+            return Err(AkeError::MalformedMessage);
+        }
+        let (length, tail) = rest.split_at(4);
+        let length = u32::from_be_bytes(length.try_into().expect("4 bytes")) as usize;
+        if tail.len() < length {
+            return Err(AkeError::MalformedMessage);
+        }
+        let (field, tail) = tail.split_at(length);
+        fields.push(field.to_vec());
+        *rest = tail;
+    }
+    Ok(fields)
+}
@@ -1,4 +1,5 @@
 use num_bigint::BigUint;
+use std::fmt;
 
 /// Base trait that defines the associated type `Element`.
 pub trait Algebra {
@@ -38,3 +39,89 @@ pub trait Group: Algebra {
 /// Trait representing a mathematical field.
 /// Combines both Ring and Group traits.
 pub trait Field: Ring + Group {}
+
+/// Trait for algebraic structures whose elements have a canonical byte encoding.
+///
+/// Equal elements must always encode to the same bytes, so encodings can be compared, hashed
+/// and fed into key derivation.
+pub trait ElementEncoding: Algebra {
+    /// Encodes an element as a fixed-length big-endian byte string.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8>;
+
+    /// Decodes the output of `encode_element`, returning `None` for malformed input.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element>;
+}
+
+/// Trait for algebraic structures that can check whether a value is one of their elements.
+pub trait Membership: Algebra {
+    /// Returns true if `a` is a well-formed element of the structure.
+    fn is_element(&self, a: &Self::Element) -> bool;
+}
+
+/// Trait for finite fields GF(q), the coefficient fields of `PolynomialModP`.
+///
+/// On top of the field operations it provides what polynomial arithmetic, Cantor's algorithm
+/// and point counting need but `Field` leaves out: subtraction, canonical representatives,
+/// comparison of elements, enumeration of small fields and roots of quadratics. Elements are
+/// compared by their canonical encodings by default, since a `Polynomial` may carry leading
+/// zero coefficients.
+pub trait FiniteField:
+    Field<Element: Clone + fmt::Debug> + ElementEncoding + Membership + Clone
+{
+    /// Returns the characteristic of the field.
+    fn characteristic(&self) -> BigUint;
+
+    /// Returns the number of elements q.
+    fn size(&self) -> BigUint;
+
+    /// Returns every element of the field, for brute-force computations on small fields.
+    fn elements(&self) -> Vec<Self::Element>;
+
+    /// Returns a uniformly random element.
+    fn random(&self) -> Self::Element;
+
+    /// Returns the canonical representative of a.
+    fn reduce(&self, a: &Self::Element) -> Self::Element;
+
+    /// Returns -a.
+    fn neg(&self, a: &Self::Element) -> Self::Element;
+
+    /// Returns a - b.
+    fn sub(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.add(a, &self.neg(b))
+    }
+
+    /// Returns true if a and b are the same element.
+    fn equal(&self, a: &Self::Element, b: &Self::Element) -> bool {
+        self.encode_element(a) == self.encode_element(b)
+    }
+
+    /// Returns true if a is zero.
+    fn is_zero(&self, a: &Self::Element) -> bool {
+        self.equal(a, &self.zero())
+    }
+
+    /// Multiplies two nonempty coefficient vectors, in ascending order of degree, as
+    /// polynomials. Fields with a faster method than the schoolbook product override this.
+    fn multiply_coefficients(
+        &self,
+        a: &[Self::Element],
+        b: &[Self::Element],
+    ) -> Vec<Self::Element> {
+        let mut coeffs = vec![self.zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                coeffs[i + j] = self.add(&coeffs[i + j], &self.mul(x, y));
+            }
+        }
+        coeffs
+    }
+
+    /// Solves y^2 + by = c. The other root is -b - y.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(y)` with y^2 + by = c.
+    /// * `None` if the quadratic has no root in the field.
+    fn solve_quadratic(&self, b: &Self::Element, c: &Self::Element) -> Option<Self::Element>;
+}
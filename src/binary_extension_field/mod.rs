@@ -1,6 +1,8 @@
 // src/finite_field/mod.rs
 
-use crate::algebra::traits::{Algebra, Field, Group, Ring};
+use crate::algebra::traits::{
+    Algebra, ElementEncoding, Field, FiniteField, Group, Membership, Ring,
+};
 pub use crate::binary_extension_field::errors::BinaryExtensionFieldError;
 use crate::polynomial::Polynomial;
 use num_bigint::BigUint;
@@ -65,6 +67,20 @@ impl BinaryExtensionField {
         }
     }
 
+    /// Creates a field from a modulus that is already known to be irreducible, such as the
+    /// trinomials and pentanomials of the NIST binary curves, skipping the check in `new`.
+    pub fn new_valid_modulus(modulus: Polynomial) -> Self {
+        BinaryExtensionField {
+            n: modulus.degree(),
+            modulus,
+        }
+    }
+
+    /// Returns the degree n of the extension, so that the field has 2^n elements.
+    pub fn degree(&self) -> usize {
+        self.n
+    }
+
     /// Returns a reference to the modulus polynomial.
     pub fn modulus(&self) -> &Polynomial {
         &self.modulus
@@ -99,6 +115,12 @@ impl BinaryExtensionField {
         self.modulo(&product)
     }
 
+    /// Squares a field element, which in characteristic 2 only spreads out the coefficients
+    /// before the reduction.
+    pub fn square(&self, a: &Polynomial) -> Polynomial {
+        self.modulo(&a.square())
+    }
+
     /// Finds the multiplicative inverse of a field element.
     ///
     /// # Arguments
@@ -112,6 +134,73 @@ impl BinaryExtensionField {
         a.inverse(&self.modulus)
     }
 
+    /// Returns the absolute trace Tr(a) = a + a^2 + a^4 + ... + a^(2^(n - 1)), which is 0 or 1.
+    pub fn trace(&self, a: &Polynomial) -> bool {
+        let mut term = self.modulo(a);
+        let mut sum = term.clone();
+        for _ in 1..self.n {
+            term = self.square(&term);
+            sum = sum.add(&term);
+        }
+        !sum.is_zero()
+    }
+
+    /// Returns the half-trace H(a) = a + a^4 + a^16 + ... + a^(2^(n - 1)) for odd n. When
+    /// Tr(a) = 0 it satisfies H(a)^2 + H(a) = a.
+    ///
+    /// # Panics
+    ///
+    /// If the degree n is even.
+    pub fn half_trace(&self, a: &Polynomial) -> Polynomial {
+        assert!(
+            self.n % 2 == 1,
+            "the half-trace needs an odd extension degree"
+        );
+        let mut term = self.modulo(a);
+        let mut sum = term.clone();
+        for _ in 0..(self.n - 1) / 2 {
+            term = self.square(&self.square(&term));
+            sum = sum.add(&term);
+        }
+        sum
+    }
+
+    /// Solves z^2 + z = c, the quadratic that decompressing a binary curve point reduces to.
+    ///
+    /// For odd n the solution is the half-trace of c. For even n the randomized method of
+    /// IEEE 1363-2000, A.4.7, is used. The other solution is z + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(z)` with z^2 + z = c.
+    /// * `None` if Tr(c) = 1, in which case there is no solution.
+    pub fn solve_quadratic(&self, c: &Polynomial) -> Option<Polynomial> {
+        let c = self.modulo(c);
+        if self.trace(&c) {
+            return None;
+        }
+        if self.n % 2 == 1 {
+            return Some(self.half_trace(&c));
+        }
+        if c.is_zero() {
+            return Some(c);
+        }
+        // After the loop z^2 + z = Tr(tau) c, so retry until tau has trace 1.
+        loop {
+            let tau = self.random_element();
+            let mut z = Polynomial::new(&[0]);
+            let mut w = c.clone();
+            for _ in 1..self.n {
+                let w_squared = self.square(&w);
+                z = self.square(&z).add(&self.multiply(&w_squared, &tau));
+                w = w_squared.add(&c);
+            }
+            if !self.square(&z).add(&z).is_zero() {
+                return Some(z);
+            }
+        }
+    }
+
     /// Performs modulo reduction with the field's modulus polynomial.
     fn modulo(&self, poly: &Polynomial) -> Polynomial {
         poly.modulo(&self.modulus)
@@ -252,3 +341,94 @@ impl Group for BinaryExtensionField {
 }
 
 impl Field for BinaryExtensionField {}
+
+impl Membership for BinaryExtensionField {
+    /// Elements are the polynomials of degree below n, possibly with leading zeros.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        a.bits.iter().rev().skip(self.n).all(|bit| !*bit)
+    }
+}
+
+impl ElementEncoding for BinaryExtensionField {
+    /// Encodes an element as ceil(n / 8) bytes, with the coefficient of x^i in bit i of the
+    /// big-endian integer. Leading zero coefficients do not change the encoding.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        let length = self.n.div_ceil(8);
+        let mut bytes = vec![0u8; length];
+        let degree = a.bits.len().saturating_sub(1);
+        for (i, bit) in a.bits.iter().enumerate() {
+            if *bit {
+                let power = degree - i;
+                bytes[length - 1 - power / 8] |= 1 << (power % 8);
+            }
+        }
+        bytes
+    }
+
+    /// Accepts exactly ceil(n / 8) bytes with no coefficient at x^n or above.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        if bytes.len() != self.n.div_ceil(8) {
+            return None;
+        }
+        let total = bytes.len() * 8;
+        let coeffs: Vec<u8> = (0..total)
+            .map(|i| (bytes[i / 8] >> (7 - i % 8)) & 1)
+            .collect();
+        if coeffs[..total - self.n].contains(&1) {
+            return None;
+        }
+        Some(Polynomial::new(&coeffs))
+    }
+}
+
+impl FiniteField for BinaryExtensionField {
+    fn characteristic(&self) -> BigUint {
+        BigUint::from(2u32)
+    }
+
+    fn size(&self) -> BigUint {
+        BigUint::one() << self.degree()
+    }
+
+    fn elements(&self) -> Vec<Polynomial> {
+        self.elements_up_to_degree(self.degree() - 1).collect()
+    }
+
+    fn random(&self) -> Polynomial {
+        self.random_element()
+    }
+
+    fn reduce(&self, a: &Polynomial) -> Polynomial {
+        self.modulo(a)
+    }
+
+    fn neg(&self, a: &Polynomial) -> Polynomial {
+        a.clone()
+    }
+
+    fn sub(&self, a: &Polynomial, b: &Polynomial) -> Polynomial {
+        BinaryExtensionField::add(self, a, b)
+    }
+
+    fn equal(&self, a: &Polynomial, b: &Polynomial) -> bool {
+        a.add(b).is_zero()
+    }
+
+    fn is_zero(&self, a: &Polynomial) -> bool {
+        a.is_zero()
+    }
+
+    /// For b = 0 the root is the square root c^(2^(n - 1)). Otherwise y = bz turns the
+    /// equation into z^2 + z = c / b^2.
+    fn solve_quadratic(&self, b: &Polynomial, c: &Polynomial) -> Option<Polynomial> {
+        if FiniteField::is_zero(self, b) {
+            return Some(self.mod_exp(c, &(BigUint::one() << (self.degree() - 1))));
+        }
+        let b_inverse = BinaryExtensionField::inverse(self, b)?;
+        let z = BinaryExtensionField::solve_quadratic(
+            self,
+            &self.multiply(c, &self.square(&b_inverse)),
+        )?;
+        Some(self.multiply(b, &z))
+    }
+}
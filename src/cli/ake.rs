@@ -0,0 +1,150 @@
+// src/cli/ake.rs
+
+use super::{CliError, Options};
+use crate::ake::{EstablishedSession, Mqv, MqvVariant, Sigma, StreamTransport, Sts};
+use crate::diffie_hellman::{DiffieHellman, NamedGroup, PublicKey};
+use crate::dsa::{DsaParameters, DsaPrivateKey, DsaPublicKey};
+use std::error::Error;
+use std::io;
+
+/// Group used when `--group` is not given.
+const DEFAULT_GROUP: NamedGroup = NamedGroup::Ffdhe2048;
+
+const USAGE: &str = "\
+Usage: cryptography_toolkit ake <protocol> --role initiator|responder --key X --peer Y
+                                [--peer Y ...] [--group NAME]
+       cryptography_toolkit ake keygen [--group NAME]
+
+Runs an authenticated key exchange with another process. Protocol messages are read from
+stdin and written to stdout; the outcome is reported on stderr.
+
+Long-term keys live in a named group (default ffdhe2048): X is a private exponent and Y the
+peer's public key g^x mod p, both decimal or 0x-prefixed hex. STS and SIGMA-I sign with DSA
+over the group; MQV and HMQV use the keys as static Diffie-Hellman keys. SIGMA-I accepts any
+of the listed peers; the other protocols take exactly one.
+
+Protocols:
+  sts      Station-to-Station
+  sigma    SIGMA-I
+  mqv      MQV
+  hmqv     HMQV
+
+Example, with a named pipe connecting the two parties:
+  mkfifo pipe
+  cryptography_toolkit ake sts --role responder --key XB --peer YA < pipe \\
+    | cryptography_toolkit ake sts --role initiator --key XA --peer YB > pipe";
+
+/// Runs an `ake` subcommand.
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let Some(name) = args.first() else {
+        println!("{}", USAGE);
+        return Ok(());
+    };
+    let options = Options::parse(&args[1..])?;
+    let group = match options.get("group") {
+        Some(name) => NamedGroup::from_name(name)
+            .ok_or_else(|| CliError::UnexpectedArgument(name.to_string()))?,
+        None => DEFAULT_GROUP,
+    };
+
+    match name.as_str() {
+        "keygen" => {
+            let key = DiffieHellman::named(group).generate_private_key();
+            println!("private = {:#x}", key.exponent());
+            println!("public = {:#x}", key.public_key().element);
+        }
+        "sts" | "sigma" => {
+            let parameters =
+                DsaParameters::new(group.prime(), group.subgroup_order(), group.generator())?;
+            let signing_key =
+                DsaPrivateKey::from_private_exponent(parameters.clone(), options.number("key")?)?;
+            let peers = options
+                .numbers("peer")?
+                .into_iter()
+                .map(|y| DsaPublicKey::new(parameters.clone(), y))
+                .collect::<Result<Vec<_>, _>>()?;
+            let dh = DiffieHellman::named(group);
+            let initiator = is_initiator(&options)?;
+            let mut transport = StreamTransport::new(io::stdin().lock(), io::stdout().lock());
+            let session = if name == "sts" {
+                let sts = Sts {
+                    dh,
+                    signing_key,
+                    peer_key: single_peer(peers)?,
+                };
+                if initiator {
+                    sts.run_initiator(&mut transport)?
+                } else {
+                    sts.run_responder(&mut transport)?
+                }
+            } else {
+                let sigma = Sigma {
+                    dh,
+                    signing_key,
+                    trusted_peers: peers,
+                };
+                if initiator {
+                    sigma.run_initiator(&mut transport)?
+                } else {
+                    sigma.run_responder(&mut transport)?
+                }
+            };
+            report(&session);
+        }
+        "mqv" | "hmqv" => {
+            let dh = DiffieHellman::named(group);
+            let static_key = dh.private_key_from_exponent(options.number("key")?)?;
+            let peer_static = PublicKey {
+                element: single_peer(options.numbers("peer")?)?,
+            };
+            let mqv = Mqv {
+                dh,
+                variant: match name.as_str() {
+                    "mqv" => MqvVariant::Mqv,
+                    _ => MqvVariant::Hmqv,
+                },
+                static_key,
+                peer_static,
+            };
+            let initiator = is_initiator(&options)?;
+            let mut transport = StreamTransport::new(io::stdin().lock(), io::stdout().lock());
+            let session = if initiator {
+                mqv.run_initiator(&mut transport)?
+            } else {
+                mqv.run_responder(&mut transport)?
+            };
+            report(&session);
+        }
+        "help" => println!("{}", USAGE),
+        other => return Err(CliError::UnknownCommand(other.to_string()).into()),
+    }
+    Ok(())
+}
+
+/// Reads `--role`, returning true for the initiator.
+fn is_initiator(options: &Options) -> Result<bool, CliError> {
+    match options.required("role")? {
+        "initiator" => Ok(true),
+        "responder" => Ok(false),
+        other => Err(CliError::UnexpectedArgument(other.to_string())),
+    }
+}
+
+/// Returns the only `--peer` value, for protocols that know their peer in advance.
+fn single_peer<T>(mut peers: Vec<T>) -> Result<T, CliError> {
+    match peers.len() {
+        0 => Err(CliError::MissingOption("peer".to_string())),
+        1 => Ok(peers.remove(0)),
+        _ => Err(CliError::UnexpectedArgument("--peer".to_string())),
+    }
+}
+
+/// Prints the session to stderr, since stdout carries protocol messages.
+fn report(session: &EstablishedSession) {
+    eprintln!("session key = {}", hex::encode(&session.key));
+    eprintln!("peer = {}", hex::encode(&session.peer_identity));
+    eprintln!(
+        "transcript hash = {}",
+        hex::encode(&session.transcript_hash)
+    );
+}
@@ -0,0 +1,128 @@
+// src/cli/attack.rs
+
+use super::{CliError, Options};
+use crate::rsa::attacks::{
+    common_modulus_attack, fermat_factor, hastad_broadcast_attack, recover_prime_factors,
+    weak_keys, wiener_attack, FactoredModulus,
+};
+use crate::rsa::{RsaPrivateKey, RsaPublicKey};
+use std::error::Error;
+use std::fs;
+
+/// Default iteration budget of the Fermat attack.
+const DEFAULT_FERMAT_ITERATIONS: u64 = 1_000_000;
+
+const USAGE: &str = "\
+Usage: cryptography_toolkit attack <name> [options]
+
+Numbers are decimal or 0x-prefixed hex. `--key FILE` reads a PKCS#1 PEM file holding an
+RSA public or private key.
+
+Attacks:
+  wiener          (--n N --e E | --key FILE)
+  fermat          (--n N | --key FILE) [--max-iterations K]
+  hastad          --e E --ciphertext C --modulus N [--ciphertext C --modulus N ...]
+  common-modulus  --n N --e1 E1 --c1 C1 --e2 E2 --c2 C2
+  recover         --n N --e E --d D
+
+Lab keys (printed as PEM, private key first):
+  weak-key        --kind small-d|close-primes --bits B";
+
+/// Runs an `attack` subcommand.
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let Some(name) = args.first() else {
+        println!("{}", USAGE);
+        return Ok(());
+    };
+    let options = Options::parse(&args[1..])?;
+
+    match name.as_str() {
+        "wiener" => {
+            let key = public_key(&options)?;
+            let result = wiener_attack(&key.n, &key.e)?;
+            println!("d = {}", result.d);
+            print_factors(&result.factors);
+            println!("convergents tried = {}", result.convergents_tried);
+        }
+        "fermat" => {
+            let n = match options.get("key") {
+                Some(_) => public_key(&options)?.n,
+                None => options.number("n")?,
+            };
+            let max_iterations = match options.get("max-iterations") {
+                Some(value) => value
+                    .parse()
+                    .map_err(|_| CliError::InvalidNumber(value.to_string()))?,
+                None => DEFAULT_FERMAT_ITERATIONS,
+            };
+            let result = fermat_factor(&n, max_iterations)?;
+            print_factors(&result.factors);
+            println!("iterations = {}", result.iterations);
+        }
+        "hastad" => {
+            let e = options.number("e")?;
+            let ciphertexts = options.numbers("ciphertext")?;
+            let moduli = options.numbers("modulus")?;
+            let result = hastad_broadcast_attack(&e, &ciphertexts, &moduli)?;
+            println!("m = {}", result.message);
+            println!("m^e = {}", result.message_power);
+            println!("ciphertexts used = {}", result.ciphertexts_used);
+        }
+        "common-modulus" => {
+            let result = common_modulus_attack(
+                &options.number("n")?,
+                &options.number("e1")?,
+                &options.number("c1")?,
+                &options.number("e2")?,
+                &options.number("c2")?,
+            )?;
+            println!("m = {}", result.message);
+            println!("a = {}", result.a);
+            println!("b = {}", result.b);
+        }
+        "recover" => {
+            let factors = recover_prime_factors(
+                &options.number("n")?,
+                &options.number("e")?,
+                &options.number("d")?,
+            )?;
+            print_factors(&factors);
+        }
+        "weak-key" => {
+            let bits: u64 = options
+                .required("bits")?
+                .parse()
+                .map_err(|_| CliError::InvalidNumber(options.get("bits").unwrap().to_string()))?;
+            let key = match options.required("kind")? {
+                "small-d" => weak_keys::small_private_exponent_key(bits)?,
+                "close-primes" => weak_keys::close_primes_key(bits, bits / 8)?,
+                other => return Err(CliError::UnexpectedArgument(other.to_string()).into()),
+            };
+            print!("{}", key.to_pkcs1_pem());
+            print!("{}", key.public_key.to_pkcs1_pem());
+        }
+        "help" => println!("{}", USAGE),
+        other => return Err(CliError::UnknownCommand(other.to_string()).into()),
+    }
+    Ok(())
+}
+
+/// Reads the public key from `--key FILE` or from `--n` and `--e`.
+fn public_key(options: &Options) -> Result<RsaPublicKey, Box<dyn Error>> {
+    if let Some(path) = options.get("key") {
+        let text = fs::read_to_string(path)?;
+        return match RsaPublicKey::from_pkcs1_pem(&text) {
+            Ok(key) => Ok(key),
+            Err(_) => Ok(RsaPrivateKey::from_pkcs1_pem(&text)?.public_key),
+        };
+    }
+    Ok(RsaPublicKey {
+        n: options.number("n")?,
+        e: options.number("e")?,
+    })
+}
+
+fn print_factors(factors: &FactoredModulus) {
+    println!("p = {}", factors.p);
+    println!("q = {}", factors.q);
+}
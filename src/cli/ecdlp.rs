@@ -0,0 +1,65 @@
+// src/cli/ecdlp.rs
+
+use super::{CliError, Options};
+use crate::elliptic_curve::{pollard_rho_ecdlp, Curve, RhoParameters};
+use std::error::Error;
+use std::time::Instant;
+
+const USAGE: &str = "\
+Usage: cryptography_toolkit ecdlp --p P --a A --b B --gx X --gy Y --qx X --qy Y --order N
+                                  [--checkpoint FILE] [--max-iterations K]
+                                  [--distinguished-bits D] [--walks W]
+
+Solves Q = kG on y^2 = x^3 + ax + b over GF(p) with parallel Pollard rho, where G has prime
+order N. Numbers are decimal or 0x-prefixed hex. With `--checkpoint`, the distinguished points
+are saved to FILE and a later run with the same problem resumes from it.";
+
+/// Runs the `ecdlp` subcommand.
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    if args.is_empty() || args[0] == "help" {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    let options = Options::parse(args)?;
+    let curve = Curve::new(
+        options.number("p")?,
+        options.number("a")?,
+        options.number("b")?,
+    )?;
+    let g = curve.point(options.number("gx")?, options.number("gy")?)?;
+    let q = curve.point(options.number("qx")?, options.number("qy")?)?;
+    let n = options.number("order")?;
+
+    let mut parameters = RhoParameters::for_order(&n);
+    if let Some(path) = options.get("checkpoint") {
+        parameters = parameters.with_checkpoint(path);
+    }
+    if options.get("max-iterations").is_some() {
+        parameters.max_iterations = Some(parse_count(&options, "max-iterations")?);
+    }
+    if options.get("distinguished-bits").is_some() {
+        parameters.distinguished_bits = parse_count(&options, "distinguished-bits")?;
+    }
+    if options.get("walks").is_some() {
+        parameters.walks = parse_count(&options, "walks")?;
+    }
+
+    let start = Instant::now();
+    let result = pollard_rho_ecdlp(&curve, &g, &q, &n, &parameters)?;
+    println!("k = {}", result.k);
+    println!("iterations = {}", result.iterations);
+    println!("expected iterations = {:.0}", result.expected_iterations);
+    println!("actual / expected = {:.2}", result.iteration_ratio());
+    println!("distinguished points = {}", result.distinguished_points);
+    println!("restarted walks = {}", result.restarted_walks);
+    println!("time = {:?}", start.elapsed());
+    Ok(())
+}
+
+/// Parses a small decimal option such as an iteration or walk count.
+fn parse_count<T: std::str::FromStr>(options: &Options, name: &str) -> Result<T, CliError> {
+    let value = options.required(name)?;
+    value
+        .parse()
+        .map_err(|_| CliError::InvalidNumber(value.to_string()))
+}
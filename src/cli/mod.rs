@@ -0,0 +1,138 @@
+// src/cli/mod.rs
+
+pub mod ake;
+pub mod attack;
+pub mod ecdlp;
+pub mod pairing;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// Errors raised while parsing the command line.
+#[derive(Debug)]
+pub enum CliError {
+    UnknownCommand(String),
+    MissingOption(String),
+    MissingValue(String),
+    UnexpectedArgument(String),
+    InvalidNumber(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(command) => {
+                write!(f, "Unknown command `{}`. Try `help`.", command)
+            }
+            CliError::MissingOption(name) => write!(f, "Missing required option `--{}`.", name),
+            CliError::MissingValue(name) => write!(f, "Option `--{}` needs a value.", name),
+            CliError::UnexpectedArgument(arg) => write!(f, "Unexpected argument `{}`.", arg),
+            CliError::InvalidNumber(value) => write!(
+                f,
+                "`{}` is not a number (use decimal or 0x-prefixed hex).",
+                value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// `--name value` options of a subcommand. Options may be repeated.
+#[derive(Debug, Default)]
+pub struct Options {
+    values: HashMap<String, Vec<String>>,
+}
+
+impl Options {
+    /// Parses a list of `--name value` pairs.
+    pub fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut options = Options::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| CliError::UnexpectedArgument(arg.clone()))?;
+            let value = iter
+                .next()
+                .ok_or_else(|| CliError::MissingValue(name.to_string()))?;
+            options
+                .values
+                .entry(name.to_string())
+                .or_default()
+                .push(value.clone());
+        }
+        Ok(options)
+    }
+
+    /// Returns the last value given for `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .and_then(|values| values.last())
+            .map(String::as_str)
+    }
+
+    /// Returns the value of a required option.
+    pub fn required(&self, name: &str) -> Result<&str, CliError> {
+        self.get(name)
+            .ok_or_else(|| CliError::MissingOption(name.to_string()))
+    }
+
+    /// Returns all values given for `name`, in order.
+    pub fn all(&self, name: &str) -> Vec<&str> {
+        self.values
+            .get(name)
+            .map(|values| values.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Parses a required numeric option.
+    pub fn number(&self, name: &str) -> Result<BigUint, CliError> {
+        parse_number(self.required(name)?)
+    }
+
+    /// Parses every value of a repeatable numeric option.
+    pub fn numbers(&self, name: &str) -> Result<Vec<BigUint>, CliError> {
+        self.all(name).into_iter().map(parse_number).collect()
+    }
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal number.
+pub fn parse_number(text: &str) -> Result<BigUint, CliError> {
+    let parsed = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => BigUint::from_str_radix(hex, 16),
+        None => BigUint::from_str_radix(text, 10),
+    };
+    parsed.map_err(|_| CliError::InvalidNumber(text.to_string()))
+}
+
+const USAGE: &str = "\
+Usage: cryptography_toolkit <command> [options]
+
+Commands:
+  ake <protocol> [options]  Run an authenticated key exchange over stdin and stdout
+  attack <name> [options]   Run an RSA attack (`attack help` lists them)
+  ecdlp [options]           Solve an elliptic-curve discrete log with Pollard rho
+  pairing <command> [...]   Check pairings and run BLS and Joux (`pairing help`)
+  help                      Show this message
+
+Without a command, the built-in demonstration runs.";
+
+/// Runs the subcommand named by the first argument.
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args[0].as_str() {
+        "ake" => ake::run(&args[1..]),
+        "attack" => attack::run(&args[1..]),
+        "ecdlp" => ecdlp::run(&args[1..]),
+        "pairing" => pairing::run(&args[1..]),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(CliError::UnknownCommand(other.to_string()).into()),
+    }
+}
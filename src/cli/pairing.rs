@@ -0,0 +1,106 @@
+// src/cli/pairing.rs
+
+use super::{CliError, Options};
+use crate::pairing::{
+    check_pairing, BlsPrivateKey, Bn254, Joux, Pairing, PairingVariant, SupersingularPairing,
+};
+use crate::signature::{SigningKey, VerifyingKey};
+use std::error::Error;
+use std::time::Instant;
+
+/// Rounds of `check` when `--rounds` is not given.
+const DEFAULT_ROUNDS: usize = 3;
+
+const USAGE: &str = "\
+Usage: cryptography_toolkit pairing <command> [options]
+
+Curves are `toy`, the supersingular curve y^2 = x^3 + x over a 128-bit field with a symmetric
+Tate pairing, and `bn254` (default), with the optimal ate pairing or, with `--variant tate`,
+the Tate pairing.
+
+Commands:
+  check  [--curve toy|bn254] [--variant ate|tate] [--rounds N]
+         Test bilinearity and non-degeneracy on random points
+  bls    --message TEXT
+         Sign and verify a message with a fresh BLS key on BN254
+  joux   [--curve toy|bn254]
+         Run Joux's three-party key agreement";
+
+/// Runs a `pairing` subcommand.
+pub fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let Some(name) = args.first() else {
+        println!("{}", USAGE);
+        return Ok(());
+    };
+    let options = Options::parse(&args[1..])?;
+    let variant = match options.get("variant").unwrap_or("ate") {
+        "ate" => PairingVariant::OptimalAte,
+        "tate" => PairingVariant::Tate,
+        other => return Err(CliError::UnexpectedArgument(other.to_string()).into()),
+    };
+    let toy = match options.get("curve").unwrap_or("bn254") {
+        "toy" => true,
+        "bn254" => false,
+        other => return Err(CliError::UnexpectedArgument(other.to_string()).into()),
+    };
+
+    match name.as_str() {
+        "check" => {
+            let rounds = match options.get("rounds") {
+                Some(value) => value
+                    .parse()
+                    .map_err(|_| CliError::InvalidNumber(value.to_string()))?,
+                None => DEFAULT_ROUNDS,
+            };
+            if toy {
+                check(&SupersingularPairing::toy(), rounds)?;
+            } else {
+                check(&Bn254::new(variant), rounds)?;
+            }
+        }
+        "bls" => {
+            let message = options.required("message")?.as_bytes();
+            let key = BlsPrivateKey::generate(&Bn254::new(PairingVariant::OptimalAte));
+            let public_key = key.verifying_key();
+            let signature = key.sign(message);
+            println!("public key = {}", hex::encode(public_key.to_bytes()));
+            println!("signature = {}", hex::encode(&signature));
+            println!("valid = {}", public_key.verify(message, &signature));
+        }
+        "joux" => {
+            if toy {
+                joux(SupersingularPairing::toy())?;
+            } else {
+                joux(Bn254::new(variant))?;
+            }
+        }
+        "help" => println!("{}", USAGE),
+        other => return Err(CliError::UnknownCommand(other.to_string()).into()),
+    }
+    Ok(())
+}
+
+/// Runs `check_pairing` and reports the time taken.
+fn check<P: Pairing>(pairing: &P, rounds: usize) -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+    check_pairing(pairing, rounds)?;
+    println!("e(G1, G2) has order r = {}", pairing.order());
+    println!("bilinear on {} random rounds", rounds);
+    println!("time = {:?}", start.elapsed());
+    Ok(())
+}
+
+/// Runs the key agreement between three local parties and compares their keys.
+fn joux<P: Pairing>(pairing: P) -> Result<(), Box<dyn Error>> {
+    let joux = Joux::new(pairing);
+    let alice = joux.generate_private_key();
+    let bob = joux.generate_private_key();
+    let carol = joux.generate_private_key();
+    let keys = [
+        joux.compute_shared_secret(&alice, bob.public_key(), carol.public_key())?,
+        joux.compute_shared_secret(&bob, carol.public_key(), alice.public_key())?,
+        joux.compute_shared_secret(&carol, alice.public_key(), bob.public_key())?,
+    ];
+    println!("keys agree = {}", keys[0] == keys[1] && keys[1] == keys[2]);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// src/diffie_hellman/errors.rs
+
+use crate::kdf::KdfError;
+use std::fmt;
+
+/// Errors raised by Diffie-Hellman parameter, key and shared secret validation
+/// (SP 800-56A Rev. 3, sections 5.5.2 and 5.6.2).
+#[derive(Debug, PartialEq, Eq)]
+pub enum DiffieHellmanError {
+    NonPrimeModulus,
+    NotSafePrime,
+    NonPrimeSubgroupOrder,
+    SubgroupOrderMismatch,
+    InvalidGenerator,
+    InvalidPrivateKey,
+    PublicKeyOutOfRange,
+    PublicKeyNotInSubgroup,
+    TrivialSharedSecret,
+    KeyDerivation(KdfError),
+}
+
+impl fmt::Display for DiffieHellmanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffieHellmanError::NonPrimeModulus => write!(f, "The modulus p is not prime."),
+            DiffieHellmanError::NotSafePrime => write!(
+                f,
+                "The modulus p is not a safe prime, so the subgroup order q must be given."
+            ),
+            DiffieHellmanError::NonPrimeSubgroupOrder => {
+                write!(f, "The subgroup order q is not prime.")
+            }
+            DiffieHellmanError::SubgroupOrderMismatch => {
+                write!(f, "The subgroup order q does not divide p - 1.")
+            }
+            DiffieHellmanError::InvalidGenerator => {
+                write!(
+                    f,
+                    "The generator g does not generate a subgroup of order q."
+                )
+            }
+            DiffieHellmanError::InvalidPrivateKey => {
+                write!(f, "The private key is outside the valid range.")
+            }
+            DiffieHellmanError::PublicKeyOutOfRange => {
+                write!(
+                    f,
+                    "The public key is not a group element of order greater than 2."
+                )
+            }
+            DiffieHellmanError::PublicKeyNotInSubgroup => {
+                write!(f, "The public key is not in the subgroup of order q.")
+            }
+            DiffieHellmanError::TrivialSharedSecret => {
+                write!(f, "The shared secret is the identity element.")
+            }
+            DiffieHellmanError::KeyDerivation(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DiffieHellmanError {}
@@ -0,0 +1,485 @@
+// src/diffie_hellman/groups.rs
+
+use num_bigint::BigUint;
+use num_traits::Num;
+use std::fmt;
+
+/// Named finite-field Diffie-Hellman groups: the MODP groups of RFC 2409 and RFC 3526 and the
+/// FFDHE groups of RFC 7919.
+///
+/// Every prime is a safe prime p = 2q + 1 and the generator 2 spans the subgroup of prime
+/// order q.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedGroup {
+    Modp1536,
+    Modp2048,
+    Modp3072,
+    Modp4096,
+    Modp6144,
+    Modp8192,
+    Ffdhe2048,
+    Ffdhe3072,
+    Ffdhe4096,
+    Ffdhe6144,
+    Ffdhe8192,
+}
+
+impl NamedGroup {
+    /// All named groups, from the smallest to the largest within each family.
+    pub const ALL: [NamedGroup; 11] = [
+        NamedGroup::Modp1536,
+        NamedGroup::Modp2048,
+        NamedGroup::Modp3072,
+        NamedGroup::Modp4096,
+        NamedGroup::Modp6144,
+        NamedGroup::Modp8192,
+        NamedGroup::Ffdhe2048,
+        NamedGroup::Ffdhe3072,
+        NamedGroup::Ffdhe4096,
+        NamedGroup::Ffdhe6144,
+        NamedGroup::Ffdhe8192,
+    ];
+
+    /// Returns the conventional name of the group, such as `modp2048` or `ffdhe3072`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedGroup::Modp1536 => "modp1536",
+            NamedGroup::Modp2048 => "modp2048",
+            NamedGroup::Modp3072 => "modp3072",
+            NamedGroup::Modp4096 => "modp4096",
+            NamedGroup::Modp6144 => "modp6144",
+            NamedGroup::Modp8192 => "modp8192",
+            NamedGroup::Ffdhe2048 => "ffdhe2048",
+            NamedGroup::Ffdhe3072 => "ffdhe3072",
+            NamedGroup::Ffdhe4096 => "ffdhe4096",
+            NamedGroup::Ffdhe6144 => "ffdhe6144",
+            NamedGroup::Ffdhe8192 => "ffdhe8192",
+        }
+    }
+
+    /// Looks a group up by the name returned by `name`.
+    pub fn from_name(name: &str) -> Option<NamedGroup> {
+        NamedGroup::ALL
+            .into_iter()
+            .find(|group| group.name() == name)
+    }
+
+    /// Returns the prime modulus p.
+    pub fn prime(&self) -> BigUint {
+        let hex = match self {
+            NamedGroup::Modp1536 => MODP_1536,
+            NamedGroup::Modp2048 => MODP_2048,
+            NamedGroup::Modp3072 => MODP_3072,
+            NamedGroup::Modp4096 => MODP_4096,
+            NamedGroup::Modp6144 => MODP_6144,
+            NamedGroup::Modp8192 => MODP_8192,
+            NamedGroup::Ffdhe2048 => FFDHE2048,
+            NamedGroup::Ffdhe3072 => FFDHE3072,
+            NamedGroup::Ffdhe4096 => FFDHE4096,
+            NamedGroup::Ffdhe6144 => FFDHE6144,
+            NamedGroup::Ffdhe8192 => FFDHE8192,
+        };
+        BigUint::from_str_radix(hex, 16).expect("named group primes are valid hex")
+    }
+
+    /// Returns the generator g = 2.
+    pub fn generator(&self) -> BigUint {
+        BigUint::from(2u32)
+    }
+
+    /// Returns the prime order q = (p - 1) / 2 of the subgroup generated by g.
+    pub fn subgroup_order(&self) -> BigUint {
+        (self.prime() - 1u32) >> 1
+    }
+
+    /// Returns the size of the modulus in bits.
+    pub fn bits(&self) -> u64 {
+        match self {
+            NamedGroup::Modp1536 => 1536,
+            NamedGroup::Modp2048 | NamedGroup::Ffdhe2048 => 2048,
+            NamedGroup::Modp3072 | NamedGroup::Ffdhe3072 => 3072,
+            NamedGroup::Modp4096 | NamedGroup::Ffdhe4096 => 4096,
+            NamedGroup::Modp6144 | NamedGroup::Ffdhe6144 => 6144,
+            NamedGroup::Modp8192 | NamedGroup::Ffdhe8192 => 8192,
+        }
+    }
+
+    /// Returns the estimated symmetric-equivalent security strength in bits.
+    ///
+    /// For the FFDHE groups these are the estimates of RFC 7919, appendix A; for the MODP
+    /// groups the upper estimates of RFC 3526, section 8.
+    pub fn security_bits(&self) -> u64 {
+        match self {
+            NamedGroup::Modp1536 => 120,
+            NamedGroup::Modp2048 => 160,
+            NamedGroup::Modp3072 => 210,
+            NamedGroup::Modp4096 => 240,
+            NamedGroup::Modp6144 => 270,
+            NamedGroup::Modp8192 => 310,
+            NamedGroup::Ffdhe2048 => 103,
+            NamedGroup::Ffdhe3072 => 125,
+            NamedGroup::Ffdhe4096 => 150,
+            NamedGroup::Ffdhe6144 => 175,
+            NamedGroup::Ffdhe8192 => 192,
+        }
+    }
+
+    /// Returns the recommended length in bits of a short private exponent.
+    ///
+    /// These are the minimum secret key sizes of RFC 7919, appendix A, and twice the upper
+    /// strength estimates of RFC 3526, section 8.
+    pub fn private_key_bits(&self) -> u64 {
+        match self {
+            NamedGroup::Modp1536 => 240,
+            NamedGroup::Modp2048 => 320,
+            NamedGroup::Modp3072 => 420,
+            NamedGroup::Modp4096 => 480,
+            NamedGroup::Modp6144 => 540,
+            NamedGroup::Modp8192 => 620,
+            NamedGroup::Ffdhe2048 => 225,
+            NamedGroup::Ffdhe3072 => 275,
+            NamedGroup::Ffdhe4096 => 325,
+            NamedGroup::Ffdhe6144 => 375,
+            NamedGroup::Ffdhe8192 => 400,
+        }
+    }
+}
+
+impl fmt::Display for NamedGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The 1536-bit MODP group (RFC 3526, section 2).
+const MODP_1536: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA237327FFFFFFFFFFFFFFFF",
+);
+
+/// The 2048-bit MODP group (RFC 3526, section 3).
+const MODP_2048: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
+    "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AACAA68FFFFFFFFFFFFFFFF",
+);
+
+/// The 3072-bit MODP group (RFC 3526, section 4).
+const MODP_3072: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
+    "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64",
+    "ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6B",
+    "F12FFA06D98A0864D87602733EC86A64521F2B18177B200C",
+    "BBE117577A615D6C770988C0BAD946E208E24FA074E5AB31",
+    "43DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF",
+);
+
+/// The 4096-bit MODP group (RFC 3526, section 5).
+const MODP_4096: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
+    "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64",
+    "ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6B",
+    "F12FFA06D98A0864D87602733EC86A64521F2B18177B200C",
+    "BBE117577A615D6C770988C0BAD946E208E24FA074E5AB31",
+    "43DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7",
+    "88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA",
+    "2583E9CA2AD44CE8DBBBC2DB04DE8EF92E8EFC141FBECAA6",
+    "287C59474E6BC05D99B2964FA090C3A2233BA186515BE7ED",
+    "1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9",
+    "93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934063199",
+    "FFFFFFFFFFFFFFFF",
+);
+
+/// The 6144-bit MODP group (RFC 3526, section 6).
+const MODP_6144: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
+    "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64",
+    "ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6B",
+    "F12FFA06D98A0864D87602733EC86A64521F2B18177B200C",
+    "BBE117577A615D6C770988C0BAD946E208E24FA074E5AB31",
+    "43DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7",
+    "88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA",
+    "2583E9CA2AD44CE8DBBBC2DB04DE8EF92E8EFC141FBECAA6",
+    "287C59474E6BC05D99B2964FA090C3A2233BA186515BE7ED",
+    "1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9",
+    "93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934028492",
+    "36C3FAB4D27C7026C1D4DCB2602646DEC9751E763DBA37BD",
+    "F8FF9406AD9E530EE5DB382F413001AEB06A53ED9027D831",
+    "179727B0865A8918DA3EDBEBCF9B14ED44CE6CBACED4BB1B",
+    "DB7F1447E6CC254B332051512BD7AF426FB8F401378CD2BF",
+    "5983CA01C64B92ECF032EA15D1721D03F482D7CE6E74FEF6",
+    "D55E702F46980C82B5A84031900B1C9E59E7C97FBEC7E8F3",
+    "23A97A7E36CC88BE0F1D45B7FF585AC54BD407B22B4154AA",
+    "CC8F6D7EBF48E1D814CC5ED20F8037E0A79715EEF29BE328",
+    "06A1D58BB7C5DA76F550AA3D8A1FBFF0EB19CCB1A313D55C",
+    "DA56C9EC2EF29632387FE8D76E3C0468043E8F663F4860EE",
+    "12BF2D5B0B7474D6E694F91E6DCC4024FFFFFFFFFFFFFFFF",
+);
+
+/// The 8192-bit MODP group (RFC 3526, section 7).
+const MODP_8192: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
+    "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
+    "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
+    "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
+    "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
+    "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64",
+    "ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6B",
+    "F12FFA06D98A0864D87602733EC86A64521F2B18177B200C",
+    "BBE117577A615D6C770988C0BAD946E208E24FA074E5AB31",
+    "43DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7",
+    "88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA",
+    "2583E9CA2AD44CE8DBBBC2DB04DE8EF92E8EFC141FBECAA6",
+    "287C59474E6BC05D99B2964FA090C3A2233BA186515BE7ED",
+    "1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9",
+    "93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934028492",
+    "36C3FAB4D27C7026C1D4DCB2602646DEC9751E763DBA37BD",
+    "F8FF9406AD9E530EE5DB382F413001AEB06A53ED9027D831",
+    "179727B0865A8918DA3EDBEBCF9B14ED44CE6CBACED4BB1B",
+    "DB7F1447E6CC254B332051512BD7AF426FB8F401378CD2BF",
+    "5983CA01C64B92ECF032EA15D1721D03F482D7CE6E74FEF6",
+    "D55E702F46980C82B5A84031900B1C9E59E7C97FBEC7E8F3",
+    "23A97A7E36CC88BE0F1D45B7FF585AC54BD407B22B4154AA",
+    "CC8F6D7EBF48E1D814CC5ED20F8037E0A79715EEF29BE328",
+    "06A1D58BB7C5DA76F550AA3D8A1FBFF0EB19CCB1A313D55C",
+    "DA56C9EC2EF29632387FE8D76E3C0468043E8F663F4860EE",
+    "12BF2D5B0B7474D6E694F91E6DBE115974A3926F12FEE5E4",
+    "38777CB6A932DF8CD8BEC4D073B931BA3BC832B68D9DD300",
+    "741FA7BF8AFC47ED2576F6936BA424663AAB639C5AE4F568",
+    "3423B4742BF1C978238F16CBE39D652DE3FDB8BEFC848AD9",
+    "22222E04A4037C0713EB57A81A23F0C73473FC646CEA306B",
+    "4BCBC8862F8385DDFA9D4B7FA2C087E879683303ED5BDD3A",
+    "062B3CF5B3A278A66D2A13F83F44F82DDF310EE074AB6A36",
+    "4597E899A0255DC164F31CC50846851DF9AB48195DED7EA1",
+    "B1D510BD7EE74D73FAF36BC31ECFA268359046F4EB879F92",
+    "4009438B481C6CD7889A002ED5EE382BC9190DA6FC026E47",
+    "9558E4475677E9AA9E3050E2765694DFC81F56E880B96E71",
+    "60C980DD98EDD3DFFFFFFFFFFFFFFFFF",
+);
+
+/// The ffdhe2048 group (RFC 7919, appendix A.1).
+const FFDHE2048: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1",
+    "D8B9C583CE2D3695A9E13641146433FBCC939DCE249B3EF9",
+    "7D2FE363630C75D8F681B202AEC4617AD3DF1ED5D5FD6561",
+    "2433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE735",
+    "30ACCA4F483A797ABC0AB182B324FB61D108A94BB2C8E3FB",
+    "B96ADAB760D7F4681D4F42A3DE394DF4AE56EDE76372BB19",
+    "0B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD73",
+    "3BB5FCBC2EC22005C58EF1837D1683B2C6F34A26C1B2EFFA",
+    "886B423861285C97FFFFFFFFFFFFFFFF",
+);
+
+/// The ffdhe3072 group (RFC 7919, appendix A.2).
+const FFDHE3072: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1",
+    "D8B9C583CE2D3695A9E13641146433FBCC939DCE249B3EF9",
+    "7D2FE363630C75D8F681B202AEC4617AD3DF1ED5D5FD6561",
+    "2433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE735",
+    "30ACCA4F483A797ABC0AB182B324FB61D108A94BB2C8E3FB",
+    "B96ADAB760D7F4681D4F42A3DE394DF4AE56EDE76372BB19",
+    "0B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD73",
+    "3BB5FCBC2EC22005C58EF1837D1683B2C6F34A26C1B2EFFA",
+    "886B4238611FCFDCDE355B3B6519035BBC34F4DEF99C0238",
+    "61B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD3",
+    "64F2E21E71F54BFF5CAE82AB9C9DF69EE86D2BC522363A0D",
+    "ABC521979B0DEADA1DBF9A42D5C4484E0ABCD06BFA53DDEF",
+    "3C1B20EE3FD59D7C25E41D2B66C62E37FFFFFFFFFFFFFFFF",
+);
+
+/// The ffdhe4096 group (RFC 7919, appendix A.3).
+const FFDHE4096: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1",
+    "D8B9C583CE2D3695A9E13641146433FBCC939DCE249B3EF9",
+    "7D2FE363630C75D8F681B202AEC4617AD3DF1ED5D5FD6561",
+    "2433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE735",
+    "30ACCA4F483A797ABC0AB182B324FB61D108A94BB2C8E3FB",
+    "B96ADAB760D7F4681D4F42A3DE394DF4AE56EDE76372BB19",
+    "0B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD73",
+    "3BB5FCBC2EC22005C58EF1837D1683B2C6F34A26C1B2EFFA",
+    "886B4238611FCFDCDE355B3B6519035BBC34F4DEF99C0238",
+    "61B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD3",
+    "64F2E21E71F54BFF5CAE82AB9C9DF69EE86D2BC522363A0D",
+    "ABC521979B0DEADA1DBF9A42D5C4484E0ABCD06BFA53DDEF",
+    "3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D55034004",
+    "87F55BA57E31CC7A7135C886EFB4318AED6A1E012D9E6832",
+    "A907600A918130C46DC778F971AD0038092999A333CB8B7A",
+    "1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E655F6A",
+    "FFFFFFFFFFFFFFFF",
+);
+
+/// The ffdhe6144 group (RFC 7919, appendix A.4).
+const FFDHE6144: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1",
+    "D8B9C583CE2D3695A9E13641146433FBCC939DCE249B3EF9",
+    "7D2FE363630C75D8F681B202AEC4617AD3DF1ED5D5FD6561",
+    "2433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE735",
+    "30ACCA4F483A797ABC0AB182B324FB61D108A94BB2C8E3FB",
+    "B96ADAB760D7F4681D4F42A3DE394DF4AE56EDE76372BB19",
+    "0B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD73",
+    "3BB5FCBC2EC22005C58EF1837D1683B2C6F34A26C1B2EFFA",
+    "886B4238611FCFDCDE355B3B6519035BBC34F4DEF99C0238",
+    "61B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD3",
+    "64F2E21E71F54BFF5CAE82AB9C9DF69EE86D2BC522363A0D",
+    "ABC521979B0DEADA1DBF9A42D5C4484E0ABCD06BFA53DDEF",
+    "3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D55034004",
+    "87F55BA57E31CC7A7135C886EFB4318AED6A1E012D9E6832",
+    "A907600A918130C46DC778F971AD0038092999A333CB8B7A",
+    "1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD902",
+    "0BFD64B645036C7A4E677D2C38532A3A23BA4442CAF53EA6",
+    "3BB454329B7624C8917BDD64B1C0FD4CB38E8C334C701C3A",
+    "CDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477",
+    "A52471F7A9A96910B855322EDB6340D8A00EF092350511E3",
+    "0ABEC1FFF9E3A26E7FB29F8C183023C3587E38DA0077D9B4",
+    "763E4E4B94B2BBC194C6651E77CAF992EEAAC0232A281BF6",
+    "B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C",
+    "D72B03746AE77F5E62292C311562A846505DC82DB854338A",
+    "E49F5235C95B91178CCF2DD5CACEF403EC9D1810C6272B04",
+    "5B3B71F9DC6B80D63FDD4A8E9ADB1E6962A69526D43161C1",
+    "A41D570D7938DAD4A40E329CD0E40E65FFFFFFFFFFFFFFFF",
+);
+
+/// The ffdhe8192 group (RFC 7919, appendix A.5).
+const FFDHE8192: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1",
+    "D8B9C583CE2D3695A9E13641146433FBCC939DCE249B3EF9",
+    "7D2FE363630C75D8F681B202AEC4617AD3DF1ED5D5FD6561",
+    "2433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE735",
+    "30ACCA4F483A797ABC0AB182B324FB61D108A94BB2C8E3FB",
+    "B96ADAB760D7F4681D4F42A3DE394DF4AE56EDE76372BB19",
+    "0B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD73",
+    "3BB5FCBC2EC22005C58EF1837D1683B2C6F34A26C1B2EFFA",
+    "886B4238611FCFDCDE355B3B6519035BBC34F4DEF99C0238",
+    "61B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD3",
+    "64F2E21E71F54BFF5CAE82AB9C9DF69EE86D2BC522363A0D",
+    "ABC521979B0DEADA1DBF9A42D5C4484E0ABCD06BFA53DDEF",
+    "3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D55034004",
+    "87F55BA57E31CC7A7135C886EFB4318AED6A1E012D9E6832",
+    "A907600A918130C46DC778F971AD0038092999A333CB8B7A",
+    "1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD902",
+    "0BFD64B645036C7A4E677D2C38532A3A23BA4442CAF53EA6",
+    "3BB454329B7624C8917BDD64B1C0FD4CB38E8C334C701C3A",
+    "CDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477",
+    "A52471F7A9A96910B855322EDB6340D8A00EF092350511E3",
+    "0ABEC1FFF9E3A26E7FB29F8C183023C3587E38DA0077D9B4",
+    "763E4E4B94B2BBC194C6651E77CAF992EEAAC0232A281BF6",
+    "B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C",
+    "D72B03746AE77F5E62292C311562A846505DC82DB854338A",
+    "E49F5235C95B91178CCF2DD5CACEF403EC9D1810C6272B04",
+    "5B3B71F9DC6B80D63FDD4A8E9ADB1E6962A69526D43161C1",
+    "A41D570D7938DAD4A40E329CCFF46AAA36AD004CF600C838",
+    "1E425A31D951AE64FDB23FCEC9509D43687FEB69EDD1CC5E",
+    "0B8CC3BDF64B10EF86B63142A3AB8829555B2F747C932665",
+    "CB2C0F1CC01BD70229388839D2AF05E454504AC78B758282",
+    "2846C0BA35C35F5C59160CC046FD8251541FC68C9C86B022",
+    "BB7099876A460E7451A8A93109703FEE1C217E6C3826E52C",
+    "51AA691E0E423CFC99E9E31650C1217B624816CDAD9A95F9",
+    "D5B8019488D9C0A0A1FE3075A577E23183F81D4A3F2FA457",
+    "1EFC8CE0BA8A4FE8B6855DFE72B0A66EDED2FBABFBE58A30",
+    "FAFABE1C5D71A87E2F741EF8C1FE86FEA6BBFDE530677F0D",
+    "97D11D49F7A8443D0822E506A9F4614E011E2A94838FF88C",
+    "D68C8BB7C5C6424CFFFFFFFFFFFFFFFF",
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer_mod_p::IntegerModP;
+    use num_integer::Integer;
+    use num_traits::One;
+
+    /// Miller-Rabin rounds for q. A single round already has a negligible error at these sizes.
+    const ROUNDS: u32 = 1;
+
+    #[test]
+    fn named_groups_have_prime_order_subgroups() {
+        for group in NamedGroup::ALL {
+            let (p, q, g) = (group.prime(), group.subgroup_order(), group.generator());
+            assert_eq!(p.bits(), group.bits(), "{}", group);
+            assert!((&p - 1u32).is_multiple_of(&q), "{}", group);
+            assert!(g.modpow(&q, &p).is_one(), "{}", group);
+            assert!(
+                IntegerModP::is_prime_miller_rabin_parallel(&q, ROUNDS),
+                "{}",
+                group
+            );
+            // With q prime and q > sqrt(p), Pocklington's criterion proves p prime from the
+            // witness g: g^(p - 1) = (g^q)^2 = 1 and gcd(g^((p - 1) / q) - 1, p) = gcd(3, p) = 1.
+            assert!(q.pow(2) > p, "{}", group);
+            let cofactor = (&p - 1u32) / &q;
+            let witness = g.modpow(&cofactor, &p) - 1u32;
+            assert!(witness.gcd(&p).is_one(), "{}", group);
+            assert_eq!(NamedGroup::from_name(group.name()), Some(group));
+        }
+    }
+}
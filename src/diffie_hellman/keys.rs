@@ -0,0 +1,28 @@
+// src/diffie_hellman/keys.rs
+
+use num_bigint::BigUint;
+
+/// A Diffie-Hellman public key: the group element g^x.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey<E> {
+    pub element: E, // g^x
+}
+
+/// A Diffie-Hellman private key: the exponent x together with its public key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivateKey<E> {
+    pub(super) exponent: BigUint, // Private exponent x
+    pub public_key: PublicKey<E>,
+}
+
+impl<E> PrivateKey<E> {
+    /// Returns the private exponent x.
+    pub fn exponent(&self) -> &BigUint {
+        &self.exponent
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &PublicKey<E> {
+        &self.public_key
+    }
+}
@@ -1,42 +1,291 @@
-// src/diffie_hellman/diffie_hellman.rs
+// src/diffie_hellman/mod.rs
 
-use crate::algebra::traits::Group;
+mod errors;
+pub mod groups;
+pub mod keys;
+pub mod x25519;
+
+pub use errors::DiffieHellmanError;
+pub use groups::NamedGroup;
+pub use keys::{PrivateKey, PublicKey};
+pub use x25519::{x25519, X25519_BASE_POINT};
+
+use crate::algebra::traits::{self, ElementEncoding, Membership};
+use crate::factor::is_probable_prime;
+use crate::hash::HashFunction;
 use crate::integer_mod_p::IntegerModP;
-use num_bigint::BigUint;
-use num_traits::One;
+use crate::kdf::hkdf;
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
 use rand::rngs::OsRng;
-use rand::RngCore;
-use std::error::Error;
 
-pub struct DiffieHellman {
-    pub field: IntegerModP, // Finite field ℤₚ
-    pub g: BigUint,         // Generator
+/// Diffie-Hellman key agreement in the subgroup of order q generated by `g` in any group.
+///
+/// The same code runs over `IntegerModP`, the multiplicative group of a
+/// `BinaryExtensionField`, elliptic-curve groups or small test groups.
+pub struct DiffieHellman<G: traits::Group = IntegerModP> {
+    pub group: G,                      // Ambient group
+    pub g: G::Element,                 // Generator
+    pub q: BigUint,                    // Order of g, or a multiple of it if unknown
+    pub private_key_bits: Option<u64>, // Length of short private exponents, if recommended
+}
+
+impl DiffieHellman<IntegerModP> {
+    /// Creates a Diffie-Hellman instance for the safe prime `p` = 2q + 1 and generator `g`,
+    /// taking q = (p - 1) / 2 as the subgroup order. For other primes the order of g has to be
+    /// supplied with `with_subgroup_order`, since without it public keys could only be
+    /// range-checked.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DiffieHellman)` if the parameters are valid.
+    /// * `Err(DiffieHellmanError::NotSafePrime)` if p is prime but not a safe prime.
+    /// * `Err(DiffieHellmanError)` describing the first failed check otherwise.
+    pub fn new(p: BigUint, g: BigUint) -> Result<Self, DiffieHellmanError> {
+        let q: BigUint = (&p - 1u32) >> 1;
+        if !is_probable_prime(&q) {
+            return Err(if is_probable_prime(&p) {
+                DiffieHellmanError::NotSafePrime
+            } else {
+                DiffieHellmanError::NonPrimeModulus
+            });
+        }
+        Self::with_subgroup_order(p, q, g)
+    }
+
+    /// Creates a Diffie-Hellman instance for the prime `p` and a generator `g` of the subgroup
+    /// of prime order `q`, after the domain parameter checks of SP 800-56A, section 5.5.2:
+    /// p and q are prime, q divides p - 1, 2 <= g <= p - 2 and g^q = 1 mod p.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DiffieHellman)` if the parameters are valid.
+    /// * `Err(DiffieHellmanError)` describing the first failed check.
+    pub fn with_subgroup_order(
+        p: BigUint,
+        q: BigUint,
+        g: BigUint,
+    ) -> Result<Self, DiffieHellmanError> {
+        let field = IntegerModP::new(p).map_err(|_| DiffieHellmanError::NonPrimeModulus)?;
+        if !is_probable_prime(&q) {
+            return Err(DiffieHellmanError::NonPrimeSubgroupOrder);
+        }
+        if !(&field.p - 1u32).is_multiple_of(&q) {
+            return Err(DiffieHellmanError::SubgroupOrderMismatch);
+        }
+        if g < BigUint::from(2u32) || g > &field.p - 2u32 {
+            return Err(DiffieHellmanError::InvalidGenerator);
+        }
+        Self::from_group(field, g, q)
+    }
+
+    /// Creates a Diffie-Hellman instance for a named RFC 3526 or RFC 7919 group, with its
+    /// generator, subgroup order and recommended private key length.
+    pub fn named(group: NamedGroup) -> Self {
+        DiffieHellman {
+            group: IntegerModP::new_valid_prime(group.prime()),
+            g: group.generator(),
+            q: group.subgroup_order(),
+            private_key_bits: Some(group.private_key_bits()),
+        }
+    }
 }
 
-impl DiffieHellman {
-    pub fn new(p: BigUint, g: BigUint) -> Result<Self, Box<dyn Error>> {
-        let field = IntegerModP::new_valid_prime(p);
-        Ok(DiffieHellman { field, g })
+impl<G> DiffieHellman<G>
+where
+    G: traits::Group + ElementEncoding + Membership,
+    G::Element: Clone,
+{
+    /// Creates a Diffie-Hellman instance over any group, given a generator and its order.
+    ///
+    /// Public key validation is only as strong as `q` is accurate: with a prime q it rejects
+    /// every element outside the subgroup generated by g.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DiffieHellman)` if g is a valid element other than the identity and g^q is the
+    ///   identity.
+    /// * `Err(DiffieHellmanError::InvalidGenerator)` otherwise.
+    pub fn from_group(group: G, g: G::Element, q: BigUint) -> Result<Self, DiffieHellmanError> {
+        let dh = DiffieHellman {
+            group,
+            g,
+            q,
+            private_key_bits: None,
+        };
+        if dh.q.is_zero()
+            || !dh.group.is_element(&dh.g)
+            || dh.is_identity(&dh.g)
+            || !dh.is_identity(&dh.group.pow(&dh.g, &dh.q))
+        {
+            return Err(DiffieHellmanError::InvalidGenerator);
+        }
+        Ok(dh)
     }
 
-    pub fn generate_private_key(&self) -> BigUint {
+    /// Generates a key pair. With a recommended length of b bits the private exponent is a
+    /// short exponent in [2^(b - 1), 2^b); otherwise it is uniform in [1, q - 1].
+    pub fn generate_private_key(&self) -> PrivateKey<G::Element> {
         let mut rng = OsRng;
-        loop {
-            let mut bytes = vec![0u8; self.field.p.bits() as usize / 8 + 1];
-            rng.fill_bytes(&mut bytes);
-            let private_key = BigUint::from_bytes_be(&bytes) % &self.field.p;
-            if private_key > BigUint::one() && private_key < &self.field.p - BigUint::one() {
-                return private_key;
-            }
+        let exponent = match self.private_key_bits {
+            Some(bits) => rng.gen_biguint(bits - 1) | (BigUint::one() << (bits - 1)),
+            None => rng.gen_biguint_range(&BigUint::one(), &self.q),
+        };
+        self.private_key_from_exponent(exponent)
+            .expect("generated exponents are in range")
+    }
+
+    /// Builds the key pair for a given private exponent.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PrivateKey)` if the exponent is in [1, q - 1].
+    /// * `Err(DiffieHellmanError::InvalidPrivateKey)` otherwise.
+    pub fn private_key_from_exponent(
+        &self,
+        exponent: BigUint,
+    ) -> Result<PrivateKey<G::Element>, DiffieHellmanError> {
+        if exponent.is_zero() || exponent >= self.q {
+            return Err(DiffieHellmanError::InvalidPrivateKey);
         }
+        let public_key = self.compute_public_key(&exponent);
+        Ok(PrivateKey {
+            exponent,
+            public_key,
+        })
     }
 
-    pub fn compute_public_key(&self, private_key: &BigUint) -> BigUint {
-        self.field.pow(&self.g, private_key)
+    /// Computes the public key g^x.
+    pub fn compute_public_key(&self, exponent: &BigUint) -> PublicKey<G::Element> {
+        PublicKey {
+            element: self.group.pow(&self.g, exponent),
+        }
+    }
+
+    /// Validates a peer's public key (SP 800-56A, section 5.6.2.3.1): it must be a valid,
+    /// invertible element whose order is greater than 2 (for `IntegerModP` this is the range check
+    /// 2 <= y <= p - 2) and y^q must be the identity. This rejects 0, 1, p - 1 and elements of
+    /// small subgroups.
+    pub fn validate_public_key(
+        &self,
+        public_key: &PublicKey<G::Element>,
+    ) -> Result<(), DiffieHellmanError> {
+        let y = &public_key.element;
+        if !self.group.is_element(y)
+            || self.group.inverse(y).is_none()
+            || self.is_identity(&self.group.combine(y, y))
+        {
+            return Err(DiffieHellmanError::PublicKeyOutOfRange);
+        }
+        if !self.is_identity(&self.group.pow(y, &self.q)) {
+            return Err(DiffieHellmanError::PublicKeyNotInSubgroup);
+        }
+        Ok(())
+    }
+
+    /// Computes the shared secret y^x after validating the peer's public key
+    /// (SP 800-56A, section 5.7.1.1).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(G::Element)` with the shared secret.
+    /// * `Err(DiffieHellmanError)` if the public key is invalid or the secret is the identity.
+    pub fn compute_shared_secret(
+        &self,
+        peer_public: &PublicKey<G::Element>,
+        private_key: &PrivateKey<G::Element>,
+    ) -> Result<G::Element, DiffieHellmanError> {
+        self.validate_public_key(peer_public)?;
+        let shared = self.group.pow(&peer_public.element, &private_key.exponent);
+        if self.is_identity(&shared) {
+            return Err(DiffieHellmanError::TrivialSharedSecret);
+        }
+        Ok(shared)
+    }
+
+    /// Computes the shared secret and encodes it as the fixed-length big-endian byte string Z
+    /// of SP 800-56A, section 5.7.1.1. Over `IntegerModP` it is left-padded to the length of
+    /// p, as RFC 7919 requires, so leading zero bytes are kept.
+    pub fn compute_shared_secret_bytes(
+        &self,
+        peer_public: &PublicKey<G::Element>,
+        private_key: &PrivateKey<G::Element>,
+    ) -> Result<Vec<u8>, DiffieHellmanError> {
+        let shared = self.compute_shared_secret(peer_public, private_key)?;
+        Ok(self.group.encode_element(&shared))
+    }
+
+    /// Derives `length` bytes of keying material from the encoded shared secret with HKDF.
+    ///
+    /// # Arguments
+    ///
+    /// * `peer_public` - The other party's public key.
+    /// * `private_key` - Our private key.
+    /// * `salt` - HKDF salt; may be empty.
+    /// * `info` - Context bound into the derived key.
+    /// * `length` - The number of bytes to derive.
+    pub fn derive_key<H: HashFunction>(
+        &self,
+        peer_public: &PublicKey<G::Element>,
+        private_key: &PrivateKey<G::Element>,
+        salt: &[u8],
+        info: &[u8],
+        length: usize,
+    ) -> Result<Vec<u8>, DiffieHellmanError> {
+        let z = self.compute_shared_secret_bytes(peer_public, private_key)?;
+        hkdf::<H>(salt, &z, info, length).map_err(DiffieHellmanError::KeyDerivation)
+    }
+
+    /// Returns true if `a` is the identity, comparing canonical encodings.
+    pub(crate) fn is_identity(&self, a: &G::Element) -> bool {
+        self.group.encode_element(a) == self.group.encode_element(&self.group.identity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_requires_a_safe_prime() {
+        let dh = DiffieHellman::new(BigUint::from(23u32), BigUint::from(2u32)).unwrap();
+        assert_eq!(dh.q, BigUint::from(11u32));
+        assert_eq!(
+            DiffieHellman::new(BigUint::from(29u32), BigUint::from(2u32)).err(),
+            Some(DiffieHellmanError::NotSafePrime)
+        );
+        assert_eq!(
+            DiffieHellman::new(BigUint::from(35u32), BigUint::from(2u32)).err(),
+            Some(DiffieHellmanError::NonPrimeModulus)
+        );
+        let dh = DiffieHellman::with_subgroup_order(
+            BigUint::from(29u32),
+            BigUint::from(7u32),
+            BigUint::from(16u32),
+        )
+        .unwrap();
+        let public_key = PublicKey {
+            element: BigUint::from(28u32),
+        };
+        assert_eq!(
+            dh.validate_public_key(&public_key),
+            Err(DiffieHellmanError::PublicKeyOutOfRange)
+        );
     }
 
-    /// Computes the shared secret: (peer_public)^private mod p
-    pub fn compute_shared_secret(&self, peer_public: &BigUint, private_key: &BigUint) -> BigUint {
-        self.field.pow(peer_public, private_key)
+    #[test]
+    fn named_groups_agree_on_a_shared_secret() {
+        let dh = DiffieHellman::named(NamedGroup::Ffdhe2048);
+        let alice = dh.generate_private_key();
+        let bob = dh.generate_private_key();
+        assert_eq!(
+            dh.compute_shared_secret(&bob.public_key, &alice).unwrap(),
+            dh.compute_shared_secret(&alice.public_key, &bob).unwrap()
+        );
+        assert_eq!(
+            NamedGroup::from_name("ffdhe2048"),
+            Some(NamedGroup::Ffdhe2048)
+        );
     }
 }
@@ -0,0 +1,144 @@
+// src/diffie_hellman/x25519.rs
+
+use super::{DiffieHellman, DiffieHellmanError, PrivateKey};
+use crate::elliptic_curve::{curve25519, AffinePoint, MontgomeryCurve};
+use num_bigint::BigUint;
+
+/// The encoded u-coordinate 9 of the Curve25519 base point.
+pub const X25519_BASE_POINT: [u8; 32] = {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 9;
+    bytes
+};
+
+/// Decodes a 32-byte scalar and clamps it (RFC 7748, section 5): the three low bits are
+/// cleared so that the scalar is a multiple of the cofactor 8, bit 255 is cleared and bit 254
+/// is set.
+pub fn clamp_scalar(scalar: &[u8; 32]) -> BigUint {
+    let mut bytes = *scalar;
+    bytes[0] &= 248;
+    bytes[31] &= 127;
+    bytes[31] |= 64;
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// The X25519 function of RFC 7748, section 5: clamps `scalar`, decodes `u` and returns the
+/// encoded u-coordinate of the scalar multiple.
+///
+/// Every 32-byte string is accepted as a u-coordinate, including points of small order and
+/// points on the twist, so the output can be all zeros; callers that need a contributory
+/// shared secret must reject that value (section 6.1).
+pub fn x25519(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let curve = MontgomeryCurve::curve25519();
+    let mut bytes = *u;
+    bytes[31] &= 127;
+    let u = BigUint::from_bytes_le(&bytes);
+    let mut out = [0u8; 32];
+    let encoded = curve.ladder(&clamp_scalar(scalar), &u).to_bytes_le();
+    out[..encoded.len()].copy_from_slice(&encoded);
+    out
+}
+
+impl DiffieHellman<MontgomeryCurve> {
+    /// Creates a Diffie-Hellman instance on Curve25519 with the base point (9, v) and its
+    /// prime order L.
+    ///
+    /// Public keys and shared secrets encode as 32-byte u-coordinates, so for keys built with
+    /// `private_key_from_bytes` they match the outputs of `x25519`. Unlike `x25519`, public keys
+    /// are validated: points outside the subgroup of order L are rejected.
+    pub fn curve25519() -> Self {
+        DiffieHellman {
+            group: MontgomeryCurve::curve25519(),
+            g: MontgomeryCurve::curve25519_base_point(),
+            q: curve25519::order(),
+            private_key_bits: None,
+        }
+    }
+
+    /// Builds the key pair for a 32-byte X25519 private key. The clamped scalar k is reduced
+    /// modulo L, which leaves kP unchanged for every P in the subgroup of order L.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PrivateKey)` unless k is a multiple of L.
+    /// * `Err(DiffieHellmanError::InvalidPrivateKey)` otherwise.
+    pub fn private_key_from_bytes(
+        &self,
+        bytes: &[u8; 32],
+    ) -> Result<PrivateKey<AffinePoint>, DiffieHellmanError> {
+        self.private_key_from_exponent(clamp_scalar(bytes) % &self.q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(value: &str) -> [u8; 32] {
+        hex::decode(value).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn rfc7748_scalar_multiplication_vectors() {
+        assert_eq!(
+            x25519(
+                &bytes("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+                &bytes("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+            ),
+            bytes("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+        assert_eq!(
+            x25519(
+                &bytes("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+                &bytes("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+            ),
+            bytes("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957")
+        );
+    }
+
+    #[test]
+    fn rfc7748_iterated_vectors() {
+        let (mut k, mut u) = (X25519_BASE_POINT, X25519_BASE_POINT);
+        for iteration in 1..=1000 {
+            (k, u) = (x25519(&k, &u), k);
+            if iteration == 1 {
+                assert_eq!(
+                    k,
+                    bytes("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            k,
+            bytes("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    fn rfc7748_diffie_hellman_vector() {
+        let alice = bytes("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = bytes("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = x25519(&alice, &X25519_BASE_POINT);
+        let bob_public = x25519(&bob, &X25519_BASE_POINT);
+        assert_eq!(
+            alice_public,
+            bytes("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            bytes("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+        let shared = bytes("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(&alice, &bob_public), shared);
+        assert_eq!(x25519(&bob, &alice_public), shared);
+
+        let dh = DiffieHellman::curve25519();
+        let alice = dh.private_key_from_bytes(&alice).unwrap();
+        let bob = dh.private_key_from_bytes(&bob).unwrap();
+        assert_eq!(
+            dh.compute_shared_secret_bytes(bob.public_key(), &alice)
+                .unwrap(),
+            shared
+        );
+    }
+}
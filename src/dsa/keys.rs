@@ -0,0 +1,84 @@
+// src/dsa/keys.rs
+
+use super::{DsaError, DsaParameters};
+use crate::algebra::traits::Group;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::rngs::OsRng;
+
+/// A DSA or Schnorr public key y = g^x mod p.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DsaPublicKey {
+    pub parameters: DsaParameters, // Domain parameters
+    pub y: BigUint,                // Public value g^x mod p
+}
+
+/// A DSA or Schnorr private key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DsaPrivateKey {
+    pub public_key: DsaPublicKey,
+    pub(crate) x: BigUint, // Private exponent in [1, q - 1]
+}
+
+impl DsaPublicKey {
+    /// Creates a public key after checking that 2 <= y <= p - 2 and y^q = 1 mod p
+    /// (SP 800-89, section 5.3.2).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DsaPublicKey)` if `y` is in the q-order subgroup.
+    /// * `Err(DsaError::InvalidPublicKey)` otherwise.
+    pub fn new(parameters: DsaParameters, y: BigUint) -> Result<Self, DsaError> {
+        let p = &parameters.p;
+        if y < BigUint::from(2u32)
+            || y > p - 2u32
+            || !parameters.field.pow(&y, &parameters.q).is_one()
+        {
+            return Err(DsaError::InvalidPublicKey);
+        }
+        Ok(DsaPublicKey { parameters, y })
+    }
+}
+
+impl DsaPrivateKey {
+    /// Generates a key pair by testing candidates (FIPS 186-4, B.1.2): c is drawn from N random
+    /// bits until c <= q - 2, and x = c + 1.
+    pub fn generate(parameters: &DsaParameters) -> Self {
+        let mut rng = OsRng;
+        let q = &parameters.q;
+        loop {
+            let c = rng.gen_biguint(q.bits());
+            if c <= q - 2u32 {
+                return Self::from_private_exponent(parameters.clone(), c + 1u32)
+                    .expect("x is in [1, q - 1]");
+            }
+        }
+    }
+
+    /// Creates the key pair for a given private exponent.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DsaPrivateKey)` if 1 <= x <= q - 1.
+    /// * `Err(DsaError::InvalidPrivateKey)` otherwise.
+    pub fn from_private_exponent(parameters: DsaParameters, x: BigUint) -> Result<Self, DsaError> {
+        if x.is_zero() || x >= parameters.q {
+            return Err(DsaError::InvalidPrivateKey);
+        }
+        let y = parameters.field.pow(&parameters.g, &x);
+        Ok(DsaPrivateKey {
+            public_key: DsaPublicKey { parameters, y },
+            x,
+        })
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &DsaPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the domain parameters of the key.
+    pub fn parameters(&self) -> &DsaParameters {
+        &self.public_key.parameters
+    }
+}
@@ -0,0 +1,68 @@
+// src/dsa/mod.rs
+
+pub mod keys;
+pub mod nonce;
+pub mod params;
+pub mod schnorr;
+pub mod signature;
+
+pub use keys::{DsaPrivateKey, DsaPublicKey};
+pub use nonce::DeterministicNonce;
+pub use params::{DomainParameterSeed, DsaParameters};
+pub use schnorr::SchnorrSignature;
+pub use signature::DsaSignature;
+
+use crate::encoding::EncodingError;
+use std::fmt;
+
+/// Errors related to DSA and Schnorr domain parameters, keys and signatures.
+#[derive(Debug)]
+pub enum DsaError {
+    UnsupportedSizes { l: u64, n: u64 },
+    HashTooShort,
+    InvalidParameters(String),
+    InvalidPublicKey,
+    InvalidPrivateKey,
+    InvalidSignature,
+    Encoding(EncodingError),
+}
+
+impl fmt::Display for DsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DsaError::UnsupportedSizes { l, n } => write!(
+                f,
+                "(L, N) = ({}, {}) is not one of the FIPS 186-4 parameter sizes.",
+                l, n
+            ),
+            DsaError::HashTooShort => {
+                write!(f, "The hash output is shorter than the subgroup order.")
+            }
+            DsaError::InvalidParameters(reason) => {
+                write!(f, "Invalid domain parameters: {}.", reason)
+            }
+            DsaError::InvalidPublicKey => {
+                write!(
+                    f,
+                    "The public key is not an element of the q-order subgroup."
+                )
+            }
+            DsaError::InvalidPrivateKey => {
+                write!(f, "The private key is not in the range [1, q - 1].")
+            }
+            DsaError::InvalidSignature => write!(f, "The signature is invalid."),
+            DsaError::Encoding(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DsaError {}
+
+impl From<EncodingError> for DsaError {
+    fn from(err: EncodingError) -> Self {
+        DsaError::Encoding(err)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+// src/dsa/nonce.rs
+
+use crate::hash::{HashFunction, Hmac};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::marker::PhantomData;
+
+/// Deterministic nonce generation for DSA-style signatures (RFC 6979, section 3.2).
+///
+/// The nonce stream is an HMAC-DRBG seeded with the private key and the message hash, so
+/// signing the same message twice yields the same signature and no randomness is needed.
+/// Call `next_nonce` again if a nonce turns out to be unusable (r = 0 or s = 0).
+pub struct DeterministicNonce<H: HashFunction> {
+    q: BigUint, // Order of the subgroup
+    k: Vec<u8>, // HMAC key K
+    v: Vec<u8>, // Chaining value V
+    _hash: PhantomData<H>,
+}
+
+impl<H: HashFunction> DeterministicNonce<H> {
+    /// Seeds the generator for the private key `x` and the message digest `h1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The order of the subgroup; nonces are in [1, q - 1].
+    /// * `x` - The private key.
+    /// * `h1` - The hash of the message, computed with `H`.
+    pub fn new(q: &BigUint, x: &BigUint, h1: &[u8]) -> Self {
+        let mut generator = DeterministicNonce {
+            q: q.clone(),
+            k: vec![0x00; H::OUTPUT_SIZE],
+            v: vec![0x01; H::OUTPUT_SIZE],
+            _hash: PhantomData,
+        };
+        let mut seed = generator.int2octets(x);
+        seed.extend(generator.bits2octets(h1));
+        generator.reseed(0x00, &seed);
+        generator.reseed(0x01, &seed);
+        generator
+    }
+
+    /// Returns the next candidate nonce in [1, q - 1].
+    pub fn next_nonce(&mut self) -> BigUint {
+        let qlen = self.q.bits() as usize;
+        loop {
+            let mut t = Vec::new();
+            while t.len() * 8 < qlen {
+                self.v = Hmac::<H>::mac(&self.k, &self.v);
+                t.extend_from_slice(&self.v);
+            }
+            let k = bits2int(&t, qlen);
+            self.reseed(0x00, &[]);
+            if !k.is_zero() && k < self.q {
+                return k;
+            }
+        }
+    }
+
+    /// K = HMAC_K(V || tag || data), V = HMAC_K(V).
+    fn reseed(&mut self, tag: u8, data: &[u8]) {
+        let mut hmac = Hmac::<H>::new(&self.k);
+        hmac.update(&self.v);
+        hmac.update(&[tag]);
+        hmac.update(data);
+        self.k = hmac.finalize();
+        self.v = Hmac::<H>::mac(&self.k, &self.v);
+    }
+
+    /// Encodes an integer modulo q as ceil(qlen / 8) bytes.
+    fn int2octets(&self, x: &BigUint) -> Vec<u8> {
+        let length = (self.q.bits() as usize).div_ceil(8);
+        let bytes = x.to_bytes_be();
+        let bytes: &[u8] = if bytes == [0] { &[] } else { &bytes };
+        let mut out = vec![0u8; length - bytes.len()];
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn bits2octets(&self, bits: &[u8]) -> Vec<u8> {
+        let z = bits2int(bits, self.q.bits() as usize) % &self.q;
+        self.int2octets(&z)
+    }
+}
+
+/// Interprets `bits` as an integer keeping only its leftmost `qlen` bits (RFC 6979, 2.3.2).
+///
+/// This is also how DSA and ECDSA turn a message digest into an integer.
+pub fn bits2int(bits: &[u8], qlen: usize) -> BigUint {
+    let x = BigUint::from_bytes_be(bits);
+    let blen = bits.len() * 8;
+    if blen > qlen {
+        x >> (blen - qlen)
+    } else {
+        x
+    }
+}
@@ -0,0 +1,276 @@
+// src/dsa/params.rs
+
+use super::DsaError;
+use crate::algebra::traits::Group;
+use crate::factor::is_probable_prime;
+use crate::hash::HashFunction;
+use crate::integer_mod_p::IntegerModP;
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// The (L, N) pairs allowed by FIPS 186-4, section 4.2.
+pub const APPROVED_SIZES: [(u64, u64); 4] = [(1024, 160), (2048, 224), (2048, 256), (3072, 256)];
+
+/// The "ggen" tag mixed into the seed when generating a verifiable generator (FIPS 186-4, A.2.3).
+const GGEN: [u8; 4] = [0x67, 0x67, 0x65, 0x6e];
+
+/// Candidates for p with a prime factor below this bound are rejected before the primality
+/// test.
+const SMALL_PRIME_BOUND: usize = 2_000;
+
+/// Index used for the generator of freshly generated parameters.
+const DEFAULT_GENERATOR_INDEX: u8 = 1;
+
+/// The values that let anyone re-derive p, q and g from a seed (FIPS 186-4, A.1.1.2 and A.2.3).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainParameterSeed {
+    pub seed: Vec<u8>,     // domain_parameter_seed
+    pub counter: u32,      // Iteration at which p was found
+    pub index: Option<u8>, // Generator index, if g was generated verifiably
+}
+
+/// DSA domain parameters: primes p and q with q | p - 1 and a generator g of order q.
+///
+/// Arithmetic modulo p happens in `field` and arithmetic on exponents and signature values
+/// in the separate scalar field `scalars` modulo q.
+#[derive(Debug, Clone)]
+pub struct DsaParameters {
+    pub p: BigUint,                        // Prime modulus of L bits
+    pub q: BigUint,                        // Prime subgroup order of N bits
+    pub g: BigUint,                        // Generator of the q-order subgroup
+    pub field: IntegerModP,                // Arithmetic modulo p
+    pub scalars: IntegerModP,              // Arithmetic modulo q
+    pub seed: Option<DomainParameterSeed>, // Generation seed, if known
+}
+
+impl PartialEq for DsaParameters {
+    fn eq(&self, other: &Self) -> bool {
+        self.p == other.p && self.q == other.q && self.g == other.g
+    }
+}
+
+impl Eq for DsaParameters {}
+
+impl DsaParameters {
+    /// Creates domain parameters from p, q and g after a partial validation: p and q must be
+    /// prime, q must divide p - 1, and g must be an element of order q (FIPS 186-4, A.2.2).
+    ///
+    /// Any sizes are accepted, which is useful for small examples; `validate` additionally
+    /// checks the approved sizes and the generation seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DsaParameters)` if the parameters pass the checks.
+    /// * `Err(DsaError::InvalidParameters)` otherwise.
+    pub fn new(p: BigUint, q: BigUint, g: BigUint) -> Result<Self, DsaError> {
+        let field = IntegerModP::new(p.clone())
+            .map_err(|_| DsaError::InvalidParameters("p is not prime".to_string()))?;
+        let scalars = IntegerModP::new(q.clone())
+            .map_err(|_| DsaError::InvalidParameters("q is not prime".to_string()))?;
+        if !(&p - 1u32).is_multiple_of(&q) {
+            return Err(DsaError::InvalidParameters(
+                "q does not divide p - 1".to_string(),
+            ));
+        }
+        if g < BigUint::from(2u32) || g >= p || !field.pow(&g, &q).is_one() {
+            return Err(DsaError::InvalidParameters(
+                "g does not generate the subgroup of order q".to_string(),
+            ));
+        }
+        Ok(DsaParameters {
+            p,
+            q,
+            g,
+            field,
+            scalars,
+            seed: None,
+        })
+    }
+
+    /// Attaches the generation seed, so that `validate` can check how p, q and g were derived.
+    pub fn with_seed(mut self, seed: DomainParameterSeed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Generates parameters of L and N bits with the probable-prime construction of FIPS 186-4,
+    /// A.1.1.2, and a verifiable canonical generator (A.2.3), using the hash function `H`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DsaParameters)` with the seed, counter and generator index recorded.
+    /// * `Err(DsaError)` if (L, N) is not an approved pair or `H` is shorter than N bits.
+    pub fn generate<H: HashFunction>(l: u64, n: u64) -> Result<Self, DsaError> {
+        check_sizes::<H>(l, n)?;
+        let mut rng = OsRng;
+        let (seed, p, q, counter) = loop {
+            let mut seed = vec![0u8; (n / 8) as usize];
+            rng.fill_bytes(&mut seed);
+            if let Some((p, q, counter)) = generate_primes::<H>(l, n, &seed) {
+                break (seed, p, q, counter);
+            }
+        };
+        let g = canonical_generator::<H>(&p, &q, &seed, DEFAULT_GENERATOR_INDEX)
+            .ok_or_else(|| DsaError::InvalidParameters("no generator for index".to_string()))?;
+        Ok(Self::new(p, q, g)?.with_seed(DomainParameterSeed {
+            seed,
+            counter,
+            index: Some(DEFAULT_GENERATOR_INDEX),
+        }))
+    }
+
+    /// Validates the parameters against FIPS 186-4.
+    ///
+    /// The sizes must be an approved (L, N) pair. If a seed is attached, p and q are re-derived
+    /// from it (A.1.1.3), and if it has a generator index, so is g (A.2.4). Without a seed only
+    /// the partial validation done by `new` is possible.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the parameters are valid.
+    /// * `Err(DsaError)` describing the first failed check.
+    pub fn validate<H: HashFunction>(&self) -> Result<(), DsaError> {
+        let l = self.p.bits();
+        let n = self.q.bits();
+        check_sizes::<H>(l, n)?;
+        let Some(seed) = &self.seed else {
+            return Ok(());
+        };
+        if seed.counter as u64 > 4 * l - 1 {
+            return Err(DsaError::InvalidParameters(
+                "counter is out of range".to_string(),
+            ));
+        }
+        if (seed.seed.len() as u64) * 8 < n {
+            return Err(DsaError::InvalidParameters(
+                "seed is shorter than q".to_string(),
+            ));
+        }
+        match generate_primes::<H>(l, n, &seed.seed) {
+            Some((p, q, counter)) if p == self.p && q == self.q && counter == seed.counter => {}
+            _ => {
+                return Err(DsaError::InvalidParameters(
+                    "p and q were not generated from the seed".to_string(),
+                ))
+            }
+        }
+        if let Some(index) = seed.index {
+            if canonical_generator::<H>(&self.p, &self.q, &seed.seed, index).as_ref()
+                != Some(&self.g)
+            {
+                return Err(DsaError::InvalidParameters(
+                    "g was not generated from the seed".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the length of q in bits (N).
+    pub fn n(&self) -> u64 {
+        self.q.bits()
+    }
+
+    /// Returns the length of p in bits (L).
+    pub fn l(&self) -> u64 {
+        self.p.bits()
+    }
+}
+
+/// Checks that (L, N) is approved and that `H` produces at least N bits.
+fn check_sizes<H: HashFunction>(l: u64, n: u64) -> Result<(), DsaError> {
+    if !APPROVED_SIZES.contains(&(l, n)) {
+        return Err(DsaError::UnsupportedSizes { l, n });
+    }
+    if (H::OUTPUT_SIZE as u64) * 8 < n {
+        return Err(DsaError::HashTooShort);
+    }
+    Ok(())
+}
+
+/// Derives p and q from `seed` as in FIPS 186-4, A.1.1.2, steps 6 to 14.
+///
+/// # Returns
+///
+/// * `Some((p, q, counter))` with the first prime p found.
+/// * `None` if the q derived from the seed is not prime or no p is found within 4L tries.
+fn generate_primes<H: HashFunction>(
+    l: u64,
+    n: u64,
+    seed: &[u8],
+) -> Option<(BigUint, BigUint, u32)> {
+    let outlen = (H::OUTPUT_SIZE * 8) as u64;
+    let seedlen = seed.len() as u64 * 8;
+    let blocks = l.div_ceil(outlen) - 1;
+    let last_bits = l - 1 - blocks * outlen;
+
+    let u = BigUint::from_bytes_be(&H::digest(seed)) % (BigUint::one() << (n - 1));
+    let q = (BigUint::one() << (n - 1)) + &u + 1u32 - (&u % 2u32);
+    if !is_probable_prime(&q) {
+        return None;
+    }
+
+    let small_primes = sieve_of_eratosthenes_bitset(SMALL_PRIME_BOUND);
+    let seed_value = BigUint::from_bytes_be(seed);
+    let seed_modulus = BigUint::one() << seedlen;
+    let two_q = &q << 1;
+    let top = BigUint::one() << (l - 1);
+    let mut offset = 1u64;
+    for counter in 0..4 * l as u32 {
+        let mut w = BigUint::zero();
+        for j in 0..=blocks {
+            let input = (&seed_value + offset + j) % &seed_modulus;
+            let mut v = BigUint::from_bytes_be(&H::digest(&to_fixed_bytes(&input, seed.len())));
+            if j == blocks {
+                v %= BigUint::one() << last_bits;
+            }
+            w += v << (j * outlen);
+        }
+        let x = w + &top;
+        let c = &x % &two_q;
+        let p: BigUint = x + 1u32 - c;
+        let has_small_factor = small_primes
+            .iter()
+            .any(|&prime| (&p % prime as u64).is_zero());
+        if p >= top && !has_small_factor && is_probable_prime(&p) {
+            return Some((p, q, counter));
+        }
+        offset += blocks + 1;
+    }
+    None
+}
+
+/// Derives the verifiable canonical generator for `index` (FIPS 186-4, A.2.3).
+fn canonical_generator<H: HashFunction>(
+    p: &BigUint,
+    q: &BigUint,
+    seed: &[u8],
+    index: u8,
+) -> Option<BigUint> {
+    let e = (p - 1u32) / q;
+    for count in 1..=u16::MAX {
+        let mut input = seed.to_vec();
+        input.extend_from_slice(&GGEN);
+        input.push(index);
+        input.extend_from_slice(&count.to_be_bytes());
+        let w = BigUint::from_bytes_be(&H::digest(&input));
+        let g = w.modpow(&e, p);
+        if g >= BigUint::from(2u32) {
+            return Some(g);
+        }
+    }
+    None
+}
+
+/// Big-endian encoding of `x` left-padded to `length` bytes.
+fn to_fixed_bytes(x: &BigUint, length: usize) -> Vec<u8> {
+    let bytes = x.to_bytes_be();
+    let bytes: &[u8] = if bytes == [0] { &[] } else { &bytes };
+    let mut out = vec![0u8; length - bytes.len()];
+    out.extend_from_slice(bytes);
+    out
+}
@@ -0,0 +1,100 @@
+// src/dsa/schnorr.rs
+
+use super::nonce::{bits2int, DeterministicNonce};
+use super::{DsaError, DsaParameters, DsaPrivateKey, DsaPublicKey};
+use crate::algebra::traits::{ElementEncoding, Group, Ring};
+use crate::hash::HashFunction;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// A Schnorr signature (e, s) over the q-order subgroup of Z_p*, with e = H(r || m) for the
+/// commitment r = g^k and s = k - x e mod q.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchnorrSignature {
+    pub e: BigUint, // Challenge, the leftmost N bits of H(r || m)
+    pub s: BigUint, // Response k - x e mod q
+}
+
+impl SchnorrSignature {
+    /// Encodes the signature as e || s, each padded to ceil(N / 8) bytes.
+    pub fn to_bytes(&self, parameters: &DsaParameters) -> Vec<u8> {
+        let mut out = parameters.scalars.encode_element(&self.e);
+        out.extend(parameters.scalars.encode_element(&self.s));
+        out
+    }
+
+    /// Decodes a signature produced by `to_bytes`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SchnorrSignature)` on success.
+    /// * `Err(DsaError::InvalidSignature)` if the length is wrong.
+    pub fn from_bytes(bytes: &[u8], parameters: &DsaParameters) -> Result<Self, DsaError> {
+        let length = (parameters.n() as usize).div_ceil(8);
+        if bytes.len() != 2 * length {
+            return Err(DsaError::InvalidSignature);
+        }
+        Ok(SchnorrSignature {
+            e: BigUint::from_bytes_be(&bytes[..length]),
+            s: BigUint::from_bytes_be(&bytes[length..]),
+        })
+    }
+}
+
+impl DsaPrivateKey {
+    /// Signs `message` with a Schnorr signature. The nonce is derived deterministically from
+    /// the key and the message hash as in RFC 6979.
+    pub fn sign_schnorr<H: HashFunction>(&self, message: &[u8]) -> SchnorrSignature {
+        let parameters = self.parameters();
+        let scalars = &parameters.scalars;
+        let mut nonces = DeterministicNonce::<H>::new(&parameters.q, &self.x, &H::digest(message));
+        loop {
+            let k = nonces.next_nonce();
+            let r = parameters.field.pow(&parameters.g, &k);
+            let e = challenge::<H>(parameters, &r, message);
+            let s = scalars.sub(&k, &scalars.mul(&self.x, &e));
+            if !e.is_zero() && !s.is_zero() {
+                return SchnorrSignature { e, s };
+            }
+        }
+    }
+}
+
+impl DsaPublicKey {
+    /// Verifies a Schnorr signature by recomputing r = g^s y^e and checking e = H(r || m).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(DsaError::InvalidSignature)` otherwise.
+    pub fn verify_schnorr<H: HashFunction>(
+        &self,
+        message: &[u8],
+        signature: &SchnorrSignature,
+    ) -> Result<(), DsaError> {
+        let parameters = &self.parameters;
+        let q = &parameters.q;
+        if signature.e.is_zero() || &signature.e >= q || signature.s.is_zero() || &signature.s >= q
+        {
+            return Err(DsaError::InvalidSignature);
+        }
+        let field = &parameters.field;
+        let r = field.mul(
+            &field.pow(&parameters.g, &signature.s),
+            &field.pow(&self.y, &signature.e),
+        );
+        if challenge::<H>(parameters, &r, message) == signature.e {
+            Ok(())
+        } else {
+            Err(DsaError::InvalidSignature)
+        }
+    }
+}
+
+/// Computes e = H(r || m), keeping the leftmost N bits and reducing modulo q.
+fn challenge<H: HashFunction>(parameters: &DsaParameters, r: &BigUint, message: &[u8]) -> BigUint {
+    let mut hasher = H::new();
+    hasher.update(&parameters.field.encode_element(r));
+    hasher.update(message);
+    bits2int(&hasher.finalize(), parameters.n() as usize) % &parameters.q
+}
@@ -0,0 +1,143 @@
+// src/dsa/signature.rs
+
+use super::nonce::{bits2int, DeterministicNonce};
+use super::{DsaError, DsaPrivateKey, DsaPublicKey};
+use crate::algebra::traits::{ElementEncoding, Group, Ring};
+use crate::encoding::der::{self, DerReader};
+use crate::hash::{HashFunction, Sha256};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// A DSA signature (r, s).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DsaSignature {
+    pub r: BigUint, // (g^k mod p) mod q
+    pub s: BigUint, // k^(-1) (z + x r) mod q
+}
+
+impl DsaSignature {
+    /// Encodes the signature as the DER structure `Dss-Sig-Value ::= SEQUENCE { r, s }`
+    /// (RFC 3279, section 2.2.2).
+    pub fn to_der(&self) -> Vec<u8> {
+        der::encode_sequence(&[der::encode_integer(&self.r), der::encode_integer(&self.s)])
+    }
+
+    /// Decodes a DER `Dss-Sig-Value`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, DsaError> {
+        let mut outer = DerReader::new(bytes);
+        let mut fields = outer.read_sequence()?;
+        outer.finish()?;
+        let r = fields.read_integer()?;
+        let s = fields.read_integer()?;
+        fields.finish()?;
+        Ok(DsaSignature { r, s })
+    }
+}
+
+impl DsaPrivateKey {
+    /// Signs `message` with a deterministic nonce derived from the key and the message hash
+    /// (RFC 6979), using `H` both for the message digest and for the nonce generator.
+    pub fn sign<H: HashFunction>(&self, message: &[u8]) -> DsaSignature {
+        let digest = H::digest(message);
+        let mut nonces = DeterministicNonce::<H>::new(&self.parameters().q, &self.x, &digest);
+        loop {
+            if let Some(signature) = self.sign_digest_with_nonce(&digest, &nonces.next_nonce()) {
+                return signature;
+            }
+        }
+    }
+
+    /// Signs `message` with the caller-supplied nonce `k` (FIPS 186-4, section 4.6). This is
+    /// only meant for reproducing test vectors: reusing or leaking a nonce reveals the key.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DsaSignature)` on success.
+    /// * `None` if k is not in [1, q - 1] or r or s comes out as zero.
+    pub fn sign_with_nonce<H: HashFunction>(
+        &self,
+        message: &[u8],
+        k: &BigUint,
+    ) -> Option<DsaSignature> {
+        self.sign_digest_with_nonce(&H::digest(message), k)
+    }
+
+    fn sign_digest_with_nonce(&self, digest: &[u8], k: &BigUint) -> Option<DsaSignature> {
+        let parameters = self.parameters();
+        let scalars = &parameters.scalars;
+        if k.is_zero() || k >= &parameters.q {
+            return None;
+        }
+        let r = parameters.field.pow(&parameters.g, k) % &parameters.q;
+        if r.is_zero() {
+            return None;
+        }
+        let z = bits2int(digest, parameters.n() as usize) % &parameters.q;
+        let k_inv = Group::inverse(scalars, k)?;
+        let s = scalars.mul(&k_inv, &scalars.add(&z, &scalars.mul(&self.x, &r)));
+        if s.is_zero() {
+            return None;
+        }
+        Some(DsaSignature { r, s })
+    }
+}
+
+impl DsaPublicKey {
+    /// Verifies a DSA signature (FIPS 186-4, section 4.7).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(DsaError::InvalidSignature)` otherwise.
+    pub fn verify<H: HashFunction>(
+        &self,
+        message: &[u8],
+        signature: &DsaSignature,
+    ) -> Result<(), DsaError> {
+        let parameters = &self.parameters;
+        let scalars = &parameters.scalars;
+        let q = &parameters.q;
+        if signature.r.is_zero() || &signature.r >= q || signature.s.is_zero() || &signature.s >= q
+        {
+            return Err(DsaError::InvalidSignature);
+        }
+        let w = Group::inverse(scalars, &signature.s).ok_or(DsaError::InvalidSignature)?;
+        let z = bits2int(&H::digest(message), parameters.n() as usize) % q;
+        let u1 = scalars.mul(&z, &w);
+        let u2 = scalars.mul(&signature.r, &w);
+        let field = &parameters.field;
+        let v = field.mul(&field.pow(&parameters.g, &u1), &field.pow(&self.y, &u2)) % q;
+        if v == signature.r {
+            Ok(())
+        } else {
+            Err(DsaError::InvalidSignature)
+        }
+    }
+}
+
+impl SigningKey for DsaPrivateKey {
+    type VerifyingKey = DsaPublicKey;
+
+    /// Signs with SHA-256 and deterministic nonces; the signature is DER encoded.
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        DsaPrivateKey::sign::<Sha256>(self, message).to_der()
+    }
+
+    fn verifying_key(&self) -> DsaPublicKey {
+        self.public_key.clone()
+    }
+}
+
+impl VerifyingKey for DsaPublicKey {
+    /// Encodes y as a big-endian integer padded to the length of p.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.parameters.field.encode_element(&self.y)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        DsaSignature::from_der(signature)
+            .map(|signature| DsaPublicKey::verify::<Sha256>(self, message, &signature).is_ok())
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,278 @@
+// src/dsa/tests.rs
+
+// Known-answer tests from RFC 6979, appendix A.2.1 (1024-bit DSA) and A.2.2 (2048-bit DSA).
+
+use super::{
+    DeterministicNonce, DsaError, DsaParameters, DsaPrivateKey, DsaPublicKey, DsaSignature,
+    SchnorrSignature,
+};
+use crate::hash::{HashFunction, Sha224, Sha256, Sha384, Sha512};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+
+/// A message signed with a hash function, with the expected nonce and signature.
+struct Vector {
+    message: &'static str,
+    k: &'static str,
+    r: &'static str,
+    s: &'static str,
+}
+
+fn hex(value: &str) -> BigUint {
+    BigUint::parse_bytes(value.as_bytes(), 16).unwrap()
+}
+
+/// The key of RFC 6979, A.2.1.
+fn key_1024() -> DsaPrivateKey {
+    let p = hex(concat!(
+        "86F5CA03DCFEB225063FF830A0C769B9DD9D6153AD91D7CE27F787C43278B447",
+        "E6533B86B18BED6E8A48B784A14C252C5BE0DBF60B86D6385BD2F12FB763ED88",
+        "73ABFD3F5BA2E0A8C0A59082EAC056935E529DAF7C610467899C77ADEDFC846C",
+        "881870B7B19B2B58F9BE0521A17002E3BDD6B86685EE90B3D9A1B02B782B1779",
+    ));
+    let q = hex("996F967F6C8E388D9E28D01E205FBA957A5698B1");
+    let g = hex(concat!(
+        "07B0F92546150B62514BB771E2A0C0CE387F03BDA6C56B505209FF25FD3C133D",
+        "89BBCD97E904E09114D9A7DEFDEADFC9078EA544D2E401AEECC40BB9FBBF78FD",
+        "87995A10A1C27CB7789B594BA7EFB5C4326A9FE59A070E136DB77175464ADCA4",
+        "17BE5DCE2F40D10A46A3A3943F26AB7FD9C0398FF8C76EE0A56826A8A88F1DBD",
+    ));
+    let x = hex("411602CB19A6CCC34494D79D98EF1E7ED5AF25F7");
+    let y = hex(concat!(
+        "5DF5E01DED31D0297E274E1691C192FE5868FEF9E19A84776454B100CF16F653",
+        "92195A38B90523E2542EE61871C0440CB87C322FC4B4D2EC5E1E7EC766E1BE8D",
+        "4CE935437DC11C3C8FD426338933EBFE739CB3465F4D3668C5E473508253B1E6",
+        "82F65CBDC4FAE93C2EA212390E54905A86E2223170B44EAA7DA5DD9FFCFB7F3B",
+    ));
+    let key =
+        DsaPrivateKey::from_private_exponent(DsaParameters::new(p, q, g).unwrap(), x).unwrap();
+    assert_eq!(key.public_key.y, y);
+    key
+}
+
+/// The key of RFC 6979, A.2.2.
+fn key_2048() -> DsaPrivateKey {
+    let p = hex(concat!(
+        "9DB6FB5951B66BB6FE1E140F1D2CE5502374161FD6538DF1648218642F0B5C48",
+        "C8F7A41AADFA187324B87674FA1822B00F1ECF8136943D7C55757264E5A1A44F",
+        "FE012E9936E00C1D3E9310B01C7D179805D3058B2A9F4BB6F9716BFE6117C6B5",
+        "B3CC4D9BE341104AD4A80AD6C94E005F4B993E14F091EB51743BF33050C38DE2",
+        "35567E1B34C3D6A5C0CEAA1A0F368213C3D19843D0B4B09DCB9FC72D39C8DE41",
+        "F1BF14D4BB4563CA28371621CAD3324B6A2D392145BEBFAC748805236F5CA2FE",
+        "92B871CD8F9C36D3292B5509CA8CAA77A2ADFC7BFD77DDA6F71125A7456FEA15",
+        "3E433256A2261C6A06ED3693797E7995FAD5AABBCFBE3EDA2741E375404AE25B",
+    ));
+    let q = hex("F2C3119374CE76C9356990B465374A17F23F9ED35089BD969F61C6DDE9998C1F");
+    let g = hex(concat!(
+        "5C7FF6B06F8F143FE8288433493E4769C4D988ACE5BE25A0E24809670716C613",
+        "D7B0CEE6932F8FAA7C44D2CB24523DA53FBE4F6EC3595892D1AA58C4328A06C4",
+        "6A15662E7EAA703A1DECF8BBB2D05DBE2EB956C142A338661D10461C0D135472",
+        "085057F3494309FFA73C611F78B32ADBB5740C361C9F35BE90997DB2014E2EF5",
+        "AA61782F52ABEB8BD6432C4DD097BC5423B285DAFB60DC364E8161F4A2A35ACA",
+        "3A10B1C4D203CC76A470A33AFDCBDD92959859ABD8B56E1725252D78EAC66E71",
+        "BA9AE3F1DD2487199874393CD4D832186800654760E1E34C09E4D155179F9EC0",
+        "DC4473F996BDCE6EED1CABED8B6F116F7AD9CF505DF0F998E34AB27514B0FFE7",
+    ));
+    let x = hex("69C7548C21D0DFEA6B9A51C9EAD4E27C33D3B3F180316E5BCAB92C933F0E4DBC");
+    let y = hex(concat!(
+        "667098C654426C78D7F8201EAC6C203EF030D43605032C2F1FA937E5237DBD94",
+        "9F34A0A2564FE126DC8B715C5141802CE0979C8246463C40E6B6BDAA2513FA61",
+        "1728716C2E4FD53BC95B89E69949D96512E873B9C8F8DFD499CC312882561ADE",
+        "CB31F658E934C0C197F2C4D96B05CBAD67381E7B768891E4DA3843D24D94CDFB",
+        "5126E9B8BF21E8358EE0E0A30EF13FD6A664C0DCE3731F7FB49A4845A4FD8254",
+        "687972A2D382599C9BAC4E0ED7998193078913032558134976410B89D2C171D1",
+        "23AC35FD977219597AA7D15C1A9A428E59194F75C721EBCBCFAE44696A499AFA",
+        "74E04299F132026601638CB87AB79190D4A0986315DA8EEC6561C938996BEADF",
+    ));
+    let key =
+        DsaPrivateKey::from_private_exponent(DsaParameters::new(p, q, g).unwrap(), x).unwrap();
+    assert_eq!(key.public_key.y, y);
+    key
+}
+
+/// Checks the RFC 6979 nonce, the signature and its verification for one vector.
+fn check<H: HashFunction>(key: &DsaPrivateKey, vector: &Vector) {
+    let message = vector.message.as_bytes();
+    let q = &key.parameters().q;
+    let mut nonces = DeterministicNonce::<H>::new(q, &key.x, &H::digest(message));
+    assert_eq!(
+        nonces.next_nonce(),
+        hex(vector.k),
+        "k for {:?}",
+        vector.message
+    );
+    let expected = DsaSignature {
+        r: hex(vector.r),
+        s: hex(vector.s),
+    };
+    assert_eq!(key.sign::<H>(message), expected);
+    assert_eq!(
+        key.sign_with_nonce::<H>(message, &hex(vector.k)),
+        Some(expected.clone())
+    );
+    assert!(key.public_key.verify::<H>(message, &expected).is_ok());
+    assert!(key.public_key.verify::<H>(b"other", &expected).is_err());
+}
+
+#[test]
+fn rfc6979_dsa_1024_vectors() {
+    let key = key_1024();
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "562097C06782D60C3037BA7BE104774344687649",
+            r: "4BC3B686AEA70145856814A6F1BB53346F02101E",
+            s: "410697B92295D994D21EDD2F4ADA85566F6F94C1",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "519BA0546D0C39202A7D34D7DFA5E760B318BCFB",
+            r: "81F2F5850BE5BC123C43F71A3033E9384611C545",
+            s: "4CDD914B65EB6C66A8AAAD27299BEE6B035F5E89",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "95897CD7BBB944AA932DBC579C1C09EB6FCFC595",
+            r: "07F2108557EE0E3921BC1774F1CA9B410B4CE65A",
+            s: "54DF70456C86FAC10FAB47C1949AB83F2C6F7595",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "09ECE7CA27D0F5A4DD4E556C9DF1D21D28104F8B",
+            r: "16C3491F9B8C3FBBDD5E7A7B667057F0D8EE8E1B",
+            s: "02C36A127A7B89EDBB72E4FFBC71DABC7D4FC69C",
+        },
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "4598B8EFC1A53BC8AECD58D1ABBB0C0C71E67297",
+            r: "6868E9964E36C1689F6037F91F28D5F2C30610F2",
+            s: "49CEC3ACDC83018C5BD2674ECAAD35B8CD22940F",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "5A67592E8128E03A417B0484410FB72C0B630E1A",
+            r: "22518C127299B0F6FDC9872B282B9E70D0790812",
+            s: "6837EC18F150D55DE95B5E29BE7AF5D01E4FE160",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "220156B761F6CA5E6C9F1B9CF9C24BE25F98CD89",
+            r: "854CF929B58D73C3CBFDC421E8D5430CD6DB5E66",
+            s: "91D0E0F53E22F898D158380676A871A157CDA622",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "65D2C2EEB175E370F28C75BFCDC028D22C7DBE9C",
+            r: "8EA47E475BA8AC6F2D821DA3BD212D11A3DEB9A0",
+            s: "7C670C7AD72B6C050C109E1790008097125433E8",
+        },
+    );
+}
+
+#[test]
+fn rfc6979_dsa_2048_vectors() {
+    let key = key_2048();
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "8926A27C40484216F052F4427CFD5647338B7B3939BC6573AF4333569D597C52",
+            r: "EACE8BDBBE353C432A795D9EC556C6D021F7A03F42C36E9BC87E4AC7932CC809",
+            s: "7081E175455F9247B812B74583E9E94F9EA79BD640DC962533B0680793A38D53",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "1D6CE6DDA1C5D37307839CD03AB0A5CBB18E60D800937D67DFB4479AAC8DEAD7",
+            r: "8190012A1969F9957D56FCCAAD223186F423398D58EF5B3CEFD5A4146A4476F0",
+            s: "7452A53F7075D417B4B013B278D1BB8BBD21863F5E7B1CEE679CF2188E1AB19E",
+        },
+    );
+}
+
+#[test]
+fn der_signatures_round_trip() {
+    let key = key_1024();
+    let signature = SigningKey::sign(&key, b"sample");
+    assert_eq!(
+        DsaSignature::from_der(&signature).unwrap(),
+        key.sign::<Sha256>(b"sample")
+    );
+    assert!(VerifyingKey::verify(
+        key.public_key(),
+        b"sample",
+        &signature
+    ));
+    assert!(!VerifyingKey::verify(key.public_key(), b"test", &signature));
+    assert!(!VerifyingKey::verify(
+        key.public_key(),
+        b"sample",
+        &signature[..signature.len() - 1]
+    ));
+}
+
+#[test]
+fn schnorr_signatures_round_trip() {
+    let key = key_2048();
+    let parameters = key.parameters();
+    let signature = key.sign_schnorr::<Sha256>(b"sample");
+    assert_eq!(key.sign_schnorr::<Sha256>(b"sample"), signature);
+    let decoded =
+        SchnorrSignature::from_bytes(&signature.to_bytes(parameters), parameters).unwrap();
+    assert_eq!(decoded, signature);
+    assert!(key
+        .public_key
+        .verify_schnorr::<Sha256>(b"sample", &decoded)
+        .is_ok());
+    assert!(key
+        .public_key
+        .verify_schnorr::<Sha256>(b"test", &decoded)
+        .is_err());
+}
+
+#[test]
+fn rejects_invalid_keys_and_parameters() {
+    let key = key_1024();
+    let parameters = key.parameters().clone();
+    assert!(matches!(
+        DsaPrivateKey::from_private_exponent(parameters.clone(), parameters.q.clone()),
+        Err(DsaError::InvalidPrivateKey)
+    ));
+    assert!(matches!(
+        DsaPublicKey::new(parameters.clone(), BigUint::from(1u32)),
+        Err(DsaError::InvalidPublicKey)
+    ));
+    assert!(matches!(
+        DsaParameters::new(
+            parameters.p.clone(),
+            parameters.q.clone(),
+            BigUint::from(2u32)
+        ),
+        Err(DsaError::InvalidParameters(_))
+    ));
+    assert!(matches!(parameters.validate::<Sha256>(), Ok(())));
+}
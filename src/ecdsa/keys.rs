@@ -0,0 +1,86 @@
+// src/ecdsa/keys.rs
+
+use super::{EcdsaError, EcdsaParameters};
+use crate::elliptic_curve::AffinePoint;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+use rand::rngs::OsRng;
+
+/// An ECDSA public key Q = dG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaPublicKey {
+    pub parameters: EcdsaParameters, // Domain parameters
+    pub q: AffinePoint,              // Public point dG
+}
+
+/// An ECDSA private key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaPrivateKey {
+    pub public_key: EcdsaPublicKey,
+    pub(crate) d: BigUint, // Private scalar in [1, n - 1]
+}
+
+impl EcdsaPublicKey {
+    /// Creates a public key after the full validation of SEC 1, section 3.2.2.1: Q is not the
+    /// point at infinity, lies on the curve and nQ = O.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EcdsaPublicKey)` if `q` is a point of order n.
+    /// * `Err(EcdsaError::InvalidPublicKey)` otherwise.
+    pub fn new(parameters: EcdsaParameters, q: AffinePoint) -> Result<Self, EcdsaError> {
+        if q.is_infinity()
+            || !parameters.curve.is_on_curve(&q)
+            || !parameters.curve.scalar_mul(&parameters.n, &q).is_infinity()
+        {
+            return Err(EcdsaError::InvalidPublicKey);
+        }
+        Ok(EcdsaPublicKey { parameters, q })
+    }
+}
+
+impl EcdsaPrivateKey {
+    /// Generates a key pair by testing candidates (FIPS 186-4, B.4.2): c is drawn from
+    /// bits(n) random bits until c <= n - 2, and d = c + 1.
+    pub fn generate(parameters: &EcdsaParameters) -> Self {
+        let mut rng = OsRng;
+        let n = &parameters.n;
+        loop {
+            let c = rng.gen_biguint(n.bits());
+            if c <= n - 2u32 {
+                return Self::from_private_scalar(parameters.clone(), c + 1u32)
+                    .expect("d is in [1, n - 1]");
+            }
+        }
+    }
+
+    /// Creates the key pair for a given private scalar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EcdsaPrivateKey)` if 1 <= d <= n - 1.
+    /// * `Err(EcdsaError::InvalidPrivateKey)` otherwise.
+    pub fn from_private_scalar(
+        parameters: EcdsaParameters,
+        d: BigUint,
+    ) -> Result<Self, EcdsaError> {
+        if d.is_zero() || d >= parameters.n {
+            return Err(EcdsaError::InvalidPrivateKey);
+        }
+        let q = parameters.curve.scalar_mul(&d, &parameters.g);
+        Ok(EcdsaPrivateKey {
+            public_key: EcdsaPublicKey { parameters, q },
+            d,
+        })
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &EcdsaPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the domain parameters of the key.
+    pub fn parameters(&self) -> &EcdsaParameters {
+        &self.public_key.parameters
+    }
+}
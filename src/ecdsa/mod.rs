@@ -0,0 +1,55 @@
+// src/ecdsa/mod.rs
+
+pub mod keys;
+pub mod params;
+pub mod signature;
+
+pub use keys::{EcdsaPrivateKey, EcdsaPublicKey};
+pub use params::EcdsaParameters;
+pub use signature::EcdsaSignature;
+
+use crate::encoding::EncodingError;
+use std::fmt;
+
+/// Errors related to ECDSA domain parameters, keys and signatures.
+#[derive(Debug)]
+pub enum EcdsaError {
+    InvalidParameters(String),
+    InvalidPublicKey,
+    InvalidPrivateKey,
+    InvalidSignature,
+    InvalidRecoveryId(u8),
+    Encoding(EncodingError),
+}
+
+impl fmt::Display for EcdsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EcdsaError::InvalidParameters(reason) => {
+                write!(f, "Invalid domain parameters: {}.", reason)
+            }
+            EcdsaError::InvalidPublicKey => {
+                write!(f, "The public key is not a point of order n on the curve.")
+            }
+            EcdsaError::InvalidPrivateKey => {
+                write!(f, "The private key is not in the range [1, n - 1].")
+            }
+            EcdsaError::InvalidSignature => write!(f, "The signature is invalid."),
+            EcdsaError::InvalidRecoveryId(v) => {
+                write!(f, "No public key can be recovered with recovery id {}.", v)
+            }
+            EcdsaError::Encoding(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for EcdsaError {}
+
+impl From<EncodingError> for EcdsaError {
+    fn from(err: EncodingError) -> Self {
+        EcdsaError::Encoding(err)
+    }
+}
+
+#[cfg(test)]
+mod tests;
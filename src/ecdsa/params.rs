@@ -0,0 +1,70 @@
+// src/ecdsa/params.rs
+
+use super::EcdsaError;
+use crate::elliptic_curve::{AffinePoint, Curve, NamedCurve};
+use crate::factor::is_probable_prime;
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+
+/// ECDSA domain parameters: a curve, a base point G and its prime order n.
+///
+/// Point arithmetic happens on `curve` and arithmetic on private keys and signature values
+/// in the scalar field `scalars` modulo n.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaParameters {
+    pub curve: Curve,         // Curve over GF(p)
+    pub g: AffinePoint,       // Base point
+    pub n: BigUint,           // Prime order of G
+    pub scalars: IntegerModP, // Arithmetic modulo n
+}
+
+impl EcdsaParameters {
+    /// Creates domain parameters after checking that n is prime, G is a finite point on the
+    /// curve and nG = O (SEC 1, section 3.1.1.2.1, without the security-level checks).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EcdsaParameters)` if the parameters pass the checks.
+    /// * `Err(EcdsaError::InvalidParameters)` otherwise.
+    pub fn new(curve: Curve, g: AffinePoint, n: BigUint) -> Result<Self, EcdsaError> {
+        if !is_probable_prime(&n) {
+            return Err(EcdsaError::InvalidParameters("n is not prime".to_string()));
+        }
+        if g.is_infinity() || !curve.is_on_curve(&g) {
+            return Err(EcdsaError::InvalidParameters(
+                "G is not a finite point on the curve".to_string(),
+            ));
+        }
+        if !curve.scalar_mul(&n, &g).is_infinity() {
+            return Err(EcdsaError::InvalidParameters(
+                "G does not have order n".to_string(),
+            ));
+        }
+        Ok(EcdsaParameters {
+            scalars: IntegerModP::new_valid_prime(n.clone()),
+            curve,
+            g,
+            n,
+        })
+    }
+
+    /// Returns the parameters of a named curve, such as P-256 or secp256k1.
+    pub fn named(curve: NamedCurve) -> Self {
+        EcdsaParameters {
+            curve: Curve::named(curve),
+            g: curve.generator(),
+            n: curve.order(),
+            scalars: IntegerModP::new_valid_prime(curve.order()),
+        }
+    }
+
+    /// Returns the length of n in bits, which is how many bits of a digest are used.
+    pub fn n_bits(&self) -> u64 {
+        self.n.bits()
+    }
+
+    /// Returns the byte length of a field element.
+    pub fn field_length(&self) -> usize {
+        self.curve.field_length()
+    }
+}
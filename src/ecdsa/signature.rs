@@ -0,0 +1,250 @@
+// src/ecdsa/signature.rs
+
+use super::{EcdsaError, EcdsaParameters, EcdsaPrivateKey, EcdsaPublicKey};
+use crate::algebra::traits::{Group, Ring};
+use crate::dsa::nonce::{bits2int, DeterministicNonce};
+use crate::elliptic_curve::AffinePoint;
+use crate::encoding::der::{self, DerReader};
+use crate::hash::{HashFunction, Sha256};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// An ECDSA signature (r, s).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaSignature {
+    pub r: BigUint, // x-coordinate of kG mod n
+    pub s: BigUint, // k^(-1) (e + d r) mod n
+}
+
+impl EcdsaSignature {
+    /// Encodes the signature as the DER structure `Ecdsa-Sig-Value ::= SEQUENCE { r, s }`
+    /// (RFC 3279, section 2.2.3).
+    pub fn to_der(&self) -> Vec<u8> {
+        der::encode_sequence(&[der::encode_integer(&self.r), der::encode_integer(&self.s)])
+    }
+
+    /// Decodes a DER `Ecdsa-Sig-Value`. Non-minimal integers, negative values and trailing
+    /// bytes are rejected.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let mut outer = DerReader::new(bytes);
+        let mut fields = outer.read_sequence()?;
+        outer.finish()?;
+        let r = fields.read_integer()?;
+        let s = fields.read_integer()?;
+        fields.finish()?;
+        Ok(EcdsaSignature { r, s })
+    }
+
+    /// Returns true if s <= n / 2. (r, s) and (r, n - s) are both valid, so systems that
+    /// must not accept a second encoding of the same signature, such as Bitcoin (BIP 62),
+    /// require the low value.
+    pub fn is_low_s(&self, parameters: &EcdsaParameters) -> bool {
+        self.s <= &parameters.n >> 1
+    }
+
+    /// Returns the equivalent signature with s <= n / 2.
+    pub fn normalize_s(&self, parameters: &EcdsaParameters) -> Self {
+        if self.is_low_s(parameters) {
+            self.clone()
+        } else {
+            EcdsaSignature {
+                r: self.r.clone(),
+                s: &parameters.n - &self.s,
+            }
+        }
+    }
+}
+
+impl EcdsaPrivateKey {
+    /// Signs `message` with a deterministic nonce derived from the key and the message hash
+    /// (RFC 6979), using `H` both for the message digest and for the nonce generator.
+    pub fn sign<H: HashFunction>(&self, message: &[u8]) -> EcdsaSignature {
+        self.sign_recoverable_unnormalized::<H>(message).0
+    }
+
+    /// Signs `message` like `sign`, normalizes s to the low half and returns the recovery id
+    /// v in [0, 3] that `EcdsaPublicKey::recover` needs: bit 0 is the parity of the
+    /// y-coordinate of R = kG and bit 1 is set when its x-coordinate was at least n.
+    pub fn sign_recoverable<H: HashFunction>(&self, message: &[u8]) -> (EcdsaSignature, u8) {
+        let (signature, v) = self.sign_recoverable_unnormalized::<H>(message);
+        if signature.is_low_s(self.parameters()) {
+            (signature, v)
+        } else {
+            // Negating s corresponds to the nonce -k, whose R has the opposite y parity.
+            (signature.normalize_s(self.parameters()), v ^ 1)
+        }
+    }
+
+    /// Signs `message` with the caller-supplied nonce `k` (SEC 1, section 4.1.3). This is
+    /// only meant for reproducing test vectors: reusing or leaking a nonce reveals the key.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(EcdsaSignature)` on success.
+    /// * `None` if k is not in [1, n - 1] or r or s comes out as zero.
+    pub fn sign_with_nonce<H: HashFunction>(
+        &self,
+        message: &[u8],
+        k: &BigUint,
+    ) -> Option<EcdsaSignature> {
+        self.sign_digest_with_nonce(&H::digest(message), k)
+            .map(|(signature, _)| signature)
+    }
+
+    fn sign_recoverable_unnormalized<H: HashFunction>(
+        &self,
+        message: &[u8],
+    ) -> (EcdsaSignature, u8) {
+        let digest = H::digest(message);
+        let mut nonces = DeterministicNonce::<H>::new(&self.parameters().n, &self.d, &digest);
+        loop {
+            if let Some(signature) = self.sign_digest_with_nonce(&digest, &nonces.next_nonce()) {
+                return signature;
+            }
+        }
+    }
+
+    fn sign_digest_with_nonce(&self, digest: &[u8], k: &BigUint) -> Option<(EcdsaSignature, u8)> {
+        let parameters = self.parameters();
+        let scalars = &parameters.scalars;
+        let n = &parameters.n;
+        if k.is_zero() || k >= n {
+            return None;
+        }
+        let AffinePoint::Finite { x, y } = parameters.curve.scalar_mul(k, &parameters.g) else {
+            return None;
+        };
+        let r = &x % n;
+        if r.is_zero() {
+            return None;
+        }
+        let e = bits2int(digest, parameters.n_bits() as usize) % n;
+        let k_inv = Group::inverse(scalars, k)?;
+        let s = scalars.mul(&k_inv, &scalars.add(&e, &scalars.mul(&self.d, &r)));
+        if s.is_zero() {
+            return None;
+        }
+        let v = u8::from(y.bit(0)) | (u8::from(&x >= n) << 1);
+        Some((EcdsaSignature { r, s }, v))
+    }
+}
+
+impl EcdsaPublicKey {
+    /// Verifies an ECDSA signature (SEC 1, section 4.1.4). Both s and n - s are accepted;
+    /// check `EcdsaSignature::is_low_s` as well where only one may be.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(EcdsaError::InvalidSignature)` otherwise.
+    pub fn verify<H: HashFunction>(
+        &self,
+        message: &[u8],
+        signature: &EcdsaSignature,
+    ) -> Result<(), EcdsaError> {
+        let parameters = &self.parameters;
+        let scalars = &parameters.scalars;
+        let n = &parameters.n;
+        if signature.r.is_zero() || &signature.r >= n || signature.s.is_zero() || &signature.s >= n
+        {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        let w = Group::inverse(scalars, &signature.s).ok_or(EcdsaError::InvalidSignature)?;
+        let e = bits2int(&H::digest(message), parameters.n_bits() as usize) % n;
+        let u1 = scalars.mul(&e, &w);
+        let u2 = scalars.mul(&signature.r, &w);
+        let curve = &parameters.curve;
+        let point = curve.add(
+            &curve.scalar_mul(&u1, &parameters.g),
+            &curve.scalar_mul(&u2, &self.q),
+        );
+        match point {
+            AffinePoint::Finite { x, .. } if &x % n == signature.r => Ok(()),
+            _ => Err(EcdsaError::InvalidSignature),
+        }
+    }
+
+    /// Recovers the public key that produced a signature from the signature, the message and
+    /// the recovery id v returned by `EcdsaPrivateKey::sign_recoverable` (SEC 1, section
+    /// 4.1.6): R is rebuilt from r and v, and Q = r^(-1) (sR - eG).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EcdsaPublicKey)` with the signer's key.
+    /// * `Err(EcdsaError)` if the signature is out of range or no point matches r and v.
+    pub fn recover<H: HashFunction>(
+        parameters: &EcdsaParameters,
+        message: &[u8],
+        signature: &EcdsaSignature,
+        v: u8,
+    ) -> Result<Self, EcdsaError> {
+        let scalars = &parameters.scalars;
+        let n = &parameters.n;
+        if signature.r.is_zero() || &signature.r >= n || signature.s.is_zero() || &signature.s >= n
+        {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        if v > 3 {
+            return Err(EcdsaError::InvalidRecoveryId(v));
+        }
+        let curve = &parameters.curve;
+        let field = &curve.field;
+        let x = &signature.r
+            + if v & 2 != 0 {
+                n.clone()
+            } else {
+                BigUint::zero()
+            };
+        if x >= field.p {
+            return Err(EcdsaError::InvalidRecoveryId(v));
+        }
+        let y = field
+            .sqrt(&curve.right_hand_side(&x))
+            .ok_or(EcdsaError::InvalidRecoveryId(v))?;
+        let y = if y.bit(0) == (v & 1 != 0) {
+            y
+        } else {
+            field.sub(&BigUint::zero(), &y)
+        };
+        let big_r = AffinePoint::new(x, y);
+        if !curve.scalar_mul(n, &big_r).is_infinity() {
+            return Err(EcdsaError::InvalidRecoveryId(v));
+        }
+
+        let e = bits2int(&H::digest(message), parameters.n_bits() as usize) % n;
+        let r_inv = Group::inverse(scalars, &signature.r).ok_or(EcdsaError::InvalidSignature)?;
+        let minus_e = scalars.sub(&BigUint::zero(), &e);
+        let q = curve.add(
+            &curve.scalar_mul(&scalars.mul(&r_inv, &signature.s), &big_r),
+            &curve.scalar_mul(&scalars.mul(&r_inv, &minus_e), &parameters.g),
+        );
+        EcdsaPublicKey::new(parameters.clone(), q)
+    }
+}
+
+impl SigningKey for EcdsaPrivateKey {
+    type VerifyingKey = EcdsaPublicKey;
+
+    /// Signs with SHA-256 and deterministic nonces; the signature is DER encoded.
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        EcdsaPrivateKey::sign::<Sha256>(self, message).to_der()
+    }
+
+    fn verifying_key(&self) -> EcdsaPublicKey {
+        self.public_key.clone()
+    }
+}
+
+impl VerifyingKey for EcdsaPublicKey {
+    /// Encodes Q as the uncompressed SEC 1 point 0x04 || x || y.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.parameters.curve.encode_point(&self.q, false)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        EcdsaSignature::from_der(signature)
+            .map(|signature| EcdsaPublicKey::verify::<Sha256>(self, message, &signature).is_ok())
+            .unwrap_or(false)
+    }
+}
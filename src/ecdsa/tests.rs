@@ -0,0 +1,431 @@
+// src/ecdsa/tests.rs
+
+// Known-answer tests from RFC 6979, appendix A.2.5 (P-256) and A.2.6 (P-384), the same
+// hashes and messages on secp256k1 with the A.2.5 private key (cross-checked against
+// OpenSSL's deterministic ECDSA), and the Wycheproof ecdsa_secp256r1_sha256 verification
+// vectors (test-data/wycheproof).
+
+use super::{EcdsaError, EcdsaParameters, EcdsaPrivateKey, EcdsaPublicKey, EcdsaSignature};
+use crate::dsa::nonce::DeterministicNonce;
+use crate::elliptic_curve::named::NamedCurve;
+use crate::elliptic_curve::AffinePoint;
+use crate::hash::{HashFunction, Sha224, Sha256, Sha384, Sha512};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+
+const WYCHEPROOF_P256_SHA256: &str =
+    include_str!("../../test-data/wycheproof/ecdsa_secp256r1_sha256_test.json");
+
+/// A message signed with a hash function, with the expected nonce and signature.
+struct Vector {
+    message: &'static str,
+    k: &'static str,
+    r: &'static str,
+    s: &'static str,
+}
+
+fn hex(value: &str) -> BigUint {
+    BigUint::parse_bytes(value.as_bytes(), 16).unwrap()
+}
+
+/// Builds the key with private scalar `d` and checks its public point against (ux, uy).
+fn key(curve: NamedCurve, d: &str, ux: &str, uy: &str) -> EcdsaPrivateKey {
+    let key = EcdsaPrivateKey::from_private_scalar(EcdsaParameters::named(curve), hex(d)).unwrap();
+    assert_eq!(
+        key.public_key.q,
+        AffinePoint::Finite {
+            x: hex(ux),
+            y: hex(uy),
+        }
+    );
+    key
+}
+
+/// Checks the RFC 6979 nonce, the signature, its verification, its DER encoding and public
+/// key recovery for one vector.
+fn check<H: HashFunction>(key: &EcdsaPrivateKey, vector: &Vector) {
+    let message = vector.message.as_bytes();
+    let parameters = key.parameters();
+    let mut nonces = DeterministicNonce::<H>::new(&parameters.n, &key.d, &H::digest(message));
+    assert_eq!(
+        nonces.next_nonce(),
+        hex(vector.k),
+        "k for {:?}",
+        vector.message
+    );
+    let expected = EcdsaSignature {
+        r: hex(vector.r),
+        s: hex(vector.s),
+    };
+    assert_eq!(key.sign::<H>(message), expected);
+    assert_eq!(
+        key.sign_with_nonce::<H>(message, &hex(vector.k)),
+        Some(expected.clone())
+    );
+    assert!(key.public_key.verify::<H>(message, &expected).is_ok());
+    assert!(key.public_key.verify::<H>(b"other", &expected).is_err());
+
+    let der = expected.to_der();
+    assert_eq!(EcdsaSignature::from_der(&der).unwrap(), expected);
+
+    let (low_s, v) = key.sign_recoverable::<H>(message);
+    assert_eq!(low_s, expected.normalize_s(parameters));
+    assert!(low_s.is_low_s(parameters));
+    let recovered = EcdsaPublicKey::recover::<H>(parameters, message, &low_s, v).unwrap();
+    assert_eq!(recovered, key.public_key);
+    let other = EcdsaPublicKey::recover::<H>(parameters, message, &low_s, v ^ 1).unwrap();
+    assert_ne!(other, key.public_key);
+}
+
+#[test]
+fn rfc6979_p256_vectors() {
+    let key = key(
+        NamedCurve::P256,
+        "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        "60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6",
+        "7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "103F90EE9DC52E5E7FB5132B7033C63066D194321491862059967C715985D473",
+            r: "53B2FFF5D1752B2C689DF257C04C40A587FABABB3F6FC2702F1343AF7CA9AA3F",
+            s: "B9AFB64FDC03DC1A131C7D2386D11E349F070AA432A4ACC918BEA988BF75C74C",
+        },
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "669F4426F2688B8BE0DB3A6BD1989BDAEFFF84B649EEB84F3DD26080F667FAA7",
+            r: "C37EDB6F0AE79D47C3C27E962FA269BB4F441770357E114EE511F662EC34A692",
+            s: "C820053A05791E521FCAAD6042D40AEA1D6B1A540138558F47D0719800E18F2D",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            r: "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+            s: "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+            r: "F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367",
+            s: "019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4",
+            r: "0EAFEA039B20E9B42309FB1D89E213057CBF973DC0CFC8F129EDDDC800EF7719",
+            s: "4861F0491E6998B9455193E34E7B0D284DDD7149A74B95B9261F13ABDE940954",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "16AEFFA357260B04B1DD199693960740066C1A8F3E8EDD79070AA914D361B3B8",
+            r: "83910E8B48BB0C74244EBDF7F07A1C5413D61472BD941EF3920E623FBCCEBEB6",
+            s: "8DDBEC54CF8CD5874883841D712142A56A8D0F218F5003CB0296B6B509619F2C",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5",
+            r: "8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00",
+            s: "2362AB1ADBE2B8ADF9CB9EDAB740EA6049C028114F2460F96554F61FAE3302FE",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "6915D11632ACA3C40D5D51C08DAF9C555933819548784480E93499000D9F0B7F",
+            r: "461D93F31B6540894788FD206C07CFA0CC35F46FA3C91816FFF1040AD1581A04",
+            s: "39AF9F15DE0DB8D97E72719C74820D304CE5226E32DEDAE67519E840D1194E55",
+        },
+    );
+}
+
+#[test]
+fn rfc6979_p384_vectors() {
+    let key = key(
+        NamedCurve::P384,
+        concat!(
+            "6B9D3DAD2E1B8C1C05B19875B6659F4DE23C3B667BF297BA",
+            "9AA47740787137D896D5724E4C70A825F872C9EA60D2EDF5"
+        ),
+        concat!(
+            "EC3A4E415B4E19A4568618029F427FA5DA9A8BC4AE92E02E",
+            "06AAE5286B300C64DEF8F0EA9055866064A254515480BC13"
+        ),
+        concat!(
+            "8015D9B72D7D57244EA8EF9AC0C621896708A59367F9DFB9",
+            "F54CA84B3F1C9DB1288B231C3AE0D4FE7344FD2533264720"
+        ),
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "A4E4D2F0E729EB786B31FC20AD5D849E304450E0AE8E3E341134A5C1AFA03CAB8083EE4E3C45B06A5899EA56C51B5879",
+            r: "42356E76B55A6D9B4631C865445DBE54E056D3B3431766D0509244793C3F9366450F76EE3DE43F5A125333A6BE060122",
+            s: "9DA0C81787064021E78DF658F2FBB0B042BF304665DB721F077A4298B095E4834C082C03D83028EFBF93A3C23940CA8D",
+        },
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "18FA39DB95AA5F561F30FA3591DC59C0FA3653A80DAFFA0B48D1A4C6DFCBFF6E3D33BE4DC5EB8886A8ECD093F2935726",
+            r: "E8C9D0B6EA72A0E7837FEA1D14A1A9557F29FAA45D3E7EE888FC5BF954B5E62464A9A817C47FF78B8C11066B24080E72",
+            s: "07041D4A7A0379AC7232FF72E6F77B6DDB8F09B16CCE0EC3286B2BD43FA8C6141C53EA5ABEF0D8231077A04540A96B66",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "180AE9F9AEC5438A44BC159A1FCB277C7BE54FA20E7CF404B490650A8ACC414E375572342863C899F9F2EDF9747A9B60",
+            r: "21B13D1E013C7FA1392D03C5F99AF8B30C570C6F98D4EA8E354B63A21D3DAA33BDE1E888E63355D92FA2B3C36D8FB2CD",
+            s: "F3AA443FB107745BF4BD77CB3891674632068A10CA67E3D45DB2266FA7D1FEEBEFDC63ECCD1AC42EC0CB8668A4FA0AB0",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "0CFAC37587532347DC3389FDC98286BBA8C73807285B184C83E62E26C401C0FAA48DD070BA79921A3457ABFF2D630AD7",
+            r: "6D6DEFAC9AB64DABAFE36C6BF510352A4CC27001263638E5B16D9BB51D451559F918EEDAF2293BE5B475CC8F0188636B",
+            s: "2D46F3BECBCC523D5F1A1256BF0C9B024D879BA9E838144C8BA6BAEB4B53B47D51AB373F9845C0514EEFB14024787265",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "94ED910D1A099DAD3254E9242AE85ABDE4BA15168EAF0CA87A555FD56D10FBCA2907E3E83BA95368623B8C4686915CF9",
+            r: "94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C81A648152E44ACF96E36DD1E80FABE46",
+            s: "99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94FA329C145786E679E7B82C71A38628AC8",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "015EE46A5BF88773ED9123A5AB0807962D193719503C527B031B4C2D225092ADA71F4A459BC0DA98ADB95837DB8312EA",
+            r: "8203B63D3C853E8D77227FB377BCF7B7B772E97892A80F36AB775D509D7A5FEB0542A7F0812998DA8F1DD3CA3CF023DB",
+            s: "DDD0760448D42D8A43AF45AF836FCE4DE8BE06B485E9B61B827C2F13173923E06A739F040649A667BF3B828246BAA5A5",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "92FC3C7183A883E24216D1141F1A8976C5B0DD797DFA597E3D7B32198BD35331A4E966532593A52980D0E3AAA5E10EC3",
+            r: "ED0959D5880AB2D869AE7F6C2915C6D60F96507F9CB3E047C0046861DA4A799CFE30F35CC900056D7C99CD7882433709",
+            s: "512C8CCEEE3890A84058CE1E22DBC2198F42323CE8ACA9135329F03C068E5112DC7CC3EF3446DEFCEB01A45C2667FDD5",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "3780C4F67CB15518B6ACAE34C9F83568D2E12E47DEAB6C50A4E4EE5319D1E8CE0E2CC8A136036DC4B9C00E6888F66B6C",
+            r: "A0D5D090C9980FAF3C2CE57B7AE951D31977DD11C775D314AF55F76C676447D06FB6495CD21B4B6E340FC236584FB277",
+            s: "976984E59B4C77B0E8E4460DCA3D9F20E07B9BB1F63BEEFAF576F6B2E8B224634A2092CD3792E0159AD9CEE37659C736",
+        },
+    );
+}
+
+#[test]
+fn rfc6979_secp256k1_vectors() {
+    let key = key(
+        NamedCurve::Secp256k1,
+        "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        "2C8C31FC9F990C6B55E3865A184A4CE50E09481F2EAEB3E60EC1CEA13A6AE645",
+        "64B95E4FDB6948C0386E189B006A29F686769B011704275E4459822DC3328085",
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "103F90EE9DC52E5E7FB5132B7033C63066D194321491862059967C715985D473",
+            r: "EFE22812AEE54594AD645AC904F792A7B78DE889CDB203D45B0AD38E91877EA0",
+            s: "E66176D972070CF93CFFFF669DAF62F72E4F169CAFCAE3152677C523D1C1EF39",
+        },
+    );
+    check::<Sha224>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "669F4426F2688B8BE0DB3A6BD1989BDAEFFF84B649EEB84F3DD26080F667FAA7",
+            r: "242670EB1D4D272FEC08D78B2F817CB38E2DEE1F721C338D2A71D1E2921F4DC2",
+            s: "84CDF656B5F7F8E6F93E83B325C556D2F49EBFA9ACAF4A6C8CE338B5E3E0449C",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            r: "432310E32CB80EB6503A26CE83CC165C783B870845FB8AAD6D970889FCD7A6C8",
+            s: "530128B6B81C548874A6305D93ED071CA6E05074D85863D4056CE89B02BFAB69",
+        },
+    );
+    check::<Sha256>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+            r: "F2ADCEA7139057BE6409855EE96D008E0E5B5F532333EC17448E26A36F47BCB2",
+            s: "570C9D342779B40F513C0D75CBF93E3F3DE7B01F6593F17BFC2EE87151414D64",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4",
+            r: "16217648FC2AB9E82F4BC6304D6F7AE0E3C5728F75786BA13F258CF02D971D44",
+            s: "899372870C08982344E4392ED218220E0B01E96F18425A2A4F2F74B0F6F57ABC",
+        },
+    );
+    check::<Sha384>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "16AEFFA357260B04B1DD199693960740066C1A8F3E8EDD79070AA914D361B3B8",
+            r: "CA8D3ACA176FFA260E78ADA8736EC9EDD2A49D1A1C6686358120812145A7020F",
+            s: "B6CE10567BDD40BDEB48EBCF87B1F82EE3A0EA15FAA7513FB815AD7403873A7E",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "sample",
+            k: "5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5",
+            r: "12AF6086A07A3347920DDB0C997918077FA90EC44AD7939E051D9C76F010B0EF",
+            s: "00559F7289748A2C6EBE6501F2BEF64E5CE94FF89C90B0DB22F5E3E01F88CC04",
+        },
+    );
+    check::<Sha512>(
+        &key,
+        &Vector {
+            message: "test",
+            k: "6915D11632ACA3C40D5D51C08DAF9C555933819548784480E93499000D9F0B7F",
+            r: "2AAED0E23C13F46ADFF7820B5C61F2692645AA9FADCEB3D05297A2D33790DD5A",
+            s: "9B24785EEAFFEF0B188A3D0B65B6322495B0311FCC90FEF5331AEB5B10AAA6E4",
+        },
+    );
+}
+
+#[test]
+fn wycheproof_p256_sha256_vectors() {
+    let suite: serde_json::Value = serde_json::from_str(WYCHEPROOF_P256_SHA256).unwrap();
+    let parameters = EcdsaParameters::named(NamedCurve::P256);
+    let mut count = 0;
+    for group in suite["testGroups"].as_array().unwrap() {
+        let q = AffinePoint::Finite {
+            x: hex(group["key"]["wx"].as_str().unwrap()),
+            y: hex(group["key"]["wy"].as_str().unwrap()),
+        };
+        let public_key = EcdsaPublicKey::new(parameters.clone(), q).unwrap();
+        for test in group["tests"].as_array().unwrap() {
+            let id = test["tcId"].as_u64().unwrap();
+            let message = hex::decode(test["msg"].as_str().unwrap()).unwrap();
+            let der = hex::decode(test["sig"].as_str().unwrap()).unwrap();
+            let accepted = EcdsaSignature::from_der(&der)
+                .is_ok_and(|sig| public_key.verify::<Sha256>(&message, &sig).is_ok());
+            let expected = test["result"].as_str().unwrap() == "valid";
+            assert_eq!(accepted, expected, "Wycheproof tcId {}", id);
+            assert_eq!(
+                VerifyingKey::verify(&public_key, &message, &der),
+                expected,
+                "Wycheproof tcId {} through VerifyingKey",
+                id
+            );
+            count += 1;
+        }
+    }
+    assert_eq!(count, suite["numberOfTests"].as_u64().unwrap());
+}
+
+#[test]
+fn der_rejects_non_canonical_encodings() {
+    let signature = EcdsaSignature {
+        r: BigUint::from(0x80u32),
+        s: BigUint::from(1u32),
+    };
+    let der = signature.to_der();
+    assert_eq!(der, [0x30, 0x07, 0x02, 0x02, 0x00, 0x80, 0x02, 0x01, 0x01]);
+    assert_eq!(EcdsaSignature::from_der(&der).unwrap(), signature);
+
+    let mut trailing = der.clone();
+    trailing.push(0);
+    assert!(EcdsaSignature::from_der(&trailing).is_err());
+    // r = 1 padded with a redundant leading zero.
+    let non_minimal = [0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01];
+    assert!(EcdsaSignature::from_der(&non_minimal).is_err());
+    // r = -128.
+    let negative = [0x30, 0x06, 0x02, 0x01, 0x80, 0x02, 0x01, 0x01];
+    assert!(EcdsaSignature::from_der(&negative).is_err());
+}
+
+#[test]
+fn signing_key_round_trip() {
+    let key = EcdsaPrivateKey::generate(&EcdsaParameters::named(NamedCurve::Secp256k1));
+    let signature = SigningKey::sign(&key, b"message");
+    let public_key = key.verifying_key();
+    assert!(VerifyingKey::verify(&public_key, b"message", &signature));
+    assert!(!VerifyingKey::verify(&public_key, b"massage", &signature));
+    assert!(!VerifyingKey::verify(
+        &public_key,
+        b"message",
+        &signature[1..]
+    ));
+}
+
+#[test]
+fn invalid_keys_and_recovery_ids_are_rejected() {
+    let parameters = EcdsaParameters::named(NamedCurve::P256);
+    assert!(matches!(
+        EcdsaPrivateKey::from_private_scalar(parameters.clone(), BigUint::from(0u32)),
+        Err(EcdsaError::InvalidPrivateKey)
+    ));
+    assert!(matches!(
+        EcdsaPrivateKey::from_private_scalar(parameters.clone(), parameters.n.clone()),
+        Err(EcdsaError::InvalidPrivateKey)
+    ));
+    let off_curve = AffinePoint::Finite {
+        x: BigUint::from(1u32),
+        y: BigUint::from(1u32),
+    };
+    assert!(matches!(
+        EcdsaPublicKey::new(parameters.clone(), off_curve),
+        Err(EcdsaError::InvalidPublicKey)
+    ));
+    assert!(matches!(
+        EcdsaPublicKey::new(parameters.clone(), AffinePoint::Infinity),
+        Err(EcdsaError::InvalidPublicKey)
+    ));
+
+    let key = EcdsaPrivateKey::generate(&parameters);
+    let (signature, _) = key.sign_recoverable::<Sha256>(b"message");
+    assert!(matches!(
+        EcdsaPublicKey::recover::<Sha256>(&parameters, b"message", &signature, 4),
+        Err(EcdsaError::InvalidRecoveryId(4))
+    ));
+}
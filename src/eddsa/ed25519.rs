@@ -0,0 +1,281 @@
+// src/eddsa/ed25519.rs
+
+use super::EdDsaError;
+use crate::algebra::traits::ElementEncoding;
+use crate::elliptic_curve::{curve25519, EdwardsCurve, EdwardsPoint};
+use crate::hash::{HashFunction, Sha512};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Length of seeds, public keys and each half of a signature, in bytes.
+const LENGTH: usize = 32;
+
+/// An Ed25519 private key (RFC 8032, section 5.1.5): a 32-byte seed, hashed with SHA-512 into
+/// the secret scalar s and the prefix used to derive nonces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ed25519SigningKey {
+    seed: [u8; LENGTH],                  // Private key as stored
+    scalar: BigUint,                     // Clamped secret scalar s
+    prefix: [u8; LENGTH],                // Second half of SHA-512(seed)
+    pub public_key: Ed25519VerifyingKey, // A = sB
+}
+
+/// An Ed25519 public key A together with its 32-byte encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ed25519VerifyingKey {
+    pub point: EdwardsPoint, // A
+    encoded: [u8; LENGTH],   // Encoding of A, hashed into every signature
+}
+
+impl Ed25519SigningKey {
+    /// Generates a key from 32 random bytes.
+    pub fn generate() -> Self {
+        let mut seed = [0u8; LENGTH];
+        OsRng.fill_bytes(&mut seed);
+        Self::from_seed(&seed)
+    }
+
+    /// Expands a 32-byte private key: the first half of SHA-512(seed) is clamped into the
+    /// scalar s and the second half becomes the nonce prefix.
+    pub fn from_seed(seed: &[u8; LENGTH]) -> Self {
+        let digest = Sha512::digest(seed);
+        let mut scalar_bytes = [0u8; LENGTH];
+        scalar_bytes.copy_from_slice(&digest[..LENGTH]);
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+        let scalar = BigUint::from_bytes_le(&scalar_bytes);
+        let mut prefix = [0u8; LENGTH];
+        prefix.copy_from_slice(&digest[LENGTH..]);
+
+        let curve = EdwardsCurve::edwards25519();
+        let point = curve.scalar_mul(&scalar, &EdwardsCurve::edwards25519_base_point());
+        let public_key = Ed25519VerifyingKey::from_point(&curve, point);
+        Ed25519SigningKey {
+            seed: *seed,
+            scalar,
+            prefix,
+            public_key,
+        }
+    }
+
+    /// Returns the 32-byte private key.
+    pub fn seed(&self) -> &[u8; LENGTH] {
+        &self.seed
+    }
+
+    /// Signs `message` (RFC 8032, section 5.1.6). Signing is deterministic: the nonce
+    /// r = SHA-512(prefix || M) mod L depends only on the key and the message.
+    pub fn sign(&self, message: &[u8]) -> [u8; 2 * LENGTH] {
+        let curve = EdwardsCurve::edwards25519();
+        let order = curve25519::order();
+        let r = hash_to_scalar(&[&self.prefix, message]);
+        let big_r = curve.scalar_mul(&r, &EdwardsCurve::edwards25519_base_point());
+        let encoded_r = curve.encode_element(&big_r);
+        let k = hash_to_scalar(&[&encoded_r, &self.public_key.encoded, message]);
+        let s = (r + k * &self.scalar) % &order;
+
+        let mut signature = [0u8; 2 * LENGTH];
+        signature[..LENGTH].copy_from_slice(&encoded_r);
+        let s_bytes = s.to_bytes_le();
+        signature[LENGTH..LENGTH + s_bytes.len()].copy_from_slice(&s_bytes);
+        signature
+    }
+}
+
+impl Ed25519VerifyingKey {
+    /// Decodes a 32-byte public key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Ed25519VerifyingKey)` if the bytes are the canonical encoding of a curve point.
+    /// * `Err(EdDsaError)` otherwise.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EdDsaError> {
+        if bytes.len() != LENGTH {
+            return Err(EdDsaError::WrongLength {
+                expected: LENGTH,
+                found: bytes.len(),
+            });
+        }
+        let curve = EdwardsCurve::edwards25519();
+        let point = curve
+            .decode_element(bytes)
+            .ok_or(EdDsaError::InvalidPublicKey)?;
+        Ok(Self::from_point(&curve, point))
+    }
+
+    fn from_point(curve: &EdwardsCurve, point: EdwardsPoint) -> Self {
+        let mut encoded = [0u8; LENGTH];
+        encoded.copy_from_slice(&curve.encode_element(&point));
+        Ed25519VerifyingKey { point, encoded }
+    }
+
+    /// Returns the 32-byte encoding of the key.
+    pub fn as_bytes(&self) -> &[u8; LENGTH] {
+        &self.encoded
+    }
+
+    /// Verifies a signature (RFC 8032, section 5.1.7): R must decode to a point, S must be
+    /// below L, and [8][S]B = [8]R + [8][k]A with k = SHA-512(R || A || M) mod L.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(EdDsaError)` otherwise.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), EdDsaError> {
+        if signature.len() != 2 * LENGTH {
+            return Err(EdDsaError::WrongLength {
+                expected: 2 * LENGTH,
+                found: signature.len(),
+            });
+        }
+        let curve = EdwardsCurve::edwards25519();
+        let (encoded_r, encoded_s) = signature.split_at(LENGTH);
+        let big_r = curve
+            .decode_element(encoded_r)
+            .ok_or(EdDsaError::InvalidSignature)?;
+        let s = BigUint::from_bytes_le(encoded_s);
+        if s >= curve25519::order() {
+            return Err(EdDsaError::InvalidSignature);
+        }
+        let k = hash_to_scalar(&[encoded_r, &self.encoded, message]);
+
+        let eight = BigUint::from(8u32);
+        let left = curve.scalar_mul(&(s * &eight), &EdwardsCurve::edwards25519_base_point());
+        let right = curve.scalar_mul(
+            &eight,
+            &curve.add(&big_r, &curve.scalar_mul(&k, &self.point)),
+        );
+        if left == right {
+            Ok(())
+        } else {
+            Err(EdDsaError::InvalidSignature)
+        }
+    }
+}
+
+/// Hashes the concatenation of `parts` with SHA-512 and reduces the little-endian digest
+/// modulo L.
+fn hash_to_scalar(parts: &[&[u8]]) -> BigUint {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    BigUint::from_bytes_le(&hasher.finalize()) % curve25519::order()
+}
+
+impl SigningKey for Ed25519SigningKey {
+    type VerifyingKey = Ed25519VerifyingKey;
+
+    /// Returns the 64-byte signature R || S.
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        Ed25519SigningKey::sign(self, message).to_vec()
+    }
+
+    fn verifying_key(&self) -> Ed25519VerifyingKey {
+        self.public_key.clone()
+    }
+}
+
+impl VerifyingKey for Ed25519VerifyingKey {
+    /// Returns the 32-byte encoding of A.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.encoded.to_vec()
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        Ed25519VerifyingKey::verify(self, message, signature).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks one RFC 8032 vector: the public key, the signature and its verification.
+    fn check(secret: &str, public: &str, message: &[u8], signature: &str) {
+        let key = Ed25519SigningKey::from_seed(&hex::decode(secret).unwrap().try_into().unwrap());
+        assert_eq!(hex::encode(key.public_key.as_bytes()), public);
+        let signed = Ed25519SigningKey::sign(&key, message);
+        assert_eq!(hex::encode(signed), signature);
+
+        let public_key = Ed25519VerifyingKey::from_bytes(&hex::decode(public).unwrap()).unwrap();
+        assert_eq!(public_key, key.public_key);
+        assert_eq!(public_key.verify(message, &signed), Ok(()));
+        let mut forged = signed;
+        forged[0] ^= 1;
+        assert!(public_key.verify(message, &forged).is_err());
+        assert!(public_key.verify(b"another message", &signed).is_err());
+    }
+
+    #[test]
+    fn rfc8032_test_1() {
+        check(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            b"",
+            concat!(
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+                "5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+            ),
+        );
+    }
+
+    #[test]
+    fn rfc8032_test_2() {
+        check(
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            &[0x72],
+            concat!(
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da",
+                "085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+            ),
+        );
+    }
+
+    #[test]
+    fn rfc8032_test_3() {
+        check(
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            &[0xaf, 0x82],
+            concat!(
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac",
+                "18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a"
+            ),
+        );
+    }
+
+    #[test]
+    fn rfc8032_test_sha_abc() {
+        check(
+            "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+            "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+            &Sha512::digest(b"abc"),
+            concat!(
+                "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b589",
+                "09351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704"
+            ),
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_keys_and_signatures() {
+        let key = Ed25519SigningKey::from_seed(&[7; LENGTH]);
+        let signature = Ed25519SigningKey::sign(&key, b"message");
+        assert_eq!(
+            key.public_key.verify(b"message", &signature[..63]),
+            Err(EdDsaError::WrongLength {
+                expected: 64,
+                found: 63
+            })
+        );
+        let mut high_s = signature;
+        high_s[63] |= 0xf0;
+        assert!(key.public_key.verify(b"message", &high_s).is_err());
+        assert!(Ed25519VerifyingKey::from_bytes(&[0; 31]).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+// src/eddsa/mod.rs
+
+pub mod ed25519;
+
+pub use ed25519::{Ed25519SigningKey, Ed25519VerifyingKey};
+
+use std::fmt;
+
+/// Errors related to EdDSA keys and signatures.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EdDsaError {
+    WrongLength { expected: usize, found: usize },
+    InvalidPublicKey,
+    InvalidSignature,
+}
+
+impl fmt::Display for EdDsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdDsaError::WrongLength { expected, found } => {
+                write!(f, "Expected {} bytes but found {}.", expected, found)
+            }
+            EdDsaError::InvalidPublicKey => {
+                write!(f, "The public key does not encode a curve point.")
+            }
+            EdDsaError::InvalidSignature => write!(f, "The signature is invalid."),
+        }
+    }
+}
+
+impl std::error::Error for EdDsaError {}
@@ -0,0 +1,175 @@
+// src/elgamal/encryption.rs
+
+use super::{ElGamal, ElGamalError};
+use crate::algebra::traits::{ElementEncoding, Group};
+use num_bigint::BigUint;
+use num_integer::Roots;
+use std::collections::HashMap;
+
+/// An ElGamal ciphertext (c1, c2) = (g^k, m * h^k).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElGamalCiphertext<E> {
+    pub c1: E, // Ephemeral key g^k
+    pub c2: E, // Masked message m * h^k
+}
+
+impl<G> ElGamal<G>
+where
+    G: Group + ElementEncoding,
+    G::Element: Clone,
+{
+    /// Encrypts the group element `m` under the public key `h` with fresh randomness.
+    pub fn encrypt(
+        &self,
+        public_key: &G::Element,
+        m: &G::Element,
+    ) -> ElGamalCiphertext<G::Element> {
+        let k = self.random_exponent();
+        self.encrypt_with_randomness(public_key, m, &k)
+    }
+
+    /// Encrypts the group element `m` under the public key `h` with the exponent `k`.
+    ///
+    /// # Returns
+    ///
+    /// The ciphertext (g^k, m * h^k).
+    pub fn encrypt_with_randomness(
+        &self,
+        public_key: &G::Element,
+        m: &G::Element,
+        k: &BigUint,
+    ) -> ElGamalCiphertext<G::Element> {
+        ElGamalCiphertext {
+            c1: self.power(k),
+            c2: self.group.combine(m, &self.group.pow(public_key, k)),
+        }
+    }
+
+    /// Exponential (additive) ElGamal: encrypts the integer `m` as the group element g^m.
+    ///
+    /// Combining two such ciphertexts yields an encryption of the sum of the plaintexts.
+    /// Decryption needs a discrete logarithm, so `m` should come from a small range.
+    pub fn encrypt_exponential(
+        &self,
+        public_key: &G::Element,
+        m: &BigUint,
+    ) -> ElGamalCiphertext<G::Element> {
+        self.encrypt(public_key, &self.power(m))
+    }
+
+    /// Decrypts a ciphertext as m = c2 * (c1^x)^(-1).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(G::Element)` with the message.
+    /// * `Err(ElGamalError::NotInvertible)` if c1^x has no inverse.
+    pub fn decrypt(
+        &self,
+        private_key: &BigUint,
+        ciphertext: &ElGamalCiphertext<G::Element>,
+    ) -> Result<G::Element, ElGamalError> {
+        let shared = self.group.pow(&ciphertext.c1, private_key);
+        let mask = self
+            .group
+            .inverse(&shared)
+            .ok_or(ElGamalError::NotInvertible)?;
+        Ok(self.group.combine(&ciphertext.c2, &mask))
+    }
+
+    /// Decrypts an exponential ElGamal ciphertext whose plaintext lies in [0, max].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` with the plaintext.
+    /// * `Err(ElGamalError)` if decryption fails or the plaintext is larger than `max`.
+    pub fn decrypt_exponential(
+        &self,
+        private_key: &BigUint,
+        ciphertext: &ElGamalCiphertext<G::Element>,
+        max: u64,
+    ) -> Result<u64, ElGamalError> {
+        let target = self.decrypt(private_key, ciphertext)?;
+        self.discrete_log(&target, max)
+    }
+
+    /// Finds m in [0, max] with g^m = target using baby-step giant-step, in O(sqrt(max))
+    /// group operations and memory.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` with the smallest such m.
+    /// * `Err(ElGamalError::DiscreteLogOutOfRange)` if there is none.
+    pub fn discrete_log(&self, target: &G::Element, max: u64) -> Result<u64, ElGamalError> {
+        let step = (max.saturating_add(1)).sqrt() + 1;
+
+        // Baby steps: g^j for j in [0, step).
+        let mut baby_steps = HashMap::new();
+        let mut current = self.group.identity();
+        for j in 0..step {
+            baby_steps
+                .entry(self.group.encode_element(&current))
+                .or_insert(j);
+            current = self.group.combine(&current, &self.generator);
+        }
+
+        // Giant steps: target * g^(-i * step) for i in [0, step].
+        let giant = self
+            .group
+            .inverse(&self.power(&BigUint::from(step)))
+            .ok_or(ElGamalError::NotInvertible)?;
+        let mut current = target.clone();
+        for i in 0..=step {
+            if let Some(j) = baby_steps.get(&self.group.encode_element(&current)) {
+                let m = i * step + j;
+                return if m <= max {
+                    Ok(m)
+                } else {
+                    Err(ElGamalError::DiscreteLogOutOfRange)
+                };
+            }
+            current = self.group.combine(&current, &giant);
+        }
+        Err(ElGamalError::DiscreteLogOutOfRange)
+    }
+
+    /// Re-randomizes a ciphertext by combining it with a fresh encryption of the identity. The
+    /// result decrypts to the same message but cannot be linked to the input.
+    pub fn rerandomize(
+        &self,
+        public_key: &G::Element,
+        ciphertext: &ElGamalCiphertext<G::Element>,
+    ) -> ElGamalCiphertext<G::Element> {
+        let zero = self.encrypt(public_key, &self.group.identity());
+        self.combine(ciphertext, &zero)
+    }
+
+    /// Homomorphic combination: component-wise product of two ciphertexts.
+    ///
+    /// For multiplicative ElGamal the result encrypts m1 * m2; for exponential ElGamal it
+    /// encrypts m1 + m2.
+    pub fn combine(
+        &self,
+        a: &ElGamalCiphertext<G::Element>,
+        b: &ElGamalCiphertext<G::Element>,
+    ) -> ElGamalCiphertext<G::Element> {
+        ElGamalCiphertext {
+            c1: self.group.combine(&a.c1, &b.c1),
+            c2: self.group.combine(&a.c2, &b.c2),
+        }
+    }
+
+    /// Raises both components to the power `k`.
+    ///
+    /// For multiplicative ElGamal the result encrypts m^k; for exponential ElGamal it
+    /// encrypts k * m.
+    pub fn scale(
+        &self,
+        ciphertext: &ElGamalCiphertext<G::Element>,
+        k: &BigUint,
+    ) -> ElGamalCiphertext<G::Element> {
+        ElGamalCiphertext {
+            c1: self.group.pow(&ciphertext.c1, k),
+            c2: self.group.pow(&ciphertext.c2, k),
+        }
+    }
+}
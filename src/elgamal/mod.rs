@@ -0,0 +1,155 @@
+// src/elgamal/mod.rs
+
+pub mod encryption;
+pub mod signature;
+
+pub use encryption::ElGamalCiphertext;
+pub use signature::ElGamalSignature;
+
+use crate::algebra::traits::{ElementEncoding, Group};
+use crate::factor::factorize;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::rngs::OsRng;
+use std::fmt;
+
+/// Errors related to ElGamal encryption and signatures.
+#[derive(Debug)]
+pub enum ElGamalError {
+    InvalidGenerator,
+    InvalidOrder,
+    NotInvertible,
+    DiscreteLogOutOfRange,
+    InvalidSignature,
+}
+
+impl fmt::Display for ElGamalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElGamalError::InvalidGenerator => {
+                write!(f, "The generator must not be the identity element.")
+            }
+            ElGamalError::InvalidOrder => {
+                write!(
+                    f,
+                    "The generator raised to the given order is not the identity."
+                )
+            }
+            ElGamalError::NotInvertible => {
+                write!(f, "The ciphertext component has no inverse in the group.")
+            }
+            ElGamalError::DiscreteLogOutOfRange => {
+                write!(f, "The plaintext exponent is outside the searched range.")
+            }
+            ElGamalError::InvalidSignature => write!(f, "The signature is invalid."),
+        }
+    }
+}
+
+impl std::error::Error for ElGamalError {}
+
+/// ElGamal over the cyclic subgroup generated by `generator` in any group.
+///
+/// Private keys are exponents x in [1, order - 1] and public keys are h = g^x. Over
+/// `IntegerModP` the generator is usually chosen to have prime order q | p - 1; over
+/// `BinaryExtensionField` it lives in the multiplicative group of order 2^n - 1.
+#[derive(Debug, Clone)]
+pub struct ElGamal<G: Group> {
+    pub group: G,              // Ambient group
+    pub generator: G::Element, // Generator g of the subgroup
+    pub order: BigUint,        // Order of g
+}
+
+impl<G> ElGamal<G>
+where
+    G: Group + ElementEncoding,
+    G::Element: Clone,
+{
+    /// Creates an ElGamal instance for a generator whose order is already known.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The ambient group.
+    /// * `generator` - The generator g.
+    /// * `order` - A multiple of the order of g; it is taken as the order of the subgroup.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ElGamal)` if g is not the identity and g^order is the identity.
+    /// * `Err(ElGamalError)` otherwise.
+    pub fn new(group: G, generator: G::Element, order: BigUint) -> Result<Self, ElGamalError> {
+        let elgamal = ElGamal {
+            group,
+            generator,
+            order,
+        };
+        if elgamal.is_identity(&elgamal.generator) {
+            return Err(ElGamalError::InvalidGenerator);
+        }
+        if elgamal.order.is_zero() || !elgamal.is_identity(&elgamal.power(&elgamal.order)) {
+            return Err(ElGamalError::InvalidOrder);
+        }
+        Ok(elgamal)
+    }
+
+    /// Creates an ElGamal instance from the order of the whole group, such as p - 1 for
+    /// `IntegerModP` or 2^n - 1 for `BinaryExtensionField`, by factoring it and computing
+    /// the exact order of the generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ElGamal)` with the exact order of g.
+    /// * `Err(ElGamalError)` if g is the identity or g^group_order is not the identity.
+    pub fn from_group_order(
+        group: G,
+        generator: G::Element,
+        group_order: &BigUint,
+    ) -> Result<Self, ElGamalError> {
+        let mut elgamal = Self::new(group, generator, group_order.clone())?;
+        for (prime, exponent) in factorize(group_order) {
+            for _ in 0..exponent {
+                let candidate = &elgamal.order / &prime;
+                if !elgamal.is_identity(&elgamal.power(&candidate)) {
+                    break;
+                }
+                elgamal.order = candidate;
+            }
+        }
+        Ok(elgamal)
+    }
+
+    /// Generates a random private key x in [1, order - 1].
+    pub fn generate_private_key(&self) -> BigUint {
+        self.random_exponent()
+    }
+
+    /// Computes the public key h = g^x.
+    pub fn public_key(&self, private_key: &BigUint) -> G::Element {
+        self.power(private_key)
+    }
+
+    /// Returns true if `a` and `b` are the same group element.
+    pub fn equal(&self, a: &G::Element, b: &G::Element) -> bool {
+        self.group.encode_element(a) == self.group.encode_element(b)
+    }
+
+    /// Computes g^exponent.
+    fn power(&self, exponent: &BigUint) -> G::Element {
+        self.group.pow(&self.generator, exponent)
+    }
+
+    fn is_identity(&self, a: &G::Element) -> bool {
+        self.equal(a, &self.group.identity())
+    }
+
+    /// Returns a uniformly random exponent in [1, order - 1], or 1 for a subgroup of order 2.
+    fn random_exponent(&self) -> BigUint {
+        if self.order <= BigUint::from(2u32) {
+            return BigUint::one();
+        }
+        OsRng.gen_biguint_range(&BigUint::one(), &self.order)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,83 @@
+// src/elgamal/signature.rs
+
+use super::{ElGamal, ElGamalError};
+use crate::algebra::traits::{ElementEncoding, Group};
+use crate::hash::HashFunction;
+use crate::integer_mod_n::IntegerModN;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// An ElGamal signature (r, s) with r = g^k and s = (H(m) - x * f(r)) * k^(-1) mod order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElGamalSignature<E> {
+    pub r: E,       // Commitment g^k
+    pub s: BigUint, // Response modulo the order of g
+}
+
+impl<G> ElGamal<G>
+where
+    G: Group + ElementEncoding,
+    G::Element: Clone,
+{
+    /// Signs `message` with the private key `x`, hashing it with `H`.
+    ///
+    /// The nonce k is drawn uniformly from the exponents coprime to the order of g, so the
+    /// order does not need to be prime.
+    pub fn sign<H: HashFunction>(
+        &self,
+        private_key: &BigUint,
+        message: &[u8],
+    ) -> ElGamalSignature<G::Element> {
+        let h = self.hash_to_exponent::<H>(message);
+        loop {
+            let k = self.random_exponent();
+            let Some(k_inv) = IntegerModN::inverse(&k, &self.order) else {
+                continue;
+            };
+            let r = self.power(&k);
+            let xr = private_key * self.element_to_exponent(&r) % &self.order;
+            let s = (&h + &self.order - xr) % &self.order * k_inv % &self.order;
+            if !s.is_zero() {
+                return ElGamalSignature { r, s };
+            }
+        }
+    }
+
+    /// Verifies a signature by checking g^H(m) = h^f(r) * r^s.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(ElGamalError::InvalidSignature)` otherwise.
+    pub fn verify<H: HashFunction>(
+        &self,
+        public_key: &G::Element,
+        message: &[u8],
+        signature: &ElGamalSignature<G::Element>,
+    ) -> Result<(), ElGamalError> {
+        if signature.s.is_zero() || signature.s >= self.order {
+            return Err(ElGamalError::InvalidSignature);
+        }
+        let left = self.power(&self.hash_to_exponent::<H>(message));
+        let right = self.group.combine(
+            &self
+                .group
+                .pow(public_key, &self.element_to_exponent(&signature.r)),
+            &self.group.pow(&signature.r, &signature.s),
+        );
+        if self.equal(&left, &right) {
+            Ok(())
+        } else {
+            Err(ElGamalError::InvalidSignature)
+        }
+    }
+
+    /// Maps a group element to an exponent: f(r) = OS2IP(encoding of r) mod order.
+    fn element_to_exponent(&self, a: &G::Element) -> BigUint {
+        BigUint::from_bytes_be(&self.group.encode_element(a)) % &self.order
+    }
+
+    fn hash_to_exponent<H: HashFunction>(&self, message: &[u8]) -> BigUint {
+        BigUint::from_bytes_be(&H::digest(message)) % &self.order
+    }
+}
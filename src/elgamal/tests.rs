@@ -0,0 +1,186 @@
+// src/elgamal/tests.rs
+
+// Round trips of ElGamal encryption and signatures over the order-q subgroup of a safe-prime
+// field and over P-256, the homomorphic operations, and the baby-step giant-step discrete
+// logarithm behind exponential ElGamal.
+
+use super::{ElGamal, ElGamalError};
+use crate::algebra::traits::{ElementEncoding, Group};
+use crate::elliptic_curve::{Curve, NamedCurve};
+use crate::hash::Sha256;
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::{BigUint, RandBigInt};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// The squares modulo the safe prime p = 2q + 1, a subgroup of prime order q generated by 4.
+fn field_elgamal() -> ElGamal<IntegerModP> {
+    let p = BigUint::from(9_223_372_036_854_778_487u64);
+    let q = BigUint::from(4_611_686_018_427_389_243u64);
+    ElGamal::new(IntegerModP::new_valid_prime(p), BigUint::from(4u32), q).unwrap()
+}
+
+fn curve_elgamal() -> ElGamal<Curve> {
+    let named = NamedCurve::P256;
+    let curve = Curve::new(named.prime(), named.a(), named.b()).unwrap();
+    ElGamal::new(curve, named.generator(), named.order()).unwrap()
+}
+
+/// Encrypts random subgroup elements and checks decryption, the multiplicative homomorphism
+/// and re-randomization.
+fn check_encryption<G>(elgamal: &ElGamal<G>, seed: u64)
+where
+    G: Group + ElementEncoding,
+    G::Element: Clone + PartialEq + std::fmt::Debug,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    let x = elgamal.generate_private_key();
+    let h = elgamal.public_key(&x);
+    let random_element = |rng: &mut StdRng| {
+        let e = rng.gen_biguint_below(&elgamal.order);
+        elgamal.group.pow(&elgamal.generator, &e)
+    };
+
+    for _ in 0..3 {
+        let (m1, m2) = (random_element(&mut rng), random_element(&mut rng));
+        let c1 = elgamal.encrypt(&h, &m1);
+        let c2 = elgamal.encrypt(&h, &m2);
+        assert!(elgamal.equal(&elgamal.decrypt(&x, &c1).unwrap(), &m1));
+        assert_ne!(c1, elgamal.encrypt(&h, &m1));
+
+        let product = elgamal.decrypt(&x, &elgamal.combine(&c1, &c2)).unwrap();
+        assert!(elgamal.equal(&product, &elgamal.group.combine(&m1, &m2)));
+        let k = BigUint::from(5u32);
+        let power = elgamal.decrypt(&x, &elgamal.scale(&c1, &k)).unwrap();
+        assert!(elgamal.equal(&power, &elgamal.group.pow(&m1, &k)));
+
+        let fresh = elgamal.rerandomize(&h, &c1);
+        assert_ne!(fresh, c1);
+        assert!(elgamal.equal(&elgamal.decrypt(&x, &fresh).unwrap(), &m1));
+
+        // The wrong key gives a different element.
+        let other = elgamal.generate_private_key();
+        if other != x {
+            assert!(!elgamal.equal(&elgamal.decrypt(&other, &c1).unwrap(), &m1));
+        }
+    }
+}
+
+/// Signs and verifies with SHA-256 and checks that changed messages, keys and signatures fail.
+fn check_signatures<G>(elgamal: &ElGamal<G>)
+where
+    G: Group + ElementEncoding,
+    G::Element: Clone + PartialEq + std::fmt::Debug,
+{
+    let x = elgamal.generate_private_key();
+    let h = elgamal.public_key(&x);
+    let message = b"ElGamal signature round trip";
+    let signature = elgamal.sign::<Sha256>(&x, message);
+    assert!(elgamal.verify::<Sha256>(&h, message, &signature).is_ok());
+
+    let invalid =
+        |result: Result<(), ElGamalError>| matches!(result, Err(ElGamalError::InvalidSignature));
+    assert!(invalid(elgamal.verify::<Sha256>(
+        &h,
+        b"another message",
+        &signature
+    )));
+    let other_key = elgamal.public_key(&(&x + 1u32));
+    assert!(invalid(
+        elgamal.verify::<Sha256>(&other_key, message, &signature)
+    ));
+    let mut changed = signature.clone();
+    changed.s = (&changed.s + 1u32) % &elgamal.order;
+    assert!(invalid(elgamal.verify::<Sha256>(&h, message, &changed)));
+    changed.s = BigUint::from(0u32);
+    assert!(invalid(elgamal.verify::<Sha256>(&h, message, &changed)));
+    changed.s = elgamal.order.clone();
+    assert!(invalid(elgamal.verify::<Sha256>(&h, message, &changed)));
+}
+
+#[test]
+fn encryption_over_a_prime_field() {
+    check_encryption(&field_elgamal(), 1);
+}
+
+#[test]
+fn encryption_over_p256() {
+    check_encryption(&curve_elgamal(), 2);
+}
+
+#[test]
+fn signatures_over_a_prime_field() {
+    check_signatures(&field_elgamal());
+}
+
+#[test]
+fn signatures_over_p256() {
+    check_signatures(&curve_elgamal());
+}
+
+#[test]
+fn exponential_elgamal_adds_plaintexts() {
+    let elgamal = field_elgamal();
+    let x = elgamal.generate_private_key();
+    let h = elgamal.public_key(&x);
+    let a = elgamal.encrypt_exponential(&h, &BigUint::from(1234u32));
+    let b = elgamal.encrypt_exponential(&h, &BigUint::from(4321u32));
+    let sum = elgamal.combine(&a, &b);
+    assert_eq!(elgamal.decrypt_exponential(&x, &sum, 10_000).unwrap(), 5555);
+    let tripled = elgamal.scale(&a, &BigUint::from(3u32));
+    assert_eq!(
+        elgamal.decrypt_exponential(&x, &tripled, 10_000).unwrap(),
+        3702
+    );
+    assert!(matches!(
+        elgamal.decrypt_exponential(&x, &sum, 5000),
+        Err(ElGamalError::DiscreteLogOutOfRange)
+    ));
+}
+
+#[test]
+fn baby_step_giant_step() {
+    let elgamal = curve_elgamal();
+    for (m, max) in [
+        (0u64, 0u64),
+        (1, 1),
+        (99, 100),
+        (100, 100),
+        (65_535, 65_535),
+        (12_345, 1 << 20),
+    ] {
+        let target = elgamal.group.pow(&elgamal.generator, &BigUint::from(m));
+        assert_eq!(elgamal.discrete_log(&target, max).unwrap(), m, "m = {}", m);
+    }
+    let target = elgamal
+        .group
+        .pow(&elgamal.generator, &BigUint::from(101u32));
+    assert!(matches!(
+        elgamal.discrete_log(&target, 100),
+        Err(ElGamalError::DiscreteLogOutOfRange)
+    ));
+}
+
+#[test]
+fn generators_and_orders_are_checked() {
+    let p = BigUint::from(9_223_372_036_854_778_487u64);
+    let q = BigUint::from(4_611_686_018_427_389_243u64);
+    let field = IntegerModP::new_valid_prime(p.clone());
+    let from_group_order =
+        ElGamal::from_group_order(field.clone(), BigUint::from(4u32), &(&p - 1u32)).unwrap();
+    assert_eq!(from_group_order.order, q);
+
+    assert!(matches!(
+        ElGamal::new(field.clone(), BigUint::from(1u32), q.clone()),
+        Err(ElGamalError::InvalidGenerator)
+    ));
+    // 4 has order q, so 4^2 is not the identity.
+    assert!(matches!(
+        ElGamal::new(field.clone(), BigUint::from(4u32), BigUint::from(2u32)),
+        Err(ElGamalError::InvalidOrder)
+    ));
+    assert!(matches!(
+        ElGamal::new(field, BigUint::from(4u32), BigUint::from(0u32)),
+        Err(ElGamalError::InvalidOrder)
+    ));
+}
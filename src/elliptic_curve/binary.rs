@@ -0,0 +1,543 @@
+// src/elliptic_curve/binary.rs
+
+use super::scalar_mul::wnaf;
+use super::{AffinePoint, EllipticCurveError, LopezDahabPoint, NamedBinaryCurve};
+use crate::algebra::traits::{self, Algebra, Membership};
+use crate::binary_extension_field::BinaryExtensionField;
+use crate::polynomial::Polynomial;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// Window width used by `scalar_mul` on curves that are not Koblitz curves.
+const DEFAULT_WNAF_WIDTH: usize = 4;
+
+/// An ordinary binary elliptic curve y^2 + xy = x^3 + ax^2 + b over `BinaryExtensionField`.
+///
+/// The curve is non-singular exactly when b != 0. Koblitz curves, with b = 1 and a in {0, 1},
+/// are defined over GF(2), so the Frobenius map (x, y) -> (x^2, y^2) is an endomorphism that
+/// scalar multiplication can use in place of doubling.
+#[derive(Debug, Clone)]
+pub struct BinaryCurve {
+    pub field: BinaryExtensionField, // Field of definition
+    pub a: Polynomial,               // Coefficient of x^2
+    pub b: Polynomial,               // Constant coefficient
+}
+
+impl BinaryCurve {
+    /// Creates the curve y^2 + xy = x^3 + ax^2 + b over `field`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BinaryCurve)` if a and b are field elements and b != 0.
+    /// * `Err(EllipticCurveError)` describing the first failed check.
+    pub fn new(
+        field: BinaryExtensionField,
+        a: Polynomial,
+        b: Polynomial,
+    ) -> Result<Self, EllipticCurveError> {
+        if !field.is_element(&a) || !field.is_element(&b) {
+            return Err(EllipticCurveError::CoefficientOutOfRange);
+        }
+        if b.is_zero() {
+            return Err(EllipticCurveError::SingularCurve);
+        }
+        Ok(BinaryCurve { field, a, b })
+    }
+
+    /// Returns the curve of a named parameter set.
+    pub fn named(curve: NamedBinaryCurve) -> Self {
+        let field = curve.field();
+        BinaryCurve {
+            a: curve.a(&field),
+            b: curve.b(&field),
+            field,
+        }
+    }
+
+    /// Returns true for a Koblitz curve: b = 1 and a is 0 or 1.
+    pub fn is_koblitz(&self) -> bool {
+        self.b.is_one() && (self.a.is_zero() || self.a.is_one())
+    }
+
+    /// Creates a point after checking that it lies on the curve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` if x and y are field elements and y^2 + xy = x^3 + ax^2 + b.
+    /// * `Err(EllipticCurveError::PointNotOnCurve)` otherwise.
+    pub fn point(
+        &self,
+        x: Polynomial,
+        y: Polynomial,
+    ) -> Result<AffinePoint<Polynomial>, EllipticCurveError> {
+        let point = AffinePoint::new(x, y);
+        if !self.is_on_curve(&point) {
+            return Err(EllipticCurveError::PointNotOnCurve);
+        }
+        Ok(point)
+    }
+
+    /// Returns true if the point is the point at infinity or satisfies the curve equation with
+    /// coordinates in the field.
+    pub fn is_on_curve(&self, point: &AffinePoint<Polynomial>) -> bool {
+        let AffinePoint::Finite { x, y } = point else {
+            return true;
+        };
+        let f = &self.field;
+        if !f.is_element(x) || !f.is_element(y) {
+            return false;
+        }
+        let left = f.multiply(&f.add(y, x), y);
+        let x2 = f.square(x);
+        let right = f.add(&f.multiply(&f.add(x, &self.a), &x2), &self.b);
+        left == right
+    }
+
+    /// Returns -P = (x, x + y).
+    pub fn negate(&self, point: &AffinePoint<Polynomial>) -> AffinePoint<Polynomial> {
+        match point {
+            AffinePoint::Infinity => AffinePoint::Infinity,
+            AffinePoint::Finite { x, y } => AffinePoint::new(x.clone(), self.field.add(x, y)),
+        }
+    }
+
+    /// Adds two points with the affine formulas.
+    pub fn add(
+        &self,
+        p: &AffinePoint<Polynomial>,
+        q: &AffinePoint<Polynomial>,
+    ) -> AffinePoint<Polynomial> {
+        let (x1, y1, x2, y2) = match (p, q) {
+            (AffinePoint::Infinity, _) => return q.clone(),
+            (_, AffinePoint::Infinity) => return p.clone(),
+            (AffinePoint::Finite { x: x1, y: y1 }, AffinePoint::Finite { x: x2, y: y2 }) => {
+                (x1, y1, x2, y2)
+            }
+        };
+        if x1 == x2 {
+            return if y1 == y2 {
+                self.double(p)
+            } else {
+                AffinePoint::Infinity
+            };
+        }
+        let f = &self.field;
+        let dx = f.add(x1, x2);
+        let slope = f.multiply(&f.add(y1, y2), &self.invert(&dx));
+        // x3 = slope^2 + slope + x1 + x2 + a, y3 = slope (x1 + x3) + x3 + y1
+        let x3 = f.add(&f.add(&f.add(&f.square(&slope), &slope), &dx), &self.a);
+        let y3 = f.add(&f.add(&f.multiply(&slope, &f.add(x1, &x3)), &x3), y1);
+        AffinePoint::new(x3, y3)
+    }
+
+    /// Doubles a point with the affine formulas. Points with x = 0 have order 2.
+    pub fn double(&self, point: &AffinePoint<Polynomial>) -> AffinePoint<Polynomial> {
+        let AffinePoint::Finite { x, y } = point else {
+            return AffinePoint::Infinity;
+        };
+        if x.is_zero() {
+            return AffinePoint::Infinity;
+        }
+        let f = &self.field;
+        // slope = x + y / x, x3 = slope^2 + slope + a, y3 = x^2 + (slope + 1) x3
+        let slope = f.add(x, &f.multiply(y, &self.invert(x)));
+        let x3 = f.add(&f.add(&f.square(&slope), &slope), &self.a);
+        let y3 = f.add(
+            &f.square(x),
+            &f.multiply(&f.add(&slope, &Polynomial::one()), &x3),
+        );
+        AffinePoint::new(x3, y3)
+    }
+
+    /// Applies the Frobenius map (x, y) -> (x^2, y^2), written tau. It maps the curve to
+    /// itself only when a and b lie in GF(2), as on Koblitz curves.
+    pub fn frobenius(&self, point: &AffinePoint<Polynomial>) -> AffinePoint<Polynomial> {
+        match point {
+            AffinePoint::Infinity => AffinePoint::Infinity,
+            AffinePoint::Finite { x, y } => {
+                AffinePoint::new(self.field.square(x), self.field.square(y))
+            }
+        }
+    }
+
+    /// Converts a López-Dahab point back to affine coordinates with one inversion.
+    pub fn to_affine(&self, point: &LopezDahabPoint) -> AffinePoint<Polynomial> {
+        if point.is_infinity() {
+            return AffinePoint::Infinity;
+        }
+        let f = &self.field;
+        let z_inv = self.invert(&point.z);
+        AffinePoint::new(
+            f.multiply(&point.x, &z_inv),
+            f.multiply(&point.y, &f.square(&z_inv)),
+        )
+    }
+
+    /// Doubles a López-Dahab point (Guide to Elliptic Curve Cryptography, equation 3.25):
+    /// Z3 = X^2 Z^2, X3 = X^4 + b Z^4 and Y3 = b Z^4 Z3 + X3 (a Z3 + Y^2 + b Z^4).
+    pub fn lopez_dahab_double(&self, point: &LopezDahabPoint) -> LopezDahabPoint {
+        if point.is_infinity() || point.x.is_zero() {
+            return LopezDahabPoint::infinity();
+        }
+        let f = &self.field;
+        let xx = f.square(&point.x);
+        let zz = f.square(&point.z);
+        let z3 = f.multiply(&xx, &zz);
+        let bz4 = f.multiply(&self.b, &f.square(&zz));
+        let x3 = f.add(&f.square(&xx), &bz4);
+        let inner = f.add(&f.add(&f.multiply(&self.a, &z3), &f.square(&point.y)), &bz4);
+        let y3 = f.add(&f.multiply(&bz4, &z3), &f.multiply(&x3, &inner));
+        LopezDahabPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Adds an affine point to a López-Dahab point (Guide to Elliptic Curve Cryptography,
+    /// algorithm 3.25, extended to any a), falling back to doubling when the points are equal.
+    pub fn lopez_dahab_add_mixed(
+        &self,
+        p: &LopezDahabPoint,
+        q: &AffinePoint<Polynomial>,
+    ) -> LopezDahabPoint {
+        let AffinePoint::Finite { x: x2, y: y2 } = q else {
+            return p.clone();
+        };
+        if p.is_infinity() {
+            return LopezDahabPoint::from(q);
+        }
+        let f = &self.field;
+        let z1z1 = f.square(&p.z);
+        let a = f.add(&f.multiply(y2, &z1z1), &p.y);
+        let b = f.add(&f.multiply(x2, &p.z), &p.x);
+        if b.is_zero() {
+            return if a.is_zero() {
+                self.lopez_dahab_double(&LopezDahabPoint::from(q))
+            } else {
+                LopezDahabPoint::infinity()
+            };
+        }
+        let c = f.multiply(&p.z, &b);
+        let d = f.multiply(&f.square(&b), &f.add(&c, &f.multiply(&self.a, &z1z1)));
+        let z3 = f.square(&c);
+        let e = f.multiply(&a, &c);
+        let x3 = f.add(&f.add(&f.square(&a), &d), &e);
+        let g = f.add(&x3, &f.multiply(x2, &z3));
+        let h = f.multiply(&f.add(x2, y2), &f.square(&z3));
+        let y3 = f.add(&f.multiply(&f.add(&e, &z3), &g), &h);
+        LopezDahabPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Applies the Frobenius map to a López-Dahab point by squaring every coordinate.
+    pub fn lopez_dahab_frobenius(&self, point: &LopezDahabPoint) -> LopezDahabPoint {
+        LopezDahabPoint {
+            x: self.field.square(&point.x),
+            y: self.field.square(&point.y),
+            z: self.field.square(&point.z),
+        }
+    }
+
+    /// Computes kP with the default method: a tau-adic NAF on Koblitz curves and a width-4
+    /// NAF otherwise.
+    pub fn scalar_mul(
+        &self,
+        k: &BigUint,
+        point: &AffinePoint<Polynomial>,
+    ) -> AffinePoint<Polynomial> {
+        match self.mul_tnaf(k, point) {
+            Ok(result) => result,
+            Err(_) => self.mul_wnaf(k, point, DEFAULT_WNAF_WIDTH),
+        }
+    }
+
+    /// Computes kP by left-to-right double-and-add in López-Dahab coordinates.
+    pub fn mul_double_and_add(
+        &self,
+        k: &BigUint,
+        point: &AffinePoint<Polynomial>,
+    ) -> AffinePoint<Polynomial> {
+        let mut result = LopezDahabPoint::infinity();
+        for i in (0..k.bits()).rev() {
+            result = self.lopez_dahab_double(&result);
+            if k.bit(i) {
+                result = self.lopez_dahab_add_mixed(&result, point);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    /// Computes kP from the width-w NAF of k. The odd multiples of P are precomputed in
+    /// affine form, so that every addition in the main loop is a mixed addition.
+    pub fn mul_wnaf(
+        &self,
+        k: &BigUint,
+        point: &AffinePoint<Polynomial>,
+        width: usize,
+    ) -> AffinePoint<Polynomial> {
+        let twice = self.double(point);
+        let mut odd_multiples = vec![point.clone()];
+        for i in 1..1usize << (width.max(2) - 2) {
+            let next = self.add(&odd_multiples[i - 1], &twice);
+            odd_multiples.push(next);
+        }
+
+        let mut result = LopezDahabPoint::infinity();
+        for &digit in wnaf(k, width).iter().rev() {
+            result = self.lopez_dahab_double(&result);
+            if digit > 0 {
+                result = self.lopez_dahab_add_mixed(&result, &odd_multiples[(digit as usize) / 2]);
+            } else if digit < 0 {
+                let negated = self.negate(&odd_multiples[(-digit as usize) / 2]);
+                result = self.lopez_dahab_add_mixed(&result, &negated);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    /// Computes kP on a Koblitz curve from the tau-adic NAF of k (Solinas, 2000).
+    ///
+    /// Frobenius satisfies tau^2 - mu tau + 2 = 0 with mu = (-1)^(1 - a), so k can be written
+    /// as a sum of signed powers of tau, and kP is evaluated with squarings in place of
+    /// doublings. k is first reduced modulo tau^m - 1, which acts as zero on every point, so the
+    /// expansion has about m digits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` with kP.
+    /// * `Err(EllipticCurveError::NotKoblitzCurve)` on other curves.
+    pub fn mul_tnaf(
+        &self,
+        k: &BigUint,
+        point: &AffinePoint<Polynomial>,
+    ) -> Result<AffinePoint<Polynomial>, EllipticCurveError> {
+        if !self.is_koblitz() {
+            return Err(EllipticCurveError::NotKoblitzCurve);
+        }
+        let mu = self.mu();
+        let (r0, r1) = reduce_mod_frobenius_power(k, self.field.degree(), mu);
+        let negated = self.negate(point);
+
+        let mut result = LopezDahabPoint::infinity();
+        for &digit in tnaf(r0, r1, mu).iter().rev() {
+            result = self.lopez_dahab_frobenius(&result);
+            if digit > 0 {
+                result = self.lopez_dahab_add_mixed(&result, point);
+            } else if digit < 0 {
+                result = self.lopez_dahab_add_mixed(&result, &negated);
+            }
+        }
+        Ok(self.to_affine(&result))
+    }
+
+    /// Returns the number of points of a Koblitz curve over GF(2^m), including the point at
+    /// infinity.
+    ///
+    /// Over GF(2) the curve has 2 + 1 - mu points, so the traces V_k of the powers of
+    /// Frobenius follow V_0 = 2, V_1 = mu and V_k = mu V_(k - 1) - 2 V_(k - 2), and the order is
+    /// 2^m + 1 - V_m. This makes Koblitz curves of any size usable where the order must be
+    /// known, such as for discrete logarithm experiments.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with #E(GF(2^m)).
+    /// * `Err(EllipticCurveError::NotKoblitzCurve)` on other curves.
+    pub fn koblitz_order(&self) -> Result<BigUint, EllipticCurveError> {
+        if !self.is_koblitz() {
+            return Err(EllipticCurveError::NotKoblitzCurve);
+        }
+        let mu = BigInt::from(self.mu());
+        let (mut previous, mut trace) = (BigInt::from(2), mu.clone());
+        for _ in 1..self.field.degree() {
+            let next = &mu * &trace - 2 * &previous;
+            previous = std::mem::replace(&mut trace, next);
+        }
+        let order: BigInt = (BigInt::one() << self.field.degree()) + 1 - trace;
+        Ok(order
+            .to_biguint()
+            .expect("the Hasse bound keeps the order positive"))
+    }
+
+    /// Returns mu = (-1)^(1 - a) of a Koblitz curve.
+    fn mu(&self) -> i32 {
+        if self.a.is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn invert(&self, a: &Polynomial) -> Polynomial {
+        self.field
+            .inverse(a)
+            .expect("nonzero field elements are invertible")
+    }
+}
+
+/// Returns the tau-adic NAF of r0 + r1 tau, least significant digit first (Guide to Elliptic
+/// Curve Cryptography, algorithm 3.61). Digits are -1, 0 or 1 and no two adjacent digits are
+/// nonzero.
+pub fn tnaf(mut r0: BigInt, mut r1: BigInt, mu: i32) -> Vec<i8> {
+    let four = BigInt::from(4);
+    let mut digits = Vec::new();
+    while !r0.is_zero() || !r1.is_zero() {
+        let digit = if r0.is_odd() {
+            let residue = (&r0 - (&r1 << 1u32)).mod_floor(&four);
+            let digit: i8 = if residue == BigInt::one() { 1 } else { -1 };
+            r0 -= digit;
+            digit
+        } else {
+            0
+        };
+        digits.push(digit);
+        let half = &r0 >> 1u32;
+        let next_r0 = if mu == 1 { &r1 + &half } else { &r1 - &half };
+        r1 = -half;
+        r0 = next_r0;
+    }
+    digits
+}
+
+/// Reduces k modulo tau^m - 1 in Z[tau], returning r0 + r1 tau congruent to k.
+///
+/// tau^m = U_m tau - 2 U_(m - 1) for the Lucas sequence U_0 = 0, U_1 = 1,
+/// U_(i + 1) = mu U_i - 2 U_(i - 1). The quotient k / (tau^m - 1) is computed with the
+/// conjugate and rounded coordinate-wise.
+fn reduce_mod_frobenius_power(k: &BigUint, m: usize, mu: i32) -> (BigInt, BigInt) {
+    let mu = BigInt::from(mu);
+    let (mut previous, mut current) = (BigInt::zero(), BigInt::one());
+    for _ in 1..m {
+        let next = &mu * &current - (&previous << 1u32);
+        previous = current;
+        current = next;
+    }
+    // tau^m - 1 = a0 + a1 tau
+    let a0 = -(previous << 1u32) - 1;
+    let a1 = current;
+    let norm = &a0 * &a0 + &mu * &a0 * &a1 + ((&a1 * &a1) << 1u32);
+
+    let k = BigInt::from_biguint(Sign::Plus, k.clone());
+    let q0 = round_div(&(&k * (&a0 + &mu * &a1)), &norm);
+    let q1 = round_div(&(-(&k * &a1)), &norm);
+    let r0 = &k - (&q0 * &a0 - ((&q1 * &a1) << 1u32));
+    let r1: BigInt = &q0 * &a1 + &q1 * &a0 + &mu * &q1 * &a1;
+    (r0, -r1)
+}
+
+/// Rounds x / n to the nearest integer for n > 0.
+fn round_div(x: &BigInt, n: &BigInt) -> BigInt {
+    ((x << 1u32) + n).div_floor(&(n << 1u32))
+}
+
+impl Algebra for BinaryCurve {
+    type Element = AffinePoint<Polynomial>;
+}
+
+impl traits::Group for BinaryCurve {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.add(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        AffinePoint::Infinity
+    }
+
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        Some(self.negate(a))
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        self.scalar_mul(exp, a)
+    }
+}
+
+impl Membership for BinaryCurve {
+    /// Elements are the points on the curve, including the point at infinity.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        self.is_on_curve(a)
+    }
+}
+
+impl fmt::Display for BinaryCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Binary elliptic curve y^2 + xy = x^3 + ({})x^2 + ({}) over GF(2^{})",
+            self.a,
+            self.b,
+            self.field.degree()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::RandBigInt;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn named_generators_lie_on_the_curve_and_have_order_n() {
+        for named in NamedBinaryCurve::ALL {
+            let curve = BinaryCurve::named(named);
+            let generator = named.generator(&curve.field);
+            assert!(curve.is_on_curve(&generator), "{}", named);
+            assert_eq!(
+                curve.scalar_mul(&named.order(), &generator),
+                AffinePoint::Infinity,
+                "{}",
+                named
+            );
+            assert_eq!(curve.is_koblitz(), named.is_koblitz(), "{}", named);
+            if named.is_koblitz() {
+                assert_eq!(
+                    curve.koblitz_order().unwrap(),
+                    named.order() * named.cofactor(),
+                    "{}",
+                    named
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tnaf_matches_double_and_add() {
+        let mut rng = StdRng::seed_from_u64(163);
+        for named in [NamedBinaryCurve::K163, NamedBinaryCurve::K233] {
+            let curve = BinaryCurve::named(named);
+            let generator = named.generator(&curve.field);
+            let n = named.order();
+            let mut scalars: Vec<BigUint> = (0u32..6).map(BigUint::from).collect();
+            scalars.extend((0..3).map(|_| rng.gen_biguint_below(&n)));
+            scalars.push(&n - 1u32);
+            scalars.push(&n + 5u32);
+            for k in &scalars {
+                let expected = curve.mul_double_and_add(k, &generator);
+                assert_eq!(
+                    curve.mul_tnaf(k, &generator).unwrap(),
+                    expected,
+                    "{} k = {}",
+                    curve,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tnaf_expansions_are_non_adjacent() {
+        for (r0, r1) in [(1, 0), (7, -3), (-12, 5), (1000, 999)] {
+            for mu in [-1, 1] {
+                let digits = tnaf(BigInt::from(r0), BigInt::from(r1), mu);
+                assert!(digits.windows(2).all(|pair| pair[0] == 0 || pair[1] == 0));
+            }
+        }
+    }
+}
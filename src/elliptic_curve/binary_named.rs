@@ -0,0 +1,314 @@
+// src/elliptic_curve/binary_named.rs
+
+use super::AffinePoint;
+use crate::algebra::traits::ElementEncoding;
+use crate::binary_extension_field::BinaryExtensionField;
+use crate::polynomial::Polynomial;
+use num_bigint::BigUint;
+use num_traits::Num;
+use std::fmt;
+
+/// Named binary curves of FIPS 186-4, appendix D.1.3: the pseudo-random curves B-163 to B-571
+/// (a = 1) and the Koblitz curves K-163 to K-571 (b = 1, a in {0, 1}).
+///
+/// Each comes with a base point G of prime order n; the cofactor is 2 for the B curves and
+/// K-163, and 4 for the other K curves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedBinaryCurve {
+    B163,
+    B233,
+    B283,
+    B409,
+    B571,
+    K163,
+    K233,
+    K283,
+    K409,
+    K571,
+}
+
+struct Parameters {
+    modulus: &'static [usize],
+    a: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    n: &'static str,
+    cofactor: u32,
+}
+
+impl NamedBinaryCurve {
+    /// All named binary curves, the B curves first.
+    pub const ALL: [NamedBinaryCurve; 10] = [
+        NamedBinaryCurve::B163,
+        NamedBinaryCurve::B233,
+        NamedBinaryCurve::B283,
+        NamedBinaryCurve::B409,
+        NamedBinaryCurve::B571,
+        NamedBinaryCurve::K163,
+        NamedBinaryCurve::K233,
+        NamedBinaryCurve::K283,
+        NamedBinaryCurve::K409,
+        NamedBinaryCurve::K571,
+    ];
+
+    /// Returns the NIST name of the curve, such as `B-163` or `K-571`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedBinaryCurve::B163 => "B-163",
+            NamedBinaryCurve::B233 => "B-233",
+            NamedBinaryCurve::B283 => "B-283",
+            NamedBinaryCurve::B409 => "B-409",
+            NamedBinaryCurve::B571 => "B-571",
+            NamedBinaryCurve::K163 => "K-163",
+            NamedBinaryCurve::K233 => "K-233",
+            NamedBinaryCurve::K283 => "K-283",
+            NamedBinaryCurve::K409 => "K-409",
+            NamedBinaryCurve::K571 => "K-571",
+        }
+    }
+
+    /// Looks a curve up by the name returned by `name`, ignoring case.
+    pub fn from_name(name: &str) -> Option<NamedBinaryCurve> {
+        NamedBinaryCurve::ALL
+            .into_iter()
+            .find(|curve| curve.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns true for the Koblitz curves K-163 to K-571.
+    pub fn is_koblitz(&self) -> bool {
+        self.name().starts_with('K')
+    }
+
+    /// Returns the field GF(2^m) with the curve's trinomial or pentanomial reduction polynomial.
+    pub fn field(&self) -> BinaryExtensionField {
+        let exponents = self.parameters().modulus;
+        let degree = exponents[0];
+        let mut coeffs = vec![0u8; degree + 1];
+        for &exponent in exponents {
+            coeffs[degree - exponent] = 1;
+        }
+        BinaryExtensionField::new_valid_modulus(Polynomial::new(&coeffs))
+    }
+
+    /// Returns the coefficient a as an element of `field`.
+    pub fn a(&self, field: &BinaryExtensionField) -> Polynomial {
+        parse(field, self.parameters().a)
+    }
+
+    /// Returns the coefficient b as an element of `field`.
+    pub fn b(&self, field: &BinaryExtensionField) -> Polynomial {
+        parse(field, self.parameters().b)
+    }
+
+    /// Returns the base point G with coordinates in `field`.
+    pub fn generator(&self, field: &BinaryExtensionField) -> AffinePoint<Polynomial> {
+        let parameters = self.parameters();
+        AffinePoint::new(parse(field, parameters.gx), parse(field, parameters.gy))
+    }
+
+    /// Returns the prime order n of G.
+    pub fn order(&self) -> BigUint {
+        BigUint::from_str_radix(self.parameters().n, 16).expect("curve orders are valid hex")
+    }
+
+    /// Returns the cofactor h = #E / n.
+    pub fn cofactor(&self) -> u32 {
+        self.parameters().cofactor
+    }
+
+    /// Returns the extension degree m.
+    pub fn degree(&self) -> usize {
+        self.parameters().modulus[0]
+    }
+
+    fn parameters(&self) -> Parameters {
+        match self {
+            NamedBinaryCurve::B163 => Parameters {
+                modulus: &[163, 7, 6, 3, 0],
+                a: "1",
+                b: "20a601907b8c953ca1481eb10512f78744a3205fd",
+                gx: "3f0eba16286a2d57ea0991168d4994637e8343e36",
+                gy: "d51fbc6c71a0094fa2cdd545b11c5c0c797324f1",
+                n: "40000000000000000000292fe77e70c12a4234c33",
+                cofactor: 2,
+            },
+            NamedBinaryCurve::B233 => Parameters {
+                modulus: &[233, 74, 0],
+                a: "1",
+                b: "66647ede6c332c7f8c0923bb58213b333b20e9ce4281fe115f7d8f90ad",
+                gx: "fac9dfcbac8313bb2139f1bb755fef65bc391f8b36f8f8eb7371fd558b",
+                gy: "1006a08a41903350678e58528bebf8a0beff867a7ca36716f7e01f81052",
+                n: "1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7",
+                cofactor: 2,
+            },
+            NamedBinaryCurve::B283 => Parameters {
+                modulus: &[283, 12, 7, 5, 0],
+                a: "1",
+                b: concat!(
+                    "27b680ac8b8596da5a4af8a",
+                    "19a0303fca97fd7645309fa2a581485af6263e313b79a2f5",
+                ),
+                gx: concat!(
+                    "5f939258db7dd90e1934f8c",
+                    "70b0dfec2eed25b8557eac9c80e2e198f8cdbecd86b12053",
+                ),
+                gy: concat!(
+                    "3676854fe24141cb98fe6d4",
+                    "b20d02b4516ff702350eddb0826779c813f0df45be8112f4",
+                ),
+                n: concat!(
+                    "3ffffffffffffffffffffff",
+                    "ffffffffffffef90399660fc938a90165b042a7cefadb307",
+                ),
+                cofactor: 2,
+            },
+            NamedBinaryCurve::B409 => Parameters {
+                modulus: &[409, 87, 0],
+                a: "1",
+                b: concat!(
+                    "21a5c2",
+                    "c8ee9feb5c4b9a753b7b476b7fd6422ef1f3dd674761fa99",
+                    "d6ac27c8a9a197b272822f6cd57a55aa4f50ae317b13545f",
+                ),
+                gx: concat!(
+                    "15d4860",
+                    "d088ddb3496b0c6064756260441cde4af1771d4db01ffe5b",
+                    "34e59703dc255a868a1180515603aeab60794e54bb7996a7",
+                ),
+                gy: concat!(
+                    "61b1cf",
+                    "ab6be5f32bbfa78324ed106a7636b9c5a7bd198d0158aa4f",
+                    "5488d08f38514f1fdf4b4f40d2181b3681c364ba0273c706",
+                ),
+                n: concat!(
+                    "1000000",
+                    "0000000000000000000000000000000000000000000001e2",
+                    "aad6a612f33307be5fa47c3c9e052f838164cd37d9a21173",
+                ),
+                cofactor: 2,
+            },
+            NamedBinaryCurve::B571 => Parameters {
+                modulus: &[571, 10, 5, 2, 0],
+                a: "1",
+                b: concat!(
+                    "2f40e7e2221f295de297117b7f3d62f5c6a97ffcb8ceff1",
+                    "cd6ba8ce4a9a18ad84ffabbd8efa59332be7ad6756a66e29",
+                    "4afd185a78ff12aa520e4de739baca0c7ffeff7f2955727a",
+                ),
+                gx: concat!(
+                    "303001d34b856296c16c0d40d3cd7750a93d1d2955fa80a",
+                    "a5f40fc8db7b2abdbde53950f4c0d293cdd711a35b67fb14",
+                    "99ae60038614f1394abfa3b4c850d927e1e7769c8eec2d19",
+                ),
+                gy: concat!(
+                    "37bf27342da639b6dccfffeb73d69d78c6c27a6009cbbca",
+                    "1980f8533921e8a684423e43bab08a576291af8f461bb2a8",
+                    "b3531d2f0485c19b16e2f1516e23dd3c1a4827af1b8ac15b",
+                ),
+                n: concat!(
+                    "3ffffffffffffffffffffffffffffffffffffffffffffff",
+                    "ffffffffffffffffffffffffe661ce18ff55987308059b18",
+                    "6823851ec7dd9ca1161de93d5174d66e8382e9bb2fe84e47",
+                ),
+                cofactor: 2,
+            },
+            NamedBinaryCurve::K163 => Parameters {
+                modulus: &[163, 7, 6, 3, 0],
+                a: "1",
+                b: "1",
+                gx: "2fe13c0537bbc11acaa07d793de4e6d5e5c94eee8",
+                gy: "289070fb05d38ff58321f2e800536d538ccdaa3d9",
+                n: "4000000000000000000020108a2e0cc0d99f8a5ef",
+                cofactor: 2,
+            },
+            NamedBinaryCurve::K233 => Parameters {
+                modulus: &[233, 74, 0],
+                a: "0",
+                b: "1",
+                gx: "17232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126",
+                gy: "1db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3",
+                n: "8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+                cofactor: 4,
+            },
+            NamedBinaryCurve::K283 => Parameters {
+                modulus: &[283, 12, 7, 5, 0],
+                a: "0",
+                b: "1",
+                gx: concat!(
+                    "503213f78ca44883f1a3b81",
+                    "62f188e553cd265f23c1567a16876913b0c2ac2458492836",
+                ),
+                gy: concat!(
+                    "1ccda380f1c9e318d90f95d",
+                    "07e5426fe87e45c0e8184698e45962364e34116177dd2259",
+                ),
+                n: concat!(
+                    "1ffffffffffffffffffffff",
+                    "ffffffffffffe9ae2ed07577265dff7f94451e061e163c61",
+                ),
+                cofactor: 4,
+            },
+            NamedBinaryCurve::K409 => Parameters {
+                modulus: &[409, 87, 0],
+                a: "0",
+                b: "1",
+                gx: concat!(
+                    "60f05f",
+                    "658f49c1ad3ab1890f7184210efd0987e307c84c27accfb8",
+                    "f9f67cc2c460189eb5aaaa62ee222eb1b35540cfe9023746",
+                ),
+                gy: concat!(
+                    "1e36905",
+                    "0b7c4e42acba1dacbf04299c3460782f918ea427e6325165",
+                    "e9ea10e3da5f6c42e9c55215aa9ca27a5863ec48d8e0286b",
+                ),
+                n: concat!(
+                    "7fffff",
+                    "fffffffffffffffffffffffffffffffffffffffffffffe5f",
+                    "83b2d4ea20400ec4557d5ed3e3e7ca5b4b5c83b8e01e5fcf",
+                ),
+                cofactor: 4,
+            },
+            NamedBinaryCurve::K571 => Parameters {
+                modulus: &[571, 10, 5, 2, 0],
+                a: "0",
+                b: "1",
+                gx: concat!(
+                    "26eb7a859923fbc82189631f8103fe4ac9ca2970012d5d4",
+                    "6024804801841ca44370958493b205e647da304db4ceb08c",
+                    "bbd1ba39494776fb988b47174dca88c7e2945283a01c8972",
+                ),
+                gy: concat!(
+                    "349dc807f4fbf374f4aeade3bca95314dd58cec9f307a54",
+                    "ffc61efc006d8a2c9d4979c0ac44aea74fbebbb9f772aedc",
+                    "b620b01a7ba7af1b320430c8591984f601cd4c143ef1c7a3",
+                ),
+                n: concat!(
+                    "20000000000000000000000000000000000000000000000",
+                    "000000000000000000000000131850e1f19a63e4b391a8db",
+                    "917f4138b630d84be5d639381e91deb45cfe778f637c1001",
+                ),
+                cofactor: 4,
+            },
+        }
+    }
+}
+
+/// Decodes a hex field element, bit i of the integer being the coefficient of x^i.
+fn parse(field: &BinaryExtensionField, hex: &str) -> Polynomial {
+    let value = BigUint::from_str_radix(hex, 16).expect("named curve parameters are valid hex");
+    let length = field.degree().div_ceil(8);
+    let bytes = value.to_bytes_be();
+    let mut padded = vec![0u8; length - bytes.len()];
+    padded.extend_from_slice(&bytes);
+    field
+        .decode_element(&padded)
+        .expect("named curve parameters are field elements")
+}
+
+impl fmt::Display for NamedBinaryCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
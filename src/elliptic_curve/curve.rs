@@ -0,0 +1,382 @@
+// src/elliptic_curve/curve.rs
+
+use super::{AffinePoint, EllipticCurveError, JacobianPoint, NamedCurve};
+use crate::algebra::traits::{self, Algebra, Membership, Ring};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::fmt;
+
+/// A short Weierstrass elliptic curve y^2 = x^3 + ax + b over the prime field `IntegerModP`.
+///
+/// Its points form an abelian group with the point at infinity as the identity, exposed through
+/// `algebra::traits::Group`, so generic code such as Diffie-Hellman and ElGamal runs on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Curve {
+    pub field: IntegerModP, // Field of definition
+    pub a: BigUint,         // Coefficient of x
+    pub b: BigUint,         // Constant coefficient
+}
+
+impl Curve {
+    /// Creates the curve y^2 = x^3 + ax + b over the prime field of order `p`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Curve)` if p is prime, a and b are reduced and 4a^3 + 27b^2 != 0 mod p.
+    /// * `Err(EllipticCurveError)` describing the first failed check.
+    pub fn new(p: BigUint, a: BigUint, b: BigUint) -> Result<Self, EllipticCurveError> {
+        let field = IntegerModP::new(p).map_err(|_| EllipticCurveError::NonPrimeModulus)?;
+        Self::over_field(field, a, b)
+    }
+
+    /// Creates the curve over an existing field, skipping the primality test of `new`.
+    pub fn over_field(
+        field: IntegerModP,
+        a: BigUint,
+        b: BigUint,
+    ) -> Result<Self, EllipticCurveError> {
+        if a >= field.p || b >= field.p {
+            return Err(EllipticCurveError::CoefficientOutOfRange);
+        }
+        let curve = Curve { field, a, b };
+        if curve.discriminant().is_zero() {
+            return Err(EllipticCurveError::SingularCurve);
+        }
+        Ok(curve)
+    }
+
+    /// Returns the curve of a named parameter set.
+    pub fn named(curve: NamedCurve) -> Self {
+        Curve {
+            field: IntegerModP::new_valid_prime(curve.prime()),
+            a: curve.a(),
+            b: curve.b(),
+        }
+    }
+
+    /// Returns 4a^3 + 27b^2 mod p, which is zero exactly when the curve is singular.
+    pub fn discriminant(&self) -> BigUint {
+        let f = &self.field;
+        let a3 = f.mul(&f.mul(&self.a, &self.a), &self.a);
+        let b2 = f.mul(&self.b, &self.b);
+        f.add(
+            &f.mul(&BigUint::from(4u32), &a3),
+            &f.mul(&BigUint::from(27u32), &b2),
+        )
+    }
+
+    /// Creates a point after checking that it lies on the curve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` if 0 <= x, y < p and y^2 = x^3 + ax + b.
+    /// * `Err(EllipticCurveError::PointNotOnCurve)` otherwise.
+    pub fn point(&self, x: BigUint, y: BigUint) -> Result<AffinePoint, EllipticCurveError> {
+        let point = AffinePoint::new(x, y);
+        if !self.is_on_curve(&point) {
+            return Err(EllipticCurveError::PointNotOnCurve);
+        }
+        Ok(point)
+    }
+
+    /// Returns true if the point is the point at infinity or satisfies the curve equation with
+    /// reduced coordinates.
+    pub fn is_on_curve(&self, point: &AffinePoint) -> bool {
+        match point {
+            AffinePoint::Infinity => true,
+            AffinePoint::Finite { x, y } => {
+                x < &self.field.p
+                    && y < &self.field.p
+                    && self.field.mul(y, y) == self.right_hand_side(x)
+            }
+        }
+    }
+
+    /// Evaluates x^3 + ax + b.
+    pub fn right_hand_side(&self, x: &BigUint) -> BigUint {
+        let f = &self.field;
+        let x2 = f.mul(x, x);
+        f.add(&f.mul(&f.add(&x2, &self.a), x), &self.b)
+    }
+
+    /// Returns -P = (x, -y).
+    pub fn negate(&self, point: &AffinePoint) -> AffinePoint {
+        match point {
+            AffinePoint::Infinity => AffinePoint::Infinity,
+            AffinePoint::Finite { x, y } => AffinePoint::new(x.clone(), self.neg(y)),
+        }
+    }
+
+    /// Adds two points with the affine chord-and-tangent formulas.
+    pub fn add(&self, p: &AffinePoint, q: &AffinePoint) -> AffinePoint {
+        let (x1, y1, x2, y2) = match (p, q) {
+            (AffinePoint::Infinity, _) => return q.clone(),
+            (_, AffinePoint::Infinity) => return p.clone(),
+            (AffinePoint::Finite { x: x1, y: y1 }, AffinePoint::Finite { x: x2, y: y2 }) => {
+                (x1, y1, x2, y2)
+            }
+        };
+        if x1 == x2 {
+            return if y1 == y2 {
+                self.double(p)
+            } else {
+                AffinePoint::Infinity
+            };
+        }
+        let f = &self.field;
+        let slope = f.mul(&f.sub(y2, y1), &self.invert(&f.sub(x2, x1)));
+        self.chord(&slope, x1, y1, x2)
+    }
+
+    /// Doubles a point with the affine tangent formula.
+    pub fn double(&self, point: &AffinePoint) -> AffinePoint {
+        let AffinePoint::Finite { x, y } = point else {
+            return AffinePoint::Infinity;
+        };
+        if y.is_zero() {
+            return AffinePoint::Infinity;
+        }
+        let f = &self.field;
+        let numerator = f.add(&f.mul(&BigUint::from(3u32), &f.mul(x, x)), &self.a);
+        let slope = f.mul(&numerator, &self.invert(&f.add(y, y)));
+        self.chord(&slope, x, y, x)
+    }
+
+    /// Returns the third intersection of the line through (x1, y1) with the given slope,
+    /// reflected in the x-axis.
+    fn chord(&self, slope: &BigUint, x1: &BigUint, y1: &BigUint, x2: &BigUint) -> AffinePoint {
+        let f = &self.field;
+        let x3 = f.sub(&f.sub(&f.mul(slope, slope), x1), x2);
+        let y3 = f.sub(&f.mul(slope, &f.sub(x1, &x3)), y1);
+        AffinePoint::new(x3, y3)
+    }
+
+    /// Converts a Jacobian point back to affine coordinates with one inversion.
+    pub fn to_affine(&self, point: &JacobianPoint) -> AffinePoint {
+        if point.is_infinity() {
+            return AffinePoint::Infinity;
+        }
+        let f = &self.field;
+        let z_inv = self.invert(&point.z);
+        let z_inv2 = f.mul(&z_inv, &z_inv);
+        AffinePoint::new(
+            f.mul(&point.x, &z_inv2),
+            f.mul(&point.y, &f.mul(&z_inv2, &z_inv)),
+        )
+    }
+
+    /// Doubles a Jacobian point without inversions; the formulas hold for any coefficient a.
+    pub fn jacobian_double(&self, point: &JacobianPoint) -> JacobianPoint {
+        if point.is_infinity() || point.y.is_zero() {
+            return JacobianPoint::infinity();
+        }
+        let f = &self.field;
+        let (x, y, z) = (&point.x, &point.y, &point.z);
+        let xx = f.mul(x, x);
+        let yy = f.mul(y, y);
+        let yyyy = f.mul(&yy, &yy);
+        let zz = f.mul(z, z);
+        let s = f.mul(&BigUint::from(4u32), &f.mul(x, &yy));
+        let m = f.add(
+            &f.mul(&BigUint::from(3u32), &xx),
+            &f.mul(&self.a, &f.mul(&zz, &zz)),
+        );
+        let x3 = f.sub(&f.mul(&m, &m), &f.add(&s, &s));
+        let y3 = f.sub(
+            &f.mul(&m, &f.sub(&s, &x3)),
+            &f.mul(&BigUint::from(8u32), &yyyy),
+        );
+        let z3 = f.mul(&BigUint::from(2u32), &f.mul(y, z));
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Adds two Jacobian points without inversions, falling back to doubling when the inputs
+    /// are equal.
+    pub fn jacobian_add(&self, p: &JacobianPoint, q: &JacobianPoint) -> JacobianPoint {
+        if p.is_infinity() {
+            return q.clone();
+        }
+        if q.is_infinity() {
+            return p.clone();
+        }
+        let f = &self.field;
+        let z1z1 = f.mul(&p.z, &p.z);
+        let z2z2 = f.mul(&q.z, &q.z);
+        let u1 = f.mul(&p.x, &z2z2);
+        let u2 = f.mul(&q.x, &z1z1);
+        let s1 = f.mul(&p.y, &f.mul(&q.z, &z2z2));
+        let s2 = f.mul(&q.y, &f.mul(&p.z, &z1z1));
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.jacobian_double(p)
+            } else {
+                JacobianPoint::infinity()
+            };
+        }
+        let h = f.sub(&u2, &u1);
+        let r = f.sub(&s2, &s1);
+        let hh = f.mul(&h, &h);
+        let hhh = f.mul(&h, &hh);
+        let v = f.mul(&u1, &hh);
+        let x3 = f.sub(&f.sub(&f.mul(&r, &r), &hhh), &f.add(&v, &v));
+        let y3 = f.sub(&f.mul(&r, &f.sub(&v, &x3)), &f.mul(&s1, &hhh));
+        let z3 = f.mul(&h, &f.mul(&p.z, &q.z));
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Negates a Jacobian point.
+    pub fn jacobian_negate(&self, point: &JacobianPoint) -> JacobianPoint {
+        JacobianPoint {
+            x: point.x.clone(),
+            y: self.neg(&point.y),
+            z: point.z.clone(),
+        }
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.field.sub(&BigUint::zero(), a)
+    }
+
+    fn invert(&self, a: &BigUint) -> BigUint {
+        traits::Group::inverse(&self.field, a).expect("nonzero field elements are invertible")
+    }
+}
+
+impl Algebra for Curve {
+    type Element = AffinePoint;
+}
+
+impl traits::Group for Curve {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.add(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        AffinePoint::Infinity
+    }
+
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        Some(self.negate(a))
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        self.scalar_mul(exp, a)
+    }
+}
+
+impl Membership for Curve {
+    /// Elements are the points on the curve, including the point at infinity.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        self.is_on_curve(a)
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Elliptic curve y^2 = x^3 + {:#x}x + {:#x} over GF({:#x})",
+            self.a, self.b, self.field.p
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::traits::Group;
+
+    /// y^2 = x^3 + 2x + 3 over GF(97) and all of its points, found by brute force.
+    fn small_curve() -> (Curve, Vec<AffinePoint>) {
+        let curve = Curve::new(97u32.into(), 2u32.into(), 3u32.into()).unwrap();
+        let mut points = vec![AffinePoint::Infinity];
+        for x in 0..97u32 {
+            for y in 0..97u32 {
+                if let Ok(point) = curve.point(x.into(), y.into()) {
+                    points.push(point);
+                }
+            }
+        }
+        (curve, points)
+    }
+
+    #[test]
+    fn invalid_curves_and_points_are_rejected() {
+        let new = |p: u32, a: u32, b: u32| Curve::new(p.into(), a.into(), b.into()).unwrap_err();
+        assert_eq!(new(91, 2, 3), EllipticCurveError::NonPrimeModulus);
+        assert_eq!(new(97, 97, 3), EllipticCurveError::CoefficientOutOfRange);
+        assert_eq!(new(97, 2, 100), EllipticCurveError::CoefficientOutOfRange);
+        // 4a^3 + 27b^2 = 0 for y^2 = x^3, and for y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2).
+        assert_eq!(new(97, 0, 0), EllipticCurveError::SingularCurve);
+        assert_eq!(new(97, 94, 2), EllipticCurveError::SingularCurve);
+
+        let (curve, _) = small_curve();
+        assert_eq!(
+            curve.point(0u32.into(), 0u32.into()),
+            Err(EllipticCurveError::PointNotOnCurve)
+        );
+        // (3, 6) is on the curve, but not with an unreduced coordinate.
+        assert!(curve.point(3u32.into(), 6u32.into()).is_ok());
+        assert!(!curve.is_on_curve(&AffinePoint::new(100u32.into(), 6u32.into())));
+        assert!(curve.is_on_curve(&AffinePoint::Infinity));
+    }
+
+    #[test]
+    fn group_law_on_a_small_curve() {
+        let (curve, points) = small_curve();
+        let order = BigUint::from(points.len());
+        // Hasse: |#E - 98| <= 2 sqrt(97).
+        assert!((points.len() as i64 - 98).abs() <= 19);
+
+        for p in &points {
+            assert_eq!(curve.add(p, &AffinePoint::Infinity), *p);
+            assert_eq!(curve.add(p, &curve.negate(p)), AffinePoint::Infinity);
+            assert_eq!(curve.add(p, p), curve.double(p));
+            assert_eq!(curve.scalar_mul(&order, p), AffinePoint::Infinity);
+            for q in &points {
+                let sum = curve.add(p, q);
+                assert!(curve.is_on_curve(&sum));
+                assert_eq!(sum, curve.add(q, p));
+                let jacobian = curve.jacobian_add(&JacobianPoint::from(p), &JacobianPoint::from(q));
+                assert_eq!(curve.to_affine(&jacobian), sum);
+            }
+            let jacobian = curve.jacobian_double(&JacobianPoint::from(p));
+            assert_eq!(curve.to_affine(&jacobian), curve.double(p));
+            let negated = curve.jacobian_negate(&JacobianPoint::from(p));
+            assert_eq!(curve.to_affine(&negated), curve.negate(p));
+        }
+
+        for (i, p) in points.iter().enumerate().step_by(7) {
+            for q in points.iter().skip(i % 5).step_by(5) {
+                for r in points.iter().step_by(11) {
+                    let left = curve.add(&curve.add(p, q), r);
+                    let right = curve.add(p, &curve.add(q, r));
+                    assert_eq!(left, right);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn group_trait_matches_the_curve_operations() {
+        let (curve, points) = small_curve();
+        let (p, q) = (&points[5], &points[17]);
+        assert_eq!(curve.identity(), AffinePoint::Infinity);
+        assert_eq!(curve.combine(p, q), curve.add(p, q));
+        assert_eq!(curve.inverse(p), Some(curve.negate(p)));
+        assert_eq!(
+            curve.pow(p, &BigUint::from(3u32)),
+            curve.add(p, &curve.double(p))
+        );
+        assert!(curve.is_element(p));
+    }
+}
@@ -0,0 +1,46 @@
+// src/elliptic_curve/curve25519.rs
+
+use num_bigint::BigUint;
+use num_traits::Num;
+
+/// The coefficient A of Curve25519, v^2 = u^3 + Au^2 + u (RFC 7748, section 4.1).
+pub const MONTGOMERY_A: u32 = 486662;
+
+/// The u-coordinate of the Montgomery base point.
+pub const BASE_U: u32 = 9;
+
+/// Returns the field prime p = 2^255 - 19.
+pub fn prime() -> BigUint {
+    (BigUint::from(1u32) << 255) - 19u32
+}
+
+/// Returns the prime order L = 2^252 + 27742317777372353535851937790883648493 of the base
+/// points. Both curves have order 8L.
+pub fn order() -> BigUint {
+    parse("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed")
+}
+
+/// Returns the v-coordinate of the Montgomery base point.
+pub fn base_v() -> BigUint {
+    parse("20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9")
+}
+
+/// Returns the coefficient d = -121665 / 121666 of edwards25519, -x^2 + y^2 = 1 + dx^2y^2
+/// (RFC 8032, section 5.1).
+pub fn edwards_d() -> BigUint {
+    parse("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3")
+}
+
+/// Returns the x-coordinate of the Edwards base point.
+pub fn edwards_base_x() -> BigUint {
+    parse("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a")
+}
+
+/// Returns the y-coordinate 4/5 of the Edwards base point.
+pub fn edwards_base_y() -> BigUint {
+    parse("6666666666666666666666666666666666666666666666666666666666666658")
+}
+
+fn parse(hex: &str) -> BigUint {
+    BigUint::from_str_radix(hex, 16).expect("curve parameters are valid hex")
+}
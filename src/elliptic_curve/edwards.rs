@@ -0,0 +1,282 @@
+// src/elliptic_curve/edwards.rs
+
+use super::{curve25519, EdwardsPoint, EllipticCurveError, ExtendedPoint};
+use crate::algebra::traits::{self, Algebra, ElementEncoding, Membership, Ring};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// A twisted Edwards curve ax^2 + y^2 = 1 + dx^2y^2 over the prime field `IntegerModP`.
+///
+/// When a is a square and d is not, as on edwards25519, the addition law is complete: the same
+/// formula adds any two points, doubling and the identity included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdwardsCurve {
+    pub field: IntegerModP, // Field of definition
+    pub a: BigUint,         // Coefficient of x^2
+    pub d: BigUint,         // Coefficient of x^2y^2
+}
+
+impl EdwardsCurve {
+    /// Creates the curve ax^2 + y^2 = 1 + dx^2y^2 over the prime field of order `p`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EdwardsCurve)` if p is prime and odd, a and d are reduced, and a, d and a - d are
+    ///   nonzero.
+    /// * `Err(EllipticCurveError)` describing the first failed check.
+    pub fn new(p: BigUint, a: BigUint, d: BigUint) -> Result<Self, EllipticCurveError> {
+        let field = IntegerModP::new(p).map_err(|_| EllipticCurveError::NonPrimeModulus)?;
+        if field.p == BigUint::from(2u32) {
+            return Err(EllipticCurveError::NonPrimeModulus);
+        }
+        if a >= field.p || d >= field.p {
+            return Err(EllipticCurveError::CoefficientOutOfRange);
+        }
+        if a.is_zero() || d.is_zero() || a == d {
+            return Err(EllipticCurveError::SingularCurve);
+        }
+        Ok(EdwardsCurve { field, a, d })
+    }
+
+    /// Returns edwards25519, -x^2 + y^2 = 1 - (121665 / 121666)x^2y^2 over GF(2^255 - 19).
+    pub fn edwards25519() -> Self {
+        let field = IntegerModP::new_valid_prime(curve25519::prime());
+        EdwardsCurve {
+            a: &field.p - 1u32,
+            d: curve25519::edwards_d(),
+            field,
+        }
+    }
+
+    /// Returns the base point B of edwards25519, of prime order `curve25519::order()`.
+    pub fn edwards25519_base_point() -> EdwardsPoint {
+        EdwardsPoint::new(curve25519::edwards_base_x(), curve25519::edwards_base_y())
+    }
+
+    /// Creates a point after checking that it lies on the curve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EdwardsPoint)` if 0 <= x, y < p and ax^2 + y^2 = 1 + dx^2y^2.
+    /// * `Err(EllipticCurveError::PointNotOnCurve)` otherwise.
+    pub fn point(&self, x: BigUint, y: BigUint) -> Result<EdwardsPoint, EllipticCurveError> {
+        let point = EdwardsPoint::new(x, y);
+        if !self.is_on_curve(&point) {
+            return Err(EllipticCurveError::PointNotOnCurve);
+        }
+        Ok(point)
+    }
+
+    /// Returns true if the point has reduced coordinates and satisfies the curve equation.
+    pub fn is_on_curve(&self, point: &EdwardsPoint) -> bool {
+        let f = &self.field;
+        let (x, y) = (&point.x, &point.y);
+        if x >= &f.p || y >= &f.p {
+            return false;
+        }
+        let xx = f.mul(x, x);
+        let yy = f.mul(y, y);
+        let left = f.add(&f.mul(&self.a, &xx), &yy);
+        let right = f.add(&BigUint::one(), &f.mul(&self.d, &f.mul(&xx, &yy)));
+        left == right
+    }
+
+    /// Returns -P = (-x, y).
+    pub fn negate(&self, point: &EdwardsPoint) -> EdwardsPoint {
+        EdwardsPoint::new(self.neg(&point.x), point.y.clone())
+    }
+
+    /// Adds two points with the affine addition law
+    /// (x1y2 + y1x2) / (1 + dx1x2y1y2), (y1y2 - ax1x2) / (1 - dx1x2y1y2).
+    ///
+    /// # Panics
+    ///
+    /// On curves whose addition law is not complete, if a denominator vanishes.
+    pub fn add(&self, p: &EdwardsPoint, q: &EdwardsPoint) -> EdwardsPoint {
+        let f = &self.field;
+        let x1x2 = f.mul(&p.x, &q.x);
+        let y1y2 = f.mul(&p.y, &q.y);
+        let dxxyy = f.mul(&self.d, &f.mul(&x1x2, &y1y2));
+        let x3 = f.add(&f.mul(&p.x, &q.y), &f.mul(&p.y, &q.x));
+        let y3 = f.sub(&y1y2, &f.mul(&self.a, &x1x2));
+        EdwardsPoint::new(
+            f.mul(&x3, &self.invert(&f.add(&BigUint::one(), &dxxyy))),
+            f.mul(&y3, &self.invert(&f.sub(&BigUint::one(), &dxxyy))),
+        )
+    }
+
+    /// Doubles a point.
+    pub fn double(&self, point: &EdwardsPoint) -> EdwardsPoint {
+        self.add(point, point)
+    }
+
+    /// Lifts an affine point to extended coordinates with Z = 1.
+    pub fn to_extended(&self, point: &EdwardsPoint) -> ExtendedPoint {
+        ExtendedPoint {
+            x: point.x.clone(),
+            y: point.y.clone(),
+            z: BigUint::one(),
+            t: self.field.mul(&point.x, &point.y),
+        }
+    }
+
+    /// Converts an extended point back to affine coordinates with one inversion.
+    pub fn to_affine(&self, point: &ExtendedPoint) -> EdwardsPoint {
+        let f = &self.field;
+        let z_inv = self.invert(&point.z);
+        EdwardsPoint::new(f.mul(&point.x, &z_inv), f.mul(&point.y, &z_inv))
+    }
+
+    /// Adds two extended points without inversions ("add-2008-hwcd" of Hisil et al.), complete
+    /// under the same conditions as `add`.
+    pub fn extended_add(&self, p: &ExtendedPoint, q: &ExtendedPoint) -> ExtendedPoint {
+        let f = &self.field;
+        let a = f.mul(&p.x, &q.x);
+        let b = f.mul(&p.y, &q.y);
+        let c = f.mul(&self.d, &f.mul(&p.t, &q.t));
+        let d = f.mul(&p.z, &q.z);
+        let e = f.sub(
+            &f.sub(&f.mul(&f.add(&p.x, &p.y), &f.add(&q.x, &q.y)), &a),
+            &b,
+        );
+        let ff = f.sub(&d, &c);
+        let g = f.add(&d, &c);
+        let h = f.sub(&b, &f.mul(&self.a, &a));
+        ExtendedPoint {
+            x: f.mul(&e, &ff),
+            y: f.mul(&g, &h),
+            z: f.mul(&ff, &g),
+            t: f.mul(&e, &h),
+        }
+    }
+
+    /// Doubles an extended point without inversions ("dbl-2008-hwcd"); T is not read.
+    pub fn extended_double(&self, point: &ExtendedPoint) -> ExtendedPoint {
+        let f = &self.field;
+        let a = f.mul(&point.x, &point.x);
+        let b = f.mul(&point.y, &point.y);
+        let zz = f.mul(&point.z, &point.z);
+        let c = f.add(&zz, &zz);
+        let d = f.mul(&self.a, &a);
+        let xy = f.add(&point.x, &point.y);
+        let e = f.sub(&f.sub(&f.mul(&xy, &xy), &a), &b);
+        let g = f.add(&d, &b);
+        let ff = f.sub(&g, &c);
+        let h = f.sub(&d, &b);
+        ExtendedPoint {
+            x: f.mul(&e, &ff),
+            y: f.mul(&g, &h),
+            z: f.mul(&ff, &g),
+            t: f.mul(&e, &h),
+        }
+    }
+
+    /// Computes kP by left-to-right double-and-add in extended coordinates.
+    pub fn scalar_mul(&self, k: &BigUint, point: &EdwardsPoint) -> EdwardsPoint {
+        let base = self.to_extended(point);
+        let mut result = ExtendedPoint::identity();
+        for i in (0..k.bits()).rev() {
+            result = self.extended_double(&result);
+            if k.bit(i) {
+                result = self.extended_add(&result, &base);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    /// Returns the byte length of an encoded point: enough for y and one more bit.
+    pub fn encoded_length(&self) -> usize {
+        (self.field.p.bits() + 1).div_ceil(8) as usize
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.field.sub(&BigUint::zero(), a)
+    }
+
+    fn invert(&self, a: &BigUint) -> BigUint {
+        traits::Group::inverse(&self.field, a).expect("denominators are nonzero")
+    }
+}
+
+impl Algebra for EdwardsCurve {
+    type Element = EdwardsPoint;
+}
+
+impl traits::Group for EdwardsCurve {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.add(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        EdwardsPoint::identity()
+    }
+
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        Some(self.negate(a))
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        self.scalar_mul(exp, a)
+    }
+}
+
+impl Membership for EdwardsCurve {
+    /// Elements are the points on the curve.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        self.is_on_curve(a)
+    }
+}
+
+impl ElementEncoding for EdwardsCurve {
+    /// Encodes y as a little-endian integer with the least significant bit of x in the top bit
+    /// of the final byte (RFC 8032, section 5.1.2).
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        let length = self.encoded_length();
+        let mut bytes = a.y.to_bytes_le();
+        bytes.resize(length, 0);
+        if a.x.bit(0) {
+            bytes[length - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    /// Decodes a point (RFC 8032, section 5.1.3): y must be below p, x is recovered as a
+    /// square root of (y^2 - 1) / (dy^2 - a) and the sign bit selects between x and -x.
+    /// Returns `None` for non-canonical encodings and y-coordinates of no point.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        let length = self.encoded_length();
+        if bytes.len() != length {
+            return None;
+        }
+        let mut bytes = bytes.to_vec();
+        let sign = bytes[length - 1] & 0x80 != 0;
+        bytes[length - 1] &= 0x7f;
+        let y = BigUint::from_bytes_le(&bytes);
+        if y >= self.field.p {
+            return None;
+        }
+        let f = &self.field;
+        let yy = f.mul(&y, &y);
+        let numerator = f.sub(&yy, &BigUint::one());
+        let denominator = f.sub(&f.mul(&self.d, &yy), &self.a);
+        let xx = f.mul(&numerator, &traits::Group::inverse(f, &denominator)?);
+        let x = f.sqrt(&xx)?;
+        if x.is_zero() && sign {
+            return None;
+        }
+        let x = if x.bit(0) != sign { self.neg(&x) } else { x };
+        Some(EdwardsPoint::new(x, y))
+    }
+}
+
+impl fmt::Display for EdwardsCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Twisted Edwards curve {:#x}x^2 + y^2 = 1 + {:#x}x^2y^2 over GF({:#x})",
+            self.a, self.d, self.field.p
+        )
+    }
+}
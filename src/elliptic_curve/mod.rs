@@ -0,0 +1,82 @@
+// src/elliptic_curve/mod.rs
+
+pub mod binary;
+pub mod binary_named;
+pub mod curve;
+pub mod curve25519;
+pub mod edwards;
+pub mod montgomery;
+pub mod named;
+pub mod point;
+pub mod point_count;
+pub mod rho;
+pub mod scalar_mul;
+pub mod sec1;
+
+pub use binary::BinaryCurve;
+pub use binary_named::NamedBinaryCurve;
+pub use curve::Curve;
+pub use edwards::EdwardsCurve;
+pub use montgomery::MontgomeryCurve;
+pub use named::NamedCurve;
+pub use point::{AffinePoint, EdwardsPoint, ExtendedPoint, JacobianPoint, LopezDahabPoint};
+pub use point_count::split_cofactor;
+pub use rho::{pollard_rho_ecdlp, RhoCurve, RhoError, RhoParameters, RhoResult};
+
+use std::fmt;
+
+/// Errors related to elliptic curves and their points.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EllipticCurveError {
+    NonPrimeModulus,
+    SingularCurve,
+    CoefficientOutOfRange,
+    PointNotOnCurve,
+    NotKoblitzCurve,
+    InvalidEncodingLength { expected: usize, found: usize },
+    InvalidEncodingTag(u8),
+    InvalidCompressionBit,
+    CoordinateOutOfRange,
+    InvalidHex,
+}
+
+impl fmt::Display for EllipticCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EllipticCurveError::NonPrimeModulus => {
+                write!(f, "The field modulus `p` must be a prime number.")
+            }
+            EllipticCurveError::SingularCurve => {
+                write!(f, "The curve is singular: its discriminant is zero.")
+            }
+            EllipticCurveError::CoefficientOutOfRange => {
+                write!(f, "The curve coefficients must be reduced field elements.")
+            }
+            EllipticCurveError::PointNotOnCurve => write!(f, "The point is not on the curve."),
+            EllipticCurveError::NotKoblitzCurve => {
+                write!(
+                    f,
+                    "The curve is not a Koblitz curve: b = 1 and a is 0 or 1."
+                )
+            }
+            EllipticCurveError::InvalidEncodingLength { expected, found } => write!(
+                f,
+                "The encoded point should be {} bytes long, found {}.",
+                expected, found
+            ),
+            EllipticCurveError::InvalidEncodingTag(tag) => {
+                write!(f, "Unknown point encoding tag {:#04x}.", tag)
+            }
+            EllipticCurveError::InvalidCompressionBit => write!(
+                f,
+                "No point with this x-coordinate has the requested compression bit."
+            ),
+            EllipticCurveError::CoordinateOutOfRange => {
+                write!(f, "An encoded coordinate is not a reduced field element.")
+            }
+            EllipticCurveError::InvalidHex => write!(f, "The encoded point is not valid hex."),
+        }
+    }
+}
+
+impl std::error::Error for EllipticCurveError {}
@@ -0,0 +1,332 @@
+// src/elliptic_curve/montgomery.rs
+
+use super::{curve25519, AffinePoint, EllipticCurveError};
+use crate::algebra::traits::{self, Algebra, ElementEncoding, Membership, Ring};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// A Montgomery curve By^2 = x^3 + Ax^2 + x over the prime field `IntegerModP`.
+///
+/// Montgomery curves admit the x-only ladder of `ladder`, which computes the x-coordinate of
+/// kP from that of P alone. Points are written (u, v) in RFC 7748 and (x, y) here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryCurve {
+    pub field: IntegerModP, // Field of definition
+    pub a: BigUint,         // Coefficient of x^2
+    pub b: BigUint,         // Coefficient of y^2
+}
+
+impl MontgomeryCurve {
+    /// Creates the curve By^2 = x^3 + Ax^2 + x over the prime field of order `p`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MontgomeryCurve)` if p is prime, A and B are reduced and B(A^2 - 4) != 0 mod p.
+    /// * `Err(EllipticCurveError)` describing the first failed check.
+    pub fn new(p: BigUint, a: BigUint, b: BigUint) -> Result<Self, EllipticCurveError> {
+        let field = IntegerModP::new(p).map_err(|_| EllipticCurveError::NonPrimeModulus)?;
+        if a >= field.p || b >= field.p {
+            return Err(EllipticCurveError::CoefficientOutOfRange);
+        }
+        let four = BigUint::from(4u32) % &field.p;
+        if b.is_zero() || field.mul(&a, &a) == four {
+            return Err(EllipticCurveError::SingularCurve);
+        }
+        Ok(MontgomeryCurve { field, a, b })
+    }
+
+    /// Returns Curve25519, v^2 = u^3 + 486662u^2 + u over GF(2^255 - 19).
+    pub fn curve25519() -> Self {
+        MontgomeryCurve {
+            field: IntegerModP::new_valid_prime(curve25519::prime()),
+            a: BigUint::from(curve25519::MONTGOMERY_A),
+            b: BigUint::one(),
+        }
+    }
+
+    /// Returns the base point (9, v) of Curve25519, of prime order `curve25519::order()`.
+    pub fn curve25519_base_point() -> AffinePoint {
+        AffinePoint::new(BigUint::from(curve25519::BASE_U), curve25519::base_v())
+    }
+
+    /// Creates a point after checking that it lies on the curve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` if 0 <= x, y < p and By^2 = x^3 + Ax^2 + x.
+    /// * `Err(EllipticCurveError::PointNotOnCurve)` otherwise.
+    pub fn point(&self, x: BigUint, y: BigUint) -> Result<AffinePoint, EllipticCurveError> {
+        let point = AffinePoint::new(x, y);
+        if !self.is_on_curve(&point) {
+            return Err(EllipticCurveError::PointNotOnCurve);
+        }
+        Ok(point)
+    }
+
+    /// Returns true if the point is the point at infinity or satisfies the curve equation with
+    /// reduced coordinates.
+    pub fn is_on_curve(&self, point: &AffinePoint) -> bool {
+        match point {
+            AffinePoint::Infinity => true,
+            AffinePoint::Finite { x, y } => {
+                let f = &self.field;
+                x < &f.p && y < &f.p && f.mul(&self.b, &f.mul(y, y)) == self.right_hand_side(x)
+            }
+        }
+    }
+
+    /// Evaluates x^3 + Ax^2 + x.
+    pub fn right_hand_side(&self, x: &BigUint) -> BigUint {
+        let f = &self.field;
+        f.mul(&f.add(&f.mul(&f.add(x, &self.a), x), &BigUint::one()), x)
+    }
+
+    /// Returns -P = (x, -y).
+    pub fn negate(&self, point: &AffinePoint) -> AffinePoint {
+        match point {
+            AffinePoint::Infinity => AffinePoint::Infinity,
+            AffinePoint::Finite { x, y } => {
+                AffinePoint::new(x.clone(), self.field.sub(&BigUint::zero(), y))
+            }
+        }
+    }
+
+    /// Adds two points with the affine chord-and-tangent formulas.
+    pub fn add(&self, p: &AffinePoint, q: &AffinePoint) -> AffinePoint {
+        let (x1, y1, x2, y2) = match (p, q) {
+            (AffinePoint::Infinity, _) => return q.clone(),
+            (_, AffinePoint::Infinity) => return p.clone(),
+            (AffinePoint::Finite { x: x1, y: y1 }, AffinePoint::Finite { x: x2, y: y2 }) => {
+                (x1, y1, x2, y2)
+            }
+        };
+        if x1 == x2 {
+            return if y1 == y2 {
+                self.double(p)
+            } else {
+                AffinePoint::Infinity
+            };
+        }
+        let f = &self.field;
+        let slope = f.mul(&f.sub(y2, y1), &self.invert(&f.sub(x2, x1)));
+        self.chord(&slope, x1, y1, x2)
+    }
+
+    /// Doubles a point with the affine tangent formula.
+    pub fn double(&self, point: &AffinePoint) -> AffinePoint {
+        let AffinePoint::Finite { x, y } = point else {
+            return AffinePoint::Infinity;
+        };
+        if y.is_zero() {
+            return AffinePoint::Infinity;
+        }
+        let f = &self.field;
+        // (3x^2 + 2Ax + 1) / 2By
+        let numerator = f.add(
+            &f.mul(
+                &f.add(&f.mul(&BigUint::from(3u32), x), &f.add(&self.a, &self.a)),
+                x,
+            ),
+            &BigUint::one(),
+        );
+        let denominator = f.mul(&f.add(&self.b, &self.b), y);
+        let slope = f.mul(&numerator, &self.invert(&denominator));
+        self.chord(&slope, x, y, x)
+    }
+
+    /// Returns the third intersection of the line through (x1, y1) with the given slope,
+    /// reflected in the x-axis: x3 = B slope^2 - A - x1 - x2.
+    fn chord(&self, slope: &BigUint, x1: &BigUint, y1: &BigUint, x2: &BigUint) -> AffinePoint {
+        let f = &self.field;
+        let x3 = f.sub(
+            &f.sub(&f.sub(&f.mul(&self.b, &f.mul(slope, slope)), &self.a), x1),
+            x2,
+        );
+        let y3 = f.sub(&f.mul(slope, &f.sub(x1, &x3)), y1);
+        AffinePoint::new(x3, y3)
+    }
+
+    /// Computes the x-coordinate of kP from the x-coordinate of P with the Montgomery ladder
+    /// (RFC 7748, section 5). The point at infinity maps to 0, as in X25519.
+    ///
+    /// The ladder performs the same operations for every bit of k, but `BigUint` arithmetic is
+    /// not constant time, so this does not protect secret scalars against timing attacks.
+    pub fn ladder(&self, k: &BigUint, x: &BigUint) -> BigUint {
+        let ((x2, z2), _) = self.ladder_projective(k, &(x % &self.field.p));
+        self.field.mul(&x2, &self.invert_or_zero(&z2))
+    }
+
+    /// Runs the ladder and returns kP and (k + 1)P as projective x-coordinates (X : Z).
+    fn ladder_projective(
+        &self,
+        k: &BigUint,
+        x1: &BigUint,
+    ) -> ((BigUint, BigUint), (BigUint, BigUint)) {
+        let f = &self.field;
+        // a24 = (A - 2) / 4
+        let a24 = f.mul(
+            &f.sub(&self.a, &BigUint::from(2u32)),
+            &self.invert(&BigUint::from(4u32)),
+        );
+        let (mut x2, mut z2) = (BigUint::one(), BigUint::zero());
+        let (mut x3, mut z3) = (x1.clone(), BigUint::one());
+        let mut swap = false;
+        for t in (0..k.bits()).rev() {
+            let bit = k.bit(t);
+            swap ^= bit;
+            if swap {
+                std::mem::swap(&mut x2, &mut x3);
+                std::mem::swap(&mut z2, &mut z3);
+            }
+            swap = bit;
+
+            let a = f.add(&x2, &z2);
+            let aa = f.mul(&a, &a);
+            let b = f.sub(&x2, &z2);
+            let bb = f.mul(&b, &b);
+            let e = f.sub(&aa, &bb);
+            let c = f.add(&x3, &z3);
+            let d = f.sub(&x3, &z3);
+            let da = f.mul(&d, &a);
+            let cb = f.mul(&c, &b);
+            let sum = f.add(&da, &cb);
+            let difference = f.sub(&da, &cb);
+            x3 = f.mul(&sum, &sum);
+            z3 = f.mul(x1, &f.mul(&difference, &difference));
+            x2 = f.mul(&aa, &bb);
+            z2 = f.mul(&e, &f.add(&aa, &f.mul(&a24, &e)));
+        }
+        if swap {
+            std::mem::swap(&mut x2, &mut x3);
+            std::mem::swap(&mut z2, &mut z3);
+        }
+        ((x2, z2), (x3, z3))
+    }
+
+    /// Computes kP with the ladder and recovers the y-coordinate from the x-coordinates of kP
+    /// and (k + 1)P (Okeya and Sakurai, 2001).
+    pub fn scalar_mul(&self, k: &BigUint, point: &AffinePoint) -> AffinePoint {
+        let AffinePoint::Finite { x, y } = point else {
+            return AffinePoint::Infinity;
+        };
+        if y.is_zero() {
+            // P has order 2.
+            return if k.bit(0) {
+                point.clone()
+            } else {
+                AffinePoint::Infinity
+            };
+        }
+        let ((x1, z1), (x2, z2)) = self.ladder_projective(k, x);
+        if z1.is_zero() {
+            return AffinePoint::Infinity;
+        }
+        if z2.is_zero() {
+            // (k + 1)P = O, so kP = -P.
+            return self.negate(point);
+        }
+        let f = &self.field;
+        let x1 = f.mul(&x1, &self.invert(&z1));
+        let x2 = f.mul(&x2, &self.invert(&z2));
+
+        // y1 = ((x1 x + 1)(x1 + x + 2A) - 2A - (x1 - x)^2 x2) / 2By
+        let two_a = f.add(&self.a, &self.a);
+        let difference = f.sub(&x1, x);
+        let numerator = f.sub(
+            &f.sub(
+                &f.mul(
+                    &f.add(&f.mul(&x1, x), &BigUint::one()),
+                    &f.add(&f.add(&x1, x), &two_a),
+                ),
+                &two_a,
+            ),
+            &f.mul(&f.mul(&difference, &difference), &x2),
+        );
+        let denominator = f.mul(&f.add(&self.b, &self.b), y);
+        let y1 = f.mul(&numerator, &self.invert(&denominator));
+        AffinePoint::new(x1, y1)
+    }
+
+    /// Returns the byte length of an encoded x-coordinate.
+    pub fn encoded_length(&self) -> usize {
+        self.field.p.bits().div_ceil(8) as usize
+    }
+
+    fn invert(&self, a: &BigUint) -> BigUint {
+        traits::Group::inverse(&self.field, a).expect("nonzero field elements are invertible")
+    }
+
+    fn invert_or_zero(&self, a: &BigUint) -> BigUint {
+        traits::Group::inverse(&self.field, a).unwrap_or_default()
+    }
+}
+
+impl Algebra for MontgomeryCurve {
+    type Element = AffinePoint;
+}
+
+impl traits::Group for MontgomeryCurve {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.add(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        AffinePoint::Infinity
+    }
+
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        Some(self.negate(a))
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        self.scalar_mul(exp, a)
+    }
+}
+
+impl Membership for MontgomeryCurve {
+    /// Elements are the points on the curve, including the point at infinity.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        self.is_on_curve(a)
+    }
+}
+
+impl ElementEncoding for MontgomeryCurve {
+    /// Encodes the x-coordinate alone as a little-endian integer (RFC 7748, section 5); the
+    /// point at infinity encodes as 0. P and -P share an encoding.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        let x = a.x().cloned().unwrap_or_default();
+        let mut bytes = x.to_bytes_le();
+        bytes.resize(self.encoded_length(), 0);
+        bytes
+    }
+
+    /// Decodes an x-coordinate as RFC 7748 requires: unused high bits of the final byte are
+    /// ignored and values of p or more are reduced. Of the two points with that x-coordinate,
+    /// the one with even y is returned; kP and -kP share an x-coordinate, so the choice does
+    /// not affect Diffie-Hellman. Returns `None` for x-coordinates of points on the twist.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        if bytes.len() != self.encoded_length() {
+            return None;
+        }
+        let mask = (BigUint::one() << self.field.p.bits()) - 1u32;
+        let x = (BigUint::from_bytes_le(bytes) & mask) % &self.field.p;
+        let y_squared = self
+            .field
+            .mul(&self.right_hand_side(&x), &self.invert(&self.b));
+        let y = self.field.sqrt(&y_squared)?;
+        let y = if y.bit(0) { &self.field.p - y } else { y };
+        Some(AffinePoint::new(x, y))
+    }
+}
+
+impl fmt::Display for MontgomeryCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Montgomery curve {:#x}y^2 = x^3 + {:#x}x^2 + x over GF({:#x})",
+            self.b, self.a, self.field.p
+        )
+    }
+}
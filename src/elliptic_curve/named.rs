@@ -0,0 +1,188 @@
+// src/elliptic_curve/named.rs
+
+use super::AffinePoint;
+use num_bigint::BigUint;
+use num_traits::Num;
+use std::fmt;
+
+/// Named short Weierstrass curves: NIST P-256 and P-384 (FIPS 186-4, appendix D.1.2) and
+/// secp256k1 (SEC 2, section 2.4.1).
+///
+/// Each comes with a base point G of prime order n and cofactor 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedCurve {
+    P256,
+    P384,
+    Secp256k1,
+}
+
+struct Parameters {
+    p: &'static str,
+    a: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    n: &'static str,
+}
+
+impl NamedCurve {
+    /// All named curves.
+    pub const ALL: [NamedCurve; 3] = [NamedCurve::P256, NamedCurve::P384, NamedCurve::Secp256k1];
+
+    /// Returns the conventional name of the curve, such as `P-256` or `secp256k1`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedCurve::P256 => "P-256",
+            NamedCurve::P384 => "P-384",
+            NamedCurve::Secp256k1 => "secp256k1",
+        }
+    }
+
+    /// Looks a curve up by its name, ignoring case; the SEC 2 names secp256r1 and secp384r1
+    /// are accepted too.
+    pub fn from_name(name: &str) -> Option<NamedCurve> {
+        match name.to_ascii_lowercase().as_str() {
+            "p-256" | "p256" | "secp256r1" | "prime256v1" => Some(NamedCurve::P256),
+            "p-384" | "p384" | "secp384r1" => Some(NamedCurve::P384),
+            "secp256k1" => Some(NamedCurve::Secp256k1),
+            _ => None,
+        }
+    }
+
+    /// Returns the field prime p.
+    pub fn prime(&self) -> BigUint {
+        parse(self.parameters().p)
+    }
+
+    /// Returns the coefficient a.
+    pub fn a(&self) -> BigUint {
+        parse(self.parameters().a)
+    }
+
+    /// Returns the coefficient b.
+    pub fn b(&self) -> BigUint {
+        parse(self.parameters().b)
+    }
+
+    /// Returns the base point G.
+    pub fn generator(&self) -> AffinePoint {
+        let parameters = self.parameters();
+        AffinePoint::new(parse(parameters.gx), parse(parameters.gy))
+    }
+
+    /// Returns the prime order n of G.
+    pub fn order(&self) -> BigUint {
+        parse(self.parameters().n)
+    }
+
+    /// Returns the cofactor h = #E / n.
+    pub fn cofactor(&self) -> u32 {
+        1
+    }
+
+    /// Returns the size of the field prime in bits.
+    pub fn bits(&self) -> u64 {
+        match self {
+            NamedCurve::P256 | NamedCurve::Secp256k1 => 256,
+            NamedCurve::P384 => 384,
+        }
+    }
+
+    fn parameters(&self) -> Parameters {
+        match self {
+            NamedCurve::P256 => Parameters {
+                p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+                b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            },
+            NamedCurve::P384 => Parameters {
+                p: concat!(
+                    "ffffffffffffffffffffffffffffffffffffffffffffffff",
+                    "fffffffffffffffeffffffff0000000000000000ffffffff",
+                ),
+                a: concat!(
+                    "ffffffffffffffffffffffffffffffffffffffffffffffff",
+                    "fffffffffffffffeffffffff0000000000000000fffffffc",
+                ),
+                b: concat!(
+                    "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe814112",
+                    "0314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+                ),
+                gx: concat!(
+                    "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b98",
+                    "59f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                ),
+                gy: concat!(
+                    "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147c",
+                    "e9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                ),
+                n: concat!(
+                    "ffffffffffffffffffffffffffffffffffffffffffffffff",
+                    "c7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                ),
+            },
+            NamedCurve::Secp256k1 => Parameters {
+                p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+                a: "0",
+                b: "7",
+                gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            },
+        }
+    }
+}
+
+fn parse(hex: &str) -> BigUint {
+    BigUint::from_str_radix(hex, 16).expect("named curve parameters are valid hex")
+}
+
+impl fmt::Display for NamedCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::Curve;
+    use crate::factor::is_probable_prime;
+
+    #[test]
+    fn generators_have_the_stated_prime_order() {
+        for named in NamedCurve::ALL {
+            let curve = Curve::named(named);
+            let g = named.generator();
+            let n = named.order();
+            assert!(curve.is_on_curve(&g), "{}", named);
+            assert!(is_probable_prime(&named.prime()), "{}", named);
+            assert!(is_probable_prime(&n), "{}", named);
+            assert_eq!(named.prime().bits(), named.bits());
+            assert_eq!(curve.scalar_mul(&n, &g), AffinePoint::Infinity, "{}", named);
+            assert_ne!(curve.scalar_mul(&(&n - 1u32), &g), AffinePoint::Infinity);
+            // With cofactor 1, n lies in the Hasse interval p + 1 +- 2 sqrt(p).
+            let p = named.prime();
+            let distance = if n > &p + 1u32 {
+                &n - &p - 1u32
+            } else {
+                &p + 1u32 - &n
+            };
+            assert!(distance.pow(2) <= 4u32 * &p, "{}", named);
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for named in NamedCurve::ALL {
+            assert_eq!(NamedCurve::from_name(named.name()), Some(named));
+            assert_eq!(named.to_string(), named.name());
+        }
+        assert_eq!(NamedCurve::from_name("secp256r1"), Some(NamedCurve::P256));
+        assert_eq!(NamedCurve::from_name("SECP384R1"), Some(NamedCurve::P384));
+        assert_eq!(NamedCurve::from_name("P-521"), None);
+    }
+}
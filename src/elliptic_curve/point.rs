@@ -0,0 +1,203 @@
+// src/elliptic_curve/point.rs
+
+use crate::polynomial::Polynomial;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// A point of an elliptic curve in affine coordinates (x, y), or the point at infinity.
+///
+/// Coordinates are `BigUint` for curves over `IntegerModP` and `Polynomial` for curves over
+/// `BinaryExtensionField`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AffinePoint<E = BigUint> {
+    Infinity,
+    Finite { x: E, y: E },
+}
+
+/// A point in Jacobian projective coordinates: (X : Y : Z) stands for the affine point
+/// (X / Z^2, Y / Z^3), and Z = 0 for the point at infinity.
+///
+/// Addition and doubling in Jacobian coordinates need no field inversions, so scalar
+/// multiplication works in this form and converts back to affine once at the end. Equal points
+/// have many representations; compare them after `Curve::to_affine`.
+#[derive(Debug, Clone)]
+pub struct JacobianPoint {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub z: BigUint,
+}
+
+/// A point of a binary curve in López-Dahab projective coordinates: (X : Y : Z) stands for
+/// the affine point (X / Z, Y / Z^2), and Z = 0 for the point at infinity.
+///
+/// Doubling and mixed addition with an affine point need no field inversions, which are far
+/// more expensive than multiplications in `BinaryExtensionField`.
+#[derive(Debug, Clone)]
+pub struct LopezDahabPoint {
+    pub x: Polynomial,
+    pub y: Polynomial,
+    pub z: Polynomial,
+}
+
+/// A point of a twisted Edwards curve in affine coordinates. Edwards curves have no point at
+/// infinity: the identity is (0, 1).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EdwardsPoint {
+    pub x: BigUint,
+    pub y: BigUint,
+}
+
+/// A point of a twisted Edwards curve in extended coordinates (Hisil, Wong, Carter and Dawson,
+/// 2008): (X : Y : Z : T) stands for the affine point (X / Z, Y / Z), with T = XY / Z.
+///
+/// The extra coordinate T makes addition cost no inversions and only a few more
+/// multiplications than doubling.
+#[derive(Debug, Clone)]
+pub struct ExtendedPoint {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub z: BigUint,
+    pub t: BigUint,
+}
+
+impl<E> AffinePoint<E> {
+    /// Creates a finite point without checking that it lies on a curve.
+    pub fn new(x: E, y: E) -> Self {
+        AffinePoint::Finite { x, y }
+    }
+
+    /// Returns true for the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, AffinePoint::Infinity)
+    }
+
+    /// Returns the x-coordinate of a finite point.
+    pub fn x(&self) -> Option<&E> {
+        match self {
+            AffinePoint::Infinity => None,
+            AffinePoint::Finite { x, .. } => Some(x),
+        }
+    }
+
+    /// Returns the y-coordinate of a finite point.
+    pub fn y(&self) -> Option<&E> {
+        match self {
+            AffinePoint::Infinity => None,
+            AffinePoint::Finite { y, .. } => Some(y),
+        }
+    }
+}
+
+impl JacobianPoint {
+    /// Returns the point at infinity, (1 : 1 : 0).
+    pub fn infinity() -> Self {
+        JacobianPoint {
+            x: BigUint::one(),
+            y: BigUint::one(),
+            z: BigUint::zero(),
+        }
+    }
+
+    /// Returns true for the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl LopezDahabPoint {
+    /// Returns the point at infinity, (1 : 0 : 0).
+    pub fn infinity() -> Self {
+        LopezDahabPoint {
+            x: Polynomial::one(),
+            y: Polynomial::new(&[0]),
+            z: Polynomial::new(&[0]),
+        }
+    }
+
+    /// Returns true for the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl EdwardsPoint {
+    /// Creates a point without checking that it lies on a curve.
+    pub fn new(x: BigUint, y: BigUint) -> Self {
+        EdwardsPoint { x, y }
+    }
+
+    /// Returns the identity (0, 1).
+    pub fn identity() -> Self {
+        EdwardsPoint::new(BigUint::zero(), BigUint::one())
+    }
+
+    /// Returns true for the identity.
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y.is_one()
+    }
+}
+
+impl ExtendedPoint {
+    /// Returns the identity, (0 : 1 : 1 : 0).
+    pub fn identity() -> Self {
+        ExtendedPoint {
+            x: BigUint::zero(),
+            y: BigUint::one(),
+            z: BigUint::one(),
+            t: BigUint::zero(),
+        }
+    }
+}
+
+impl From<&AffinePoint<Polynomial>> for LopezDahabPoint {
+    /// Lifts an affine point to Z = 1.
+    fn from(point: &AffinePoint<Polynomial>) -> Self {
+        match point {
+            AffinePoint::Infinity => LopezDahabPoint::infinity(),
+            AffinePoint::Finite { x, y } => LopezDahabPoint {
+                x: x.clone(),
+                y: y.clone(),
+                z: Polynomial::one(),
+            },
+        }
+    }
+}
+
+impl From<&AffinePoint> for JacobianPoint {
+    /// Lifts an affine point to Z = 1.
+    fn from(point: &AffinePoint) -> Self {
+        match point {
+            AffinePoint::Infinity => JacobianPoint::infinity(),
+            AffinePoint::Finite { x, y } => JacobianPoint {
+                x: x.clone(),
+                y: y.clone(),
+                z: BigUint::one(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for AffinePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AffinePoint::Infinity => write!(f, "O"),
+            AffinePoint::Finite { x, y } => write!(f, "({:#x}, {:#x})", x, y),
+        }
+    }
+}
+
+impl fmt::Display for AffinePoint<Polynomial> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AffinePoint::Infinity => write!(f, "O"),
+            AffinePoint::Finite { x, y } => write!(f, "({}, {})", x, y),
+        }
+    }
+}
+
+impl fmt::Display for EdwardsPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:#x}, {:#x})", self.x, self.y)
+    }
+}
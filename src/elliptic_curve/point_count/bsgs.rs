@@ -0,0 +1,116 @@
+// src/elliptic_curve/point_count/bsgs.rs
+
+use crate::elliptic_curve::{AffinePoint, Curve};
+use crate::factor::factorize;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use std::collections::HashMap;
+
+impl Curve {
+    /// Counts the points with Mestre's baby-step giant-step method in O(p^(1/4)) group
+    /// operations.
+    ///
+    /// Random points are taken alternately on the curve and on its quadratic twist, whose orders
+    /// N and 2p + 2 - N both lie in the Hasse interval [p + 1 - 2 sqrt(p), p + 1 + 2 sqrt(p)].
+    /// Baby-step giant-step finds a multiple of each point's order in that interval, and the
+    /// orders accumulate into L and L' with L | N and L' | 2p + 2 - N. Once lcm(L, L') exceeds
+    /// the width of the interval only one candidate for N is left. Mestre showed that for
+    /// p > 229 the curve or its twist has a point of large enough order, so this terminates.
+    ///
+    /// # Panics
+    ///
+    /// If p <= 229; use `count_points_naive` there.
+    pub fn count_points_bsgs(&self) -> BigUint {
+        let p = &self.field.p;
+        assert!(p > &BigUint::from(229u32), "Mestre's method needs p > 229");
+        let radius = (p << 2u32).sqrt(); // floor(2 sqrt(p))
+        let low = p + 1u32 - &radius;
+        let width = &radius << 1u32;
+        let twist = self.quadratic_twist();
+        let twisted_sum = (p + 1u32) << 1u32;
+
+        let mut lcm = BigUint::one();
+        let mut twist_lcm = BigUint::one();
+        loop {
+            let order = self.random_point_order(&low, &width);
+            lcm = lcm.lcm(&order);
+            let order = twist.random_point_order(&low, &width);
+            twist_lcm = twist_lcm.lcm(&order);
+
+            // Solve N = 0 mod L and N = 2p + 2 mod L'.
+            let g = lcm.gcd(&twist_lcm);
+            let modulus = lcm.lcm(&twist_lcm);
+            if modulus <= width {
+                continue;
+            }
+            let target = &twisted_sum % &twist_lcm;
+            let reduced = &twist_lcm / &g;
+            let k = if reduced.is_one() {
+                BigUint::zero()
+            } else {
+                let step = (&lcm / &g) % &reduced;
+                let inverse = step.modinv(&reduced).expect("L / g and L' / g are coprime");
+                (&target / &g % &reduced) * inverse % &reduced
+            };
+            let residue = &lcm * k % &modulus;
+            let offset = (&modulus + &residue - &low % &modulus) % &modulus;
+            let n = &low + offset;
+            if n <= &low + &width {
+                return n;
+            }
+        }
+    }
+
+    /// Picks a random point and returns its order, starting from a multiple found in the
+    /// interval [low, low + width].
+    fn random_point_order(&self, low: &BigUint, width: &BigUint) -> BigUint {
+        let point = self.random_point();
+        let mut order = self.multiple_in_interval(&point, low, width);
+        for (q, e) in factorize(&order) {
+            for _ in 0..e {
+                let candidate = &order / &q;
+                if !self.scalar_mul(&candidate, &point).is_infinity() {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        order
+    }
+
+    /// Finds m in [low, low + width] with mP = O, with baby steps jP for j < s and giant steps
+    /// (low + is)P, where s = floor(sqrt(width + 1)) + 1.
+    fn multiple_in_interval(&self, point: &AffinePoint, low: &BigUint, width: &BigUint) -> BigUint {
+        let steps = (width + 1u32).sqrt() + 1u32;
+        let steps_count = steps
+            .to_u64()
+            .expect("the interval is small enough to search");
+        let mut baby_steps = HashMap::new();
+        let mut current = AffinePoint::Infinity;
+        for j in 0..steps_count {
+            baby_steps.entry(current.clone()).or_insert(j);
+            current = self.add(&current, point);
+        }
+        let giant_step = self.negate(&self.scalar_mul(&steps, point));
+        let mut current = self.negate(&self.scalar_mul(low, point));
+        for i in 0..=steps_count {
+            // -(low + is)P = jP  <=>  (low + is + j)P = O.
+            if let Some(&j) = baby_steps.get(&current) {
+                return low + &steps * i + j;
+            }
+            current = self.add(&current, &giant_step);
+        }
+        unreachable!("the group order lies in the Hasse interval")
+    }
+
+    /// Returns a uniformly chosen x with a point above it, and one of its two y-coordinates.
+    fn random_point(&self) -> AffinePoint {
+        loop {
+            let x = self.field.random_element();
+            if let Some(y) = self.field.sqrt(&self.right_hand_side(&x)) {
+                return AffinePoint::new(x, y);
+            }
+        }
+    }
+}
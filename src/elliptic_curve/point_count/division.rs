@@ -0,0 +1,113 @@
+// src/elliptic_curve/point_count/division.rs
+
+use crate::algebra::traits::Ring;
+use crate::elliptic_curve::Curve;
+use crate::integer_mod_p::{IntegerModP, PolynomialModP};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+
+impl Curve {
+    /// Returns the n-th division polynomial in its x-only form F_n: psi_n for odd n and
+    /// psi_n / (2y) for even n, with y^2 replaced by f = x^3 + ax + b.
+    ///
+    /// For odd n the roots of F_n are the x-coordinates of the points of order dividing n other
+    /// than O, and for n coprime to p its degree is (n^2 - 1) / 2. Only the O(log n) indices
+    /// that the doubling recurrences reach are computed.
+    pub fn division_polynomial(&self, n: usize) -> PolynomialModP {
+        let mut cache = HashMap::new();
+        self.division_polynomial_cached(n, &mut cache)
+    }
+
+    /// Returns x^3 + ax + b as a polynomial.
+    pub fn right_hand_side_polynomial(&self) -> PolynomialModP {
+        PolynomialModP::new(
+            vec![
+                self.b.clone(),
+                self.a.clone(),
+                BigUint::from(0u32),
+                BigUint::from(1u32),
+            ],
+            &self.field,
+        )
+    }
+
+    fn division_polynomial_cached(
+        &self,
+        n: usize,
+        cache: &mut HashMap<usize, PolynomialModP>,
+    ) -> PolynomialModP {
+        if let Some(psi) = cache.get(&n) {
+            return psi.clone();
+        }
+        let field = &self.field;
+        let c = |value: u32| BigUint::from(value);
+        let a = &self.a;
+        let b = &self.b;
+        let psi = match n {
+            0 => PolynomialModP::zero(),
+            1 | 2 => PolynomialModP::one(),
+            // 3x^4 + 6ax^2 + 12bx - a^2
+            3 => PolynomialModP::new(
+                vec![
+                    field.sub(&c(0), &field.mul(a, a)),
+                    field.mul(&c(12), b),
+                    field.mul(&c(6), a),
+                    c(0),
+                    c(3),
+                ],
+                field,
+            ),
+            // 2(x^6 + 5ax^4 + 20bx^3 - 5a^2x^2 - 4abx - 8b^2 - a^3)
+            4 => {
+                let a2 = field.mul(a, a);
+                let constant = field.add(&field.mul(&c(8), &field.mul(b, b)), &field.mul(&a2, a));
+                PolynomialModP::new(
+                    vec![
+                        field.sub(&c(0), &constant),
+                        field.sub(&c(0), &field.mul(&c(4), &field.mul(a, b))),
+                        field.sub(&c(0), &field.mul(&c(5), &a2)),
+                        field.mul(&c(20), b),
+                        field.mul(&c(5), a),
+                        c(0),
+                        c(1),
+                    ],
+                    field,
+                )
+                .scale(&c(2), field)
+            }
+            _ => {
+                let m = n / 2;
+                let mut get = |i: usize| self.division_polynomial_cached(i, cache);
+                if n % 2 == 1 {
+                    // psi_(2m+1) = psi_(m+2) psi_m^3 - psi_(m-1) psi_(m+1)^3, where the even
+                    // factors carry (2y)^4 = 16 f^2.
+                    let (psi_m2, psi_m, psi_m1, psi_p1) =
+                        (get(m + 2), get(m), get(m - 1), get(m + 1));
+                    let first = psi_m2.mul(&cube(&psi_m, field), field);
+                    let second = psi_m1.mul(&cube(&psi_p1, field), field);
+                    let f = self.right_hand_side_polynomial();
+                    let sixteen_f2 = f.mul(&f, field).scale(&c(16), field);
+                    if m.is_multiple_of(2) {
+                        sixteen_f2.mul(&first, field).sub(&second, field)
+                    } else {
+                        first.sub(&sixteen_f2.mul(&second, field), field)
+                    }
+                } else {
+                    // psi_(2m) / (2y) = psi_m / (2y) (psi_(m+2) psi_(m-1)^2 - psi_(m-2) psi_(m+1)^2),
+                    // which reads the same in the x-only form for either parity of m.
+                    let (psi_m, psi_m2, psi_m1, psi_mm2, psi_p1) =
+                        (get(m), get(m + 2), get(m - 1), get(m - 2), get(m + 1));
+                    let first = psi_m2.mul(&psi_m1.mul(&psi_m1, field), field);
+                    let second = psi_mm2.mul(&psi_p1.mul(&psi_p1, field), field);
+                    psi_m.mul(&first.sub(&second, field), field)
+                }
+            }
+        };
+        cache.insert(n, psi.clone());
+        psi
+    }
+}
+
+fn cube(a: &PolynomialModP, field: &IntegerModP) -> PolynomialModP {
+    a.mul(&a.mul(a, field), field)
+}
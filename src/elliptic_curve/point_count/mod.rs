@@ -0,0 +1,126 @@
+// src/elliptic_curve/point_count/mod.rs
+
+pub mod bsgs;
+pub mod division;
+pub mod schoof;
+
+use crate::algebra::traits::Ring;
+use crate::elliptic_curve::Curve;
+use crate::factor::is_probable_prime;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+/// `Curve::order` counts points one x at a time for primes up to this many bits.
+const NAIVE_MAX_BITS: u64 = 16;
+
+/// `Curve::order` uses baby-step giant-step for primes up to this many bits and Schoof's
+/// algorithm above.
+const BSGS_MAX_BITS: u64 = 56;
+
+impl Curve {
+    /// Returns the number of points #E(GF(p)), including the point at infinity, choosing the
+    /// counting method by the size of p.
+    pub fn order(&self) -> BigUint {
+        let bits = self.field.p.bits();
+        if bits <= NAIVE_MAX_BITS {
+            self.count_points_naive()
+        } else if bits <= BSGS_MAX_BITS {
+            self.count_points_bsgs()
+        } else {
+            self.count_points_schoof()
+        }
+    }
+
+    /// Counts the points by checking for every x whether x^3 + ax + b is zero, a nonzero square
+    /// (two points) or a non-square (none). This takes p steps, so it is only for tiny fields.
+    pub fn count_points_naive(&self) -> BigUint {
+        let p = &self.field.p;
+        let mut count = BigUint::one(); // The point at infinity
+        let mut x = BigUint::zero();
+        while &x < p {
+            let rhs = self.right_hand_side(&x);
+            if rhs.is_zero() {
+                count += 1u32;
+            } else if self.field.is_square(&rhs) {
+                count += 2u32;
+            }
+            x += 1u32;
+        }
+        count
+    }
+
+    /// Returns the trace of Frobenius t = p + 1 - #E as a signed integer, given the order.
+    pub fn trace_of_frobenius(&self, order: &BigUint) -> BigInt {
+        BigInt::from(&self.field.p + 1u32) - BigInt::from(order.clone())
+    }
+
+    /// Returns the quadratic twist y^2 = x^3 + ad^2 x + bd^3 for the smallest non-square d.
+    /// It is isomorphic to the curve over GF(p^2) but not over GF(p), and its order is
+    /// 2p + 2 - #E.
+    pub fn quadratic_twist(&self) -> Curve {
+        let f = &self.field;
+        let mut d = BigUint::from(2u32);
+        while f.is_square(&d) {
+            d += 1u32;
+        }
+        let d2 = f.mul(&d, &d);
+        Curve {
+            field: f.clone(),
+            a: f.mul(&self.a, &d2),
+            b: f.mul(&self.b, &f.mul(&d2, &d)),
+        }
+    }
+
+    /// Returns the order of the quadratic twist, 2p + 2 - #E, given the order of the curve.
+    pub fn twist_order(&self, order: &BigUint) -> BigUint {
+        ((&self.field.p + 1u32) << 1u32) - order
+    }
+
+    /// Returns true if the twist has a prime-order subgroup with a cofactor of at most
+    /// `max_cofactor`. An attacker who can make an x-only implementation process an x-coordinate
+    /// off the curve computes on the twist, so its order needs the same care as the curve's.
+    pub fn is_twist_secure(&self, order: &BigUint, max_cofactor: u32) -> bool {
+        split_cofactor(&self.twist_order(order), max_cofactor).is_some()
+    }
+
+    /// Returns the embedding degree of a subgroup of prime order n: the smallest k with
+    /// n | p^k - 1, so that the Weil and Tate pairings map it into GF(p^k). The MOV attack moves
+    /// discrete logarithms there, so it has to be large for a secure curve.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(k)` if k <= `max_degree`.
+    /// * `None` otherwise, including when n = p and no k exists.
+    pub fn embedding_degree(&self, n: &BigUint, max_degree: u32) -> Option<u32> {
+        let base = &self.field.p % n;
+        let mut power = base.clone();
+        for k in 1..=max_degree {
+            if power.is_one() {
+                return Some(k);
+            }
+            power = power * &base % n;
+        }
+        None
+    }
+}
+
+/// Splits a group order into h * n with n prime and h <= `max_cofactor`, the form needed for
+/// a curve to be usable with a cofactor h.
+///
+/// # Returns
+///
+/// * `Some((h, n))` with the smallest such cofactor.
+/// * `None` if there is none.
+pub fn split_cofactor(order: &BigUint, max_cofactor: u32) -> Option<(u32, BigUint)> {
+    (1..=max_cofactor).find_map(|h| {
+        let h_big = BigUint::from(h);
+        if !(order % &h_big).is_zero() {
+            return None;
+        }
+        let n = order / &h_big;
+        is_probable_prime(&n).then_some((h, n))
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,307 @@
+// src/elliptic_curve/point_count/schoof.rs
+
+use crate::elliptic_curve::Curve;
+use crate::integer_mod_p::{PolynomialModP, QuotientRing};
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, ToPrimitive, Zero};
+use rayon::prelude::*;
+
+/// A point (X / Z^2, yY / Z^3) in Jacobian coordinates over GF(p)[x] / (h), where
+/// h divides a division polynomial. The y-coordinate is stored as its quotient by y, so that
+/// every coordinate is a polynomial in x alone.
+#[derive(Debug, Clone)]
+struct TorsionPoint {
+    x: PolynomialModP, // X
+    y: PolynomialModP, // Y, the y-coordinate divided by y
+    z: PolynomialModP, // Z
+}
+
+/// The ring in which the trace is computed modulo one prime l, with the reduction of
+/// f = x^3 + ax + b and of the Frobenius images of the generic point (x, y).
+struct TorsionRing {
+    ring: QuotientRing,
+    f: PolynomialModP, // x^3 + ax + b
+    a: PolynomialModP, // Coefficient a as a constant
+    pi: TorsionPoint,  // (x^p, y^p) = (x^p, y f^((p - 1) / 2))
+    pi2: TorsionPoint, // (x^(p^2), y^(p^2))
+}
+
+/// The outcome of adding pi^2(P) and [p mod l]P.
+enum Sum {
+    Point(TorsionPoint),
+    Infinity,
+    Split(PolynomialModP),
+}
+
+impl Curve {
+    /// Counts the points with Schoof's algorithm.
+    ///
+    /// The trace t = p + 1 - #E is recovered modulo small primes l until their product exceeds
+    /// 4 sqrt(p), which pins t down by Hasse's bound |t| <= 2 sqrt(p). Modulo l = 2, t is even
+    /// exactly when f has a root. Modulo an odd l, the Frobenius endomorphism satisfies
+    /// pi^2 - t pi + p = 0 on the l-torsion, so t mod l is the tau with
+    /// pi^2(P) + [p mod l]P = [tau] pi(P), found by computing in GF(p)[x, y] / (psi_l, y^2 - f).
+    /// The primes are handled in parallel.
+    ///
+    /// For a 256-bit p the largest l is about 110 and psi_l has degree about 6000, so expect a
+    /// long run.
+    ///
+    /// # Panics
+    ///
+    /// If p < 5, where the division polynomials of a short Weierstrass curve degenerate.
+    pub fn count_points_schoof(&self) -> BigUint {
+        let p = &self.field.p;
+        assert!(p > &BigUint::from(3u32), "Schoof's algorithm needs p >= 5");
+        // The primes must multiply to more than the width 4 sqrt(p) of the Hasse interval.
+        let bound = (p << 4u32).sqrt() + 1u32;
+        let mut primes = Vec::new();
+        let mut product = BigUint::one();
+        let mut limit = 64;
+        'search: loop {
+            for l in sieve_of_eratosthenes_bitset(limit) {
+                if primes.contains(&l) || BigUint::from(l) == *p {
+                    continue;
+                }
+                primes.push(l);
+                product *= l;
+                if product > bound {
+                    break 'search;
+                }
+            }
+            limit *= 2;
+        }
+
+        let residues: Vec<(usize, usize)> = primes
+            .par_iter()
+            .map(|&l| (l, self.trace_modulo(l)))
+            .collect();
+
+        // Chinese remaindering, then the representative of t in (-M / 2, M / 2].
+        let mut t = BigUint::zero();
+        let mut modulus = BigUint::one();
+        for (l, residue) in residues {
+            let current = (&t % l).to_usize().expect("l is small");
+            let step = (&modulus % l).to_usize().expect("l is small");
+            let inverse = (1..l)
+                .find(|k| step * k % l == 1)
+                .expect("the primes are distinct");
+            t += &modulus * ((residue + l - current) * inverse % l);
+            modulus *= l;
+        }
+        let half = &modulus >> 1u32;
+        let t = if t > half {
+            BigInt::from(t) - BigInt::from(modulus)
+        } else {
+            BigInt::from(t)
+        };
+        (BigInt::from(p.clone()) + 1u32 - t)
+            .to_biguint()
+            .expect("Hasse's bound keeps the order positive")
+    }
+
+    /// Returns the trace of Frobenius modulo the prime l.
+    fn trace_modulo(&self, l: usize) -> usize {
+        let field = &self.field;
+        let p = &field.p;
+        if l == 2 {
+            // t is even exactly when E has a point of order 2, a root of f.
+            let ring = QuotientRing::new(field.clone(), &self.right_hand_side_polynomial());
+            let xp = ring.pow(&PolynomialModP::x(), p);
+            let roots = xp
+                .sub(&PolynomialModP::x(), field)
+                .gcd(&ring.modulus, field);
+            return if roots.is_one() { 1 } else { 0 };
+        }
+
+        let mut torsion = TorsionRing::new(
+            self,
+            QuotientRing::new(field.clone(), &self.division_polynomial(l)),
+        );
+        let q = (p % l).to_usize().expect("l is small");
+        let sum = loop {
+            let x = torsion.ring.reduce(&PolynomialModP::x());
+            let generic = TorsionPoint::affine(x, PolynomialModP::one());
+            let multiple = torsion.multiply(q, &generic);
+            match torsion.add_checked(&multiple, &torsion.pi2) {
+                Sum::Point(point) => break point,
+                Sum::Infinity => return 0,
+                Sum::Split(factor) => torsion = torsion.restrict(&factor),
+            }
+        };
+
+        // Compare with tau pi(P) for tau = 1, ..., (l - 1) / 2; a matching x-coordinate leaves
+        // tau or -tau, told apart by the y-coordinate.
+        let mut multiple = torsion.pi.clone();
+        for tau in 1..=(l - 1) / 2 {
+            if torsion.same_x(&multiple, &sum) {
+                return if torsion.same_y(&multiple, &sum) {
+                    tau
+                } else {
+                    l - tau
+                };
+            }
+            multiple = if tau == 1 {
+                torsion.double(&multiple)
+            } else {
+                torsion.add(&multiple, &torsion.pi)
+            };
+        }
+        unreachable!("pi^2(P) + [p]P is a multiple of pi(P)")
+    }
+}
+
+impl TorsionPoint {
+    fn affine(x: PolynomialModP, y: PolynomialModP) -> Self {
+        TorsionPoint {
+            x,
+            y,
+            z: PolynomialModP::one(),
+        }
+    }
+}
+
+impl TorsionRing {
+    fn new(curve: &Curve, ring: QuotientRing) -> Self {
+        let p = &curve.field.p;
+        let f = ring.reduce(&curve.right_hand_side_polynomial());
+        let xp = ring.pow(&PolynomialModP::x(), p);
+        let yp = ring.pow(&f, &((p - 1u32) >> 1u32));
+        let xpp = ring.pow(&xp, p);
+        let ypp = ring.mul(&yp, &ring.pow(&yp, p));
+        TorsionRing {
+            a: PolynomialModP::constant(&curve.a, &curve.field),
+            pi: TorsionPoint::affine(xp, yp),
+            pi2: TorsionPoint::affine(xpp, ypp),
+            f,
+            ring,
+        }
+    }
+
+    /// Moves to the ring modulo a factor of the current modulus. The trace can be read off on
+    /// any nonzero l-torsion points, so a factor met by a non-invertible element will do.
+    fn restrict(&self, factor: &PolynomialModP) -> Self {
+        let cofactor = self.ring.modulus.div_rem(factor, &self.ring.field).0;
+        let smaller = if cofactor.degree() < factor.degree() {
+            cofactor
+        } else {
+            factor.clone()
+        };
+        let ring = self.ring.restrict(&smaller);
+        let reduce = |point: &TorsionPoint| TorsionPoint {
+            x: ring.reduce(&point.x),
+            y: ring.reduce(&point.y),
+            z: ring.reduce(&point.z),
+        };
+        TorsionRing {
+            f: ring.reduce(&self.f),
+            a: self.a.clone(),
+            pi: reduce(&self.pi),
+            pi2: reduce(&self.pi2),
+            ring,
+        }
+    }
+
+    /// Computes [k]P for 0 < k < l by double-and-add. None of the intermediate sums can meet
+    /// the exceptional cases of the addition law.
+    fn multiply(&self, k: usize, point: &TorsionPoint) -> TorsionPoint {
+        let mut result = point.clone();
+        for i in (0..usize::BITS - 1 - k.leading_zeros()).rev() {
+            result = self.double(&result);
+            if (k >> i) & 1 == 1 {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+
+    /// Adds an affine point (Z = 1) to P, assuming their x-coordinates differ at every root
+    /// of the modulus.
+    fn add(&self, p: &TorsionPoint, q: &TorsionPoint) -> TorsionPoint {
+        let r = &self.ring;
+        let (h, s) = self.mixed_differences(p, q);
+        let hh = r.square(&h);
+        let hhh = r.mul(&h, &hh);
+        let v = r.mul(&p.x, &hh);
+        // With the slope y s / h, X3 = f s^2 - h^3 - 2 X1 h^2.
+        let x3 = r.sub(&r.sub(&r.mul(&self.f, &r.square(&s)), &hhh), &r.add(&v, &v));
+        let y3 = r.sub(&r.mul(&s, &r.sub(&v, &x3)), &r.mul(&p.y, &hhh));
+        TorsionPoint {
+            x: x3,
+            y: y3,
+            z: r.mul(&p.z, &h),
+        }
+    }
+
+    /// Doubles P, whose y-coordinate is nonzero at every root of the modulus since l is odd.
+    fn double(&self, p: &TorsionPoint) -> TorsionPoint {
+        let r = &self.ring;
+        let field = &r.field;
+        let c = |value: u32| PolynomialModP::constant(&BigUint::from(value), field);
+        let yy = r.mul(&self.f, &r.square(&p.y));
+        let zz = r.square(&p.z);
+        let m = r.add(
+            &r.mul(&c(3), &r.square(&p.x)),
+            &r.mul(&self.a, &r.square(&zz)),
+        );
+        let s = r.mul(&c(4), &r.mul(&p.x, &yy));
+        let x3 = r.sub(&r.square(&m), &r.add(&s, &s));
+        let y3 = r.sub(&r.mul(&m, &r.sub(&s, &x3)), &r.mul(&c(8), &r.square(&yy)));
+        let z3 = r.mul(&c(2), &r.mul(&p.y, &p.z));
+        // Z3 picked up a factor y; scaling by y turns (X3, Y3, y Z3) into (f X3, y f Y3, f Z3).
+        TorsionPoint {
+            x: r.mul(&self.f, &x3),
+            y: r.mul(&self.f, &y3),
+            z: r.mul(&self.f, &z3),
+        }
+    }
+
+    /// Adds an affine point to P, detecting the cases that `add` rules out: equal or opposite
+    /// points, and points that are equal or opposite only at some roots of the modulus, which
+    /// reveal a factor of it.
+    fn add_checked(&self, p: &TorsionPoint, q: &TorsionPoint) -> Sum {
+        let field = &self.ring.field;
+        let (h, s) = self.mixed_differences(p, q);
+        if h.is_zero() {
+            if s.is_zero() {
+                return Sum::Point(self.double(p));
+            }
+            let g = s.gcd(&self.ring.modulus, field);
+            return if g.is_one() {
+                Sum::Infinity
+            } else {
+                Sum::Split(g)
+            };
+        }
+        let g = h.gcd(&self.ring.modulus, field);
+        if g.is_one() {
+            Sum::Point(self.add(p, q))
+        } else {
+            Sum::Split(g)
+        }
+    }
+
+    /// Returns H = x2 Z1^2 - X1 and S = y2 Z1^3 - Y1 for an affine second point.
+    fn mixed_differences(
+        &self,
+        p: &TorsionPoint,
+        q: &TorsionPoint,
+    ) -> (PolynomialModP, PolynomialModP) {
+        let r = &self.ring;
+        let zz = r.square(&p.z);
+        let h = r.sub(&r.mul(&q.x, &zz), &p.x);
+        let s = r.sub(&r.mul(&q.y, &r.mul(&p.z, &zz)), &p.y);
+        (h, s)
+    }
+
+    fn same_x(&self, p: &TorsionPoint, q: &TorsionPoint) -> bool {
+        let r = &self.ring;
+        r.mul(&p.x, &r.square(&q.z)) == r.mul(&q.x, &r.square(&p.z))
+    }
+
+    fn same_y(&self, p: &TorsionPoint, q: &TorsionPoint) -> bool {
+        let r = &self.ring;
+        let cube = |z: &PolynomialModP| r.mul(z, &r.square(z));
+        r.mul(&p.y, &cube(&q.z)) == r.mul(&q.y, &cube(&p.z))
+    }
+}
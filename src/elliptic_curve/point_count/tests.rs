@@ -0,0 +1,131 @@
+// src/elliptic_curve/point_count/tests.rs
+
+// Cross-checks of the three point-counting methods against each other on random curves
+// over small primes, and of the division polynomials against the torsion points they cut out.
+
+use crate::elliptic_curve::{AffinePoint, Curve};
+use crate::factor::is_probable_prime;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Returns the smallest prime at or above `start`.
+fn next_prime(start: u64) -> u64 {
+    (start..)
+        .find(|&n| is_probable_prime(&BigUint::from(n)))
+        .unwrap()
+}
+
+/// Returns a random non-singular curve over GF(p).
+fn random_curve(p: u64, rng: &mut StdRng) -> Curve {
+    loop {
+        let a = BigUint::from(rng.gen_range(0..p));
+        let b = BigUint::from(rng.gen_range(0..p));
+        if let Ok(curve) = Curve::new(BigUint::from(p), a, b) {
+            return curve;
+        }
+    }
+}
+
+/// Lists every finite point of a curve over a small field.
+fn points(curve: &Curve) -> Vec<AffinePoint> {
+    let p = &curve.field.p;
+    let mut points = Vec::new();
+    let mut x = BigUint::zero();
+    while &x < p {
+        if let Some(y) = curve.field.sqrt(&curve.right_hand_side(&x)) {
+            if !y.is_zero() {
+                points.push(AffinePoint::Finite {
+                    x: x.clone(),
+                    y: p - &y,
+                });
+            }
+            points.push(AffinePoint::Finite { x: x.clone(), y });
+        }
+        x += 1u32;
+    }
+    points
+}
+
+/// Returns a random finite point, for fields too large to list.
+fn random_point(curve: &Curve, rng: &mut StdRng) -> AffinePoint {
+    let p = curve.field.p.clone();
+    loop {
+        let x = BigUint::from(rng.gen_range(0..u64::MAX)) % &p;
+        if let Some(y) = curve.field.sqrt(&curve.right_hand_side(&x)) {
+            return AffinePoint::Finite { x, y };
+        }
+    }
+}
+
+#[test]
+fn schoof_agrees_with_naive_counting_on_tiny_primes() {
+    let mut rng = StdRng::seed_from_u64(46);
+    for p in [5, 7, 11, 13, 101, 229] {
+        for _ in 0..3 {
+            let curve = random_curve(p, &mut rng);
+            let naive = curve.count_points_naive();
+            assert_eq!(
+                curve.count_points_naive(),
+                BigUint::from(points(&curve).len() + 1)
+            );
+            assert_eq!(curve.count_points_schoof(), naive, "p = {}", p);
+        }
+    }
+}
+
+#[test]
+fn all_methods_agree_on_small_primes() {
+    let mut rng = StdRng::seed_from_u64(4646);
+    for bits in [9, 12, 17, 18] {
+        let p = next_prime(rng.gen_range(1 << (bits - 1)..1 << bits));
+        let curve = random_curve(p, &mut rng);
+        let naive = curve.count_points_naive();
+        assert_eq!(curve.count_points_bsgs(), naive, "BSGS, p = {}", p);
+        assert_eq!(curve.count_points_schoof(), naive, "Schoof, p = {}", p);
+        assert_eq!(curve.order(), naive);
+
+        let twist = curve.quadratic_twist();
+        assert_eq!(twist.count_points_naive(), curve.twist_order(&naive));
+    }
+}
+
+#[test]
+fn schoof_agrees_with_bsgs_on_a_40_bit_prime() {
+    let mut rng = StdRng::seed_from_u64(40);
+    let p = next_prime(rng.gen_range(1 << 39..1 << 40));
+    let curve = random_curve(p, &mut rng);
+    let order = curve.count_points_bsgs();
+    assert_eq!(curve.count_points_schoof(), order);
+    let point = random_point(&curve, &mut rng);
+    assert_eq!(curve.scalar_mul(&order, &point), AffinePoint::Infinity);
+}
+
+#[test]
+fn division_polynomials_vanish_exactly_on_torsion_points() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let curve = random_curve(1009, &mut rng);
+    let field = &curve.field;
+    let points = points(&curve);
+    for n in 3..=8usize {
+        let psi = curve.division_polynomial(n);
+        let n_big = BigUint::from(n);
+        for point in &points {
+            let AffinePoint::Finite { x, y } = point else {
+                unreachable!()
+            };
+            if y.is_zero() {
+                continue; // The even-index F_n have psi_n / (2y) removed.
+            }
+            let is_torsion = curve.scalar_mul(&n_big, point) == AffinePoint::Infinity;
+            assert_eq!(
+                psi.evaluate(x, field).is_zero(),
+                is_torsion,
+                "F_{} at {:?}",
+                n,
+                point
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// src/elliptic_curve/rho/checkpoint.rs
+
+use super::{RhoCurve, RhoError};
+use crate::hash::{HashFunction, Sha256};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// First line of a checkpoint file, with the format version.
+const HEADER: &str = "pollard-rho-checkpoint 1";
+
+/// The persistent state of a Pollard rho run.
+///
+/// The file is plain text: the header, the problem fingerprint, the iteration count, the
+/// coefficients (c_j, d_j) of the steps R_j = c_j P + d_j Q and one line per distinguished
+/// point with its x-coordinate and (a, b), all numbers in hex. The step table has to be
+/// saved too, since walks with different steps would never merge with the stored points.
+pub(super) struct Checkpoint {
+    pub steps: Vec<(BigUint, BigUint)>,
+    pub points: HashMap<Vec<u8>, (BigUint, BigUint)>,
+    pub iterations: u64,
+}
+
+/// Returns a SHA-256 digest identifying the problem: the curve, P, Q and n.
+pub(super) fn fingerprint<C: RhoCurve>(
+    curve: &C,
+    p: &C::Element,
+    q: &C::Element,
+    n: &BigUint,
+) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(curve.to_string().as_bytes());
+    for point in [p, q] {
+        let (x, y) = curve.coordinate_bytes(point).unwrap_or_default();
+        for coordinate in [x, y] {
+            hasher.update(&(coordinate.len() as u32).to_be_bytes());
+            hasher.update(&coordinate);
+        }
+    }
+    hasher.update(&n.to_bytes_be());
+    hasher.finalize()
+}
+
+impl Checkpoint {
+    /// Reads a checkpoint, checking that it belongs to the problem with this fingerprint and
+    /// that every coefficient is below n.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Checkpoint)` with the saved state.
+    /// * `Err(RhoError::CheckpointMismatch)` if it was written for another problem.
+    /// * `Err(RhoError::InvalidCheckpoint)` or `Err(RhoError::Io)` if it cannot be read.
+    pub fn load(path: &Path, fingerprint: &[u8], n: &BigUint) -> Result<Self, RhoError> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(RhoError::InvalidCheckpoint);
+        }
+        if field(lines.next(), "problem")? != hex::encode(fingerprint) {
+            return Err(RhoError::CheckpointMismatch);
+        }
+        let iterations = field(lines.next(), "iterations")?
+            .parse()
+            .map_err(|_| RhoError::InvalidCheckpoint)?;
+        let step_count = count(field(lines.next(), "steps")?)?;
+        let steps = (0..step_count)
+            .map(|_| {
+                let [c, d] = numbers(lines.next(), n)?;
+                Ok((c, d))
+            })
+            .collect::<Result<Vec<_>, RhoError>>()?;
+        if steps.len() < 2 {
+            return Err(RhoError::InvalidCheckpoint);
+        }
+        let point_count = count(field(lines.next(), "points")?)?;
+        let mut points = HashMap::with_capacity(point_count);
+        for _ in 0..point_count {
+            let mut words = lines
+                .next()
+                .ok_or(RhoError::InvalidCheckpoint)?
+                .splitn(2, ' ');
+            let x = hex::decode(words.next().ok_or(RhoError::InvalidCheckpoint)?)
+                .map_err(|_| RhoError::InvalidCheckpoint)?;
+            let [a, b] = numbers(words.next(), n)?;
+            points.insert(x, (a, b));
+        }
+        Ok(Checkpoint {
+            steps,
+            points,
+            iterations,
+        })
+    }
+
+    /// Writes the checkpoint to a temporary file next to `path` and renames it into place, so
+    /// an interrupted write never destroys the previous checkpoint.
+    pub fn save(&self, path: &Path, fingerprint: &[u8]) -> Result<(), RhoError> {
+        let mut text = format!(
+            "{}\nproblem {}\niterations {}\nsteps {}\n",
+            HEADER,
+            hex::encode(fingerprint),
+            self.iterations,
+            self.steps.len()
+        );
+        for (c, d) in &self.steps {
+            text.push_str(&format!("{:x} {:x}\n", c, d));
+        }
+        text.push_str(&format!("points {}\n", self.points.len()));
+        for (x, (a, b)) in &self.points {
+            text.push_str(&format!("{} {:x} {:x}\n", hex::encode(x), a, b));
+        }
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, text)?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+}
+
+/// Returns the value of a "name value" line.
+fn field<'a>(line: Option<&'a str>, name: &str) -> Result<&'a str, RhoError> {
+    line.and_then(|line| line.strip_prefix(name))
+        .and_then(|rest| rest.strip_prefix(' '))
+        .ok_or(RhoError::InvalidCheckpoint)
+}
+
+/// Parses a decimal count.
+fn count(value: &str) -> Result<usize, RhoError> {
+    value.parse().map_err(|_| RhoError::InvalidCheckpoint)
+}
+
+/// Parses a line of two hex numbers below n.
+fn numbers(line: Option<&str>, n: &BigUint) -> Result<[BigUint; 2], RhoError> {
+    let line = line.ok_or(RhoError::InvalidCheckpoint)?;
+    let mut values = line
+        .split(' ')
+        .map(|word| BigUint::parse_bytes(word.as_bytes(), 16).filter(|value| value < n));
+    match (values.next(), values.next(), values.next()) {
+        (Some(Some(first)), Some(Some(second)), None) => Ok([first, second]),
+        _ => Err(RhoError::InvalidCheckpoint),
+    }
+}
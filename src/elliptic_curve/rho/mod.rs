@@ -0,0 +1,512 @@
+// src/elliptic_curve/rho/mod.rs
+
+mod checkpoint;
+
+use super::{AffinePoint, BinaryCurve, Curve};
+use crate::algebra::traits::{ElementEncoding, Group, Membership};
+use crate::factor::is_probable_prime;
+use crate::polynomial::Polynomial;
+use checkpoint::Checkpoint;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{ToPrimitive, Zero};
+use rand::rngs::OsRng;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of precomputed steps R_j of the r-adding walk. Teske found r = 20 enough to behave
+/// like a random walk; the negation map wants more, since a fruitless 2-cycle starts with
+/// probability about 1 / (2r) per step.
+const DEFAULT_PARTITIONS: usize = 64;
+
+/// A walk that goes this many times the expected distance between distinguished points
+/// without meeting one is assumed to be stuck in a fruitless cycle and restarted.
+const MAX_WALK_FACTOR: u64 = 20;
+
+/// Default number of seconds between checkpoint writes.
+const DEFAULT_CHECKPOINT_SECONDS: u64 = 60;
+
+/// Trait for the elliptic curves the Pollard rho solver runs on: `Curve` over `IntegerModP`
+/// and `BinaryCurve` over `BinaryExtensionField`.
+pub trait RhoCurve: Group<Element: Clone + Send + Sync> + Membership + fmt::Display + Sync {
+    /// Returns the canonical encodings of the x- and y-coordinates, or `None` for the point at
+    /// infinity.
+    fn coordinate_bytes(&self, point: &Self::Element) -> Option<(Vec<u8>, Vec<u8>)>;
+}
+
+impl RhoCurve for Curve {
+    fn coordinate_bytes(&self, point: &AffinePoint) -> Option<(Vec<u8>, Vec<u8>)> {
+        let AffinePoint::Finite { x, y } = point else {
+            return None;
+        };
+        Some((self.field.encode_element(x), self.field.encode_element(y)))
+    }
+}
+
+impl RhoCurve for BinaryCurve {
+    fn coordinate_bytes(&self, point: &AffinePoint<Polynomial>) -> Option<(Vec<u8>, Vec<u8>)> {
+        let AffinePoint::Finite { x, y } = point else {
+            return None;
+        };
+        Some((self.field.encode_element(x), self.field.encode_element(y)))
+    }
+}
+
+/// Errors related to the Pollard rho discrete logarithm solver.
+#[derive(Debug)]
+pub enum RhoError {
+    NonPrimeOrder,
+    PointNotOnCurve,
+    PointNotInSubgroup,
+    InvalidParameters,
+    IterationLimit { iterations: u64 },
+    CheckpointMismatch,
+    InvalidCheckpoint,
+    Io(std::io::Error),
+}
+
+impl fmt::Display for RhoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RhoError::NonPrimeOrder => write!(f, "The order of the base point must be prime."),
+            RhoError::PointNotOnCurve => write!(f, "The points must lie on the curve."),
+            RhoError::PointNotInSubgroup => write!(
+                f,
+                "The target is not in the subgroup generated by the base point, or the order \
+                 is wrong."
+            ),
+            RhoError::InvalidParameters => write!(
+                f,
+                "The walk needs at least 2 partitions and at most 32 distinguished bits."
+            ),
+            RhoError::IterationLimit { iterations } => write!(
+                f,
+                "No collision after {} iterations; the checkpoint, if any, has been saved.",
+                iterations
+            ),
+            RhoError::CheckpointMismatch => {
+                write!(f, "The checkpoint file belongs to a different problem.")
+            }
+            RhoError::InvalidCheckpoint => write!(f, "The checkpoint file is malformed."),
+            RhoError::Io(err) => write!(f, "Checkpoint I/O failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RhoError {}
+
+impl From<std::io::Error> for RhoError {
+    fn from(err: std::io::Error) -> Self {
+        RhoError::Io(err)
+    }
+}
+
+/// Tuning parameters of the Pollard rho solver.
+#[derive(Debug, Clone)]
+pub struct RhoParameters {
+    pub partitions: usize,             // Number r of precomputed steps of the walk
+    pub distinguished_bits: u32,       // A point is distinguished if this many hash bits are 0
+    pub walks: usize,                  // Concurrent walks, one per rayon task
+    pub max_iterations: Option<u64>,   // Total iterations before giving up, if bounded
+    pub checkpoint: Option<PathBuf>,   // File holding the distinguished-point table
+    pub checkpoint_interval: Duration, // Time between checkpoint writes
+}
+
+impl RhoParameters {
+    /// Chooses parameters for a base point of order n: one walk per rayon thread and about
+    /// n^(1/4) / 2 steps between distinguished points. The table then holds about 2 n^(1/4)
+    /// points, a few megabytes for n near 2^70, and the steps after the collision until the
+    /// next distinguished point are negligible next to sqrt(n).
+    pub fn for_order(n: &BigUint) -> Self {
+        RhoParameters {
+            partitions: DEFAULT_PARTITIONS,
+            distinguished_bits: (n.bits() / 4).saturating_sub(1).min(32) as u32,
+            walks: rayon::current_num_threads(),
+            max_iterations: None,
+            checkpoint: None,
+            checkpoint_interval: Duration::from_secs(DEFAULT_CHECKPOINT_SECONDS),
+        }
+    }
+
+    /// Sets the checkpoint file, which is read on start if it exists and rewritten every
+    /// `checkpoint_interval`.
+    pub fn with_checkpoint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+}
+
+/// The result of a successful Pollard rho run.
+#[derive(Debug)]
+pub struct RhoResult {
+    /// The discrete logarithm k with kP = Q, in [0, n - 1].
+    pub k: BigUint,
+    /// Steps taken by all walks, including those of earlier runs restored from a checkpoint.
+    pub iterations: u64,
+    /// The expected number of steps, sqrt(pi n / 4) for the n / 2 classes {R, -R} plus
+    /// 2^d for each walk to reach its next distinguished point.
+    pub expected_iterations: f64,
+    /// Distinguished points in the table when the collision was found.
+    pub distinguished_points: usize,
+    /// Walks restarted after a fruitless cycle or a useless self-collision.
+    pub restarted_walks: u64,
+}
+
+impl RhoResult {
+    /// Returns the ratio of actual to expected iterations.
+    pub fn iteration_ratio(&self) -> f64 {
+        self.iterations as f64 / self.expected_iterations
+    }
+}
+
+/// A point R = aP + bQ of a walk, kept as the representative of {R, -R} with the smaller
+/// encoded y-coordinate.
+#[derive(Clone)]
+struct WalkPoint<E> {
+    point: E,
+    x: Vec<u8>,
+    a: BigUint,
+    b: BigUint,
+}
+
+/// The shared state of one run.
+struct Search<'c, C: RhoCurve> {
+    curve: &'c C,
+    p: &'c C::Element,
+    q: &'c C::Element,
+    n: &'c BigUint,
+    steps: Vec<(C::Element, BigUint, BigUint)>, // R_j = c_j P + d_j Q
+    distinguished_mask: u64,
+    max_walk: u64,
+    max_iterations: Option<u64>,
+    table: Mutex<HashMap<Vec<u8>, (BigUint, BigUint)>>,
+    iterations: AtomicU64,
+    restarted_walks: AtomicU64,
+    done: AtomicBool,
+    solution: Mutex<Option<BigUint>>,
+    failure: Mutex<Option<RhoError>>, // First failed checkpoint write, which stops the search
+    checkpoint: Option<(PathBuf, Duration, Mutex<Instant>)>,
+    fingerprint: Vec<u8>,
+}
+
+/// Solves Q = kP on an elliptic curve with the parallel Pollard rho method of van Oorschot
+/// and Wiener.
+///
+/// Every walk follows the same r-adding walk R -> R + R_j, with j given by a hash of the
+/// x-coordinate and R_j = c_j P + d_j Q fixed in advance, keeping track of R = aP + bQ. With
+/// the negation map the walk runs on the classes {R, -R}, which halves the search space
+/// and saves a factor sqrt(2); fruitless 2-cycles are avoided by skipping to R_(j + 1) when
+/// R + R_j lands in partition j again, and longer cycles are escaped by restarting walks that
+/// go too long without a distinguished point. Walks only report the points whose hash ends
+/// in `distinguished_bits` zero bits to the shared table, and two reports of the same point
+/// with different b give k = (a - a') / (b' - b) mod n.
+///
+/// With a checkpoint file, the step table R_j, the distinguished points and the iteration
+/// count are written to disk periodically and read back on start, so a long run can resume
+/// where it stopped. A restored step table takes precedence over `partitions`.
+///
+/// # Returns
+///
+/// * `Ok(RhoResult)` with k and the iteration statistics.
+/// * `Err(RhoError)` if n is not prime, a point is not on the curve or not in the subgroup
+///   of order n, the iteration limit is reached or the checkpoint cannot be used. A failed
+///   checkpoint write stops every walk and is returned as `Err(RhoError::Io)`.
+pub fn pollard_rho_ecdlp<C: RhoCurve>(
+    curve: &C,
+    p: &C::Element,
+    q: &C::Element,
+    n: &BigUint,
+    parameters: &RhoParameters,
+) -> Result<RhoResult, RhoError> {
+    if !is_probable_prime(n) {
+        return Err(RhoError::NonPrimeOrder);
+    }
+    if parameters.partitions < 2 || parameters.distinguished_bits > 32 || parameters.walks == 0 {
+        return Err(RhoError::InvalidParameters);
+    }
+    if !curve.is_element(p) || !curve.is_element(q) {
+        return Err(RhoError::PointNotOnCurve);
+    }
+    let is_identity = |point: &C::Element| curve.coordinate_bytes(point).is_none();
+    if is_identity(p) || !is_identity(&curve.pow(p, n)) || !is_identity(&curve.pow(q, n)) {
+        return Err(RhoError::PointNotInSubgroup);
+    }
+    let n_float = n.to_f64().unwrap_or(f64::MAX);
+    let expected_iterations = (std::f64::consts::PI * n_float / 4.0).sqrt()
+        + parameters.walks as f64 * 2f64.powi(parameters.distinguished_bits as i32);
+    let result = |k: BigUint, search: &Search<C>| RhoResult {
+        k,
+        iterations: search.iterations.load(Ordering::Relaxed),
+        expected_iterations,
+        distinguished_points: search.table.lock().unwrap().len(),
+        restarted_walks: search.restarted_walks.load(Ordering::Relaxed),
+    };
+
+    let fingerprint = checkpoint::fingerprint(curve, p, q, n);
+    let restored = match &parameters.checkpoint {
+        Some(path) if path.exists() => Some(Checkpoint::load(path, &fingerprint, n)?),
+        _ => None,
+    };
+    let (coefficients, table, iterations) = match restored {
+        Some(checkpoint) => (checkpoint.steps, checkpoint.points, checkpoint.iterations),
+        None => {
+            let mut rng = OsRng;
+            let coefficients = (0..parameters.partitions)
+                .map(|_| (rng.gen_biguint_below(n), rng.gen_biguint_below(n)))
+                .collect();
+            (coefficients, HashMap::new(), 0)
+        }
+    };
+    let steps = coefficients
+        .into_iter()
+        .map(|(c, d)| (curve.combine(&curve.pow(p, &c), &curve.pow(q, &d)), c, d))
+        .collect();
+
+    let search = Search {
+        curve,
+        p,
+        q,
+        n,
+        steps,
+        distinguished_mask: (1u64 << parameters.distinguished_bits) - 1,
+        max_walk: MAX_WALK_FACTOR << parameters.distinguished_bits,
+        max_iterations: parameters.max_iterations,
+        table: Mutex::new(table),
+        iterations: AtomicU64::new(iterations),
+        restarted_walks: AtomicU64::new(0),
+        done: AtomicBool::new(false),
+        solution: Mutex::new(None),
+        failure: Mutex::new(None),
+        checkpoint: parameters.checkpoint.clone().map(|path| {
+            (
+                path,
+                parameters.checkpoint_interval,
+                Mutex::new(Instant::now()),
+            )
+        }),
+        fingerprint,
+    };
+
+    // Walks only store finite points, so Q = O is handled directly.
+    if is_identity(q) {
+        return Ok(result(BigUint::zero(), &search));
+    }
+
+    (0..parameters.walks)
+        .into_par_iter()
+        .for_each(|_| search.run_walk());
+
+    if let Some(err) = search.failure.lock().unwrap().take() {
+        return Err(err);
+    }
+    let solution = search.solution.lock().unwrap().take();
+    match solution {
+        Some(k) => {
+            search.save_checkpoint()?;
+            Ok(result(k, &search))
+        }
+        None => {
+            search.save_checkpoint()?;
+            Err(RhoError::IterationLimit {
+                iterations: search.iterations.load(Ordering::Relaxed),
+            })
+        }
+    }
+}
+
+impl<C: RhoCurve> Search<'_, C> {
+    /// Runs one walk until some walk finds the logarithm or the iteration limit is reached.
+    fn run_walk(&self) {
+        let mut walk = self.random_start();
+        let mut unreported = 0u64;
+        while !self.done.load(Ordering::Relaxed) {
+            unreported += 1;
+            let Some(next) = self.step(&walk) else {
+                walk = self.restart(&mut unreported);
+                continue;
+            };
+            walk = next;
+            if (hash_bytes(&walk.x) >> 32) & self.distinguished_mask == 0 {
+                self.count_iterations(&mut unreported);
+                if !self.report(&walk) {
+                    walk = self.restart(&mut unreported);
+                }
+            } else if unreported > self.max_walk {
+                walk = self.restart(&mut unreported);
+            }
+        }
+        self.count_iterations(&mut unreported);
+    }
+
+    /// Adds the steps taken since the last call to the shared count and stops the search if
+    /// that reaches the iteration limit.
+    fn count_iterations(&self, unreported: &mut u64) {
+        let total = self.iterations.fetch_add(*unreported, Ordering::Relaxed) + *unreported;
+        *unreported = 0;
+        if self.max_iterations.is_some_and(|limit| total >= limit) {
+            self.done.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Counts a restarted walk and returns a fresh random start.
+    fn restart(&self, unreported: &mut u64) -> WalkPoint<C::Element> {
+        self.restarted_walks.fetch_add(1, Ordering::Relaxed);
+        self.count_iterations(unreported);
+        self.random_start()
+    }
+
+    /// Returns aP + bQ for random a and b, which is not the point at infinity.
+    fn random_start(&self) -> WalkPoint<C::Element> {
+        let mut rng = OsRng;
+        loop {
+            let a = rng.gen_biguint_below(self.n);
+            let b = rng.gen_biguint_below(self.n);
+            let point = self
+                .curve
+                .combine(&self.curve.pow(self.p, &a), &self.curve.pow(self.q, &b));
+            if let Some(walk) = self.canonical(point, a, b) {
+                return walk;
+            }
+        }
+    }
+
+    /// Takes one step of the walk with look-ahead: R + R_j for the partition j of R, or
+    /// R + R_(j + 1) and so on while the sum falls back into the partition just used.
+    ///
+    /// Returns `None` if the walk hits the point at infinity.
+    fn step(&self, walk: &WalkPoint<C::Element>) -> Option<WalkPoint<C::Element>> {
+        let r = self.steps.len();
+        let first = (hash_bytes(&walk.x) % r as u64) as usize;
+        let mut candidate = None;
+        for offset in 0..r {
+            let j = (first + offset) % r;
+            let (step, c, d) = &self.steps[j];
+            let next = self.canonical(
+                self.curve.combine(&walk.point, step),
+                (&walk.a + c) % self.n,
+                (&walk.b + d) % self.n,
+            )?;
+            let lands_in_j = (hash_bytes(&next.x) % r as u64) as usize == j;
+            candidate = Some(next);
+            if !lands_in_j {
+                break;
+            }
+        }
+        candidate
+    }
+
+    /// Returns the representative of {R, -R} with the smaller encoded y-coordinate, negating
+    /// a and b along with the point, or `None` for the point at infinity.
+    fn canonical(
+        &self,
+        point: C::Element,
+        a: BigUint,
+        b: BigUint,
+    ) -> Option<WalkPoint<C::Element>> {
+        let (x, y) = self.curve.coordinate_bytes(&point)?;
+        let negated = self
+            .curve
+            .inverse(&point)
+            .expect("points always have negatives");
+        let (_, negated_y) = self.curve.coordinate_bytes(&negated)?;
+        Some(if negated_y < y {
+            WalkPoint {
+                point: negated,
+                x,
+                a: (self.n - a) % self.n,
+                b: (self.n - b) % self.n,
+            }
+        } else {
+            WalkPoint { point, x, a, b }
+        })
+    }
+
+    /// Adds a distinguished point to the table and checks it for a collision.
+    ///
+    /// Returns false if the walk should restart because it met its own earlier point with the
+    /// same b, which gives no information.
+    fn report(&self, walk: &WalkPoint<C::Element>) -> bool {
+        let mut table = self.table.lock().unwrap();
+        match table.get(&walk.x) {
+            Some((a, b)) if b != &walk.b => {
+                // aP + bQ = a'P + b'Q, so k = (a - a') / (b' - b).
+                let numerator = (a + self.n - &walk.a) % self.n;
+                let denominator = (&walk.b + self.n - b) % self.n;
+                let inverse = denominator.modpow(&(self.n - 2u32), self.n);
+                let k = numerator * inverse % self.n;
+                if self.curve.coordinate_bytes(&self.curve.pow(self.p, &k))
+                    == self.curve.coordinate_bytes(self.q)
+                {
+                    *self.solution.lock().unwrap() = Some(k);
+                    self.done.store(true, Ordering::Relaxed);
+                    return true;
+                }
+                false
+            }
+            Some(_) => false,
+            None => {
+                table.insert(walk.x.clone(), (walk.a.clone(), walk.b.clone()));
+                drop(table);
+                if let Err(err) = self.save_checkpoint_if_due() {
+                    self.failure.lock().unwrap().get_or_insert(err);
+                    self.done.store(true, Ordering::Relaxed);
+                }
+                true
+            }
+        }
+    }
+
+    /// Writes the checkpoint if the interval since the last write has passed.
+    fn save_checkpoint_if_due(&self) -> Result<(), RhoError> {
+        let Some((_, interval, last_save)) = &self.checkpoint else {
+            return Ok(());
+        };
+        let mut last_save = last_save.lock().unwrap();
+        if last_save.elapsed() < *interval {
+            return Ok(());
+        }
+        *last_save = Instant::now();
+        drop(last_save);
+        self.save_checkpoint()
+    }
+
+    /// Writes the step table, the distinguished points and the iteration count, if a
+    /// checkpoint file is set.
+    fn save_checkpoint(&self) -> Result<(), RhoError> {
+        let Some((path, _, _)) = &self.checkpoint else {
+            return Ok(());
+        };
+        let checkpoint = Checkpoint {
+            steps: self
+                .steps
+                .iter()
+                .map(|(_, c, d)| (c.clone(), d.clone()))
+                .collect(),
+            points: self.table.lock().unwrap().clone(),
+            iterations: self.iterations.load(Ordering::Relaxed),
+        };
+        checkpoint.save(path, &self.fingerprint)
+    }
+}
+
+/// FNV-1a followed by the MurmurHash3 finalizer, a fast hash of a coordinate used to choose
+/// the partition (low 32 bits) and to decide whether a point is distinguished (high 32 bits).
+/// The finalizer is needed because the high bits of plain FNV-1a hardly change for short
+/// inputs. The hash depends only on x, so R and -R always agree.
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hash = bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,206 @@
+// src/elliptic_curve/rho/tests.rs
+
+// Pollard rho on a small curve of prime order over GF(p), found by point counting, and on one
+// of order 2n over GF(2^13), and runs that stop at the iteration limit and resume from their
+// checkpoint file.
+
+use super::{pollard_rho_ecdlp, RhoError, RhoParameters};
+use crate::algebra::traits::{FiniteField, Group};
+use crate::binary_extension_field::BinaryExtensionField;
+use crate::elliptic_curve::{AffinePoint, BinaryCurve, Curve};
+use crate::factor::is_probable_prime;
+use crate::polynomial::Polynomial;
+use num_bigint::{BigUint, RandBigInt};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Returns y^2 = x^3 + x + b over GF(2^24 + 43) for the first b with a prime number of
+/// points, the order n and the point with the smallest x.
+fn prime_order_curve() -> (Curve, AffinePoint, BigUint) {
+    let p = BigUint::from((1u32 << 24) + 43);
+    let mut b = BigUint::from(1u32);
+    loop {
+        let curve = Curve::new(p.clone(), BigUint::from(1u32), b.clone()).unwrap();
+        let n = curve.count_points_bsgs();
+        if is_probable_prime(&n) {
+            let mut x = BigUint::from(0u32);
+            let g = loop {
+                if let Some(y) = curve.field.sqrt(&curve.right_hand_side(&x)) {
+                    break AffinePoint::new(x, y);
+                }
+                x += 1u32;
+            };
+            return (curve, g, n);
+        }
+        b += 1u32;
+    }
+}
+
+/// Returns y^2 + xy = x^3 + x^2 + (t^3 + 1) over GF(2^13) = GF(2)[t] / (t^13 + t^4 + t^3 +
+/// t + 1), which has 2n points for the prime n = 4091, and a point of order n. The solver
+/// itself checks that nG = O.
+fn binary_curve() -> (BinaryCurve, AffinePoint<Polynomial>, BigUint) {
+    let field = BinaryExtensionField::new(13, &[1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1]).unwrap();
+    let elements = field.elements();
+    let one = elements[1].clone();
+    let curve =
+        BinaryCurve::new(field.clone(), one.clone(), Polynomial::new(&[1, 0, 0, 1])).unwrap();
+    // For x != 0, y = xz turns the equation into z^2 + z = x + a + b / x^2.
+    let g = elements[1..]
+        .iter()
+        .find_map(|x| {
+            let inverse = field.inverse(x).unwrap();
+            let c = field.add(
+                &field.add(x, &one),
+                &field.multiply(&curve.b, &field.square(&inverse)),
+            );
+            let z = field.solve_quadratic(&c)?;
+            let point = curve.pow(
+                &AffinePoint::new(x.clone(), field.multiply(x, &z)),
+                &BigUint::from(2u32),
+            );
+            (!point.is_infinity()).then_some(point)
+        })
+        .unwrap();
+    (curve, g, BigUint::from(4091u32))
+}
+
+fn parameters(n: &BigUint) -> RhoParameters {
+    RhoParameters {
+        walks: 2,
+        ..RhoParameters::for_order(n)
+    }
+}
+
+/// Returns a checkpoint path in the temporary directory, removing any leftover file.
+fn checkpoint_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rho-{}-{}.txt", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn solves_discrete_logs_on_a_prime_curve() {
+    let (curve, g, n) = prime_order_curve();
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..3 {
+        let k = rng.gen_biguint_below(&n);
+        let q = curve.pow(&g, &k);
+        let result = pollard_rho_ecdlp(&curve, &g, &q, &n, &parameters(&n)).unwrap();
+        assert_eq!(result.k, k);
+        assert!(result.iterations > 0 && result.distinguished_points > 0);
+        // About sqrt(pi n / 4) = 3600 steps are expected; allow for bad luck.
+        assert!(result.expected_iterations > 3000.0 && result.iteration_ratio() < 20.0);
+    }
+
+    let infinity = AffinePoint::Infinity;
+    let result = pollard_rho_ecdlp(&curve, &g, &infinity, &n, &parameters(&n)).unwrap();
+    assert_eq!(result.k, BigUint::from(0u32));
+}
+
+#[test]
+fn solves_discrete_logs_on_a_binary_curve() {
+    let (curve, g, n) = binary_curve();
+    let mut rng = StdRng::seed_from_u64(2);
+    for _ in 0..3 {
+        let k = rng.gen_biguint_below(&n);
+        let q = curve.pow(&g, &k);
+        let result = pollard_rho_ecdlp(&curve, &g, &q, &n, &parameters(&n)).unwrap();
+        assert_eq!(result.k, k);
+    }
+}
+
+#[test]
+fn invalid_problems_are_rejected() {
+    let (curve, g, n) = prime_order_curve();
+    let q = curve.pow(&g, &BigUint::from(5u32));
+    let solve = |q: &AffinePoint, n: &BigUint, parameters: &RhoParameters| {
+        pollard_rho_ecdlp(&curve, &g, q, n, parameters).unwrap_err()
+    };
+    let composite = &n + 1u32;
+    assert!(matches!(
+        solve(&q, &composite, &parameters(&n)),
+        RhoError::NonPrimeOrder
+    ));
+    let off_curve = AffinePoint::new(BigUint::from(0u32), BigUint::from(0u32));
+    assert!(matches!(
+        solve(&off_curve, &n, &parameters(&n)),
+        RhoError::PointNotOnCurve
+    ));
+    let too_few_partitions = RhoParameters {
+        partitions: 1,
+        ..parameters(&n)
+    };
+    assert!(matches!(
+        solve(&q, &n, &too_few_partitions),
+        RhoError::InvalidParameters
+    ));
+    // The curve has prime order n, so no order below it kills G.
+    let small = BigUint::from(1009u32);
+    assert!(matches!(
+        solve(&q, &small, &parameters(&n)),
+        RhoError::PointNotInSubgroup
+    ));
+}
+
+#[test]
+fn resumes_from_a_checkpoint() {
+    let (curve, g, n) = prime_order_curve();
+    let k = BigUint::from(0xabcdefu32);
+    let q = curve.pow(&g, &k);
+    let path = checkpoint_path("resume");
+    let limited = RhoParameters {
+        max_iterations: Some(200),
+        distinguished_bits: 2,
+        ..parameters(&n).with_checkpoint(&path)
+    };
+
+    let RhoError::IterationLimit { iterations } =
+        pollard_rho_ecdlp(&curve, &g, &q, &n, &limited).unwrap_err()
+    else {
+        panic!("expected the iteration limit");
+    };
+    assert!(iterations >= 200 && path.exists());
+
+    let unlimited = RhoParameters {
+        max_iterations: None,
+        ..limited
+    };
+    let result = pollard_rho_ecdlp(&curve, &g, &q, &n, &unlimited).unwrap();
+    assert_eq!(result.k, k);
+    assert!(result.iterations > iterations);
+
+    // The file now belongs to this Q and cannot be used for another.
+    let other = curve.pow(&g, &BigUint::from(7u32));
+    assert!(matches!(
+        pollard_rho_ecdlp(&curve, &g, &other, &n, &unlimited).unwrap_err(),
+        RhoError::CheckpointMismatch
+    ));
+
+    std::fs::write(&path, "pollard-rho-checkpoint 1\nproblem 00\n").unwrap();
+    assert!(matches!(
+        pollard_rho_ecdlp(&curve, &g, &q, &n, &unlimited).unwrap_err(),
+        RhoError::CheckpointMismatch
+    ));
+    std::fs::write(&path, "not a checkpoint\n").unwrap();
+    assert!(matches!(
+        pollard_rho_ecdlp(&curve, &g, &q, &n, &unlimited).unwrap_err(),
+        RhoError::InvalidCheckpoint
+    ));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn failed_checkpoint_writes_are_returned() {
+    let (curve, g, n) = prime_order_curve();
+    let q = curve.pow(&g, &BigUint::from(12345u32));
+    let path = checkpoint_path("missing").join("checkpoint.txt");
+    let mut parameters = parameters(&n).with_checkpoint(&path);
+    parameters.checkpoint_interval = Duration::ZERO;
+    assert!(matches!(
+        pollard_rho_ecdlp(&curve, &g, &q, &n, &parameters).unwrap_err(),
+        RhoError::Io(_)
+    ));
+}
@@ -0,0 +1,198 @@
+// src/elliptic_curve/scalar_mul.rs
+
+use super::{AffinePoint, Curve, JacobianPoint};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Window width used by `scalar_mul`.
+const DEFAULT_WNAF_WIDTH: usize = 4;
+
+impl Curve {
+    /// Computes kP with the default method, a width-4 NAF.
+    pub fn scalar_mul(&self, k: &BigUint, point: &AffinePoint) -> AffinePoint {
+        self.mul_wnaf(k, point, DEFAULT_WNAF_WIDTH)
+    }
+
+    /// Computes kP by left-to-right double-and-add in Jacobian coordinates.
+    pub fn mul_double_and_add(&self, k: &BigUint, point: &AffinePoint) -> AffinePoint {
+        let base = JacobianPoint::from(point);
+        let mut result = JacobianPoint::infinity();
+        for i in (0..k.bits()).rev() {
+            result = self.jacobian_double(&result);
+            if k.bit(i) {
+                result = self.jacobian_add(&result, &base);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    /// Computes kP from the width-w NAF of k, with the odd multiples P, 3P, ...,
+    /// (2^(w - 1) - 1)P precomputed. On average only one in w + 1 digits is nonzero.
+    pub fn mul_wnaf(&self, k: &BigUint, point: &AffinePoint, width: usize) -> AffinePoint {
+        let base = JacobianPoint::from(point);
+        let twice = self.jacobian_double(&base);
+        let mut odd_multiples = vec![base];
+        for i in 1..1usize << (width.max(2) - 2) {
+            let next = self.jacobian_add(&odd_multiples[i - 1], &twice);
+            odd_multiples.push(next);
+        }
+
+        let mut result = JacobianPoint::infinity();
+        for &digit in wnaf(k, width).iter().rev() {
+            result = self.jacobian_double(&result);
+            if digit > 0 {
+                result = self.jacobian_add(&result, &odd_multiples[(digit as usize) / 2]);
+            } else if digit < 0 {
+                let negated = self.jacobian_negate(&odd_multiples[(-digit as usize) / 2]);
+                result = self.jacobian_add(&result, &negated);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    /// Computes kP with the Montgomery ladder, which performs one addition and one doubling for
+    /// every bit of k whatever its value. The sequence of operations depends only on the bit
+    /// length of k, not on its bits.
+    pub fn mul_montgomery_ladder(&self, k: &BigUint, point: &AffinePoint) -> AffinePoint {
+        let mut r0 = JacobianPoint::infinity();
+        let mut r1 = JacobianPoint::from(point);
+        for i in (0..k.bits()).rev() {
+            if k.bit(i) {
+                r0 = self.jacobian_add(&r0, &r1);
+                r1 = self.jacobian_double(&r1);
+            } else {
+                r1 = self.jacobian_add(&r0, &r1);
+                r0 = self.jacobian_double(&r0);
+            }
+        }
+        self.to_affine(&r0)
+    }
+}
+
+/// Returns the width-w non-adjacent form of k, least significant digit first.
+///
+/// Every nonzero digit is odd with absolute value below 2^(w - 1), and any w consecutive digits
+/// contain at most one nonzero digit. A width below 2 is treated as 2, the ordinary NAF.
+pub fn wnaf(k: &BigUint, width: usize) -> Vec<i64> {
+    let width = width.max(2);
+    let modulus = 1i64 << width;
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(k.bits() as usize + 1);
+    while !k.is_zero() {
+        let digit = if k.bit(0) {
+            let low = (&k % modulus as u64)
+                .to_u64_digits()
+                .first()
+                .copied()
+                .unwrap_or(0) as i64;
+            let digit = if low >= modulus / 2 {
+                low - modulus
+            } else {
+                low
+            };
+            if digit > 0 {
+                k -= digit as u64;
+            } else {
+                k += (-digit) as u64;
+            }
+            digit
+        } else {
+            0
+        };
+        digits.push(digit);
+        k >>= 1;
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::NamedCurve;
+    use num_bigint::RandBigInt;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn methods_agree() {
+        let mut rng = StdRng::seed_from_u64(41);
+        for named in [NamedCurve::P256, NamedCurve::Secp256k1] {
+            let curve = Curve::named(named);
+            let g = named.generator();
+            let n = named.order();
+
+            // Small multiples against repeated addition.
+            let mut expected = AffinePoint::Infinity;
+            for k in 0..20u32 {
+                let k = BigUint::from(k);
+                assert_eq!(curve.mul_double_and_add(&k, &g), expected, "{}", named);
+                assert_eq!(curve.mul_montgomery_ladder(&k, &g), expected, "{}", named);
+                for width in 2..=6 {
+                    assert_eq!(curve.mul_wnaf(&k, &g, width), expected, "{}", named);
+                }
+                expected = curve.add(&expected, &g);
+            }
+
+            let mut scalars: Vec<BigUint> = (0..3).map(|_| rng.gen_biguint_below(&n)).collect();
+            scalars.push(&n - 1u32);
+            scalars.push(&n + 1u32);
+            for k in &scalars {
+                let expected = curve.mul_double_and_add(k, &g);
+                assert_eq!(curve.mul_montgomery_ladder(k, &g), expected);
+                assert_eq!(curve.scalar_mul(k, &g), expected);
+                assert_eq!(curve.mul_wnaf(k, &g, 5), expected);
+            }
+            assert_eq!(curve.scalar_mul(&(&n - 1u32), &g), curve.negate(&g));
+            assert_eq!(curve.scalar_mul(&(&n + 1u32), &g), g);
+        }
+    }
+
+    #[test]
+    fn zero_scalars_and_the_point_at_infinity() {
+        let curve = Curve::named(NamedCurve::P256);
+        let g = NamedCurve::P256.generator();
+        let zero = BigUint::zero();
+        let k = BigUint::from(123_456_789u32);
+        let infinity = AffinePoint::Infinity;
+        for point in [&g, &infinity] {
+            assert_eq!(curve.scalar_mul(&zero, point), infinity);
+            assert_eq!(curve.mul_double_and_add(&zero, point), infinity);
+            assert_eq!(curve.mul_montgomery_ladder(&zero, point), infinity);
+        }
+        assert_eq!(curve.scalar_mul(&k, &infinity), infinity);
+        assert_eq!(curve.mul_double_and_add(&k, &infinity), infinity);
+        assert_eq!(curve.mul_montgomery_ladder(&k, &infinity), infinity);
+        assert!(wnaf(&zero, 4).is_empty());
+    }
+
+    #[test]
+    fn wnaf_digits() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for width in 1..=7 {
+            let effective = width.max(2);
+            for _ in 0..20 {
+                let k = rng.gen_biguint(200);
+                let digits = wnaf(&k, width);
+                let bound = 1i64 << (effective - 1);
+                assert!(digits
+                    .iter()
+                    .all(|&d| d == 0 || (d % 2 != 0 && d.abs() < bound)));
+                for window in digits.windows(effective) {
+                    assert!(window.iter().filter(|&&d| d != 0).count() <= 1);
+                }
+                // Reconstruct k from its digits.
+                let (mut positive, mut negative) = (BigUint::zero(), BigUint::zero());
+                for &d in digits.iter().rev() {
+                    positive <<= 1;
+                    negative <<= 1;
+                    if d > 0 {
+                        positive += d as u64;
+                    } else {
+                        negative += (-d) as u64;
+                    }
+                }
+                assert_eq!(positive - negative, k);
+            }
+        }
+    }
+}
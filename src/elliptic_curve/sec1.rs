@@ -0,0 +1,475 @@
+// src/elliptic_curve/sec1.rs
+
+use super::{AffinePoint, BinaryCurve, Curve, EllipticCurveError};
+use crate::algebra::traits::ElementEncoding;
+use crate::polynomial::Polynomial;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Leading byte of the encoding of the point at infinity.
+pub const TAG_INFINITY: u8 = 0x00;
+/// Leading byte of a compressed point whose compression bit is 0.
+pub const TAG_COMPRESSED_EVEN: u8 = 0x02;
+/// Leading byte of a compressed point whose compression bit is 1.
+pub const TAG_COMPRESSED_ODD: u8 = 0x03;
+/// Leading byte of an uncompressed point.
+pub const TAG_UNCOMPRESSED: u8 = 0x04;
+
+impl Curve {
+    /// Returns the length in bytes of an encoded field element, ceil(log2(p) / 8).
+    pub fn field_length(&self) -> usize {
+        self.field.p.bits().div_ceil(8) as usize
+    }
+
+    /// Encodes a point as an octet string (SEC 1, section 2.3.3).
+    ///
+    /// The point at infinity is the single byte 0x00. A finite point is 0x04 || x || y, or
+    /// 0x02 / 0x03 || x when compressed, where the tag carries the parity of y.
+    pub fn encode_point(&self, point: &AffinePoint, compressed: bool) -> Vec<u8> {
+        let AffinePoint::Finite { x, y } = point else {
+            return vec![TAG_INFINITY];
+        };
+        let length = self.field_length();
+        let mut out = if !compressed {
+            vec![TAG_UNCOMPRESSED]
+        } else if y.bit(0) {
+            vec![TAG_COMPRESSED_ODD]
+        } else {
+            vec![TAG_COMPRESSED_EVEN]
+        };
+        out.extend(pad(&x.to_bytes_be(), length));
+        if !compressed {
+            out.extend(pad(&y.to_bytes_be(), length));
+        }
+        out
+    }
+
+    /// Decodes a compressed or uncompressed point (SEC 1, section 2.3.4). A compressed point
+    /// is recovered by taking the square root of x^3 + ax + b and choosing the root whose
+    /// parity matches the tag. When that root is 0 there is no odd y, so tag 0x03 is rejected.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` if `bytes` encodes a point on the curve.
+    /// * `Err(EllipticCurveError)` describing why the encoding was rejected.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<AffinePoint, EllipticCurveError> {
+        let length = self.field_length();
+        let Some((&tag, rest)) = bytes.split_first() else {
+            return Err(EllipticCurveError::InvalidEncodingLength {
+                expected: 1,
+                found: 0,
+            });
+        };
+        match tag {
+            TAG_INFINITY => {
+                check_length(bytes, 1)?;
+                Ok(AffinePoint::Infinity)
+            }
+            TAG_COMPRESSED_EVEN | TAG_COMPRESSED_ODD => {
+                check_length(bytes, 1 + length)?;
+                let x = self.decode_coordinate(rest)?;
+                let y = self
+                    .field
+                    .sqrt(&self.right_hand_side(&x))
+                    .ok_or(EllipticCurveError::PointNotOnCurve)?;
+                if y.is_zero() && tag == TAG_COMPRESSED_ODD {
+                    return Err(EllipticCurveError::InvalidCompressionBit);
+                }
+                let y = if y.bit(0) == (tag == TAG_COMPRESSED_ODD) {
+                    y
+                } else {
+                    self.field.sub(&BigUint::zero(), &y)
+                };
+                Ok(AffinePoint::new(x, y))
+            }
+            TAG_UNCOMPRESSED => {
+                check_length(bytes, 1 + 2 * length)?;
+                let x = self.decode_coordinate(&rest[..length])?;
+                let y = self.decode_coordinate(&rest[length..])?;
+                self.point(x, y)
+            }
+            _ => Err(EllipticCurveError::InvalidEncodingTag(tag)),
+        }
+    }
+
+    /// Encodes a point like `encode_point` and returns it as lowercase hex.
+    pub fn encode_point_hex(&self, point: &AffinePoint, compressed: bool) -> String {
+        hex::encode(self.encode_point(point, compressed))
+    }
+
+    /// Decodes a point from a hex string, in either case, like `decode_point`.
+    pub fn decode_point_hex(&self, encoded: &str) -> Result<AffinePoint, EllipticCurveError> {
+        let bytes = hex::decode(encoded).map_err(|_| EllipticCurveError::InvalidHex)?;
+        self.decode_point(&bytes)
+    }
+
+    fn decode_coordinate(&self, bytes: &[u8]) -> Result<BigUint, EllipticCurveError> {
+        let value = BigUint::from_bytes_be(bytes);
+        if value >= self.field.p {
+            return Err(EllipticCurveError::CoordinateOutOfRange);
+        }
+        Ok(value)
+    }
+}
+
+impl BinaryCurve {
+    /// Returns the length in bytes of an encoded field element, ceil(m / 8).
+    pub fn field_length(&self) -> usize {
+        self.field.degree().div_ceil(8)
+    }
+
+    /// Encodes a point as an octet string (SEC 1, section 2.3.3).
+    ///
+    /// The layout matches `Curve::encode_point`. The compression bit is the constant term of
+    /// y / x, or 0 when x = 0, since y alone does not tell P and -P = (x, x + y) apart.
+    pub fn encode_point(&self, point: &AffinePoint<Polynomial>, compressed: bool) -> Vec<u8> {
+        let AffinePoint::Finite { x, y } = point else {
+            return vec![TAG_INFINITY];
+        };
+        let mut out = if !compressed {
+            vec![TAG_UNCOMPRESSED]
+        } else if self.compression_bit(x, y) {
+            vec![TAG_COMPRESSED_ODD]
+        } else {
+            vec![TAG_COMPRESSED_EVEN]
+        };
+        out.extend(self.field.encode_element(x));
+        if !compressed {
+            out.extend(self.field.encode_element(y));
+        }
+        out
+    }
+
+    /// Decodes a compressed or uncompressed point (SEC 1, section 2.3.4).
+    ///
+    /// For a compressed point with x != 0, substituting y = xz turns the curve equation into
+    /// z^2 + z = x + a + b / x^2, which `BinaryExtensionField::solve_quadratic` solves; the
+    /// compression bit picks between the roots z and z + 1. When x = 0 the only point is
+    /// (0, sqrt(b)), whose compression bit is 0, so tag 0x03 is rejected.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` if `bytes` encodes a point on the curve.
+    /// * `Err(EllipticCurveError)` describing why the encoding was rejected.
+    pub fn decode_point(
+        &self,
+        bytes: &[u8],
+    ) -> Result<AffinePoint<Polynomial>, EllipticCurveError> {
+        let length = self.field_length();
+        let Some((&tag, rest)) = bytes.split_first() else {
+            return Err(EllipticCurveError::InvalidEncodingLength {
+                expected: 1,
+                found: 0,
+            });
+        };
+        match tag {
+            TAG_INFINITY => {
+                check_length(bytes, 1)?;
+                Ok(AffinePoint::Infinity)
+            }
+            TAG_COMPRESSED_EVEN | TAG_COMPRESSED_ODD => {
+                check_length(bytes, 1 + length)?;
+                let x = self.decode_coordinate(rest)?;
+                let y = if x.is_zero() {
+                    if tag == TAG_COMPRESSED_ODD {
+                        return Err(EllipticCurveError::InvalidCompressionBit);
+                    }
+                    self.field_sqrt(&self.b)
+                } else {
+                    let field = &self.field;
+                    let x_inv = field.inverse(&x).expect("x is nonzero");
+                    let beta = field.add(
+                        &field.add(&x, &self.a),
+                        &field.multiply(&self.b, &field.square(&x_inv)),
+                    );
+                    let z = field
+                        .solve_quadratic(&beta)
+                        .ok_or(EllipticCurveError::PointNotOnCurve)?;
+                    let z = if constant_term(&z) == (tag == TAG_COMPRESSED_ODD) {
+                        z
+                    } else {
+                        field.add(&z, &Polynomial::one())
+                    };
+                    field.multiply(&x, &z)
+                };
+                Ok(AffinePoint::new(x, y))
+            }
+            TAG_UNCOMPRESSED => {
+                check_length(bytes, 1 + 2 * length)?;
+                let x = self.decode_coordinate(&rest[..length])?;
+                let y = self.decode_coordinate(&rest[length..])?;
+                self.point(x, y)
+            }
+            _ => Err(EllipticCurveError::InvalidEncodingTag(tag)),
+        }
+    }
+
+    /// Encodes a point like `encode_point` and returns it as lowercase hex.
+    pub fn encode_point_hex(&self, point: &AffinePoint<Polynomial>, compressed: bool) -> String {
+        hex::encode(self.encode_point(point, compressed))
+    }
+
+    /// Decodes a point from a hex string, in either case, like `decode_point`.
+    pub fn decode_point_hex(
+        &self,
+        encoded: &str,
+    ) -> Result<AffinePoint<Polynomial>, EllipticCurveError> {
+        let bytes = hex::decode(encoded).map_err(|_| EllipticCurveError::InvalidHex)?;
+        self.decode_point(&bytes)
+    }
+
+    fn compression_bit(&self, x: &Polynomial, y: &Polynomial) -> bool {
+        if x.is_zero() {
+            return false;
+        }
+        let x_inv = self.field.inverse(x).expect("x is nonzero");
+        constant_term(&self.field.multiply(y, &x_inv))
+    }
+
+    /// Squaring is a bijection in characteristic 2, so sqrt(a) = a^(2^(m - 1)).
+    fn field_sqrt(&self, a: &Polynomial) -> Polynomial {
+        (1..self.field.degree()).fold(a.clone(), |root, _| self.field.square(&root))
+    }
+
+    fn decode_coordinate(&self, bytes: &[u8]) -> Result<Polynomial, EllipticCurveError> {
+        self.field
+            .decode_element(bytes)
+            .ok_or(EllipticCurveError::CoordinateOutOfRange)
+    }
+}
+
+impl ElementEncoding for Curve {
+    /// Encodes points in compressed SEC 1 form.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        self.encode_point(a, true)
+    }
+
+    /// Accepts both compressed and uncompressed SEC 1 points.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        self.decode_point(bytes).ok()
+    }
+}
+
+impl ElementEncoding for BinaryCurve {
+    /// Encodes points in compressed SEC 1 form.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        self.encode_point(a, true)
+    }
+
+    /// Accepts both compressed and uncompressed SEC 1 points.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        self.decode_point(bytes).ok()
+    }
+}
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<(), EllipticCurveError> {
+    if bytes.len() != expected {
+        return Err(EllipticCurveError::InvalidEncodingLength {
+            expected,
+            found: bytes.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Left-pads a big-endian integer with zeros to `length` bytes.
+fn pad(bytes: &[u8], length: usize) -> Vec<u8> {
+    let mut out = vec![0u8; length - bytes.len()];
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// Returns the coefficient of x^0.
+fn constant_term(a: &Polynomial) -> bool {
+    a.bits.last().is_some_and(|bit| *bit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{NamedBinaryCurve, NamedCurve};
+
+    fn p256() -> (Curve, AffinePoint) {
+        let named = NamedCurve::P256;
+        let curve = Curve::new(named.prime(), named.a(), named.b()).unwrap();
+        (curve, named.generator())
+    }
+
+    /// Returns `bytes` with the tag replaced.
+    fn retag(bytes: &[u8], tag: u8) -> Vec<u8> {
+        let mut out = bytes.to_vec();
+        out[0] = tag;
+        out
+    }
+
+    #[test]
+    fn prime_curve_round_trips() {
+        let (curve, g) = p256();
+        let mut point = g.clone();
+        for _ in 0..4 {
+            let uncompressed = curve.encode_point(&point, false);
+            let compressed = curve.encode_point(&point, true);
+            assert_eq!((uncompressed.len(), compressed.len()), (65, 33));
+            assert_eq!(uncompressed[0], TAG_UNCOMPRESSED);
+            assert_eq!(curve.decode_point(&uncompressed).unwrap(), point);
+            assert_eq!(curve.decode_point(&compressed).unwrap(), point);
+            let hex = curve.encode_point_hex(&point, true);
+            assert_eq!(curve.decode_point_hex(&hex.to_uppercase()).unwrap(), point);
+            point = curve.add(&point, &g);
+        }
+        assert_eq!(
+            curve.encode_point(&AffinePoint::Infinity, true),
+            [TAG_INFINITY]
+        );
+        assert_eq!(
+            curve.decode_point(&[TAG_INFINITY]).unwrap(),
+            AffinePoint::Infinity
+        );
+
+        // The other tag selects -G.
+        let compressed = curve.encode_point(&g, true);
+        let flipped = retag(&compressed, compressed[0] ^ 1);
+        assert_eq!(curve.decode_point(&flipped).unwrap(), curve.negate(&g));
+    }
+
+    #[test]
+    fn binary_curve_round_trips() {
+        for named in [NamedBinaryCurve::B163, NamedBinaryCurve::K233] {
+            let curve = BinaryCurve::named(named);
+            let g = named.generator(&curve.field);
+            let length = curve.field_length();
+            let mut point = g.clone();
+            for _ in 0..4 {
+                let uncompressed = curve.encode_point(&point, false);
+                let compressed = curve.encode_point(&point, true);
+                assert_eq!(uncompressed.len(), 1 + 2 * length);
+                assert_eq!(compressed.len(), 1 + length);
+                assert_eq!(
+                    curve.decode_point(&uncompressed).unwrap(),
+                    point,
+                    "{}",
+                    named
+                );
+                assert_eq!(curve.decode_point(&compressed).unwrap(), point, "{}", named);
+                let hex = curve.encode_point_hex(&point, true);
+                assert_eq!(curve.decode_point_hex(&hex).unwrap(), point);
+                point = curve.add(&point, &g);
+            }
+            assert_eq!(
+                curve.decode_point(&[TAG_INFINITY]).unwrap(),
+                AffinePoint::Infinity
+            );
+        }
+    }
+
+    #[test]
+    fn bad_tags_and_lengths_are_rejected() {
+        let (curve, g) = p256();
+        let uncompressed = curve.encode_point(&g, false);
+        let compressed = curve.encode_point(&g, true);
+        // 0x06 and 0x07 are the hybrid forms, which are not supported.
+        for tag in [0x01, 0x05, 0x06, 0x07, 0xff] {
+            assert_eq!(
+                curve.decode_point(&retag(&compressed, tag)),
+                Err(EllipticCurveError::InvalidEncodingTag(tag))
+            );
+        }
+
+        let length =
+            |expected, found| EllipticCurveError::InvalidEncodingLength { expected, found };
+        assert_eq!(curve.decode_point(&[]), Err(length(1, 0)));
+        assert_eq!(curve.decode_point(&[TAG_INFINITY, 0]), Err(length(1, 2)));
+        assert_eq!(curve.decode_point(&compressed[..32]), Err(length(33, 32)));
+        assert_eq!(curve.decode_point(&uncompressed[..64]), Err(length(65, 64)));
+        let mut long = uncompressed.clone();
+        long.push(0);
+        assert_eq!(curve.decode_point(&long), Err(length(65, 66)));
+        assert_eq!(
+            curve.decode_point(&retag(&uncompressed, TAG_COMPRESSED_EVEN)),
+            Err(length(33, 65))
+        );
+        assert_eq!(
+            curve.decode_point(&retag(&compressed, TAG_UNCOMPRESSED)),
+            Err(length(65, 33))
+        );
+        assert_eq!(
+            curve.decode_point_hex("04zz"),
+            Err(EllipticCurveError::InvalidHex)
+        );
+
+        let binary = BinaryCurve::named(NamedBinaryCurve::K163);
+        let point = NamedBinaryCurve::K163.generator(&binary.field);
+        let encoded = binary.encode_point(&point, true);
+        assert_eq!(
+            binary.decode_point(&retag(&encoded, 0x05)),
+            Err(EllipticCurveError::InvalidEncodingTag(0x05))
+        );
+        assert_eq!(binary.decode_point(&encoded[..21]), Err(length(22, 21)));
+    }
+
+    #[test]
+    fn invalid_coordinates_are_rejected() {
+        let (curve, g) = p256();
+        let length = curve.field_length();
+
+        // x^3 + ax + b is a non-residue for about half of all x.
+        let x = (0u32..)
+            .map(BigUint::from)
+            .find(|x| curve.field.sqrt(&curve.right_hand_side(x)).is_none())
+            .unwrap();
+        let mut compressed = vec![TAG_COMPRESSED_EVEN];
+        compressed.extend(pad(&x.to_bytes_be(), length));
+        assert_eq!(
+            curve.decode_point(&compressed),
+            Err(EllipticCurveError::PointNotOnCurve)
+        );
+
+        let mut off_curve = curve.encode_point(&g, false);
+        off_curve[2 * length] ^= 1;
+        assert_eq!(
+            curve.decode_point(&off_curve),
+            Err(EllipticCurveError::PointNotOnCurve)
+        );
+
+        let mut too_large = vec![TAG_COMPRESSED_EVEN];
+        too_large.extend(pad(&curve.field.p.to_bytes_be(), length));
+        assert_eq!(
+            curve.decode_point(&too_large),
+            Err(EllipticCurveError::CoordinateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn a_zero_y_has_no_odd_encoding() {
+        // y^2 = x^3 + x over GF(23) contains the point (0, 0) of order 2.
+        let curve = Curve::new(BigUint::from(23u32), BigUint::from(1u32), BigUint::zero()).unwrap();
+        let point = AffinePoint::new(BigUint::zero(), BigUint::zero());
+        assert_eq!(curve.encode_point(&point, true), [TAG_COMPRESSED_EVEN, 0]);
+        assert_eq!(
+            curve.decode_point(&[TAG_COMPRESSED_EVEN, 0]).unwrap(),
+            point
+        );
+        assert_eq!(
+            curve.decode_point(&[TAG_COMPRESSED_ODD, 0]),
+            Err(EllipticCurveError::InvalidCompressionBit)
+        );
+    }
+
+    #[test]
+    fn a_zero_x_has_no_odd_encoding_on_binary_curves() {
+        // (0, sqrt(b)) is the point of order 2 on every binary curve.
+        let curve = BinaryCurve::named(NamedBinaryCurve::B163);
+        let zero = curve
+            .decode_coordinate(&vec![0; curve.field_length()])
+            .unwrap();
+        let point = AffinePoint::new(zero, curve.field_sqrt(&curve.b));
+        assert!(curve.is_on_curve(&point));
+        let compressed = curve.encode_point(&point, true);
+        assert_eq!(compressed[0], TAG_COMPRESSED_EVEN);
+        assert_eq!(curve.decode_point(&compressed).unwrap(), point);
+        assert_eq!(
+            curve.decode_point(&retag(&compressed, TAG_COMPRESSED_ODD)),
+            Err(EllipticCurveError::InvalidCompressionBit)
+        );
+    }
+}
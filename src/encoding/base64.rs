@@ -0,0 +1,60 @@
+// src/encoding/base64.rs
+
+use super::EncodingError;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `data` in standard base64 with padding (RFC 4648).
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Decodes standard base64 with padding, ignoring ASCII whitespace.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with the decoded bytes.
+/// * `Err(EncodingError::InvalidBase64)` if the input is malformed.
+pub fn decode(text: &str) -> Result<Vec<u8>, EncodingError> {
+    let symbols: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !symbols.len().is_multiple_of(4) {
+        return Err(EncodingError::InvalidBase64);
+    }
+
+    let mut out = Vec::with_capacity(symbols.len() / 4 * 3);
+    for (index, quad) in symbols.chunks(4).enumerate() {
+        let last = index == symbols.len() / 4 - 1;
+        let padding = quad.iter().rev().take_while(|&&b| b == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return Err(EncodingError::InvalidBase64);
+        }
+
+        let mut group = 0u32;
+        for &symbol in &quad[..4 - padding] {
+            let value = ALPHABET
+                .iter()
+                .position(|&a| a == symbol)
+                .ok_or(EncodingError::InvalidBase64)?;
+            group = group << 6 | value as u32;
+        }
+        group <<= 6 * padding;
+        out.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Ok(out)
+}
@@ -0,0 +1,164 @@
+// src/encoding/der.rs
+
+use super::EncodingError;
+use num_bigint::BigUint;
+
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_NULL: u8 = 0x05;
+pub const TAG_OBJECT_IDENTIFIER: u8 = 0x06;
+pub const TAG_SEQUENCE: u8 = 0x30;
+
+/// Encodes a value with the given tag and contents.
+pub fn encode_tlv(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = contents.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|&b| b == 0)
+            .collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend_from_slice(&bytes);
+    }
+    out.extend_from_slice(contents);
+    out
+}
+
+/// Encodes a non-negative INTEGER.
+pub fn encode_integer(value: &BigUint) -> Vec<u8> {
+    let mut bytes = value.to_bytes_be();
+    // A leading 1 bit would make the value negative.
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    encode_tlv(TAG_INTEGER, &bytes)
+}
+
+/// Encodes a SEQUENCE from already encoded elements.
+pub fn encode_sequence(elements: &[Vec<u8>]) -> Vec<u8> {
+    encode_tlv(TAG_SEQUENCE, &elements.concat())
+}
+
+/// Encodes an OCTET STRING.
+pub fn encode_octet_string(bytes: &[u8]) -> Vec<u8> {
+    encode_tlv(TAG_OCTET_STRING, bytes)
+}
+
+/// Encodes NULL.
+pub fn encode_null() -> Vec<u8> {
+    encode_tlv(TAG_NULL, &[])
+}
+
+/// Encodes an OBJECT IDENTIFIER from its arcs.
+pub fn encode_oid(arcs: &[u64]) -> Vec<u8> {
+    let mut contents = Vec::new();
+    let first = arcs[0] * 40 + arcs[1];
+    for &arc in std::iter::once(&first).chain(&arcs[2..]) {
+        // Base-128 digits, most significant first, with the high bit set on all but the last.
+        let mut digits = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            digits.push((rest & 0x7f) as u8 | 0x80);
+            rest >>= 7;
+        }
+        contents.extend(digits.iter().rev());
+    }
+    encode_tlv(TAG_OBJECT_IDENTIFIER, &contents)
+}
+
+/// A cursor over a sequence of DER values.
+#[derive(Debug, Clone)]
+pub struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    /// Creates a reader over `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        DerReader { data }
+    }
+
+    /// Returns `true` if all values have been read.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Checks that all values have been read.
+    pub fn finish(&self) -> Result<(), EncodingError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(EncodingError::TrailingData)
+        }
+    }
+
+    /// Reads the next value, which must carry `tag`, and returns its contents.
+    pub fn read_tlv(&mut self, tag: u8) -> Result<&'a [u8], EncodingError> {
+        let (&found, rest) = self
+            .data
+            .split_first()
+            .ok_or(EncodingError::UnexpectedEnd)?;
+        if found != tag {
+            return Err(EncodingError::UnexpectedTag {
+                expected: tag,
+                found,
+            });
+        }
+        let (&first, mut rest) = rest.split_first().ok_or(EncodingError::UnexpectedEnd)?;
+
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > std::mem::size_of::<usize>() || rest.len() < count {
+                return Err(EncodingError::InvalidLength);
+            }
+            let (len_bytes, tail) = rest.split_at(count);
+            rest = tail;
+            // DER requires the shortest length encoding.
+            if len_bytes[0] == 0 {
+                return Err(EncodingError::InvalidLength);
+            }
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |acc, &b| acc << 8 | b as usize);
+            if len < 0x80 {
+                return Err(EncodingError::InvalidLength);
+            }
+            len
+        };
+
+        if rest.len() < len {
+            return Err(EncodingError::UnexpectedEnd);
+        }
+        let (contents, tail) = rest.split_at(len);
+        self.data = tail;
+        Ok(contents)
+    }
+
+    /// Reads a non-negative INTEGER.
+    pub fn read_integer(&mut self) -> Result<BigUint, EncodingError> {
+        let contents = self.read_tlv(TAG_INTEGER)?;
+        match contents {
+            [] => Err(EncodingError::InvalidInteger),
+            [first, ..] if first & 0x80 != 0 => Err(EncodingError::InvalidInteger),
+            [0, second, ..] if second & 0x80 == 0 => Err(EncodingError::InvalidInteger),
+            _ => Ok(BigUint::from_bytes_be(contents)),
+        }
+    }
+
+    /// Reads a SEQUENCE and returns a reader over its elements.
+    pub fn read_sequence(&mut self) -> Result<DerReader<'a>, EncodingError> {
+        self.read_tlv(TAG_SEQUENCE).map(DerReader::new)
+    }
+
+    /// Reads an OCTET STRING.
+    pub fn read_octet_string(&mut self) -> Result<&'a [u8], EncodingError> {
+        self.read_tlv(TAG_OCTET_STRING)
+    }
+}
@@ -0,0 +1,43 @@
+// src/encoding/mod.rs
+
+pub mod base64;
+pub mod der;
+pub mod pem;
+
+use std::fmt;
+
+/// Errors raised while decoding DER, base64 or PEM data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodingError {
+    UnexpectedEnd,
+    UnexpectedTag { expected: u8, found: u8 },
+    InvalidLength,
+    InvalidInteger,
+    TrailingData,
+    InvalidBase64,
+    MissingPemBoundary(String),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::UnexpectedEnd => write!(f, "The input ended unexpectedly."),
+            EncodingError::UnexpectedTag { expected, found } => write!(
+                f,
+                "Expected DER tag 0x{:02x} but found 0x{:02x}.",
+                expected, found
+            ),
+            EncodingError::InvalidLength => write!(f, "The DER length field is invalid."),
+            EncodingError::InvalidInteger => {
+                write!(f, "The DER integer is negative or not minimally encoded.")
+            }
+            EncodingError::TrailingData => write!(f, "Unexpected data after the DER value."),
+            EncodingError::InvalidBase64 => write!(f, "The input is not valid base64."),
+            EncodingError::MissingPemBoundary(label) => {
+                write!(f, "No PEM block labelled `{}` was found.", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodingError {}
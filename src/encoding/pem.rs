@@ -0,0 +1,31 @@
+// src/encoding/pem.rs
+
+use super::{base64, EncodingError};
+
+/// Wraps DER bytes in a PEM block with the given label, using 64-character lines.
+pub fn encode(label: &str, der: &[u8]) -> String {
+    let body = base64::encode(der);
+    let mut out = format!("-----BEGIN {}-----\n", label);
+    for line in body.as_bytes().chunks(64) {
+        out.push_str(std::str::from_utf8(line).unwrap());
+        out.push('\n');
+    }
+    out.push_str(&format!("-----END {}-----\n", label));
+    out
+}
+
+/// Extracts the DER bytes of the first PEM block with the given label.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with the decoded contents.
+/// * `Err(EncodingError)` if no such block exists or its body is not valid base64.
+pub fn decode(label: &str, text: &str) -> Result<Vec<u8>, EncodingError> {
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+    let missing = || EncodingError::MissingPemBoundary(label.to_string());
+
+    let start = text.find(&begin).ok_or_else(missing)? + begin.len();
+    let stop = start + text[start..].find(&end).ok_or_else(missing)?;
+    base64::decode(&text[start..stop])
+}
@@ -0,0 +1,248 @@
+// src/factor/batch_gcd.rs
+
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+/// A product tree over a list of numbers.
+///
+/// Level 0 holds the numbers themselves, each further level holds the products of adjacent
+/// pairs of the level below (an odd element out is carried up unchanged), and the last level
+/// holds the product of everything.
+#[derive(Debug, Clone)]
+pub struct ProductTree {
+    levels: Vec<Vec<BigUint>>,
+}
+
+impl ProductTree {
+    /// Builds the product tree of `values`, computing each level in parallel.
+    pub fn new(values: &[BigUint]) -> Self {
+        let mut levels = vec![values.to_vec()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a * b,
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        ProductTree { levels }
+    }
+
+    /// Returns the product of all leaves (1 for an empty tree).
+    pub fn root(&self) -> BigUint {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .cloned()
+            .unwrap_or_else(BigUint::one)
+    }
+
+    /// Returns the numbers the tree was built from.
+    pub fn leaves(&self) -> &[BigUint] {
+        &self.levels[0]
+    }
+
+    /// Returns all levels, from the leaves up to the root.
+    pub fn levels(&self) -> &[Vec<BigUint>] {
+        &self.levels
+    }
+
+    /// Reduces `value` modulo every leaf with a remainder tree.
+    ///
+    /// `value` is reduced modulo the root, and each remainder is then reduced modulo the two
+    /// children of its node, which keeps the operands small on the way down.
+    ///
+    /// # Returns
+    ///
+    /// The remainders `value mod leaf`, in the order of the leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any leaf is zero.
+    pub fn remainders(&self, value: &BigUint) -> Vec<BigUint> {
+        self.descend(value, |node| node.clone())
+    }
+
+    /// Reduces `value` modulo the square of every leaf with a remainder tree.
+    ///
+    /// # Returns
+    ///
+    /// The remainders `value mod leaf^2`, in the order of the leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any leaf is zero.
+    pub fn remainders_squared(&self, value: &BigUint) -> Vec<BigUint> {
+        self.descend(value, |node| node * node)
+    }
+
+    /// Walks down the tree, reducing modulo `modulus(node)` at every node.
+    fn descend<F>(&self, value: &BigUint, modulus: F) -> Vec<BigUint>
+    where
+        F: Fn(&BigUint) -> BigUint + Sync,
+    {
+        if self.leaves().is_empty() {
+            return Vec::new();
+        }
+
+        let mut remainders = vec![value % modulus(&self.root())];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .par_iter()
+                .enumerate()
+                .map(|(i, node)| &remainders[i / 2] % modulus(node))
+                .collect();
+        }
+        remainders
+    }
+}
+
+/// A modulus found to share a prime with another modulus in the batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedFactor {
+    /// The position of the modulus in the input.
+    pub index: usize,
+    /// The modulus itself.
+    pub modulus: BigUint,
+    /// The greatest common divisor of the modulus and the product of all other moduli.
+    pub common_divisor: BigUint,
+    /// A split of the modulus into two non-trivial factors, if one could be recovered. This is
+    /// `None` only when the modulus shares all of its primes with identical moduli.
+    pub factors: Option<(BigUint, BigUint)>,
+}
+
+/// Finds every modulus that shares a factor with another one, using Bernstein's batch GCD.
+///
+/// With P the product of all moduli, gcd(n, (P mod n^2) / n) equals gcd(n, P / n) for every
+/// modulus n, so all these gcds cost one product tree and one remainder tree instead of a
+/// gcd per pair. A modulus whose gcd is the modulus itself shares every prime with the rest
+/// of the batch; it is split by pairwise gcds against the other affected moduli.
+///
+/// # Arguments
+///
+/// * `moduli` - The moduli to audit. Zero moduli are skipped, since every number divides
+///   them; they never appear in the result and do not count as sharing a factor.
+///
+/// # Returns
+///
+/// One entry per affected modulus, in input order.
+pub fn batch_gcd(moduli: &[BigUint]) -> Vec<SharedFactor> {
+    // Positions of the moduli that take part, so that results refer to the caller's indices.
+    let positions: Vec<usize> = (0..moduli.len())
+        .filter(|&i| !moduli[i].is_zero())
+        .collect();
+    let moduli: Vec<BigUint> = positions.iter().map(|&i| moduli[i].clone()).collect();
+
+    let tree = ProductTree::new(&moduli);
+    let remainders = tree.remainders_squared(&tree.root());
+
+    let divisors: Vec<BigUint> = moduli
+        .par_iter()
+        .zip(remainders.par_iter())
+        .map(|(n, r)| n.gcd(&(r / n)))
+        .collect();
+
+    let affected: Vec<usize> = (0..moduli.len())
+        .filter(|&i| !divisors[i].is_one())
+        .collect();
+
+    affected
+        .par_iter()
+        .map(|&i| {
+            let n = &moduli[i];
+            let divisor = &divisors[i];
+            let proper = if divisor != n {
+                Some(divisor.clone())
+            } else {
+                affected.iter().find_map(|&j| {
+                    let g = n.gcd(&moduli[j]);
+                    (!g.is_one() && &g != n).then_some(g)
+                })
+            };
+            SharedFactor {
+                index: positions[i],
+                modulus: n.clone(),
+                common_divisor: divisor.clone(),
+                factors: proper.map(|p| {
+                    let q = n / &p;
+                    (p, q)
+                }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(values: &[u64]) -> Vec<BigUint> {
+        values.iter().map(|&v| BigUint::from(v)).collect()
+    }
+
+    #[test]
+    fn product_and_remainder_trees() {
+        let leaves = big(&[3, 5, 7, 11, 13]);
+        let tree = ProductTree::new(&leaves);
+        assert_eq!(tree.root(), BigUint::from(15015u32));
+        assert_eq!(tree.leaves(), &leaves[..]);
+        assert_eq!(tree.levels().len(), 4);
+        let value = BigUint::from(1_000_000u32);
+        assert_eq!(tree.remainders(&value), big(&[1, 0, 1, 1, 1]));
+        assert_eq!(tree.remainders_squared(&value), big(&[1, 0, 8, 56, 27]));
+        assert_eq!(ProductTree::new(&[]).root(), BigUint::one());
+    }
+
+    #[test]
+    fn finds_a_shared_prime() {
+        // 1000003 is shared by the first and third modulus; the others are coprime to all.
+        let moduli = big(&[
+            1_000_003 * 1_000_033,
+            1_000_037 * 1_000_039,
+            1_000_003 * 1_000_081,
+            1_000_099 * 1_000_117,
+        ]);
+        let shared = batch_gcd(&moduli);
+        assert_eq!(shared.len(), 2);
+        for (entry, (index, cofactor)) in shared.iter().zip([(0, 1_000_033), (2, 1_000_081)]) {
+            assert_eq!(entry.index, index);
+            assert_eq!(entry.modulus, moduli[index]);
+            assert_eq!(entry.common_divisor, BigUint::from(1_000_003u32));
+            let factors = (BigUint::from(1_000_003u32), BigUint::from(cofactor as u32));
+            assert_eq!(entry.factors, Some(factors));
+        }
+    }
+
+    #[test]
+    fn splits_a_modulus_that_shares_every_prime() {
+        // pq shares p with pr and q with qs, so its gcd with the rest is pq itself.
+        let (p, q, r, s) = (1_000_003u64, 1_000_033, 1_000_037, 1_000_039);
+        let moduli = big(&[p * q, p * r, q * s]);
+        let shared = batch_gcd(&moduli);
+        assert_eq!(shared.len(), 3);
+        assert_eq!(shared[0].common_divisor, moduli[0]);
+        let (a, b) = shared[0].factors.clone().unwrap();
+        assert_eq!(&a * &b, moduli[0]);
+        assert!(!a.is_one() && !b.is_one());
+
+        // Identical moduli share all of their primes with each other only.
+        let shared = batch_gcd(&big(&[p * q, p * q]));
+        assert!(shared.iter().all(|entry| entry.factors.is_none()));
+    }
+
+    #[test]
+    fn skips_zero_moduli() {
+        let moduli = big(&[0, 1_000_003 * 1_000_033, 0, 1_000_003 * 1_000_037]);
+        let shared = batch_gcd(&moduli);
+        let indices: Vec<usize> = shared.iter().map(|entry| entry.index).collect();
+        assert_eq!(indices, vec![1, 3]);
+        assert!(batch_gcd(&big(&[0])).is_empty());
+    }
+}
@@ -0,0 +1,356 @@
+// src/factor/ecm.rs
+
+use crate::algebra::traits::Ring;
+use crate::integer_mod_n::IntegerModN;
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use bitvec::prelude::*;
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+/// Width of the stage 2 giant steps; baby steps cover the residues coprime to it.
+const STAGE2_WIDTH: u64 = 2310;
+
+/// A point on a Montgomery curve in projective X:Z coordinates (the Y coordinate is never
+/// needed for the ladder).
+#[derive(Debug, Clone)]
+struct MontgomeryPoint {
+    x: BigUint,
+    z: BigUint,
+}
+
+/// A Montgomery curve By^2 = x^3 + Ax^2 + x over Z/nZ, stored through (A + 2) / 4.
+struct MontgomeryCurve<'a> {
+    ring: &'a IntegerModN,
+    a24: BigUint,
+}
+
+/// Either a value or a non-trivial factor of `n` uncovered while computing it.
+type EcmResult<T> = Result<T, BigUint>;
+
+impl MontgomeryCurve<'_> {
+    /// Doubles a point: X2 = (X+Z)^2 (X-Z)^2, Z2 = 4XZ ((X-Z)^2 + a24 * 4XZ).
+    fn double(&self, p: &MontgomeryPoint) -> MontgomeryPoint {
+        let r = self.ring;
+        let sum = r.add(&p.x, &p.z);
+        let diff = r.sub(&p.x, &p.z);
+        let sum_sq = r.mul(&sum, &sum);
+        let diff_sq = r.mul(&diff, &diff);
+        let cross = r.sub(&sum_sq, &diff_sq);
+        MontgomeryPoint {
+            x: r.mul(&sum_sq, &diff_sq),
+            z: r.mul(&cross, &r.add(&diff_sq, &r.mul(&self.a24, &cross))),
+        }
+    }
+
+    /// Differential addition: computes P + Q given P - Q.
+    fn add(
+        &self,
+        p: &MontgomeryPoint,
+        q: &MontgomeryPoint,
+        difference: &MontgomeryPoint,
+    ) -> MontgomeryPoint {
+        let r = self.ring;
+        let u = r.mul(&r.sub(&p.x, &p.z), &r.add(&q.x, &q.z));
+        let v = r.mul(&r.add(&p.x, &p.z), &r.sub(&q.x, &q.z));
+        let plus = r.add(&u, &v);
+        let minus = r.sub(&u, &v);
+        MontgomeryPoint {
+            x: r.mul(&difference.z, &r.mul(&plus, &plus)),
+            z: r.mul(&difference.x, &r.mul(&minus, &minus)),
+        }
+    }
+
+    /// Computes [k]P with the Montgomery ladder.
+    fn multiply(&self, p: &MontgomeryPoint, k: &BigUint) -> MontgomeryPoint {
+        if k.is_zero() {
+            return MontgomeryPoint {
+                x: BigUint::one(),
+                z: BigUint::zero(),
+            };
+        }
+        // Invariant: (r0, r1) = ([m]P, [m + 1]P) for the prefix m of k read so far.
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+        for bit in (0..k.bits() - 1).rev() {
+            if k.bit(bit) {
+                r0 = self.add(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+        r0
+    }
+}
+
+/// Inverts `a` modulo `n`, or returns the factor gcd(a, n) if `a` is not invertible.
+fn invert(ring: &IntegerModN, a: &BigUint) -> EcmResult<BigUint> {
+    let n = ring.modulus();
+    IntegerModN::inverse(a, n).ok_or_else(|| a.gcd(n))
+}
+
+/// The work shared by all curves of one ECM run: the stage 1 multipliers and the stage 2
+/// prime pairing.
+struct EcmPlan {
+    /// The largest power of each prime up to b1 that does not exceed b1.
+    prime_powers: Vec<u64>,
+    /// The residues 0 < j < D / 2 coprime to D.
+    baby_indices: Vec<u64>,
+    first_k: u64,
+    last_k: u64,
+    /// Bit `(k - first_k) * baby_indices.len() + i` is set if kD - j or kD + j is a prime in
+    /// (b1, b2] for j = `baby_indices[i]`.
+    pairs: BitVec,
+}
+
+impl EcmPlan {
+    fn new(b1: u64, b2: u64) -> Self {
+        let b2 = b2.max(b1);
+        let primes = sieve_of_eratosthenes_bitset(b2 as usize);
+
+        let prime_powers = primes
+            .iter()
+            .map(|&p| p as u64)
+            .take_while(|&p| p <= b1)
+            .map(|p| {
+                let mut power = p;
+                while power <= b1 / p {
+                    power *= p;
+                }
+                power
+            })
+            .collect();
+
+        // Every prime q in (b1, b2] is written as q = kD + j or q = kD - j with j < D / 2.
+        let width = STAGE2_WIDTH;
+        let baby_indices: Vec<u64> = (1..width / 2).filter(|j| j.gcd(&width) == 1).collect();
+        let mut position = vec![usize::MAX; width as usize / 2];
+        for (i, &j) in baby_indices.iter().enumerate() {
+            position[j as usize] = i;
+        }
+        // Stage 2 starts at the first giant step, so primes below D / 2 are left to stage 1.
+        let first_k = ((b1 + width / 2) / width).max(1);
+        let last_k = (b2 + width / 2) / width;
+        let mut pairs = bitvec![0; (last_k - first_k + 1) as usize * baby_indices.len()];
+        for q in primes
+            .iter()
+            .map(|&q| q as u64)
+            .filter(|&q| q > b1.max(width / 2))
+        {
+            let k = (q + width / 2) / width;
+            let j = (k * width).abs_diff(q);
+            let i = position[j as usize];
+            if i != usize::MAX {
+                pairs.set((k - first_k) as usize * baby_indices.len() + i, true);
+            }
+        }
+
+        EcmPlan {
+            prime_powers,
+            baby_indices,
+            first_k,
+            last_k,
+            pairs,
+        }
+    }
+}
+
+/// Runs stage 1 and stage 2 of ECM on one curve with Suyama parameter `sigma`.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if this curve revealed a factor, otherwise `None`.
+fn ecm_one_curve(ring: &IntegerModN, sigma: &BigUint, plan: &EcmPlan) -> Option<BigUint> {
+    let n = ring.modulus();
+    let found = match run_curve(ring, sigma, plan) {
+        Ok(()) => return None,
+        Err(d) => d,
+    };
+    (!found.is_one() && &found != n).then_some(found)
+}
+
+fn run_curve(ring: &IntegerModN, sigma: &BigUint, plan: &EcmPlan) -> EcmResult<()> {
+    let n = ring.modulus();
+
+    // Suyama's parametrization gives a curve whose group order is divisible by 12.
+    let five = BigUint::from(5u32);
+    let u = ring.sub(&ring.mul(sigma, sigma), &five);
+    let v = ring.mul(&BigUint::from(4u32), sigma);
+    let u3 = ring.mul(&u, &ring.mul(&u, &u));
+    let v_minus_u = ring.sub(&v, &u);
+    let numerator = ring.mul(
+        &ring.mul(&v_minus_u, &ring.mul(&v_minus_u, &v_minus_u)),
+        &ring.add(&ring.mul(&BigUint::from(3u32), &u), &v),
+    );
+    let denominator = ring.mul(&BigUint::from(16u32), &ring.mul(&u3, &v));
+    let curve = MontgomeryCurve {
+        ring,
+        a24: ring.mul(&numerator, &invert(ring, &denominator)?),
+    };
+    let mut point = MontgomeryPoint {
+        x: u3,
+        z: ring.mul(&v, &ring.mul(&v, &v)),
+    };
+
+    // Stage 1: multiply by every prime power up to b1.
+    for &prime_power in &plan.prime_powers {
+        point = curve.multiply(&point, &BigUint::from(prime_power));
+    }
+    let g = point.z.gcd(n);
+    if !g.is_one() {
+        return Err(g);
+    }
+    if plan.pairs.not_any() {
+        return Ok(());
+    }
+
+    // Stage 2: [q]Q = O modulo p for q = kD +- j exactly when x([kD]Q) = x([j]Q) modulo p,
+    // so one product of cross differences covers every prime in (b1, b2].
+    let width = STAGE2_WIDTH;
+    let mut baby_x = Vec::with_capacity(plan.baby_indices.len());
+    let mut baby_z = Vec::with_capacity(plan.baby_indices.len());
+    {
+        // Walk j = 1, 3, 5, ... with differential additions of [2]Q.
+        let q2 = curve.double(&point);
+        let mut previous = point.clone();
+        let mut current = curve.add(&q2, &point, &point); // [3]Q
+        let mut multiple = point.clone();
+        for j in (1..width / 2).step_by(2) {
+            if j.gcd(&width) == 1 {
+                baby_x.push(multiple.x.clone());
+                baby_z.push(multiple.z.clone());
+            }
+            // Advance from [j]Q to [j + 2]Q.
+            multiple = current.clone();
+            let next = curve.add(&current, &q2, &previous);
+            previous = current;
+            current = next;
+        }
+    }
+    let baby_x = normalize(ring, &baby_x, &baby_z)?;
+
+    let giant_step = curve.multiply(&point, &BigUint::from(width));
+    let mut giant_previous = curve.multiply(&point, &BigUint::from((plan.first_k - 1) * width));
+    let mut giant = curve.multiply(&point, &BigUint::from(plan.first_k * width));
+
+    let mut accumulator = BigUint::one();
+    let row_length = plan.baby_indices.len();
+    for (row, k) in (plan.first_k..=plan.last_k).enumerate() {
+        for index in plan.pairs[row * row_length..(row + 1) * row_length].iter_ones() {
+            // X_kD * Z_j - X_j * Z_kD with the baby step normalised to Z_j = 1.
+            let term = ring.sub(&giant.x, &ring.mul(&baby_x[index], &giant.z));
+            accumulator = ring.mul(&accumulator, &term);
+        }
+        if k == plan.last_k {
+            break;
+        }
+        // The differential addition needs P != Q, so [D]Q -> [2D]Q is a doubling.
+        let next = if giant_previous.z.is_zero() {
+            curve.double(&giant)
+        } else {
+            curve.add(&giant, &giant_step, &giant_previous)
+        };
+        giant_previous = giant;
+        giant = next;
+    }
+
+    let g = accumulator.gcd(n);
+    if g.is_one() {
+        Ok(())
+    } else {
+        Err(g)
+    }
+}
+
+/// Converts projective X:Z pairs to affine x = X / Z with a single inversion
+/// (Montgomery's simultaneous inversion trick).
+fn normalize(ring: &IntegerModN, xs: &[BigUint], zs: &[BigUint]) -> EcmResult<Vec<BigUint>> {
+    let mut prefix = Vec::with_capacity(zs.len());
+    let mut running = BigUint::one();
+    for z in zs {
+        prefix.push(running.clone());
+        running = ring.mul(&running, z);
+    }
+
+    let mut inverse = invert(ring, &running)?;
+    let mut result = vec![BigUint::zero(); xs.len()];
+    for i in (0..zs.len()).rev() {
+        let z_inverse = ring.mul(&inverse, &prefix[i]);
+        result[i] = ring.mul(&xs[i], &z_inverse);
+        inverse = ring.mul(&inverse, &zs[i]);
+    }
+    Ok(result)
+}
+
+/// Lenstra's elliptic curve method with Montgomery curves.
+///
+/// Each curve is a random Suyama curve over Z/nZ. Stage 1 multiplies a point by all prime
+/// powers up to `b1`; stage 2 looks for one more prime in `(b1, b2]` with a baby-step
+/// giant-step continuation. A factor p of n is found when the curve order modulo p is smooth
+/// enough; it then turns up as a coordinate or denominator that is not invertible modulo n.
+/// Curves run in parallel and the first factor found is returned.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number.
+/// * `b1` - The stage 1 bound.
+/// * `b2` - The stage 2 bound (no stage 2 is run if `b2 <= b1`).
+/// * `curves` - The number of curves to try.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn ecm(n: &BigUint, b1: u64, b2: u64, curves: usize) -> Option<BigUint> {
+    let ring = IntegerModN::new(n.clone());
+    let plan = EcmPlan::new(b1, b2);
+    let low = BigUint::from(6u32);
+    (0..curves).into_par_iter().find_map_any(|_| {
+        let sigma = rand::thread_rng().gen_biguint_range(&low, n);
+        ecm_one_curve(&ring, &sigma, &plan)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 30-bit prime times an 80-bit prime.
+    const SMALL: u32 = 536_871_703;
+    const LARGE: &str = "604462909807314587357359";
+
+    fn composite() -> BigUint {
+        BigUint::from(SMALL) * LARGE.parse::<BigUint>().unwrap()
+    }
+
+    #[test]
+    fn finds_a_30_bit_factor() {
+        assert_eq!(
+            ecm(&composite(), 2_000, 50_000, 60),
+            Some(BigUint::from(SMALL))
+        );
+    }
+
+    #[test]
+    fn stage_two_finds_more_factors() {
+        // Fixed Suyama parameters, so the counts do not depend on the random curves.
+        let ring = IntegerModN::new(composite());
+        let count = |plan: &EcmPlan| {
+            (6..46u32)
+                .filter_map(|sigma| ecm_one_curve(&ring, &BigUint::from(sigma), plan))
+                .inspect(|d| assert_eq!(d, &BigUint::from(SMALL)))
+                .count()
+        };
+        let stage1 = count(&EcmPlan::new(2_000, 2_000));
+        let stage2 = count(&EcmPlan::new(2_000, 50_000));
+        assert!(stage1 > 0 && stage2 > stage1, "{} {}", stage1, stage2);
+    }
+
+    #[test]
+    fn primes_give_none() {
+        assert_eq!(ecm(&BigUint::from(SMALL), 2_000, 20_000, 4), None);
+        assert_eq!(ecm(&LARGE.parse().unwrap(), 2_000, 20_000, 4), None);
+    }
+}
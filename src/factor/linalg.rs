@@ -0,0 +1,309 @@
+// src/factor/linalg.rs
+
+use std::collections::BTreeSet;
+
+/// A row of the sparse matrix during structured elimination, together with the set of
+/// original rows it is the sum of.
+#[derive(Debug, Clone)]
+struct WorkRow {
+    cols: Vec<usize>,
+    history: Vec<usize>,
+}
+
+/// Finds linear dependencies between the rows of a sparse matrix over GF(2).
+///
+/// The matrix is first shrunk by structured Gaussian elimination: columns hit by a single
+/// row are discarded together with that row, and light columns are eliminated by adding one
+/// of their rows to the others. The remainder is solved by dense Gaussian elimination on
+/// bit-packed rows.
+///
+/// # Arguments
+///
+/// * `rows` - Each row lists the column indices holding a 1.
+/// * `num_cols` - The number of columns.
+/// * `max_dependencies` - The maximum number of dependencies to return.
+///
+/// # Returns
+///
+/// Sets of original row indices whose rows sum to zero.
+pub fn find_dependencies(
+    rows: &[Vec<usize>],
+    num_cols: usize,
+    max_dependencies: usize,
+) -> Vec<Vec<usize>> {
+    let mut work: Vec<Option<WorkRow>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, cols)| {
+            let mut cols = cols.clone();
+            cols.sort_unstable();
+            cols.dedup();
+            Some(WorkRow {
+                cols,
+                history: vec![i],
+            })
+        })
+        .collect();
+
+    reduce_sparse(&mut work, num_cols);
+
+    let remaining: Vec<WorkRow> = work.into_iter().flatten().collect();
+    dense_dependencies(&remaining, num_cols, max_dependencies)
+}
+
+/// Columns of at most this weight are eliminated by merging rows.
+const MAX_MERGE_WEIGHT: usize = 40;
+
+/// Merging stops once rows hold this many entries on average, to limit fill-in.
+const MAX_AVERAGE_ROW_WEIGHT: usize = 300;
+
+/// Applies singleton removal and merges until neither changes the matrix.
+///
+/// Columns are merged in order of increasing weight: the lightest row of a column of weight w
+/// is added to the other w - 1 rows and then dropped, which removes one row and one column
+/// from the matrix at the cost of some fill-in. The weight limit grows each time a pass finds
+/// nothing left to do.
+fn reduce_sparse(work: &mut [Option<WorkRow>], num_cols: usize) {
+    let mut max_weight = 2;
+    loop {
+        let mut changed = false;
+
+        // Build the column incidence lists for this pass.
+        let mut incidence: Vec<Vec<usize>> = vec![Vec::new(); num_cols];
+        let mut entries = 0;
+        let mut live_rows = 0;
+        for (r, row) in work.iter().enumerate() {
+            if let Some(row) = row {
+                for &c in &row.cols {
+                    incidence[c].push(r);
+                }
+                entries += row.cols.len();
+                live_rows += 1;
+            }
+        }
+        let merging = entries <= MAX_AVERAGE_ROW_WEIGHT * live_rows.max(1);
+
+        let mut order: Vec<usize> = (0..num_cols)
+            .filter(|&c| !incidence[c].is_empty() && incidence[c].len() <= max_weight)
+            .collect();
+        order.sort_by_key(|&c| incidence[c].len());
+
+        let mut touched = vec![false; work.len()];
+        for c in order {
+            let rows_in_col = &incidence[c];
+            if rows_in_col.iter().any(|&r| touched[r]) {
+                continue;
+            }
+            if rows_in_col.len() == 1 {
+                // A column hit by one row can never cancel, so that row is useless.
+                work[rows_in_col[0]] = None;
+                touched[rows_in_col[0]] = true;
+                changed = true;
+                continue;
+            }
+            if !merging {
+                continue;
+            }
+
+            let pivot_index = *rows_in_col
+                .iter()
+                .min_by_key(|&&r| work[r].as_ref().map_or(usize::MAX, |row| row.cols.len()))
+                .unwrap();
+            let pivot = work[pivot_index].take().unwrap();
+            for &r in rows_in_col {
+                touched[r] = true;
+                if let Some(target) = work[r].as_mut() {
+                    target.cols = symmetric_difference(&target.cols, &pivot.cols);
+                    target.history = symmetric_difference(&target.history, &pivot.history);
+                }
+            }
+            changed = true;
+        }
+
+        if !changed {
+            if merging && max_weight < MAX_MERGE_WEIGHT {
+                max_weight += 1;
+            } else {
+                return;
+            }
+        }
+    }
+}
+
+/// Dense Gaussian elimination over GF(2) with history tracking.
+fn dense_dependencies(
+    rows: &[WorkRow],
+    num_cols: usize,
+    max_dependencies: usize,
+) -> Vec<Vec<usize>> {
+    // Compress the column indices to the ones still in use.
+    let used: BTreeSet<usize> = rows.iter().flat_map(|r| r.cols.iter().copied()).collect();
+    let mut column_map = vec![usize::MAX; num_cols];
+    for (dense, &c) in used.iter().enumerate() {
+        column_map[c] = dense;
+    }
+
+    let ncols = used.len();
+    let nrows = rows.len();
+    let matrix_words = ncols.div_ceil(64);
+    let history_words = nrows.div_ceil(64);
+    let width = matrix_words + history_words;
+
+    let mut dense: Vec<Vec<u64>> = rows
+        .iter()
+        .enumerate()
+        .map(|(r, row)| {
+            let mut bits = vec![0u64; width];
+            for &c in &row.cols {
+                let d = column_map[c];
+                bits[d / 64] ^= 1 << (d % 64);
+            }
+            bits[matrix_words + r / 64] |= 1 << (r % 64);
+            bits
+        })
+        .collect();
+
+    let mut is_pivot = vec![false; nrows];
+    for col in 0..ncols {
+        let (word, mask) = (col / 64, 1u64 << (col % 64));
+        let pivot = match (0..nrows).find(|&r| !is_pivot[r] && dense[r][word] & mask != 0) {
+            Some(p) => p,
+            None => continue,
+        };
+        is_pivot[pivot] = true;
+
+        let pivot_row = dense[pivot].clone();
+        for (r, row) in dense.iter_mut().enumerate() {
+            if !is_pivot[r] && row[word] & mask != 0 {
+                for (dst, src) in row[word..].iter_mut().zip(&pivot_row[word..]) {
+                    *dst ^= src;
+                }
+            }
+        }
+    }
+
+    // Every non-pivot row has been reduced to zero; its history is a dependency.
+    let mut dependencies = Vec::new();
+    for (r, row) in dense.iter().enumerate() {
+        if is_pivot[r] {
+            continue;
+        }
+        let mut combination: Vec<usize> = Vec::new();
+        for w in 0..history_words {
+            let mut bits = row[matrix_words + w];
+            while bits != 0 {
+                let b = bits.trailing_zeros() as usize;
+                combination = symmetric_difference(&combination, &rows[w * 64 + b].history);
+                bits &= bits - 1;
+            }
+        }
+        if !combination.is_empty() {
+            dependencies.push(combination);
+            if dependencies.len() >= max_dependencies {
+                break;
+            }
+        }
+    }
+
+    dependencies
+}
+
+/// Symmetric difference of two sorted, duplicate-free index lists.
+fn symmetric_difference(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(a[i]);
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                result.push(b[j]);
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result.extend_from_slice(&a[i..]);
+    result.extend_from_slice(&b[j..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Checks that the rows listed in `dependency` add up to zero over GF(2).
+    fn sums_to_zero(rows: &[Vec<usize>], dependency: &[usize]) -> bool {
+        let mut sum = BTreeSet::new();
+        for &i in dependency {
+            for &col in &rows[i] {
+                if !sum.insert(col) {
+                    sum.remove(&col);
+                }
+            }
+        }
+        sum.is_empty()
+    }
+
+    #[test]
+    fn finds_a_known_null_space() {
+        // Rank 4, so the null space is {0, {0, 1, 2}, {0, 4, 5}, {1, 2, 4, 5}}.
+        let rows = vec![
+            vec![0, 1],
+            vec![1, 2],
+            vec![0, 2],
+            vec![3],
+            vec![2, 3],
+            vec![0, 1, 2, 3],
+        ];
+        let null_space = [vec![0, 1, 2], vec![0, 4, 5], vec![1, 2, 4, 5]];
+
+        let mut dependencies: Vec<Vec<usize>> = find_dependencies(&rows, 4, 10)
+            .into_iter()
+            .map(|mut dependency| {
+                dependency.sort_unstable();
+                dependency
+            })
+            .collect();
+        dependencies.sort();
+        dependencies.dedup();
+        assert_eq!(dependencies.len(), 2);
+        assert!(dependencies.iter().all(|d| null_space.contains(d)));
+
+        assert_eq!(find_dependencies(&rows, 4, 1).len(), 1);
+    }
+
+    #[test]
+    fn independent_rows_have_no_dependencies() {
+        let rows = vec![vec![0], vec![0, 1], vec![1, 2]];
+        assert!(find_dependencies(&rows, 3, 10).is_empty());
+    }
+
+    #[test]
+    fn random_sparse_matrices() {
+        let mut rng = StdRng::seed_from_u64(28);
+        let (num_rows, num_cols) = (300, 260);
+        let rows: Vec<Vec<usize>> = (0..num_rows)
+            .map(|_| {
+                let cols: BTreeSet<usize> = (0..rng.gen_range(1..8))
+                    .map(|_| rng.gen_range(0..num_cols))
+                    .collect();
+                cols.into_iter().collect()
+            })
+            .collect();
+        // At least num_rows - num_cols independent dependencies exist.
+        let dependencies = find_dependencies(&rows, num_cols, 64);
+        assert!(dependencies.len() >= num_rows - num_cols);
+        for dependency in &dependencies {
+            assert!(!dependency.is_empty());
+            assert!(sums_to_zero(&rows, dependency));
+        }
+    }
+}
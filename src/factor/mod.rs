@@ -0,0 +1,155 @@
+// src/factor/mod.rs
+
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, ToPrimitive, Zero};
+use rand::rngs::OsRng;
+use std::collections::BTreeMap;
+
+pub mod batch_gcd;
+pub mod ecm;
+pub mod linalg;
+pub mod perfect_power;
+pub mod pm1;
+pub mod rho;
+pub mod siqs;
+pub mod squfof;
+pub mod trial;
+pub mod williams;
+
+pub use batch_gcd::{batch_gcd, ProductTree, SharedFactor};
+pub use ecm::ecm;
+pub use perfect_power::perfect_power;
+pub use pm1::pollard_pm1;
+pub use rho::brent_rho;
+pub use siqs::siqs;
+pub use squfof::squfof;
+pub use trial::trial_division;
+pub use williams::williams_pp1;
+
+/// Trial division bound used before any of the general-purpose methods run.
+const TRIAL_DIVISION_BOUND: usize = 10_000;
+
+/// Composites of at least this many bits go to ECM and the quadratic sieve once the cheap
+/// methods fail.
+const SIQS_MIN_BITS: u64 = 100;
+
+/// Composites above this size (about 100 digits) are out of reach of the quadratic sieve and are
+/// left to ECM.
+const SIQS_MAX_BITS: u64 = 330;
+
+/// ECM stage 1 bounds and curve counts for successive rounds, each aimed at factors about five
+/// digits larger than the previous one (15, 20, 25, 30, 35 and 40 digits). The stage 2 bound is
+/// `ECM_STAGE2_RATIO` times the stage 1 bound.
+const ECM_LEVELS: [(u64, usize); 6] = [
+    (2_000, 25),
+    (11_000, 90),
+    (50_000, 300),
+    (250_000, 700),
+    (1_000_000, 1_800),
+    (3_000_000, 5_100),
+];
+
+const ECM_STAGE2_RATIO: u64 = 100;
+
+/// Number of Miller-Rabin witnesses used to certify each cofactor as prime.
+const PRIMALITY_ROUNDS: u32 = 40;
+
+/// Returns `true` if `n` is (very probably) prime.
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    IntegerModP::is_prime_miller_rabin_parallel(n, PRIMALITY_ROUNDS)
+}
+
+/// Computes the full prime factorization of `n`.
+///
+/// The pipeline strips small primes by trial division, then repeatedly splits each composite
+/// cofactor: perfect powers are reduced to their root, and the remaining numbers go through
+/// SQUFOF (below 62 bits), Brent's rho, Pollard's p - 1 and Williams' p + 1. Numbers of 100 bits
+/// and more that survive one round of these get a short ECM run for small factors and then go to
+/// the self-initialising quadratic sieve, or, beyond about 100 digits, to ECM with growing bounds.
+/// Smaller ones keep cycling with ever larger bounds. Every reported factor passes a primality
+/// test.
+///
+/// # Returns
+///
+/// Pairs `(p, k)` in ascending order of `p` such that `n` is the product of all `p^k`.
+/// Both 0 and 1 yield an empty factorization.
+pub fn factorize(n: &BigUint) -> Vec<(BigUint, u32)> {
+    if n.is_zero() {
+        return Vec::new();
+    }
+
+    let (small, cofactor) = trial_division(n, TRIAL_DIVISION_BOUND);
+    let mut factors: BTreeMap<BigUint, u32> = small.into_iter().collect();
+
+    // Composites still to split, each with the multiplicity it carries into the result.
+    let mut pending = vec![(cofactor, 1u32)];
+    while let Some((m, multiplicity)) = pending.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if is_probable_prime(&m) {
+            *factors.entry(m).or_insert(0) += multiplicity;
+            continue;
+        }
+        if let Some((root, k)) = perfect_power(&m) {
+            pending.push((root, multiplicity * k));
+            continue;
+        }
+
+        let d = find_factor(&m);
+        let e = &m / &d;
+        pending.push((d, multiplicity));
+        pending.push((e, multiplicity));
+    }
+
+    factors.into_iter().collect()
+}
+
+/// Finds a non-trivial factor of an odd composite `n` that is not a perfect power.
+///
+/// Each round runs the methods in order of increasing cost, then doubles their budgets. Large
+/// inputs get a single round before the quadratic sieve takes over; inputs too large for the
+/// sieve move one ECM level up every round.
+pub fn find_factor(n: &BigUint) -> BigUint {
+    if n.bits() <= squfof::SQUFOF_MAX_BITS {
+        if let Some(d) = n.to_u64().and_then(squfof) {
+            return BigUint::from(d);
+        }
+    }
+
+    let mut rng = OsRng;
+    let mut rho_iterations = 1u64 << 16;
+    let mut b1 = 10_000usize;
+    let mut ecm_level = 0;
+    loop {
+        let c = rng.gen_biguint_range(&BigUint::one(), &(n - 2u32));
+        if let Some(d) = brent_rho(n, &c, rho_iterations) {
+            return d;
+        }
+        if let Some(d) = pollard_pm1(n, b1, 50 * b1) {
+            return d;
+        }
+        if let Some(d) = williams_pp1(n, b1, &[3, 5, 7]) {
+            return d;
+        }
+        if n.bits() >= SIQS_MIN_BITS {
+            let (ecm_b1, curves) = ECM_LEVELS[ecm_level];
+            if let Some(d) = ecm(n, ecm_b1, ECM_STAGE2_RATIO * ecm_b1, curves) {
+                return d;
+            }
+            if n.bits() <= SIQS_MAX_BITS {
+                if let Some(d) = siqs(n) {
+                    return d;
+                }
+            }
+            ecm_level = (ecm_level + 1).min(ECM_LEVELS.len() - 1);
+        }
+
+        rho_iterations *= 2;
+        b1 *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests;
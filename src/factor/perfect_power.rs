@@ -0,0 +1,58 @@
+// src/factor/perfect_power.rs
+
+use num_bigint::BigUint;
+
+/// Detects whether `n` is a perfect power.
+///
+/// # Returns
+///
+/// `Some((r, k))` with `k >= 2` maximal such that `n = r^k`, or `None` if `n` is not a
+/// perfect power. Because `k` is maximal, `r` is never itself a perfect power.
+pub fn perfect_power(n: &BigUint) -> Option<(BigUint, u32)> {
+    if n.bits() < 2 {
+        return None;
+    }
+
+    let max_exponent = n.bits() as u32;
+    for k in (2..=max_exponent).rev() {
+        let root = n.nth_root(k);
+        if root.bits() > 1 && &root.pow(k) == n {
+            return Some((root, k));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_largest_exponent() {
+        let power = |r: u32, k: u32| BigUint::from(r).pow(k);
+        assert_eq!(
+            perfect_power(&power(2, 12)),
+            Some((BigUint::from(2u32), 12))
+        );
+        assert_eq!(
+            perfect_power(&power(3, 20)),
+            Some((BigUint::from(3u32), 20))
+        );
+        // 12^4 = 144^2, but 12 itself is not a perfect power.
+        assert_eq!(
+            perfect_power(&power(12, 4)),
+            Some((BigUint::from(12u32), 4))
+        );
+        let prime = BigUint::from(2_147_483_647u32);
+        assert_eq!(perfect_power(&prime.pow(3)), Some((prime, 3)));
+    }
+
+    #[test]
+    fn rejects_non_powers() {
+        for n in [0u32, 1, 2, 3, 6, 12, 1000001] {
+            assert_eq!(perfect_power(&BigUint::from(n)), None, "n = {}", n);
+        }
+        let almost = BigUint::from(7u32).pow(15) + 1u32;
+        assert_eq!(perfect_power(&almost), None);
+    }
+}
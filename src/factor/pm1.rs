@@ -0,0 +1,135 @@
+// src/factor/pm1.rs
+
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
+use std::collections::HashMap;
+
+/// Pollard's p - 1 method with the standard prime-by-prime stage 2.
+///
+/// Stage 1 raises a base to every prime power up to `b1`, which finds a prime factor `p`
+/// whenever p - 1 is `b1`-smooth. Stage 2 then allows p - 1 to have one extra prime factor
+/// in `(b1, b2]`, stepping between consecutive primes with precomputed powers of the base.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number.
+/// * `b1` - The stage 1 smoothness bound.
+/// * `b2` - The stage 2 bound (no stage 2 is run if `b2 <= b1`).
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn pollard_pm1(n: &BigUint, b1: usize, b2: usize) -> Option<BigUint> {
+    let one = BigUint::one();
+    let primes = sieve_of_eratosthenes_bitset(b1.max(b2));
+    let mut a = BigUint::from(2u32);
+
+    // Stage 1: a <- a^(p^e) for every prime power p^e <= b1.
+    for &p in primes.iter().take_while(|&&p| p <= b1) {
+        let mut prime_power = p;
+        while prime_power <= b1 / p {
+            prime_power *= p;
+        }
+        a = a.modpow(&BigUint::from(prime_power), n);
+    }
+
+    let g = (&a + n - &one).gcd(n);
+    if &g == n {
+        // Every factor was found at once; retry with a gcd after each prime.
+        return pm1_backtrack(n, &primes, b1);
+    }
+    if !g.is_one() {
+        return Some(g);
+    }
+    if b2 <= b1 {
+        return None;
+    }
+
+    // Stage 2: walk over primes q in (b1, b2], maintaining a^q and accumulating a^q - 1.
+    let mut gap_powers: HashMap<usize, BigUint> = HashMap::new();
+    let mut stage2 = primes.iter().copied().skip_while(|&q| q <= b1);
+    let first = stage2.next()?;
+    let mut aq = a.modpow(&BigUint::from(first), n);
+    let mut accumulator = (&aq + n - &one) % n;
+    let mut previous = first;
+
+    for (count, q) in stage2.enumerate() {
+        let gap = q - previous;
+        let step = gap_powers
+            .entry(gap)
+            .or_insert_with(|| a.modpow(&BigUint::from(gap), n));
+        aq = (&aq * &*step) % n;
+        accumulator = (accumulator * (&aq + n - &one)) % n;
+        previous = q;
+
+        if count % 256 == 255 {
+            let g = accumulator.gcd(n);
+            if !g.is_one() {
+                return (&g != n).then_some(g);
+            }
+        }
+    }
+
+    let g = accumulator.gcd(n);
+    (!g.is_one() && &g != n).then_some(g)
+}
+
+/// Reruns stage 1 with a gcd after every prime, to separate factors found simultaneously.
+fn pm1_backtrack(n: &BigUint, primes: &[usize], b1: usize) -> Option<BigUint> {
+    let one = BigUint::one();
+    let mut a = BigUint::from(2u32);
+
+    for &p in primes.iter().take_while(|&&p| p <= b1) {
+        let p_big = BigUint::from(p);
+        let mut prime_power = p;
+        loop {
+            a = a.modpow(&p_big, n);
+            let g = (&a + n - &one).gcd(n);
+            if &g == n {
+                return None;
+            }
+            if !g.is_one() {
+                return Some(g);
+            }
+            if prime_power > b1 / p {
+                break;
+            }
+            prime_power *= p;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2^31 - 1 - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331, while 2147483477 - 1 = 4 * 536870869.
+    const SMOOTH: u32 = 2_147_483_647;
+    const ROUGH: u32 = 2_147_483_477;
+    // 2147483053 - 1 = 2^2 * 3^2 * 11 * 13 * 157 * 2657.
+    const STAGE2: u32 = 2_147_483_053;
+
+    #[test]
+    fn stage_one_finds_a_smooth_factor() {
+        let n = BigUint::from(SMOOTH) * BigUint::from(ROUGH);
+        assert_eq!(pollard_pm1(&n, 1000, 0), Some(BigUint::from(SMOOTH)));
+    }
+
+    #[test]
+    fn stage_two_finds_one_large_prime() {
+        let n = BigUint::from(STAGE2) * BigUint::from(ROUGH);
+        assert_eq!(pollard_pm1(&n, 1000, 0), None);
+        assert_eq!(pollard_pm1(&n, 1000, 5000), Some(BigUint::from(STAGE2)));
+    }
+
+    #[test]
+    fn backtracks_when_every_factor_is_found_at_once() {
+        // Both p - 1 are 1000-smooth, so stage 1 alone returns gcd = n.
+        let n = BigUint::from(SMOOTH) * BigUint::from(STAGE2);
+        let d = pollard_pm1(&n, 3000, 0).unwrap();
+        assert!(d == BigUint::from(SMOOTH) || d == BigUint::from(STAGE2));
+    }
+}
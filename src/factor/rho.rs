@@ -0,0 +1,110 @@
+// src/factor/rho.rs
+
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// Brent's variant of Pollard's rho method.
+///
+/// Iterates `x -> x^2 + c (mod n)` with Brent's cycle detection, accumulating the differences
+/// into batches of `m` before taking a gcd with `n`.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number that is not a prime power.
+/// * `c` - The additive constant of the iteration (should not be 0 or n - 2).
+/// * `max_iterations` - The number of iterations after which to give up.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn brent_rho(n: &BigUint, c: &BigUint, max_iterations: u64) -> Option<BigUint> {
+    let one = BigUint::one();
+    let m = 128u64;
+    let step = |v: &BigUint| (v * v + c) % n;
+
+    let mut y = BigUint::from(2u32);
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = one.clone();
+    let mut g = one.clone();
+    let mut r = 1u64;
+    let mut iterations = 0u64;
+
+    while g.is_one() {
+        x = y.clone();
+        for _ in 0..r {
+            y = step(&y);
+        }
+        let mut k = 0u64;
+        while k < r && g.is_one() {
+            ys = y.clone();
+            for _ in 0..m.min(r - k) {
+                y = step(&y);
+                q = (q * abs_diff(&x, &y)) % n;
+            }
+            g = q.gcd(n);
+            k += m;
+        }
+        iterations += r;
+        r *= 2;
+        if iterations > max_iterations && g.is_one() {
+            return None;
+        }
+    }
+
+    // The batch overshot: replay it one step at a time.
+    if &g == n {
+        loop {
+            ys = step(&ys);
+            g = abs_diff(&x, &ys).gcd(n);
+            if !g.is_one() {
+                break;
+            }
+        }
+    }
+
+    if &g == n || g.is_zero() {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+/// Returns |a - b| for unsigned big integers.
+pub(crate) fn abs_diff(a: &BigUint, b: &BigUint) -> BigUint {
+    if a >= b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_a_semiprime() {
+        let (p, q) = (
+            BigUint::from(2_147_483_647u32),
+            BigUint::from(2_147_483_477u32),
+        );
+        let n = &p * &q;
+        let d = brent_rho(&n, &BigUint::one(), 1 << 20).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    #[test]
+    fn gives_up_after_the_iteration_limit() {
+        let n = BigUint::from(2_147_483_647u32) * BigUint::from(2_147_483_477u32);
+        assert_eq!(brent_rho(&n, &BigUint::one(), 16), None);
+    }
+
+    #[test]
+    fn abs_diff_is_symmetric() {
+        let (a, b) = (BigUint::from(3u32), BigUint::from(10u32));
+        assert_eq!(abs_diff(&a, &b), BigUint::from(7u32));
+        assert_eq!(abs_diff(&b, &a), BigUint::from(7u32));
+    }
+}
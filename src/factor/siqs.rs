@@ -0,0 +1,793 @@
+// src/factor/siqs.rs
+
+use super::linalg::find_dependencies;
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use rand::Rng;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Number of relations collected beyond the size of the factor base.
+const EXTRA_RELATIONS: usize = 64;
+
+/// Batches in a row without a fresh A after which sieving gives up (tiny inputs only).
+const MAX_STALLED_BATCHES: usize = 100;
+
+/// Primes below this bound are not sieved; the threshold fudge factor absorbs their share.
+const SMALL_PRIME_SKIP: u32 = 128;
+
+/// Candidate multipliers for the Knuth-Schroeppel selection.
+const MULTIPLIERS: [u64; 20] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47,
+];
+
+/// Tuning parameters for a run of the self-initialising quadratic sieve.
+#[derive(Debug, Clone)]
+pub struct SiqsParameters {
+    /// Number of primes in the factor base.
+    pub factor_base_size: usize,
+    /// Each polynomial is sieved over x in [-M, M).
+    pub half_interval: usize,
+    /// Partial relations may leave a cofactor up to this multiple of the largest base prime.
+    pub large_prime_multiplier: u64,
+    /// How many bits of the largest base prime the sieve threshold allows to be missing.
+    pub threshold_fudge: f64,
+}
+
+impl SiqsParameters {
+    /// Returns parameters tuned for a number of the given size in decimal digits, by linear
+    /// interpolation in a table of known good settings.
+    pub fn for_digits(digits: usize) -> Self {
+        // (digits, factor base size, half interval M)
+        const TABLE: [(usize, usize, usize); 9] = [
+            (20, 100, 16_384),
+            (30, 200, 32_768),
+            (40, 600, 32_768),
+            (50, 1_500, 32_768),
+            (60, 4_000, 49_152),
+            (70, 10_000, 65_536),
+            (80, 20_000, 98_304),
+            (90, 35_000, 131_072),
+            (100, 60_000, 196_608),
+        ];
+
+        let (factor_base_size, half_interval) = if digits <= TABLE[0].0 {
+            (TABLE[0].1, TABLE[0].2)
+        } else if digits >= TABLE[TABLE.len() - 1].0 {
+            (TABLE[TABLE.len() - 1].1, TABLE[TABLE.len() - 1].2)
+        } else {
+            let i = TABLE.iter().position(|row| row.0 > digits).unwrap();
+            let (d0, f0, m0) = TABLE[i - 1];
+            let (d1, f1, m1) = TABLE[i];
+            let t = (digits - d0) as f64 / (d1 - d0) as f64;
+            let lerp = |a: usize, b: usize| (a as f64 + t * (b as f64 - a as f64)) as usize;
+            (lerp(f0, f1), lerp(m0, m1) / 4096 * 4096)
+        };
+
+        SiqsParameters {
+            factor_base_size,
+            half_interval,
+            large_prime_multiplier: 64,
+            threshold_fudge: 2.4,
+        }
+    }
+}
+
+/// A prime of the factor base together with a square root of kN modulo it.
+#[derive(Debug, Clone)]
+struct FactorBasePrime {
+    p: u32,
+    sqrt: u32,
+    log: u8,
+    magic: u64, // ceil(2^64 / p), for division-free reduction
+}
+
+impl FactorBasePrime {
+    /// Reduces `n` modulo `p` with a multiplication instead of a division (Lemire's fastmod).
+    fn reduce(&self, n: u32) -> u32 {
+        let low = self.magic.wrapping_mul(n as u64);
+        ((low as u128 * self.p as u128) >> 64) as u32
+    }
+}
+
+/// A relation (Ax + B)^2 ≡ (-1)^negative * prod(factors) * square^2 (mod n).
+#[derive(Debug, Clone)]
+struct Relation {
+    y: BigUint,
+    factors: Vec<usize>,
+    negative: bool,
+    square: BigUint,
+}
+
+/// Everything that stays fixed across all polynomials of one factorization.
+struct SieveContext {
+    n: BigUint,
+    kn: BigUint,
+    factor_base: Vec<FactorBasePrime>,
+    half_interval: usize,
+    large_prime_bound: u64,
+    sieve_start: u8,
+    a_factor_count: usize,
+    a_log_target: f64,
+}
+
+/// One polynomial g(x) = Ax^2 + 2Bx + C together with the sieve array offsets of its roots.
+struct SievePolynomial<'a> {
+    a_factors: &'a [usize],
+    is_a_factor: &'a [bool],
+    a: BigInt,
+    b: BigInt,
+    c: BigInt,
+    offsets1: Vec<u32>,
+    offsets2: Vec<u32>,
+}
+
+/// Relations found while sieving all polynomials that share one value of A.
+struct SieveBatch {
+    a: BigUint,
+    full: Vec<Relation>,
+    partial: Vec<(u64, Relation)>,
+}
+
+/// Self-initialising quadratic sieve with the large prime variation.
+///
+/// Polynomials g(x) = ((Ax + B)^2 - kN) / A are sieved over [-M, M) for many A, each the
+/// product of several factor base primes. All 2^(s-1) choices of B for one A are walked in
+/// Gray code order so that the sieve roots update by a single addition per prime. Different
+/// values of A are sieved in parallel. Relations that leave one prime cofactor above the
+/// factor base are paired up by that prime. Once there are more relations than base primes,
+/// structured Gaussian elimination over GF(2) yields congruences of squares.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number that is not a perfect power.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn siqs(n: &BigUint) -> Option<BigUint> {
+    let digits = n.to_string().len();
+    siqs_with_parameters(n, &SiqsParameters::for_digits(digits))
+}
+
+/// Runs the self-initialising quadratic sieve with explicit tuning parameters.
+pub fn siqs_with_parameters(n: &BigUint, params: &SiqsParameters) -> Option<BigUint> {
+    if n.is_even() {
+        return (n > &BigUint::from(2u32)).then(|| BigUint::from(2u32));
+    }
+    let root = n.sqrt();
+    if &root * &root == *n {
+        return Some(root);
+    }
+
+    let multiplier = knuth_schroeppel(n);
+    let kn = n * multiplier;
+    let factor_base = match build_factor_base(n, &kn, multiplier, params.factor_base_size) {
+        Ok(factor_base) => factor_base,
+        Err(small_factor) => return Some(small_factor),
+    };
+
+    let context = prepare_context(n, kn, factor_base, params);
+    let relations = collect_relations(&context);
+
+    let rows: Vec<Vec<usize>> = relations.iter().map(relation_columns).collect();
+    let dependencies = find_dependencies(&rows, context.factor_base.len() + 1, 64);
+
+    dependencies
+        .iter()
+        .find_map(|dependency| combine_dependency(&context, &relations, dependency))
+}
+
+/// Chooses a small multiplier k so that many small primes are quadratic residues modulo kN.
+fn knuth_schroeppel(n: &BigUint) -> u64 {
+    let primes = sieve_of_eratosthenes_bitset(1000);
+    let ln2 = 2f64.ln();
+
+    let score = |k: u64| -> f64 {
+        let kn = n * k;
+        let mut f = -0.5 * (k as f64).ln();
+        f += match (&kn % 8u32).to_u32().unwrap() {
+            1 => 2.0 * ln2,
+            5 => ln2,
+            3 | 7 => 0.5 * ln2,
+            _ => 0.0,
+        };
+        for &p in primes.iter().skip(1) {
+            let p = p as u64;
+            let residue = (&kn % p).to_u64().unwrap();
+            let ln_p = (p as f64).ln();
+            if k.is_multiple_of(p) {
+                f += ln_p / p as f64;
+            } else if legendre(residue, p) == 1 {
+                f += 2.0 * ln_p / (p - 1) as f64;
+            }
+        }
+        f
+    };
+
+    MULTIPLIERS
+        .iter()
+        .copied()
+        .max_by(|&a, &b| score(a).total_cmp(&score(b)))
+        .unwrap_or(1)
+}
+
+/// Collects the first `size` primes modulo which kN is a square.
+///
+/// Returns `Err(p)` if some base candidate `p` divides `n` outright.
+fn build_factor_base(
+    n: &BigUint,
+    kn: &BigUint,
+    multiplier: u64,
+    size: usize,
+) -> Result<Vec<FactorBasePrime>, BigUint> {
+    let mut bound = (size * 30).max(1000);
+    loop {
+        let mut factor_base = Vec::with_capacity(size);
+        for p in sieve_of_eratosthenes_bitset(bound) {
+            let p64 = p as u64;
+            let residue = (kn % p64).to_u64().unwrap();
+            if (n % p64).is_zero() && n != &BigUint::from(p64) {
+                return Err(BigUint::from(p64));
+            }
+            let sqrt = if p64 == 2 {
+                residue
+            } else if multiplier.is_multiple_of(p64) {
+                0
+            } else if legendre(residue, p64) == 1 {
+                sqrt_mod(residue, p64)
+            } else {
+                continue;
+            };
+            factor_base.push(FactorBasePrime {
+                p: p as u32,
+                sqrt: sqrt as u32,
+                log: 0,
+                magic: u64::MAX / p64 + 1,
+            });
+            if factor_base.len() == size {
+                return Ok(factor_base);
+            }
+        }
+        bound *= 2;
+    }
+}
+
+/// Derives the sieve threshold and the shape of A from the parameters.
+fn prepare_context(
+    n: &BigUint,
+    kn: BigUint,
+    mut factor_base: Vec<FactorBasePrime>,
+    params: &SiqsParameters,
+) -> SieveContext {
+    let m = params.half_interval as f64;
+    let pmax = factor_base.last().map_or(2, |fb| fb.p) as u64;
+    let log2_kn = kn.bits() as f64;
+
+    // |g(x)| is at most about M * sqrt(kN / 2) over the interval.
+    let log2_max = m.log2() + log2_kn / 2.0 - 0.5;
+    let threshold = (log2_max - params.threshold_fudge * (pmax as f64).log2()).max(1.0);
+
+    // Logarithms are scaled so that the threshold lands at most at 100, and the sieve starts
+    // from 128 - threshold: candidates are then exactly the bytes with the top bit set.
+    let log_scale = (100.0 / threshold).min(1.0);
+    for prime in factor_base.iter_mut() {
+        prime.log = ((prime.p as f64).log2() * log_scale).round() as u8;
+    }
+    let sieve_start = 128 - (threshold * log_scale).round() as u8;
+
+    // A should be close to sqrt(2kN) / M, split into primes of about 2000 if possible.
+    let a_log_target = (2f64.ln() + log2_kn * 2f64.ln()) / 2.0 - m.ln();
+    let largest_ln = (pmax as f64).ln();
+    let mut a_factor_count = ((a_log_target / 2000f64.ln()).round() as usize).max(1);
+    while a_log_target / (a_factor_count as f64) > largest_ln - 1.0 {
+        a_factor_count += 1;
+    }
+
+    SieveContext {
+        n: n.clone(),
+        kn,
+        factor_base,
+        half_interval: params.half_interval,
+        large_prime_bound: (pmax * params.large_prime_multiplier).min(pmax * pmax),
+        sieve_start,
+        a_factor_count,
+        a_log_target,
+    }
+}
+
+/// Sieves batches of polynomials in parallel until enough relations have been found.
+fn collect_relations(context: &SieveContext) -> Vec<Relation> {
+    let needed = context.factor_base.len() + 1 + EXTRA_RELATIONS;
+    let batch_size = 2 * rayon::current_num_threads();
+
+    let mut relations: Vec<Relation> = Vec::new();
+    let mut seen_y: HashSet<BigUint> = HashSet::new();
+    let mut seen_a: HashSet<BigUint> = HashSet::new();
+    let mut partials: HashMap<u64, Relation> = HashMap::new();
+    let mut stalled_batches = 0;
+
+    while relations.len() < needed && stalled_batches < MAX_STALLED_BATCHES {
+        let batches: Vec<SieveBatch> = (0..batch_size)
+            .into_par_iter()
+            .map(|_| sieve_polynomial_family(context, &mut rand::thread_rng()))
+            .collect();
+
+        stalled_batches += 1;
+        for batch in batches {
+            // The same A produces exactly the same relations again.
+            if !seen_a.insert(batch.a) {
+                continue;
+            }
+            stalled_batches = 0;
+            for relation in batch.full {
+                if seen_y.insert(relation.y.clone()) {
+                    relations.push(relation);
+                }
+            }
+            for (large_prime, relation) in batch.partial {
+                match partials.get(&large_prime) {
+                    None => {
+                        partials.insert(large_prime, relation);
+                    }
+                    Some(other) if other.y != relation.y => {
+                        let combined = combine_partials(context, other, &relation, large_prime);
+                        if seen_y.insert(combined.y.clone()) {
+                            relations.push(combined);
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    relations
+}
+
+/// Multiplies two partial relations that share the same large prime into a full one.
+fn combine_partials(
+    context: &SieveContext,
+    a: &Relation,
+    b: &Relation,
+    large_prime: u64,
+) -> Relation {
+    let mut factors = a.factors.clone();
+    factors.extend_from_slice(&b.factors);
+    Relation {
+        y: (&a.y * &b.y) % &context.n,
+        factors,
+        negative: a.negative ^ b.negative,
+        square: (&a.square * &b.square * large_prime) % &context.n,
+    }
+}
+
+/// Picks the factor base indices of the primes making up a new A.
+fn choose_a_factors<R: Rng>(context: &SieveContext, rng: &mut R) -> Vec<usize> {
+    let fb = &context.factor_base;
+    let s = context.a_factor_count;
+    let target_ln = context.a_log_target / s as f64;
+
+    // Candidates lie within a factor of about two of the ideal size.
+    let first_usable = fb
+        .iter()
+        .position(|q| q.p > SMALL_PRIME_SKIP && q.sqrt != 0);
+    let first_usable = first_usable.unwrap_or(1);
+    let mut lo = fb
+        .iter()
+        .position(|q| (q.p as f64).ln() >= target_ln - 0.7)
+        .unwrap_or(fb.len() - 1)
+        .max(first_usable);
+    let mut hi = fb
+        .iter()
+        .position(|q| (q.p as f64).ln() > target_ln + 0.7)
+        .unwrap_or(fb.len());
+    while hi - lo < s + 4 {
+        lo = lo.saturating_sub(1).max(first_usable);
+        hi = (hi + 1).min(fb.len());
+        if lo == first_usable && hi == fb.len() {
+            break;
+        }
+    }
+
+    let mut chosen: Vec<usize> = Vec::with_capacity(s);
+    let mut chosen_ln = 0.0;
+    if s == 1 {
+        // With a single prime there is nothing to balance, so any candidate will do.
+        let candidates: Vec<usize> = (lo..hi).filter(|&i| fb[i].sqrt != 0).collect();
+        chosen.push(candidates[rng.gen_range(0..candidates.len())]);
+        return chosen;
+    }
+    while chosen.len() + 1 < s {
+        let i = rng.gen_range(lo..hi);
+        if fb[i].sqrt != 0 && !chosen.contains(&i) {
+            chosen.push(i);
+            chosen_ln += (fb[i].p as f64).ln();
+        }
+    }
+
+    // The last prime brings the product as close to the target as possible.
+    let remaining_ln = context.a_log_target - chosen_ln;
+    let last = (first_usable..fb.len())
+        .filter(|i| !chosen.contains(i) && fb[*i].sqrt != 0)
+        .min_by(|&i, &j| {
+            let di = ((fb[i].p as f64).ln() - remaining_ln).abs();
+            let dj = ((fb[j].p as f64).ln() - remaining_ln).abs();
+            di.total_cmp(&dj)
+        })
+        .unwrap();
+    chosen.push(last);
+    chosen
+}
+
+/// Generates one A and sieves every polynomial belonging to it.
+fn sieve_polynomial_family<R: Rng>(context: &SieveContext, rng: &mut R) -> SieveBatch {
+    let fb = &context.factor_base;
+    let m = context.half_interval;
+    let a_factors = choose_a_factors(context, rng);
+    let a = a_factors
+        .iter()
+        .fold(BigUint::one(), |acc, &i| acc * fb[i].p);
+
+    // B_l = (A / q_l) * (sqrt(kN) * (A / q_l)^-1 mod q_l), so that B^2 ≡ kN (mod A).
+    let b_terms: Vec<BigUint> = a_factors
+        .iter()
+        .map(|&i| {
+            let q = fb[i].p as u64;
+            let a_over_q = &a / q;
+            let inv = inv_mod((&a_over_q % q).to_u64().unwrap(), q);
+            let mut gamma = (fb[i].sqrt as u64 * inv) % q;
+            if gamma > q / 2 {
+                gamma = q - gamma;
+            }
+            a_over_q * gamma
+        })
+        .collect();
+    let b = BigInt::from(b_terms.iter().sum::<BigUint>());
+    let mut signs = vec![1i8; b_terms.len()];
+
+    // Initial roots x ≡ A^-1 (±sqrt(kN) - B) (mod p), and the Gray code increments.
+    let is_a_factor: Vec<bool> = {
+        let mut flags = vec![false; fb.len()];
+        for &i in &a_factors {
+            flags[i] = true;
+        }
+        flags
+    };
+    let mut sol1 = vec![0u32; fb.len()];
+    let mut sol2 = vec![0u32; fb.len()];
+    let mut deltas: Vec<Vec<u32>> = vec![vec![0u32; fb.len()]; b_terms.len()];
+    for (j, prime) in fb.iter().enumerate() {
+        if is_a_factor[j] {
+            continue;
+        }
+        let p = prime.p as u64;
+        let a_inv = inv_mod((&a % p).to_u64().unwrap(), p);
+        let b_mod = (&b % BigInt::from(p)).to_u64().unwrap();
+        let t = prime.sqrt as u64;
+        sol1[j] = ((a_inv * ((t + p - b_mod) % p)) % p) as u32;
+        sol2[j] = ((a_inv * ((2 * p - t - b_mod) % p)) % p) as u32;
+        for (l, term) in b_terms.iter().enumerate() {
+            let term_mod = (term % p).to_u64().unwrap();
+            deltas[l][j] = ((2 * term_mod % p * a_inv) % p) as u32;
+        }
+    }
+
+    let mut batch = SieveBatch {
+        a: a.clone(),
+        full: Vec::new(),
+        partial: Vec::new(),
+    };
+    let kn_int = BigInt::from(context.kn.clone());
+    let mut poly = SievePolynomial {
+        a_factors: &a_factors,
+        is_a_factor: &is_a_factor,
+        a: BigInt::from(a),
+        b,
+        c: BigInt::zero(),
+        offsets1: vec![0u32; fb.len()],
+        offsets2: vec![0u32; fb.len()],
+    };
+    let m_mod: Vec<u32> = fb
+        .iter()
+        .map(|prime| (m % prime.p as usize) as u32)
+        .collect();
+    let mut sieve = vec![0u8; 2 * m];
+    let family_size = 1usize << (b_terms.len() - 1);
+
+    for index in 0..family_size {
+        if index > 0 {
+            // Flip the sign of B_v, with v chosen by the Gray code.
+            let v = index.trailing_zeros() as usize + 1;
+            let two_term = BigInt::from(b_terms[v].clone()) * 2;
+            let subtract = signs[v] > 0;
+            signs[v] = -signs[v];
+            if subtract {
+                poly.b -= two_term;
+            } else {
+                poly.b += two_term;
+            }
+            for (j, prime) in fb.iter().enumerate() {
+                if is_a_factor[j] {
+                    continue;
+                }
+                let p = prime.p;
+                let d = if subtract {
+                    deltas[v][j]
+                } else {
+                    p - deltas[v][j]
+                };
+                sol1[j] = add_mod(sol1[j], d, p);
+                sol2[j] = add_mod(sol2[j], d, p);
+            }
+        }
+        poly.c = (&poly.b * &poly.b - &kn_int) / &poly.a;
+
+        sieve.fill(context.sieve_start);
+        for (j, prime) in fb.iter().enumerate() {
+            if is_a_factor[j] {
+                continue;
+            }
+            let start1 = add_mod(sol1[j], m_mod[j], prime.p) as usize;
+            let start2 = add_mod(sol2[j], m_mod[j], prime.p) as usize;
+            poly.offsets1[j] = start1 as u32;
+            poly.offsets2[j] = start2 as u32;
+            if prime.p < SMALL_PRIME_SKIP {
+                continue;
+            }
+            let p = prime.p as usize;
+            for i in (start1..2 * m).step_by(p) {
+                sieve[i] = sieve[i].wrapping_add(prime.log);
+            }
+            if start2 != start1 {
+                for i in (start2..2 * m).step_by(p) {
+                    sieve[i] = sieve[i].wrapping_add(prime.log);
+                }
+            }
+        }
+
+        for (w, chunk) in sieve.chunks_exact(8).enumerate() {
+            let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+            if word & 0x8080_8080_8080_8080 == 0 {
+                continue;
+            }
+            for (k, &value) in chunk.iter().enumerate() {
+                if value & 0x80 == 0 {
+                    continue;
+                }
+                if let Some((relation, cofactor)) = check_candidate(context, &poly, 8 * w + k) {
+                    if cofactor == 1 {
+                        batch.full.push(relation);
+                    } else {
+                        batch.partial.push((cofactor, relation));
+                    }
+                }
+            }
+        }
+    }
+
+    batch
+}
+
+/// Trial divides g(x) over the factor base, where x = i - M for the sieve array index `i`.
+///
+/// Only primes whose sieve offsets line up with `i` are tried, apart from the primes of A.
+/// Returns the relation and its leftover cofactor, if the cofactor is 1 or a large prime.
+fn check_candidate(
+    context: &SieveContext,
+    poly: &SievePolynomial,
+    i: usize,
+) -> Option<(Relation, u64)> {
+    let x = BigInt::from(i as i64 - context.half_interval as i64);
+    let g: BigInt = (&poly.a * &x + &poly.b * 2) * &x + &poly.c;
+    let negative = g.sign() == Sign::Minus;
+    let mut value = g.abs().to_biguint().unwrap();
+    if value.is_zero() {
+        return None;
+    }
+
+    // Q(x) = A * g(x), so the primes of A always belong to the relation.
+    let mut factors: Vec<usize> = poly.a_factors.to_vec();
+    let i = i as u32;
+    for (j, prime) in context.factor_base.iter().enumerate() {
+        let p = prime.p;
+        let divides = if poly.is_a_factor[j] {
+            (&value % p).is_zero()
+        } else {
+            let offset = prime.reduce(i);
+            offset == poly.offsets1[j] || offset == poly.offsets2[j]
+        };
+        if !divides {
+            continue;
+        }
+        while (&value % p).is_zero() {
+            value /= p;
+            factors.push(j);
+        }
+    }
+
+    let cofactor = value.to_u64()?;
+    if cofactor != 1 && cofactor >= context.large_prime_bound {
+        return None;
+    }
+
+    let y = (&poly.a * &x + &poly.b).abs().to_biguint().unwrap() % &context.n;
+    Some((
+        Relation {
+            y,
+            factors,
+            negative,
+            square: BigUint::one(),
+        },
+        cofactor,
+    ))
+}
+
+/// Lists the matrix columns with an odd exponent: column 0 is the sign, column j + 1 is the
+/// j-th factor base prime.
+fn relation_columns(relation: &Relation) -> Vec<usize> {
+    let mut counts: HashMap<usize, u32> = HashMap::new();
+    for &j in &relation.factors {
+        *counts.entry(j + 1).or_insert(0) += 1;
+    }
+    let mut columns: Vec<usize> = counts
+        .into_iter()
+        .filter(|(_, e)| e % 2 == 1)
+        .map(|(c, _)| c)
+        .collect();
+    if relation.negative {
+        columns.push(0);
+    }
+    columns.sort_unstable();
+    columns
+}
+
+/// Turns a dependency into X^2 ≡ Y^2 (mod n) and tries gcd(X - Y, n).
+fn combine_dependency(
+    context: &SieveContext,
+    relations: &[Relation],
+    dependency: &[usize],
+) -> Option<BigUint> {
+    let n = &context.n;
+    let mut x = BigUint::one();
+    let mut y = BigUint::one();
+    let mut exponents = vec![0u32; context.factor_base.len()];
+
+    for &r in dependency {
+        let relation = &relations[r];
+        x = (x * &relation.y) % n;
+        y = (y * &relation.square) % n;
+        for &j in &relation.factors {
+            exponents[j] += 1;
+        }
+    }
+    for (j, &e) in exponents.iter().enumerate() {
+        if e > 0 {
+            let p = BigUint::from(context.factor_base[j].p);
+            y = (y * p.modpow(&BigUint::from(e / 2), n)) % n;
+        }
+    }
+
+    let d = ((&x + n - &y) % n).gcd(n);
+    (!d.is_one() && &d != n).then_some(d)
+}
+
+/// Computes (a + b) mod p for a, b < p.
+fn add_mod(a: u32, b: u32, p: u32) -> u32 {
+    let sum = a as u64 + b as u64;
+    (if sum >= p as u64 { sum - p as u64 } else { sum }) as u32
+}
+
+/// Computes base^exp mod m for a modulus below 2^32.
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Computes the inverse of `a` modulo the prime `p`.
+fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+/// Legendre symbol (a | p) for an odd prime `p`, as 1, 0 or p - 1.
+fn legendre(a: u64, p: u64) -> u64 {
+    pow_mod(a, (p - 1) / 2, p)
+}
+
+/// Tonelli-Shanks square root of a quadratic residue `a` modulo an odd prime `p`.
+fn sqrt_mod(a: u64, p: u64) -> u64 {
+    let a = a % p;
+    if a == 0 {
+        return 0;
+    }
+    if p % 4 == 3 {
+        return pow_mod(a, (p + 1) / 4, p);
+    }
+
+    let mut q = p - 1;
+    let mut s = 0;
+    while q.is_multiple_of(2) {
+        q /= 2;
+        s += 1;
+    }
+    let z = (2..p).find(|&z| legendre(z, p) == p - 1).unwrap();
+
+    let mut m = s;
+    let mut c = pow_mod(z, q, p);
+    let mut t = pow_mod(a, q, p);
+    let mut r = pow_mod(a, q.div_ceil(2), p);
+    while t != 1 {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = t2 * t2 % p;
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        m = i;
+        c = b * b % p;
+        t = t * c % p;
+        r = r * b % p;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factors_a_100_bit_semiprime() {
+        let p: BigUint = "562949953433701".parse().unwrap();
+        let q: BigUint = "1125899906941409".parse().unwrap();
+        let n = &p * &q;
+        assert_eq!(n.bits(), 100);
+        let d = siqs(&n).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    #[test]
+    fn explicit_parameters() {
+        let p = BigUint::from(2_147_483_647u32);
+        let q = BigUint::from(4_294_967_291u32);
+        let params = SiqsParameters {
+            factor_base_size: 80,
+            half_interval: 8_192,
+            ..SiqsParameters::for_digits(20)
+        };
+        let d = siqs_with_parameters(&(&p * &q), &params).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    #[test]
+    fn even_numbers_and_squares() {
+        let p = BigUint::from(2_147_483_647u32);
+        assert_eq!(siqs(&(&p * 2u32)), Some(BigUint::from(2u32)));
+        assert_eq!(siqs(&(&p * &p)), Some(p));
+    }
+
+    #[test]
+    fn modular_helpers() {
+        // 1000003 takes the p = 3 (mod 4) shortcut, 1000033 and 65537 need Tonelli-Shanks.
+        for p in [1_000_003u64, 1_000_033, 65_537] {
+            for a in 1..200 {
+                if legendre(a, p) == 1 {
+                    let r = sqrt_mod(a, p);
+                    assert_eq!(r * r % p, a, "a = {}, p = {}", a, p);
+                }
+                assert_eq!(inv_mod(a, p) * a % p, 1);
+            }
+            assert_eq!(pow_mod(3, p - 1, p), 1);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// src/factor/squfof.rs
+
+use num_integer::{Integer, Roots};
+
+/// Multipliers tried in turn; each gives SQUFOF a different continued fraction to search.
+const MULTIPLIERS: [u64; 16] = [
+    1,
+    3,
+    5,
+    7,
+    11,
+    3 * 5,
+    3 * 7,
+    3 * 11,
+    5 * 7,
+    5 * 11,
+    7 * 11,
+    3 * 5 * 7,
+    3 * 5 * 11,
+    3 * 7 * 11,
+    5 * 7 * 11,
+    3 * 5 * 7 * 11,
+];
+
+/// Largest input accepted by SQUFOF; beyond this rho and p - 1 are the better tools anyway.
+pub const SQUFOF_MAX_BITS: u64 = 62;
+
+/// Shanks' square forms factorization.
+///
+/// Expands the continued fraction of sqrt(kn) until it meets a square form, then walks the
+/// reduced forms backwards from its square root until the cycle reaches an ambiguous form,
+/// whose coefficient shares a factor with `n`.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number below 2^62.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn squfof(n: u64) -> Option<u64> {
+    if n < 4 || 64 - n.leading_zeros() as u64 > SQUFOF_MAX_BITS {
+        return None;
+    }
+    let s = n.sqrt();
+    if s * s == n {
+        return Some(s);
+    }
+
+    for &k in MULTIPLIERS.iter() {
+        if let Some(factor) = squfof_with_multiplier(n, k) {
+            return Some(factor);
+        }
+    }
+    None
+}
+
+fn squfof_with_multiplier(n: u64, k: u64) -> Option<u64> {
+    let d = k as i128 * n as i128;
+    let p0 = d.sqrt();
+    let mut q = d - p0 * p0;
+    if q == 0 {
+        return None;
+    }
+
+    // Forward cycle: look for a square Q at an even index.
+    let bound = 3 * 2 * (2 * (n as i128).sqrt()).sqrt();
+    let mut p_prev = p0;
+    let mut p = p0;
+    let mut q_prev = 1i128;
+    let mut r = 0i128;
+    let mut found = false;
+    for i in 2..bound {
+        let b = (p0 + p) / q;
+        p = b * q - p;
+        let q_old = q;
+        q = q_prev + b * (p_prev - p);
+        r = q.sqrt();
+        if i % 2 == 0 && r * r == q {
+            found = true;
+            break;
+        }
+        q_prev = q_old;
+        p_prev = p;
+    }
+    if !found {
+        return None;
+    }
+
+    // Reverse cycle from the square root form until P repeats.
+    let b = (p0 - p) / r;
+    p += b * r;
+    q_prev = r;
+    q = (d - p * p) / q_prev;
+    for _ in 0..bound {
+        let b = (p0 + p) / q;
+        p_prev = p;
+        p = b * q - p;
+        let q_old = q;
+        q = q_prev + b * (p_prev - p);
+        q_prev = q_old;
+        if p == p_prev {
+            let factor = (n as i128).gcd(&q_prev) as u64;
+            return (factor != 1 && factor != n).then_some(factor);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_semiprimes() {
+        let semiprimes = [
+            11 * 13,
+            10007 * 10009,
+            1_000_003 * 1_000_033,
+            2_147_483_647 * 2_147_483_477,
+        ];
+        for n in semiprimes {
+            let d = squfof(n).unwrap();
+            assert!(d > 1 && d < n && n % d == 0, "n = {}", n);
+        }
+        assert_eq!(squfof(1_000_003 * 1_000_003), Some(1_000_003));
+    }
+
+    #[test]
+    fn rejects_primes_and_out_of_range_inputs() {
+        assert_eq!(squfof(0), None);
+        assert_eq!(squfof(2), None);
+        assert_eq!(squfof(1_000_003), None);
+        assert_eq!(squfof(2_147_483_647), None);
+        assert_eq!(squfof((1 << 62) + 1), None);
+    }
+}
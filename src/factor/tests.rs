@@ -0,0 +1,90 @@
+// src/factor/tests.rs
+
+// Full factorizations through the whole pipeline: the edge cases 0, 1 and 2, small and
+// prime-power inputs handled by trial division, perfect powers of large primes, and semiprimes
+// that need the general-purpose methods.
+
+use super::{factorize, find_factor, is_probable_prime};
+use num_bigint::BigUint;
+
+fn big(n: u64) -> BigUint {
+    BigUint::from(n)
+}
+
+fn pairs(values: &[(u64, u32)]) -> Vec<(BigUint, u32)> {
+    values.iter().map(|&(p, k)| (big(p), k)).collect()
+}
+
+fn product(factors: &[(BigUint, u32)]) -> BigUint {
+    factors.iter().map(|(p, k)| p.pow(*k)).product()
+}
+
+#[test]
+fn primality() {
+    for p in [2u64, 3, 5, 10007, 2_147_483_647, 18_446_744_073_709_551_557] {
+        assert!(is_probable_prime(&big(p)), "p = {}", p);
+    }
+    // 561 and 41041 are Carmichael numbers.
+    for n in [0u64, 1, 4, 561, 41041, 2_147_483_647 * 2_147_483_477] {
+        assert!(!is_probable_prime(&big(n)), "n = {}", n);
+    }
+    assert!(is_probable_prime(&(BigUint::from(2u32).pow(127) - 1u32)));
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(factorize(&big(0)), vec![]);
+    assert_eq!(factorize(&big(1)), vec![]);
+    assert_eq!(factorize(&big(2)), pairs(&[(2, 1)]));
+}
+
+#[test]
+fn small_numbers_and_prime_powers() {
+    assert_eq!(factorize(&big(360)), pairs(&[(2, 3), (3, 2), (5, 1)]));
+    assert_eq!(factorize(&big(1 << 40)), pairs(&[(2, 40)]));
+    assert_eq!(factorize(&big(3u64.pow(30))), pairs(&[(3, 30)]));
+    assert_eq!(factorize(&big(10007)), pairs(&[(10007, 1)]));
+    for n in 2..2000u64 {
+        let factors = factorize(&big(n));
+        assert_eq!(product(&factors), big(n));
+        assert!(factors.iter().all(|(p, _)| is_probable_prime(p)));
+    }
+}
+
+#[test]
+fn perfect_powers_of_large_primes() {
+    let p = big(2_147_483_647);
+    let q = big(2_147_483_477);
+    assert_eq!(factorize(&p.pow(5)), vec![(p.clone(), 5)]);
+    // (pq)^3 is a perfect power whose root still has to be split.
+    let n = (&p * &q).pow(3) * 12u32;
+    assert_eq!(
+        factorize(&n),
+        vec![(big(2), 2), (big(3), 1), (q.clone(), 3), (p.clone(), 3)]
+    );
+}
+
+#[test]
+fn semiprimes() {
+    let cases = [
+        (10007u64, 10009u64),
+        (1_000_003, 2_147_483_647),
+        (2_147_483_477, 2_147_483_647),
+        (4_294_967_291, 4_294_967_279),
+    ];
+    for (p, q) in cases {
+        let n = big(p) * big(q);
+        let mut expected = pairs(&[(p, 1), (q, 1)]);
+        expected.sort();
+        assert_eq!(factorize(&n), expected, "n = {}", n);
+        let d = find_factor(&n);
+        assert!(d == big(p) || d == big(q));
+    }
+
+    // Three primes above the trial division bound.
+    let n = big(10007) * big(1_000_003) * big(2_147_483_647);
+    assert_eq!(
+        factorize(&n),
+        pairs(&[(10007, 1), (1_000_003, 1), (2_147_483_647, 1)])
+    );
+}
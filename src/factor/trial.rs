@@ -0,0 +1,84 @@
+// src/factor/trial.rs
+
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Strips every prime factor up to `bound` from `n` by trial division.
+///
+/// # Arguments
+///
+/// * `n` - The number to factor (must be non-zero).
+/// * `bound` - The largest trial divisor.
+///
+/// # Returns
+///
+/// The small prime factors as `(p, k)` pairs in ascending order, together with the
+/// remaining cofactor (which is 1 if `n` factored completely).
+pub fn trial_division(n: &BigUint, bound: usize) -> (Vec<(BigUint, u32)>, BigUint) {
+    let mut factors = Vec::new();
+    let mut remaining = n.clone();
+
+    for p in sieve_of_eratosthenes_bitset(bound) {
+        let p = BigUint::from(p);
+        if &p * &p > remaining {
+            break;
+        }
+        let mut exponent = 0;
+        while (&remaining % &p).is_zero() {
+            remaining /= &p;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+    }
+
+    // Whatever is left below bound^2 has no factor up to `bound`, so it is prime.
+    if !remaining.is_one() && remaining <= BigUint::from(bound).pow(2) {
+        factors.push((remaining, 1));
+        remaining = BigUint::one();
+    }
+
+    (factors, remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(values: &[(u32, u32)]) -> Vec<(BigUint, u32)> {
+        values.iter().map(|&(p, k)| (BigUint::from(p), k)).collect()
+    }
+
+    #[test]
+    fn strips_small_primes() {
+        // 2^10 * 3^5 * 97 * 10007 * 10009, with 10007 * 10009 left above the bound.
+        let large = BigUint::from(10007u32 * 10009);
+        let n = BigUint::from(2u32.pow(10) * 3u32.pow(5) * 97) * &large;
+        let (factors, cofactor) = trial_division(&n, 1000);
+        assert_eq!(factors, pairs(&[(2, 10), (3, 5), (97, 1)]));
+        assert_eq!(cofactor, large);
+    }
+
+    #[test]
+    fn a_cofactor_below_the_square_of_the_bound_is_prime() {
+        let n = BigUint::from(8u32 * 10007);
+        assert_eq!(
+            trial_division(&n, 1000),
+            (pairs(&[(2, 3), (10007, 1)]), BigUint::one())
+        );
+    }
+
+    #[test]
+    fn small_inputs() {
+        assert_eq!(
+            trial_division(&BigUint::one(), 100),
+            (vec![], BigUint::one())
+        );
+        assert_eq!(
+            trial_division(&BigUint::from(2u32), 100),
+            (pairs(&[(2, 1)]), BigUint::one())
+        );
+    }
+}
@@ -0,0 +1,100 @@
+// src/factor/williams.rs
+
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
+
+/// Williams' p + 1 method.
+///
+/// Works with the Lucas sequence V_m(A) = α^m + α^-m, where α is a root of x^2 - Ax + 1.
+/// If the discriminant A^2 - 4 is a non-residue modulo a prime factor `p` and p + 1 is
+/// `b1`-smooth, then V_M(A) ≡ 2 (mod p) for M the product of all prime powers up to `b1`.
+/// Several starting values are tried, since the discriminant condition holds for about half
+/// of them.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite number.
+/// * `b1` - The smoothness bound.
+/// * `seeds` - The starting values `A` to try.
+///
+/// # Returns
+///
+/// `Some(d)` with `1 < d < n` if a non-trivial factor was found, otherwise `None`.
+pub fn williams_pp1(n: &BigUint, b1: usize, seeds: &[u32]) -> Option<BigUint> {
+    let two = BigUint::from(2u32);
+    let primes = sieve_of_eratosthenes_bitset(b1);
+
+    for &seed in seeds {
+        let mut v = BigUint::from(seed) % n;
+        for &p in &primes {
+            let mut prime_power = p;
+            while prime_power <= b1 / p {
+                prime_power *= p;
+            }
+            // V_m(V_k(A)) = V_mk(A), so the exponents can be applied one at a time.
+            v = lucas_v(&v, prime_power as u64, n);
+        }
+
+        let g = (&v + n - &two).gcd(n);
+        if !g.is_one() && &g != n {
+            return Some(g);
+        }
+    }
+    None
+}
+
+/// Computes V_m(A) mod n with the Montgomery-style Lucas ladder.
+fn lucas_v(a: &BigUint, m: u64, n: &BigUint) -> BigUint {
+    let two = BigUint::from(2u32);
+    if m == 0 {
+        return two;
+    }
+
+    // Invariant: (x, y) = (V_k, V_{k+1}) for k the prefix of m read so far.
+    let mut x = a.clone();
+    let mut y = (a * a + n - &two) % n;
+    for bit in (0..63 - m.leading_zeros()).rev() {
+        if (m >> bit) & 1 == 1 {
+            x = (&x * &y + n - a) % n;
+            y = (&y * &y + n - &two) % n;
+        } else {
+            y = (&x * &y + n - a) % n;
+            x = (&x * &x + n - &two) % n;
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2147483579 + 1 = 2^2 * 3 * 5 * 11 * 47 * 107 * 647, while both p - 1 and the other factor's
+    // p + 1 have a prime factor above 10^6.
+    const SMOOTH: u32 = 2_147_483_579;
+    const ROUGH: u32 = 2_147_483_477;
+
+    #[test]
+    fn needs_a_non_residue_discriminant() {
+        let n = BigUint::from(SMOOTH) * BigUint::from(ROUGH);
+        // 3^2 - 4 is a square modulo SMOOTH, 6^2 - 4 is not.
+        assert_eq!(williams_pp1(&n, 1000, &[3]), None);
+        assert_eq!(williams_pp1(&n, 1000, &[3, 6]), Some(BigUint::from(SMOOTH)));
+    }
+
+    #[test]
+    fn lucas_v_matches_the_recurrence() {
+        // V_0 = 2, V_1 = A, V_(k+1) = A V_k - V_(k-1).
+        let n = BigUint::from(1_000_003u32);
+        let a = BigUint::from(7u32);
+        let (mut previous, mut current) = (BigUint::from(2u32), a.clone());
+        for m in 1..40u64 {
+            assert_eq!(lucas_v(&a, m, &n), current, "m = {}", m);
+            let next = (&a * &current + &n - &previous % &n) % &n;
+            previous = current;
+            current = next;
+        }
+    }
+}
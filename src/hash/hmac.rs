@@ -0,0 +1,189 @@
+// src/hash/hmac.rs
+
+use super::HashFunction;
+
+const INNER_PAD: u8 = 0x36;
+const OUTER_PAD: u8 = 0x5c;
+
+/// HMAC (RFC 2104) over any hash function, with an incremental interface.
+#[derive(Clone)]
+pub struct Hmac<H: HashFunction> {
+    inner: H, // Hash of (K xor ipad) || message
+    outer: H, // Hash of (K xor opad), completed in `finalize`
+}
+
+impl<H: HashFunction> Hmac<H> {
+    /// Creates an HMAC instance keyed with `key`. Keys longer than a block are hashed first.
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = if key.len() > H::BLOCK_SIZE {
+            H::digest(key)
+        } else {
+            key.to_vec()
+        };
+        block.resize(H::BLOCK_SIZE, 0);
+
+        let mut inner = H::new();
+        inner.update(&block.iter().map(|b| b ^ INNER_PAD).collect::<Vec<u8>>());
+        let mut outer = H::new();
+        outer.update(&block.iter().map(|b| b ^ OUTER_PAD).collect::<Vec<u8>>());
+        Hmac { inner, outer }
+    }
+
+    /// Absorbs more of the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Consumes the instance and returns the tag.
+    pub fn finalize(self) -> Vec<u8> {
+        let mut outer = self.outer;
+        outer.update(&self.inner.finalize());
+        outer.finalize()
+    }
+
+    /// Computes the tag of `data` under `key` in one call.
+    pub fn mac(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut hmac = Self::new(key);
+        hmac.update(data);
+        hmac.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{Sha224, Sha256, Sha384, Sha512};
+
+    /// The keys and data of RFC 4231, test cases 1 to 7.
+    fn rfc4231_cases() -> Vec<(Vec<u8>, Vec<u8>)> {
+        vec![
+            (vec![0x0b; 20], b"Hi There".to_vec()),
+            (b"Jefe".to_vec(), b"what do ya want for nothing?".to_vec()),
+            (vec![0xaa; 20], vec![0xdd; 50]),
+            ((1..=25).collect(), vec![0xcd; 50]),
+            (vec![0x0c; 20], b"Test With Truncation".to_vec()),
+            (
+                vec![0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+            ),
+            (
+                vec![0xaa; 131],
+                concat!(
+                    "This is a test using a larger than block-size key and a larger than ",
+                    "block-size data. The key needs to be hashed before being used by the ",
+                    "HMAC algorithm."
+                )
+                .as_bytes()
+                .to_vec(),
+            ),
+        ]
+    }
+
+    /// Checks HMAC-H against the expected outputs; test case 5 is truncated to 128 bits.
+    fn check<H: HashFunction>(expected: [&str; 7]) {
+        for (i, ((key, data), expected)) in rfc4231_cases().iter().zip(expected).enumerate() {
+            let mac = hex::encode(Hmac::<H>::mac(key, data));
+            let mac = if i == 4 { &mac[..32] } else { &mac[..] };
+            assert_eq!(mac, expected, "test case {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn hmac_sha224_rfc4231_vectors() {
+        check::<Sha224>([
+            "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+            "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44",
+            "7fb3cb3588c6c1f6ffa9694d7d6ad2649365b0c1f65d69d1ec8333ea",
+            "6c11506874013cac6a2abc1bb382627cec6a90d86efc012de7afec5a",
+            "0e2aea68a90c8d37c988bcdb9fca6fa8",
+            "95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e",
+            "3a854166ac5d9f023f54d517d0b39dbd946770db9c2b95c9f6f565d1",
+        ]);
+    }
+
+    #[test]
+    fn hmac_sha256_rfc4231_vectors() {
+        check::<Sha256>([
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+            "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+            "a3b6167473100ee06e0c796c2955552b",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+        ]);
+    }
+
+    #[test]
+    fn hmac_sha384_rfc4231_vectors() {
+        check::<Sha384>([
+            concat!(
+                "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59c",
+                "faea9ea9076ede7f4af152e8b2fa9cb6"
+            ),
+            concat!(
+                "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e",
+                "8e2240ca5e69e2c78b3239ecfab21649"
+            ),
+            concat!(
+                "88062608d3e6ad8a0aa2ace014c8a86f0aa635d947ac9febe83ef4e55966144b",
+                "2a5ab39dc13814b94e3ab6e101a34f27"
+            ),
+            concat!(
+                "3e8a69b7783c25851933ab6290af6ca77a9981480850009cc5577c6e1f573b4e",
+                "6801dd23c4a7d679ccf8a386c674cffb"
+            ),
+            "3abf34c3503b2a23a46efc619baef897",
+            concat!(
+                "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c6",
+                "0c2ef6ab4030fe8296248df163f44952"
+            ),
+            concat!(
+                "6617178e941f020d351e2f254e8fd32c602420feb0b8fb9adccebb82461e99c5",
+                "a678cc31e799176d3860e6110c46523e"
+            ),
+        ]);
+    }
+
+    #[test]
+    fn hmac_sha512_rfc4231_vectors() {
+        check::<Sha512>([
+            concat!(
+                "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde",
+                "daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+            ),
+            concat!(
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554",
+                "9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+            ),
+            concat!(
+                "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39",
+                "bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb"
+            ),
+            concat!(
+                "b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3db",
+                "a91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd"
+            ),
+            "415fad6271580a531d4179bc891d87a6",
+            concat!(
+                "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352",
+                "6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598"
+            ),
+            concat!(
+                "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944",
+                "b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58"
+            ),
+        ]);
+    }
+
+    #[test]
+    fn incremental_updates_match_one_shot() {
+        let mut hmac = Hmac::<Sha256>::new(b"Jefe");
+        hmac.update(b"what do ya want ");
+        hmac.update(b"for nothing?");
+        assert_eq!(
+            hmac.finalize(),
+            Hmac::<Sha256>::mac(b"Jefe", b"what do ya want for nothing?")
+        );
+    }
+}
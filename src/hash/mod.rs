@@ -0,0 +1,37 @@
+// src/hash/mod.rs
+
+pub mod hmac;
+pub mod sha256;
+pub mod sha512;
+
+pub use hmac::Hmac;
+pub use sha256::{Sha224, Sha256};
+pub use sha512::{Sha384, Sha512, Sha512_224, Sha512_256};
+
+/// Trait for cryptographic hash functions with an incremental interface.
+pub trait HashFunction: Clone {
+    /// The length of a digest in bytes.
+    const OUTPUT_SIZE: usize;
+
+    /// The length of an input block in bytes.
+    const BLOCK_SIZE: usize;
+
+    /// The arcs of the algorithm's ASN.1 object identifier.
+    const OID: &'static [u64];
+
+    /// Creates a hasher with an empty input.
+    fn new() -> Self;
+
+    /// Absorbs more input.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hasher and returns the digest.
+    fn finalize(self) -> Vec<u8>;
+
+    /// Hashes `data` in one call.
+    fn digest(data: &[u8]) -> Vec<u8> {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
@@ -0,0 +1,401 @@
+// src/hash/sha512.rs
+
+use super::HashFunction;
+
+/// The first 64 bits of the fractional parts of the cube roots of the first 80 primes.
+const K: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// The first 64 bits of the fractional parts of the square roots of the first 8 primes.
+const SHA512_INITIAL_STATE: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The first 64 bits of the fractional parts of the square roots of the 9th to 16th primes.
+const SHA384_INITIAL_STATE: [u64; 8] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+/// Initial state of SHA-512/224, from the IV generation function of FIPS 180-4, 5.3.6.
+const SHA512_224_INITIAL_STATE: [u64; 8] = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+
+/// Initial state of SHA-512/256, from the IV generation function of FIPS 180-4, 5.3.6.
+const SHA512_256_INITIAL_STATE: [u64; 8] = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
+
+/// SHA-512 as specified in FIPS 180-4. SHA-384, SHA-512/224 and SHA-512/256 run the same
+/// compression function from a different initial state and truncate the digest.
+#[derive(Debug, Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: Vec<u8>,
+    length: u128,
+}
+
+impl Sha512 {
+    fn with_state(state: [u64; 8]) -> Self {
+        Sha512 {
+            state,
+            buffer: Vec::with_capacity(128),
+            length: 0,
+        }
+    }
+
+    /// Applies the compression function to one 128-byte block.
+    fn compress(state: &mut [u64; 8], block: &[u8]) {
+        let mut w = [0u64; 80];
+        for (i, word) in block.chunks_exact(8).enumerate() {
+            w[i] = u64::from_be_bytes(word.try_into().expect("8-byte chunk"));
+        }
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for i in 0..80 {
+            let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    fn absorb(&mut self, mut data: &[u8]) {
+        self.length += data.len() as u128;
+
+        if !self.buffer.is_empty() {
+            let take = (128 - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if self.buffer.len() < 128 {
+                return;
+            }
+            Self::compress(&mut self.state, &self.buffer);
+            self.buffer.clear();
+        }
+
+        let mut blocks = data.chunks_exact(128);
+        for block in &mut blocks {
+            Self::compress(&mut self.state, block);
+        }
+        self.buffer.extend_from_slice(blocks.remainder());
+    }
+
+    /// Pads the input and returns the first `length` bytes of the final state.
+    fn finish(mut self, length: usize) -> Vec<u8> {
+        // Pad with a single 1 bit, zeros, and the message length in bits as 128 bits.
+        let bit_length = self.length.wrapping_mul(8);
+        let mut padding = vec![0x80u8];
+        let padded = (self.buffer.len() + 1) % 128;
+        let zeros = if padded <= 112 {
+            112 - padded
+        } else {
+            240 - padded
+        };
+        padding.resize(1 + zeros, 0);
+        padding.extend_from_slice(&bit_length.to_be_bytes());
+        self.absorb(&padding);
+
+        let mut digest: Vec<u8> = self
+            .state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect();
+        digest.truncate(length);
+        digest
+    }
+}
+
+impl HashFunction for Sha512 {
+    const OUTPUT_SIZE: usize = 64;
+    const BLOCK_SIZE: usize = 128;
+    const OID: &'static [u64] = &[2, 16, 840, 1, 101, 3, 4, 2, 3];
+
+    fn new() -> Self {
+        Self::with_state(SHA512_INITIAL_STATE)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.finish(Self::OUTPUT_SIZE)
+    }
+}
+
+/// SHA-384 as specified in FIPS 180-4.
+#[derive(Debug, Clone)]
+pub struct Sha384(Sha512);
+
+impl HashFunction for Sha384 {
+    const OUTPUT_SIZE: usize = 48;
+    const BLOCK_SIZE: usize = 128;
+    const OID: &'static [u64] = &[2, 16, 840, 1, 101, 3, 4, 2, 2];
+
+    fn new() -> Self {
+        Sha384(Sha512::with_state(SHA384_INITIAL_STATE))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finish(Self::OUTPUT_SIZE)
+    }
+}
+
+/// SHA-512/224 as specified in FIPS 180-4.
+#[derive(Debug, Clone)]
+pub struct Sha512_224(Sha512);
+
+impl HashFunction for Sha512_224 {
+    const OUTPUT_SIZE: usize = 28;
+    const BLOCK_SIZE: usize = 128;
+    const OID: &'static [u64] = &[2, 16, 840, 1, 101, 3, 4, 2, 5];
+
+    fn new() -> Self {
+        Sha512_224(Sha512::with_state(SHA512_224_INITIAL_STATE))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finish(Self::OUTPUT_SIZE)
+    }
+}
+
+/// SHA-512/256 as specified in FIPS 180-4.
+#[derive(Debug, Clone)]
+pub struct Sha512_256(Sha512);
+
+impl HashFunction for Sha512_256 {
+    const OUTPUT_SIZE: usize = 32;
+    const BLOCK_SIZE: usize = 128;
+    const OID: &'static [u64] = &[2, 16, 840, 1, 101, 3, 4, 2, 6];
+
+    fn new() -> Self {
+        Sha512_256(Sha512::with_state(SHA512_256_INITIAL_STATE))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.0.finish(Self::OUTPUT_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples from FIPS 180-4 (NIST CSRC "Examples with Intermediate Values").
+    const TWO_BLOCK: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+    #[test]
+    fn sha512_fips_180_4_examples() {
+        assert_eq!(
+            hex::encode(Sha512::digest(b"abc")),
+            concat!(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a",
+                "2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        );
+        assert_eq!(
+            hex::encode(Sha512::digest(TWO_BLOCK)),
+            concat!(
+                "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018",
+                "501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+            )
+        );
+        let mut hasher = Sha512::new();
+        for _ in 0..1000 {
+            hasher.update(&[b'a'; 1000]);
+        }
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            concat!(
+                "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973eb",
+                "de0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b"
+            )
+        );
+    }
+
+    #[test]
+    fn sha384_fips_180_4_examples() {
+        assert_eq!(
+            hex::encode(Sha384::digest(b"abc")),
+            concat!(
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed",
+                "8086072ba1e7cc2358baeca134c825a7"
+            )
+        );
+        assert_eq!(
+            hex::encode(Sha384::digest(TWO_BLOCK)),
+            concat!(
+                "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712",
+                "fcc7c71a557e2db966c3e9fa91746039"
+            )
+        );
+    }
+
+    #[test]
+    fn truncated_sha512_fips_180_4_examples() {
+        assert_eq!(
+            hex::encode(Sha512_224::digest(b"abc")),
+            "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa"
+        );
+        assert_eq!(
+            hex::encode(Sha512_224::digest(TWO_BLOCK)),
+            "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9"
+        );
+        assert_eq!(
+            hex::encode(Sha512_256::digest(b"abc")),
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"
+        );
+        assert_eq!(
+            hex::encode(Sha512_256::digest(TWO_BLOCK)),
+            "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// src/hash_to_curve/elligator2.rs
+
+use super::HashToField;
+use crate::algebra::traits::{Group, Ring};
+use crate::elliptic_curve::{AffinePoint, EdwardsCurve, EdwardsPoint, MontgomeryCurve};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+impl MontgomeryCurve {
+    /// Maps a field element to the curve with Elligator 2 (RFC 9380, section 6.7.1), written
+    /// for K t^2 = s^3 + J s^2 + s with J = A and K = B.
+    ///
+    /// The candidates x1 = -(J / K) / (1 + Z u^2) and x2 = -x1 - J / K satisfy
+    /// g(x2) = Z u^2 g(x1) for g(x) = x^3 + (J / K) x^2 + x / K^2, so exactly one of them gives
+    /// a square when Z is a non-square. The sign of y tells which was used: odd for x1 and
+    /// even for x2.
+    pub fn map_to_curve_elligator2(&self, u: &BigUint, z: &BigUint) -> AffinePoint {
+        let f = &self.field;
+        let invert_or_zero = |x: &BigUint| Group::inverse(f, x).unwrap_or_default();
+        let k_inverse = invert_or_zero(&self.b);
+        let j_over_k = f.mul(&self.a, &k_inverse);
+        let minus_j_over_k = f.sub(&BigUint::zero(), &j_over_k);
+        let g = |x: &BigUint| {
+            let x2_term = f.mul(&f.mul(x, x), &f.add(x, &j_over_k));
+            f.add(&x2_term, &f.mul(x, &f.mul(&k_inverse, &k_inverse)))
+        };
+
+        let denominator = f.add(&BigUint::one(), &f.mul(z, &f.mul(u, u)));
+        let mut x1 = f.mul(&minus_j_over_k, &invert_or_zero(&denominator));
+        if x1.is_zero() {
+            x1 = minus_j_over_k.clone();
+        }
+        let (x, y) = match f.sqrt(&g(&x1)) {
+            Some(y) => (
+                x1,
+                if f.sgn0(&y) {
+                    y
+                } else {
+                    f.sub(&BigUint::zero(), &y)
+                },
+            ),
+            None => {
+                let x2 = f.sub(&minus_j_over_k, &x1);
+                let y = f
+                    .sqrt(&g(&x2))
+                    .expect("g(x2) is a square when g(x1) is not");
+                (
+                    x2,
+                    if f.sgn0(&y) {
+                        f.sub(&BigUint::zero(), &y)
+                    } else {
+                        y
+                    },
+                )
+            }
+        };
+        AffinePoint::new(f.mul(&x, &self.b), f.mul(&y, &self.b))
+    }
+
+    /// Returns the constant Z of Elligator 2 (RFC 9380, appendix H.3): the first non-square
+    /// among 1, -1, 2, -2, .... For Curve25519 this gives 2.
+    pub fn elligator2_z(&self) -> BigUint {
+        let f = &self.field;
+        let mut counter = BigUint::one();
+        loop {
+            for z in [counter.clone(), f.sub(&BigUint::zero(), &counter)] {
+                if !f.is_square(&z) {
+                    return z;
+                }
+            }
+            counter += 1u32;
+        }
+    }
+}
+
+impl EdwardsCurve {
+    /// Maps a point of Curve25519 to edwards25519 with the rational map of RFC 9380,
+    /// appendix D.1: (x, y) = (c s / t, (s - 1) / (s + 1)) with c = sqrt(-486664) of sign 0.
+    /// The exceptional points t = 0 and s = -1, and the point at infinity, go to the
+    /// identity (0, 1).
+    pub fn edwards25519_from_montgomery(&self, point: &AffinePoint) -> EdwardsPoint {
+        let f = &self.field;
+        let AffinePoint::Finite { x: s, y: t } = point else {
+            return EdwardsPoint::new(BigUint::zero(), BigUint::one());
+        };
+        let s_plus_one = f.add(s, &BigUint::one());
+        if t.is_zero() || s_plus_one.is_zero() {
+            return EdwardsPoint::new(BigUint::zero(), BigUint::one());
+        }
+        let c = f
+            .sqrt(&f.sub(&BigUint::zero(), &BigUint::from(486664u32)))
+            .expect("-486664 is a square modulo 2^255 - 19");
+        let c = if f.sgn0(&c) {
+            f.sub(&BigUint::zero(), &c)
+        } else {
+            c
+        };
+        let invert = |x: &BigUint| Group::inverse(f, x).expect("the denominators are nonzero");
+        EdwardsPoint::new(
+            f.mul(&f.mul(&c, s), &invert(t)),
+            f.mul(&f.sub(s, &BigUint::one()), &invert(&s_plus_one)),
+        )
+    }
+}
@@ -0,0 +1,68 @@
+// src/hash_to_curve/expand.rs
+
+use super::HashToCurveError;
+use crate::hash::HashFunction;
+
+/// Prefix for hashing a domain separation tag longer than 255 bytes (RFC 9380, 5.3.3).
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes with `expand_message_xmd`
+/// (RFC 9380, section 5.3.1), built on a Merkle-Damgård hash H with b-byte output:
+///
+/// b_0 = H(Z_pad || msg || I2OSP(len_in_bytes, 2) || 0x00 || DST_prime),
+/// b_1 = H(b_0 || 0x01 || DST_prime) and b_i = H((b_0 xor b_(i - 1)) || i || DST_prime),
+///
+/// where Z_pad is one zero block and DST_prime = DST || I2OSP(len(DST), 1). The output is the
+/// first `len_in_bytes` bytes of b_1 || ... || b_ell.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with `len_in_bytes` bytes.
+/// * `Err(HashToCurveError::EmptyDomainSeparationTag)` for an empty tag.
+/// * `Err(HashToCurveError::OutputTooLong)` if ell = ceil(len_in_bytes / b) exceeds 255 or
+///   `len_in_bytes` exceeds 65535.
+pub fn expand_message_xmd<H: HashFunction>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    if dst.is_empty() {
+        return Err(HashToCurveError::EmptyDomainSeparationTag);
+    }
+    let ell = len_in_bytes.div_ceil(H::OUTPUT_SIZE);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(HashToCurveError::OutputTooLong);
+    }
+    let mut dst_prime = if dst.len() > 255 {
+        let mut hasher = H::new();
+        hasher.update(OVERSIZE_DST_PREFIX);
+        hasher.update(dst);
+        hasher.finalize()
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+
+    let mut hasher = H::new();
+    hasher.update(&vec![0u8; H::BLOCK_SIZE]);
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(&[0]);
+    hasher.update(&dst_prime);
+    let b0 = hasher.finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * H::OUTPUT_SIZE);
+    let mut previous = vec![0u8; H::OUTPUT_SIZE];
+    for i in 1..=ell {
+        // For i = 1 this hashes b_0 xor 0 = b_0.
+        let mixed: Vec<u8> = b0.iter().zip(&previous).map(|(a, b)| a ^ b).collect();
+        let mut hasher = H::new();
+        hasher.update(&mixed);
+        hasher.update(&[i as u8]);
+        hasher.update(&dst_prime);
+        previous = hasher.finalize();
+        uniform_bytes.extend_from_slice(&previous);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
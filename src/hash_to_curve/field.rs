@@ -0,0 +1,83 @@
+// src/hash_to_curve/field.rs
+
+use super::{expand_message_xmd, HashToCurveError};
+use crate::algebra::traits::Algebra;
+use crate::hash::HashFunction;
+use crate::integer_mod_p::IntegerModP;
+use crate::pairing::{Fp2, Fp2Element};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Trait for fields GF(p^m) that messages can be hashed into (RFC 9380, section 5).
+pub trait HashToField: Algebra {
+    /// Hashes `msg` to `count` field elements. Each of the m coordinates of an element is
+    /// read from L = ceil((ceil(log2(p)) + k) / 8) bytes of `expand_message_xmd` output and
+    /// reduced modulo p, so that its bias is at most 2^-k for the security level k =
+    /// `security_bits`.
+    fn hash_to_field<H: HashFunction>(
+        &self,
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+        security_bits: u64,
+    ) -> Result<Vec<Self::Element>, HashToCurveError>;
+
+    /// Returns the sign of an element, sgn0 (RFC 9380, section 4.1): the parity of its first
+    /// nonzero coordinate.
+    fn sgn0(&self, a: &Self::Element) -> bool;
+}
+
+/// Hashes `msg` to `total` integers modulo p, L bytes each.
+fn hash_to_coordinates<H: HashFunction>(
+    p: &BigUint,
+    msg: &[u8],
+    dst: &[u8],
+    total: usize,
+    security_bits: u64,
+) -> Result<Vec<BigUint>, HashToCurveError> {
+    let length = (p.bits() + security_bits).div_ceil(8) as usize;
+    let uniform_bytes = expand_message_xmd::<H>(msg, dst, total * length)?;
+    Ok(uniform_bytes
+        .chunks(length)
+        .map(|chunk| BigUint::from_bytes_be(chunk) % p)
+        .collect())
+}
+
+impl HashToField for IntegerModP {
+    fn hash_to_field<H: HashFunction>(
+        &self,
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+        security_bits: u64,
+    ) -> Result<Vec<BigUint>, HashToCurveError> {
+        hash_to_coordinates::<H>(&self.p, msg, dst, count, security_bits)
+    }
+
+    fn sgn0(&self, a: &BigUint) -> bool {
+        a.bit(0)
+    }
+}
+
+impl HashToField for Fp2 {
+    /// Elements take 2L bytes, read as c0 then c1.
+    fn hash_to_field<H: HashFunction>(
+        &self,
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+        security_bits: u64,
+    ) -> Result<Vec<Fp2Element>, HashToCurveError> {
+        let coordinates =
+            hash_to_coordinates::<H>(&self.base.p, msg, dst, 2 * count, security_bits)?;
+        Ok(coordinates
+            .chunks(2)
+            .map(|pair| self.element(pair[0].clone(), pair[1].clone()))
+            .collect())
+    }
+
+    /// The sign of c0, or of c1 when c0 = 0.
+    fn sgn0(&self, a: &Fp2Element) -> bool {
+        a.c0.bit(0) || (a.c0.is_zero() && a.c1.bit(0))
+    }
+}
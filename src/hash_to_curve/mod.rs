@@ -0,0 +1,42 @@
+// src/hash_to_curve/mod.rs
+
+pub mod elligator2;
+pub mod expand;
+pub mod field;
+pub mod sswu;
+pub mod suites;
+
+pub use expand::expand_message_xmd;
+pub use field::HashToField;
+pub use suites::{
+    encode_to_curve25519, encode_to_edwards25519, encode_to_p256, hash_to_curve25519,
+    hash_to_edwards25519, hash_to_p256,
+};
+
+use std::fmt;
+
+/// Errors related to hashing to fields and curves.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    EmptyDomainSeparationTag,
+    OutputTooLong,
+}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashToCurveError::EmptyDomainSeparationTag => {
+                write!(f, "The domain separation tag must not be empty.")
+            }
+            HashToCurveError::OutputTooLong => write!(
+                f,
+                "expand_message_xmd can produce at most 255 hash blocks and 65535 bytes."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HashToCurveError {}
+
+#[cfg(test)]
+mod tests;
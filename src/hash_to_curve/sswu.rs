@@ -0,0 +1,89 @@
+// src/hash_to_curve/sswu.rs
+
+use super::HashToField;
+use crate::algebra::traits::{Group, Ring};
+use crate::elliptic_curve::{AffinePoint, Curve};
+use crate::integer_mod_p::{PolynomialModP, QuotientRing};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+impl Curve {
+    /// Maps a field element to the curve with the simplified Shallue-van de Woestijne-Ulas
+    /// method (RFC 9380, section 6.6.2), for curves with a, b != 0.
+    ///
+    /// With tv1 = 1 / (Z^2 u^4 + Z u^2) (or 0), the candidate x1 = (-b / a)(1 + tv1) is chosen
+    /// so that when g(x1) = x1^3 + a x1 + b is not a square, g(Z u^2 x1) = Z^3 u^6 g(x1) is.
+    /// The sign of y is set to that of u.
+    ///
+    /// # Panics
+    ///
+    /// If a or b is zero, or if `z` does not satisfy the conditions of `sswu_z`.
+    pub fn map_to_curve_simple_swu(&self, u: &BigUint, z: &BigUint) -> AffinePoint {
+        let f = &self.field;
+        assert!(
+            !self.a.is_zero() && !self.b.is_zero(),
+            "simplified SWU needs a, b != 0"
+        );
+        let invert_or_zero = |x: &BigUint| Group::inverse(f, x).unwrap_or_default();
+        let u2 = f.mul(u, u);
+        let z_u2 = f.mul(z, &u2);
+        let tv1 = invert_or_zero(&f.add(&f.mul(&z_u2, &z_u2), &z_u2));
+        let minus_b_over_a = f.sub(&BigUint::zero(), &f.mul(&self.b, &invert_or_zero(&self.a)));
+        let x1 = if tv1.is_zero() {
+            // Exceptional case u = 0 or Z u^2 = -1: x1 = b / (Z a).
+            f.mul(&self.b, &invert_or_zero(&f.mul(z, &self.a)))
+        } else {
+            f.mul(&minus_b_over_a, &f.add(&BigUint::from(1u32), &tv1))
+        };
+        let (x, y) = match f.sqrt(&self.right_hand_side(&x1)) {
+            Some(y) => (x1, y),
+            None => {
+                let x2 = f.mul(&z_u2, &x1);
+                let y = f
+                    .sqrt(&self.right_hand_side(&x2))
+                    .expect("g(x2) is a square when g(x1) is not");
+                (x2, y)
+            }
+        };
+        let y = if f.sgn0(u) != f.sgn0(&y) {
+            f.sub(&BigUint::zero(), &y)
+        } else {
+            y
+        };
+        AffinePoint::new(x, y)
+    }
+
+    /// Finds the constant Z of simplified SWU with the procedure of RFC 9380, appendix H.2:
+    /// the first of 1, -1, 2, -2, ... that is a non-square other than -1, makes
+    /// x^3 + ax + b - Z irreducible and makes g(b / (Z a)) a square. For P-256 this gives -10.
+    pub fn sswu_z(&self) -> BigUint {
+        let f = &self.field;
+        let mut counter = BigUint::from(1u32);
+        loop {
+            for z in [counter.clone(), f.sub(&BigUint::zero(), &counter)] {
+                if f.is_square(&z) || z == &f.p - 1u32 {
+                    continue;
+                }
+                // A cubic is irreducible exactly when it has no root, that is when it is
+                // coprime to x^p - x.
+                let cubic = self
+                    .right_hand_side_polynomial()
+                    .sub(&PolynomialModP::constant(&z, f), f);
+                let ring = QuotientRing::new(f.clone(), &cubic);
+                let x = PolynomialModP::x();
+                let roots = ring.pow(&x, &f.p).sub(&x, f).gcd(&ring.modulus, f);
+                if !roots.is_one() {
+                    continue;
+                }
+                let exceptional_x = f.mul(
+                    &self.b,
+                    &Group::inverse(f, &f.mul(&z, &self.a)).unwrap_or_default(),
+                );
+                if f.is_square(&self.right_hand_side(&exceptional_x)) {
+                    return z;
+                }
+            }
+            counter += 1u32;
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// src/hash_to_curve/suites.rs
+
+use super::{HashToCurveError, HashToField};
+use crate::elliptic_curve::{
+    AffinePoint, Curve, EdwardsCurve, EdwardsPoint, MontgomeryCurve, NamedCurve,
+};
+use crate::hash::{HashFunction, Sha256, Sha512};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+
+/// Suite identifiers of RFC 9380, section 8. Applications build their domain separation tags
+/// from them, as in "MYAPP-V01-CS01-with-P256_XMD:SHA-256_SSWU_RO_".
+pub const P256_XMD_SHA256_SSWU_RO: &str = "P256_XMD:SHA-256_SSWU_RO_";
+pub const P256_XMD_SHA256_SSWU_NU: &str = "P256_XMD:SHA-256_SSWU_NU_";
+pub const CURVE25519_XMD_SHA512_ELL2_RO: &str = "curve25519_XMD:SHA-512_ELL2_RO_";
+pub const CURVE25519_XMD_SHA512_ELL2_NU: &str = "curve25519_XMD:SHA-512_ELL2_NU_";
+pub const EDWARDS25519_XMD_SHA512_ELL2_RO: &str = "edwards25519_XMD:SHA-512_ELL2_RO_";
+pub const EDWARDS25519_XMD_SHA512_ELL2_NU: &str = "edwards25519_XMD:SHA-512_ELL2_NU_";
+
+/// Security level k of all the suites here, in bits.
+const SECURITY_BITS: u64 = 128;
+
+/// The constant Z of the P-256 suites, -10.
+const P256_Z: u32 = 10;
+
+/// The constant Z of the curve25519 and edwards25519 suites.
+const CURVE25519_Z: u32 = 2;
+
+/// Cofactor of Curve25519 and edwards25519.
+const CURVE25519_COFACTOR: u32 = 8;
+
+/// Runs the common part of `hash_to_curve` and `encode_to_curve` (RFC 9380, section 3): the
+/// random-oracle variant maps two field elements and adds the images, which makes the output
+/// indistinguishable from a random point; the nonuniform variant maps a single element, which
+/// reaches only part of the curve. Cofactor clearing is left to the caller.
+fn map_field_elements<H, P>(
+    field: &IntegerModP,
+    msg: &[u8],
+    dst: &[u8],
+    random_oracle: bool,
+    map: impl Fn(&BigUint) -> P,
+    add: impl Fn(&P, &P) -> P,
+) -> Result<P, HashToCurveError>
+where
+    H: HashFunction,
+{
+    let count = if random_oracle { 2 } else { 1 };
+    let u = field.hash_to_field::<H>(msg, dst, count, SECURITY_BITS)?;
+    let q0 = map(&u[0]);
+    Ok(match u.get(1) {
+        Some(u1) => add(&q0, &map(u1)),
+        None => q0,
+    })
+}
+
+/// Hashes to P-256 with P256_XMD:SHA-256_SSWU_RO_. The cofactor is 1.
+pub fn hash_to_p256(msg: &[u8], dst: &[u8]) -> Result<AffinePoint, HashToCurveError> {
+    p256_suite(msg, dst, true)
+}
+
+/// Encodes to P-256 with P256_XMD:SHA-256_SSWU_NU_.
+pub fn encode_to_p256(msg: &[u8], dst: &[u8]) -> Result<AffinePoint, HashToCurveError> {
+    p256_suite(msg, dst, false)
+}
+
+/// Hashes to Curve25519 with curve25519_XMD:SHA-512_ELL2_RO_, returning a point of the
+/// prime-order subgroup in (u, v) coordinates.
+pub fn hash_to_curve25519(msg: &[u8], dst: &[u8]) -> Result<AffinePoint, HashToCurveError> {
+    curve25519_suite(msg, dst, true)
+}
+
+/// Encodes to Curve25519 with curve25519_XMD:SHA-512_ELL2_NU_.
+pub fn encode_to_curve25519(msg: &[u8], dst: &[u8]) -> Result<AffinePoint, HashToCurveError> {
+    curve25519_suite(msg, dst, false)
+}
+
+/// Hashes to edwards25519 with edwards25519_XMD:SHA-512_ELL2_RO_.
+pub fn hash_to_edwards25519(msg: &[u8], dst: &[u8]) -> Result<EdwardsPoint, HashToCurveError> {
+    edwards25519_suite(msg, dst, true)
+}
+
+/// Encodes to edwards25519 with edwards25519_XMD:SHA-512_ELL2_NU_.
+pub fn encode_to_edwards25519(msg: &[u8], dst: &[u8]) -> Result<EdwardsPoint, HashToCurveError> {
+    edwards25519_suite(msg, dst, false)
+}
+
+fn p256_suite(
+    msg: &[u8],
+    dst: &[u8],
+    random_oracle: bool,
+) -> Result<AffinePoint, HashToCurveError> {
+    let curve = Curve::named(NamedCurve::P256);
+    let z = &curve.field.p - P256_Z;
+    map_field_elements::<Sha256, _>(
+        &curve.field,
+        msg,
+        dst,
+        random_oracle,
+        |u| curve.map_to_curve_simple_swu(u, &z),
+        |p, q| curve.add(p, q),
+    )
+}
+
+fn curve25519_suite(
+    msg: &[u8],
+    dst: &[u8],
+    random_oracle: bool,
+) -> Result<AffinePoint, HashToCurveError> {
+    let curve = MontgomeryCurve::curve25519();
+    let z = BigUint::from(CURVE25519_Z);
+    let point = map_field_elements::<Sha512, _>(
+        &curve.field,
+        msg,
+        dst,
+        random_oracle,
+        |u| curve.map_to_curve_elligator2(u, &z),
+        |p, q| curve.add(p, q),
+    )?;
+    Ok(curve.scalar_mul(&BigUint::from(CURVE25519_COFACTOR), &point))
+}
+
+fn edwards25519_suite(
+    msg: &[u8],
+    dst: &[u8],
+    random_oracle: bool,
+) -> Result<EdwardsPoint, HashToCurveError> {
+    let montgomery = MontgomeryCurve::curve25519();
+    let curve = EdwardsCurve::edwards25519();
+    let z = BigUint::from(CURVE25519_Z);
+    let point = map_field_elements::<Sha512, _>(
+        &curve.field,
+        msg,
+        dst,
+        random_oracle,
+        |u| curve.edwards25519_from_montgomery(&montgomery.map_to_curve_elligator2(u, &z)),
+        |p, q| curve.add(p, q),
+    )?;
+    Ok(curve.scalar_mul(&BigUint::from(CURVE25519_COFACTOR), &point))
+}
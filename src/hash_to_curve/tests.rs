@@ -0,0 +1,191 @@
+// src/hash_to_curve/tests.rs
+
+// Known-answer tests from RFC 9380: expand_message_xmd (appendix K) and the P256, curve25519
+// and edwards25519 suites (appendix J), stored in test-data/hash_to_curve.
+
+use super::{
+    encode_to_p256, expand_message_xmd, hash_to_curve25519, hash_to_edwards25519, hash_to_p256,
+    HashToCurveError, HashToField,
+};
+use crate::elliptic_curve::AffinePoint;
+use crate::hash::{HashFunction, Sha256, Sha512};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+
+const VECTORS: &str = include_str!("../../test-data/hash_to_curve/rfc9380.txt");
+
+/// The messages of the RFC, by the names used in the vector file.
+const MESSAGES: [&str; 5] = ["empty", "abc", "abcdef0123456789", "q128", "a512"];
+
+fn message(name: &str) -> Vec<u8> {
+    match name {
+        "empty" => Vec::new(),
+        "q128" => [b"q128_".as_slice(), &[b'q'; 128]].concat(),
+        "a512" => [b"a512_".as_slice(), &[b'a'; 512]].concat(),
+        other => other.as_bytes().to_vec(),
+    }
+}
+
+/// Returns the fields after the first two of every line whose first two fields match.
+fn lines<'a>(first: &'a str, second: &'a str) -> impl Iterator<Item = Vec<&'a str>> + 'a {
+    VECTORS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .filter(move |fields| fields.len() > 2 && fields[0] == first && fields[1] == second)
+        .map(|fields| fields[2..].to_vec())
+}
+
+/// Looks up one value of a suite vector as an integer.
+fn value(suite: &str, message: &str, name: &str) -> BigUint {
+    lines(suite, message)
+        .find(|fields| fields[0] == name)
+        .map(|fields| BigUint::parse_bytes(fields[1].as_bytes(), 16).unwrap())
+        .unwrap_or_else(|| panic!("missing vector {} {} {}", suite, message, name))
+}
+
+fn dst(suite: &str) -> Vec<u8> {
+    format!("QUUX-V01-CS02-with-{}", suite).into_bytes()
+}
+
+/// Checks the hash-to-field outputs and the point (P.x, P.y) of a suite for every message.
+fn check_suite(
+    suite: &str,
+    field: &IntegerModP,
+    count: usize,
+    hash_to_field: impl Fn(&IntegerModP, &[u8], &[u8], usize) -> Vec<BigUint>,
+    map: impl Fn(&[u8], &[u8]) -> (BigUint, BigUint),
+) {
+    let dst = dst(suite);
+    for name in MESSAGES {
+        let msg = message(name);
+        let u = hash_to_field(field, &msg, &dst, count);
+        for (i, u_i) in u.iter().enumerate() {
+            assert_eq!(
+                u_i,
+                &value(suite, name, &format!("u{}", i)),
+                "{} {}",
+                suite,
+                name
+            );
+        }
+        let expected = (value(suite, name, "P.x"), value(suite, name, "P.y"));
+        assert_eq!(map(&msg, &dst), expected, "{} {}", suite, name);
+    }
+}
+
+fn check_expander<H: HashFunction>(expander: &str, dst: &[u8]) {
+    let mut count = 0;
+    for name in MESSAGES {
+        for fields in lines(expander, name) {
+            let length: usize = fields[0].parse().unwrap();
+            let output = expand_message_xmd::<H>(&message(name), dst, length).unwrap();
+            assert_eq!(
+                hex::encode(output),
+                fields[1],
+                "{} {} {}",
+                expander,
+                name,
+                length
+            );
+            count += 1;
+        }
+    }
+    assert_eq!(count, 10);
+}
+
+fn affine(point: AffinePoint) -> (BigUint, BigUint) {
+    match point {
+        AffinePoint::Finite { x, y } => (x, y),
+        AffinePoint::Infinity => panic!("the suites never return the identity here"),
+    }
+}
+
+fn p256_field() -> IntegerModP {
+    let p = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    IntegerModP::new(BigUint::parse_bytes(p.as_bytes(), 16).unwrap()).unwrap()
+}
+
+fn curve25519_field() -> IntegerModP {
+    IntegerModP::new((BigUint::from(1u32) << 255u32) - 19u32).unwrap()
+}
+
+#[test]
+fn expand_message_xmd_vectors() {
+    check_expander::<Sha256>(
+        "expander-SHA256-128",
+        b"QUUX-V01-CS02-with-expander-SHA256-128",
+    );
+    let long_dst = format!(
+        "QUUX-V01-CS02-with-expander-SHA256-128-long-DST-{}",
+        "1".repeat(208)
+    );
+    check_expander::<Sha256>("expander-SHA256-128-long-DST", long_dst.as_bytes());
+    check_expander::<Sha512>(
+        "expander-SHA512-256",
+        b"QUUX-V01-CS02-with-expander-SHA512-256",
+    );
+}
+
+#[test]
+fn expand_message_xmd_rejects_bad_inputs() {
+    assert_eq!(
+        expand_message_xmd::<Sha256>(b"msg", b"", 32),
+        Err(HashToCurveError::EmptyDomainSeparationTag)
+    );
+    assert_eq!(
+        expand_message_xmd::<Sha256>(b"msg", b"DST", 255 * 32 + 1),
+        Err(HashToCurveError::OutputTooLong)
+    );
+    assert_eq!(
+        expand_message_xmd::<Sha256>(b"msg", b"DST", 255 * 32).map(|out| out.len()),
+        Ok(255 * 32)
+    );
+}
+
+#[test]
+fn p256_xmd_sha256_sswu_ro_vectors() {
+    check_suite(
+        "P256_XMD:SHA-256_SSWU_RO_",
+        &p256_field(),
+        2,
+        |field, msg, dst, count| field.hash_to_field::<Sha256>(msg, dst, count, 128).unwrap(),
+        |msg, dst| affine(hash_to_p256(msg, dst).unwrap()),
+    );
+}
+
+#[test]
+fn p256_xmd_sha256_sswu_nu_vectors() {
+    check_suite(
+        "P256_XMD:SHA-256_SSWU_NU_",
+        &p256_field(),
+        1,
+        |field, msg, dst, count| field.hash_to_field::<Sha256>(msg, dst, count, 128).unwrap(),
+        |msg, dst| affine(encode_to_p256(msg, dst).unwrap()),
+    );
+}
+
+#[test]
+fn curve25519_xmd_sha512_ell2_ro_vectors() {
+    check_suite(
+        "curve25519_XMD:SHA-512_ELL2_RO_",
+        &curve25519_field(),
+        2,
+        |field, msg, dst, count| field.hash_to_field::<Sha512>(msg, dst, count, 128).unwrap(),
+        |msg, dst| affine(hash_to_curve25519(msg, dst).unwrap()),
+    );
+}
+
+#[test]
+fn edwards25519_xmd_sha512_ell2_ro_vectors() {
+    check_suite(
+        "edwards25519_XMD:SHA-512_ELL2_RO_",
+        &curve25519_field(),
+        2,
+        |field, msg, dst, count| field.hash_to_field::<Sha512>(msg, dst, count, 128).unwrap(),
+        |msg, dst| {
+            let point = hash_to_edwards25519(msg, dst).unwrap();
+            (point.x, point.y)
+        },
+    );
+}
@@ -0,0 +1,148 @@
+// src/hyperelliptic/curve.rs
+
+use super::HyperellipticError;
+use crate::algebra::traits::FiniteField;
+use crate::integer_mod_p::PolynomialModP;
+use num_bigint::BigUint;
+
+/// Genus of the curves here: f has degree 2g + 1 = 5.
+pub const GENUS: usize = 2;
+
+/// Random x-coordinates tried by `random_point` before giving up. About half of all x give
+/// points, so only curves over tiny fields with almost no points run out.
+const RANDOM_POINT_ATTEMPTS: usize = 256;
+
+/// A genus-2 hyperelliptic curve y^2 + h(x)y = f(x) over a finite field.
+///
+/// The curve is in imaginary form: f is monic of degree 5 and h has degree at most 2, so there
+/// is a single point at infinity and every element of the Jacobian has a unique reduced
+/// Mumford representation. Over fields of characteristic 2, h must be nonzero.
+#[derive(Debug, Clone)]
+pub struct HyperellipticCurve<F: FiniteField> {
+    pub field: F,                      // Field of definition
+    pub h: PolynomialModP<F::Element>, // Coefficient of y, of degree at most 2
+    pub f: PolynomialModP<F::Element>, // Monic right-hand side of degree 5
+}
+
+impl<F: FiniteField> HyperellipticCurve<F> {
+    /// Creates the curve y^2 + h(x)y = f(x) from coefficients in ascending order of degree.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HyperellipticCurve)` if the coefficients are field elements, f is monic of degree
+    ///   5, h has degree at most 2 and the curve is non-singular.
+    /// * `Err(HyperellipticError)` describing the first failed check.
+    pub fn new(
+        field: F,
+        h: Vec<F::Element>,
+        f: Vec<F::Element>,
+    ) -> Result<Self, HyperellipticError> {
+        if !h.iter().chain(&f).all(|c| field.is_element(c)) {
+            return Err(HyperellipticError::CoefficientOutOfRange);
+        }
+        let h = PolynomialModP::new(h, &field);
+        let f = PolynomialModP::new(f, &field);
+        let f_is_monic = f
+            .leading_coefficient()
+            .is_some_and(|lead| field.equal(lead, &field.one()));
+        if f.degree() != Some(2 * GENUS + 1) || !f_is_monic || h.degree() > Some(GENUS) {
+            return Err(HyperellipticError::InvalidDegree);
+        }
+        let curve = HyperellipticCurve { field, h, f };
+        if curve.is_singular() {
+            return Err(HyperellipticError::SingularCurve);
+        }
+        Ok(curve)
+    }
+
+    /// Returns true if the affine curve has a singular point over the algebraic closure.
+    ///
+    /// In odd characteristic, completing the square turns the curve into
+    /// (2y + h)^2 = 4f + h^2, which is singular exactly when 4f + h^2 has a repeated root. In
+    /// characteristic 2 a singular point has h(x) = 0 and h'(x) y = f'(x) with y^2 = f(x), so
+    /// its x is a common root of h and f'^2 + h'^2 f.
+    fn is_singular(&self) -> bool {
+        let field = &self.field;
+        let common_root = |a: &PolynomialModP<F::Element>, b: &PolynomialModP<F::Element>| {
+            a.gcd(b, field).degree() != Some(0)
+        };
+        if field.characteristic() == BigUint::from(2u32) {
+            let f_prime = self.f.derivative(field);
+            let h_prime = self.h.derivative(field);
+            let condition = f_prime
+                .mul(&f_prime, field)
+                .add(&h_prime.mul(&h_prime, field).mul(&self.f, field), field);
+            self.h.is_zero() || common_root(&self.h, &condition)
+        } else {
+            let two = field.add(&field.one(), &field.one());
+            let four = field.add(&two, &two);
+            let g = self
+                .f
+                .scale(&four, field)
+                .add(&self.h.mul(&self.h, field), field);
+            common_root(&g, &g.derivative(field))
+        }
+    }
+
+    /// Returns true if (x, y) satisfies y^2 + h(x)y = f(x).
+    pub fn is_on_curve(&self, x: &F::Element, y: &F::Element) -> bool {
+        let field = &self.field;
+        let left = field.mul(y, &field.add(y, &self.h.evaluate(x, field)));
+        field.equal(&left, &self.f.evaluate(x, field))
+    }
+
+    /// Returns the y-coordinate of the opposite point (x, -h(x) - y).
+    pub fn opposite_y(&self, x: &F::Element, y: &F::Element) -> F::Element {
+        let field = &self.field;
+        field.sub(&field.neg(&self.h.evaluate(x, field)), y)
+    }
+
+    /// Returns a point with the given x-coordinate, if there is one. The other point is
+    /// (x, `opposite_y(x, y)`).
+    pub fn lift_x(&self, x: &F::Element) -> Option<(F::Element, F::Element)> {
+        let field = &self.field;
+        let y = field.solve_quadratic(&self.h.evaluate(x, field), &self.f.evaluate(x, field))?;
+        Some((x.clone(), y))
+    }
+
+    /// Returns a random affine point, choosing between the two points above a random x with
+    /// a coin flip.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((x, y))` on the curve.
+    /// * `None` if no point turned up in `RANDOM_POINT_ATTEMPTS` tries.
+    pub fn random_point(&self) -> Option<(F::Element, F::Element)> {
+        let field = &self.field;
+        (0..RANDOM_POINT_ATTEMPTS).find_map(|_| {
+            let (x, y) = self.lift_x(&field.random())?;
+            if rand::random::<bool>() {
+                let other = self.opposite_y(&x, &y);
+                Some((x, other))
+            } else {
+                Some((x, y))
+            }
+        })
+    }
+
+    /// Lists the affine points by trying every x and solving for y, for small fields.
+    pub fn affine_points(&self) -> Vec<(F::Element, F::Element)> {
+        let field = &self.field;
+        let mut points = Vec::new();
+        for x in field.elements() {
+            if let Some((x, y)) = self.lift_x(&x) {
+                let other = self.opposite_y(&x, &y);
+                if !field.equal(&y, &other) {
+                    points.push((x.clone(), other));
+                }
+                points.push((x, y));
+            }
+        }
+        points
+    }
+
+    /// Counts the points over the field of definition, including the point at infinity.
+    pub fn count_points(&self) -> BigUint {
+        BigUint::from(self.affine_points().len() + 1)
+    }
+}
@@ -0,0 +1,287 @@
+// src/hyperelliptic/jacobian.rs
+
+use super::curve::GENUS;
+use super::{HyperellipticCurve, HyperellipticError};
+use crate::algebra::traits::{Algebra, ElementEncoding, FiniteField, Group, Membership};
+use crate::integer_mod_p::PolynomialModP;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// A divisor class in Mumford representation (u, v).
+///
+/// In reduced form u is monic of degree at most 2, deg v < deg u and u divides
+/// v^2 + hv - f: the roots of u are the x-coordinates of the points in the divisor and v
+/// interpolates their y-coordinates. The identity is (1, 0).
+#[derive(Debug, Clone)]
+pub struct MumfordDivisor<E> {
+    pub u: PolynomialModP<E>, // Monic, with the x-coordinates as roots
+    pub v: PolynomialModP<E>, // Interpolates the y-coordinates
+}
+
+/// The Jacobian of a genus-2 curve, the group of degree-zero divisor classes, with Cantor's
+/// algorithm as the group law.
+///
+/// Implementing `Group`, `ElementEncoding` and `Membership` lets the generic Diffie-Hellman
+/// and ElGamal code, including the baby-step giant-step discrete logarithm, run on it.
+#[derive(Debug, Clone)]
+pub struct Jacobian<F: FiniteField> {
+    pub curve: HyperellipticCurve<F>, // Curve whose divisor classes form the group
+}
+
+impl<F: FiniteField> Jacobian<F> {
+    /// Creates the Jacobian of `curve`.
+    pub fn new(curve: HyperellipticCurve<F>) -> Self {
+        Jacobian { curve }
+    }
+
+    /// Returns the class of P - ∞ for an affine point P = (x, y), that is (x - x_P, y_P).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MumfordDivisor)` if the point is on the curve.
+    /// * `Err(HyperellipticError::PointNotOnCurve)` otherwise.
+    pub fn divisor_from_point(
+        &self,
+        x: &F::Element,
+        y: &F::Element,
+    ) -> Result<MumfordDivisor<F::Element>, HyperellipticError> {
+        let field = &self.curve.field;
+        if !field.is_element(x) || !field.is_element(y) || !self.curve.is_on_curve(x, y) {
+            return Err(HyperellipticError::PointNotOnCurve);
+        }
+        Ok(MumfordDivisor {
+            u: PolynomialModP::linear(x, field),
+            v: PolynomialModP::constant(y, field),
+        })
+    }
+
+    /// Returns a random element as the sum of two random points. This reaches every divisor
+    /// class of the form P + Q - 2∞ with P and Q rational, which is most of the group, but not
+    /// uniformly.
+    ///
+    /// # Panics
+    ///
+    /// If the curve has so few points that `random_point` finds none.
+    pub fn random_element(&self) -> MumfordDivisor<F::Element> {
+        let mut divisor = self.identity();
+        for _ in 0..GENUS {
+            let (x, y) = self
+                .curve
+                .random_point()
+                .expect("the curve should have affine points");
+            let point = self
+                .divisor_from_point(&x, &y)
+                .expect("random points are on the curve");
+            divisor = self.combine(&divisor, &point);
+        }
+        divisor
+    }
+
+    /// Cantor's composition: returns a divisor equivalent to D1 + D2, not yet reduced.
+    ///
+    /// With d = gcd(u1, u2, v1 + v2 + h) = s1 u1 + s2 u2 + s3 (v1 + v2 + h), the sum is
+    /// u = u1 u2 / d^2 and v = (s1 u1 v2 + s2 u2 v1 + s3 (v1 v2 + f)) / d mod u. The factor d
+    /// cancels points of D1 that are opposite to points of D2.
+    pub fn compose(
+        &self,
+        a: &MumfordDivisor<F::Element>,
+        b: &MumfordDivisor<F::Element>,
+    ) -> MumfordDivisor<F::Element> {
+        let field = &self.curve.field;
+        let (d1, e1, e2) = a.u.extended_gcd(&b.u, field);
+        let v_sum = a.v.add(&b.v, field).add(&self.curve.h, field);
+        let (d, c1, c2) = d1.extended_gcd(&v_sum, field);
+        let (s1, s2, s3) = (c1.mul(&e1, field), c1.mul(&e2, field), c2);
+
+        let u = a.u.mul(&b.u, field).div_rem(&d.mul(&d, field), field).0;
+        let numerator = s1
+            .mul(&a.u, field)
+            .mul(&b.v, field)
+            .add(&s2.mul(&b.u, field).mul(&a.v, field), field)
+            .add(
+                &s3.mul(&a.v.mul(&b.v, field).add(&self.curve.f, field), field),
+                field,
+            );
+        let v = numerator.div_rem(&d, field).0.rem(&u, field);
+        MumfordDivisor { u, v }
+    }
+
+    /// Cantor's reduction: while deg u > 2, replaces (u, v) by the equivalent
+    /// ((f - hv - v^2) / u, (-h - v) mod u'), which lowers the degree of u by at least 2.
+    pub fn reduce(&self, divisor: &MumfordDivisor<F::Element>) -> MumfordDivisor<F::Element> {
+        let field = &self.curve.field;
+        let (h, f) = (&self.curve.h, &self.curve.f);
+        let (mut u, mut v) = (divisor.u.clone(), divisor.v.clone());
+        while u.degree().is_some_and(|degree| degree > GENUS) {
+            let next_u = f
+                .sub(&h.mul(&v, field), field)
+                .sub(&v.mul(&v, field), field)
+                .div_rem(&u, field)
+                .0;
+            v = h.add(&v, field).neg(field).rem(&next_u, field);
+            u = next_u;
+        }
+        let u = u.monic(field);
+        let v = v.rem(&u, field);
+        MumfordDivisor { u, v }
+    }
+
+    /// Computes the order of the Jacobian by listing every reduced divisor: all monic u of
+    /// degree at most 2 and all v of lower degree with u | v^2 + hv - f. This takes about q^4
+    /// checks, so it is only meant for fields of a few dozen elements, to test faster methods
+    /// and the group law against.
+    pub fn order_brute_force(&self) -> BigUint {
+        let field = &self.curve.field;
+        let elements = field.elements();
+        let mut order = BigUint::zero();
+        // Coefficient vectors of length n over the field.
+        let tuples = |n: usize| {
+            (0..n).fold(vec![Vec::new()], |tuples: Vec<Vec<F::Element>>, _| {
+                tuples
+                    .iter()
+                    .flat_map(|t| {
+                        elements.iter().map(move |c| {
+                            let mut t = t.clone();
+                            t.push(c.clone());
+                            t
+                        })
+                    })
+                    .collect()
+            })
+        };
+        for degree in 0..=GENUS {
+            let v_candidates: Vec<_> = tuples(degree)
+                .into_iter()
+                .map(|v| PolynomialModP::new(v, field))
+                .collect();
+            for mut u in tuples(degree) {
+                u.push(field.one());
+                let u = PolynomialModP::new(u, field);
+                // v^2 + hv - f = v (v + (h mod u)) - (f mod u) modulo u.
+                let h_mod_u = self.curve.h.rem(&u, field);
+                let f_mod_u = self.curve.f.rem(&u, field);
+                for v in &v_candidates {
+                    let residue = v
+                        .mul(&v.add(&h_mod_u, field), field)
+                        .sub(&f_mod_u, field)
+                        .rem(&u, field);
+                    if residue.is_zero() {
+                        order += 1u32;
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns true if u divides v^2 + hv - f.
+    fn satisfies_mumford_condition(&self, divisor: &MumfordDivisor<F::Element>) -> bool {
+        let field = &self.curve.field;
+        let MumfordDivisor { u, v } = divisor;
+        v.mul(&v.add(&self.curve.h, field), field)
+            .sub(&self.curve.f, field)
+            .rem(u, field)
+            .is_zero()
+    }
+
+    /// Returns true if both divisors are the same reduced divisor.
+    pub fn equals(&self, a: &MumfordDivisor<F::Element>, b: &MumfordDivisor<F::Element>) -> bool {
+        let field = &self.curve.field;
+        a.u.equals(&b.u, field) && a.v.equals(&b.v, field)
+    }
+}
+
+impl<F: FiniteField> Algebra for Jacobian<F> {
+    type Element = MumfordDivisor<F::Element>;
+}
+
+impl<F: FiniteField> Group for Jacobian<F> {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.reduce(&self.compose(a, b))
+    }
+
+    fn identity(&self) -> Self::Element {
+        MumfordDivisor {
+            u: PolynomialModP::constant(&self.curve.field.one(), &self.curve.field),
+            v: PolynomialModP::zero(),
+        }
+    }
+
+    /// The inverse of (u, v) is (u, -h - v mod u), which swaps every point for its opposite.
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        let field = &self.curve.field;
+        Some(MumfordDivisor {
+            u: a.u.clone(),
+            v: self.curve.h.add(&a.v, field).neg(field).rem(&a.u, field),
+        })
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        let mut result = self.identity();
+        for i in (0..exp.bits()).rev() {
+            result = self.combine(&result, &result);
+            if exp.bit(i) {
+                result = self.combine(&result, a);
+            }
+        }
+        result
+    }
+}
+
+impl<F: FiniteField> Membership for Jacobian<F> {
+    /// Elements are reduced divisors: u monic of degree at most 2, deg v < deg u,
+    /// coefficients in the field and u | v^2 + hv - f.
+    fn is_element(&self, a: &Self::Element) -> bool {
+        let field = &self.curve.field;
+        let u_is_monic =
+            a.u.leading_coefficient()
+                .is_some_and(|lead| field.equal(lead, &field.one()));
+        a.u.coeffs
+            .iter()
+            .chain(&a.v.coeffs)
+            .all(|c| field.is_element(c))
+            && u_is_monic
+            && a.u.degree() <= Some(GENUS)
+            && a.v.degree() < a.u.degree()
+            && self.satisfies_mumford_condition(a)
+    }
+}
+
+impl<F: FiniteField> ElementEncoding for Jacobian<F> {
+    /// Encodes (u, v) as the degree of u in one byte, followed by the two low coefficients of
+    /// u and then the two coefficients of v, each as a field element with missing
+    /// coefficients set to zero.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        let field = &self.curve.field;
+        let zero = field.zero();
+        let mut bytes = vec![a.u.degree().unwrap_or(0) as u8];
+        for polynomial in [&a.u, &a.v] {
+            for i in 0..GENUS {
+                bytes.extend(field.encode_element(polynomial.coeffs.get(i).unwrap_or(&zero)));
+            }
+        }
+        bytes
+    }
+
+    /// Accepts exactly the output of `encode_element` for a reduced divisor.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        let field = &self.curve.field;
+        let (&degree, rest) = bytes.split_first()?;
+        let length = field.encode_element(&field.zero()).len();
+        if degree as usize > GENUS || rest.len() != 2 * GENUS * length {
+            return None;
+        }
+        let coefficients = rest
+            .chunks(length)
+            .map(|chunk| field.decode_element(chunk))
+            .collect::<Option<Vec<_>>>()?;
+        let (u, v) = coefficients.split_at(GENUS);
+        let mut u = u[..degree as usize].to_vec();
+        u.push(field.one());
+        let divisor = MumfordDivisor {
+            u: PolynomialModP::new(u, field),
+            v: PolynomialModP::new(v.to_vec(), field),
+        };
+        (self.is_element(&divisor) && self.encode_element(&divisor) == bytes).then_some(divisor)
+    }
+}
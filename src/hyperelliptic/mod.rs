@@ -0,0 +1,39 @@
+// src/hyperelliptic/mod.rs
+
+pub mod curve;
+pub mod jacobian;
+
+pub use curve::HyperellipticCurve;
+pub use jacobian::{Jacobian, MumfordDivisor};
+
+use std::fmt;
+
+/// Errors related to hyperelliptic curves and their Jacobians.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HyperellipticError {
+    CoefficientOutOfRange,
+    InvalidDegree,
+    SingularCurve,
+    PointNotOnCurve,
+}
+
+impl fmt::Display for HyperellipticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HyperellipticError::CoefficientOutOfRange => {
+                write!(f, "The curve coefficients must be reduced field elements.")
+            }
+            HyperellipticError::InvalidDegree => write!(
+                f,
+                "f must be monic of degree 5 and h of degree at most 2 for a genus-2 curve."
+            ),
+            HyperellipticError::SingularCurve => write!(f, "The curve is singular."),
+            HyperellipticError::PointNotOnCurve => write!(f, "The point is not on the curve."),
+        }
+    }
+}
+
+impl std::error::Error for HyperellipticError {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+// src/hyperelliptic/tests.rs
+
+// Checks of Cantor's group law on genus-2 Jacobians over small prime and binary fields: the
+// divisors P - ∞ generate a group whose size matches the brute-force count of reduced
+// divisors, every element is killed by that order, and the group axioms hold.
+
+use super::{HyperellipticCurve, HyperellipticError, Jacobian, MumfordDivisor};
+use crate::algebra::traits::{ElementEncoding, FiniteField, Group, Membership};
+use crate::binary_extension_field::BinaryExtensionField;
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashSet, VecDeque};
+
+fn prime_field(p: u32) -> IntegerModP {
+    IntegerModP::new_valid_prime(BigUint::from(p))
+}
+
+fn coefficients(values: &[u32]) -> Vec<BigUint> {
+    values.iter().map(|&c| BigUint::from(c)).collect()
+}
+
+/// Lists the subgroup generated by the divisors P - ∞ of the affine points, by closing the
+/// identity under Cantor's addition of each generator.
+fn generated_group<F: FiniteField>(jacobian: &Jacobian<F>) -> Vec<MumfordDivisor<F::Element>> {
+    let generators: Vec<_> = jacobian
+        .curve
+        .affine_points()
+        .iter()
+        .map(|(x, y)| jacobian.divisor_from_point(x, y).unwrap())
+        .collect();
+    let identity = jacobian.identity();
+    let mut seen = HashSet::from([jacobian.encode_element(&identity)]);
+    let mut elements = vec![identity.clone()];
+    let mut queue = VecDeque::from([identity]);
+    while let Some(element) = queue.pop_front() {
+        for generator in &generators {
+            let sum = jacobian.combine(&element, generator);
+            if seen.insert(jacobian.encode_element(&sum)) {
+                elements.push(sum.clone());
+                queue.push_back(sum);
+            }
+        }
+    }
+    elements
+}
+
+/// Compares the group generated under Cantor's law with `order_brute_force` and checks the
+/// Hasse-Weil bounds (sqrt(q) - 1)^4 <= #J <= (sqrt(q) + 1)^4, then tests the group axioms on
+/// random elements.
+fn check_jacobian<F: FiniteField>(jacobian: &Jacobian<F>, seed: u64) {
+    let order = jacobian.order_brute_force();
+    let q: f64 = jacobian.curve.field.size().to_string().parse().unwrap();
+    let n: f64 = order.to_string().parse().unwrap();
+    assert!((q.sqrt() - 1.0).powi(4) <= n && n <= (q.sqrt() + 1.0).powi(4));
+
+    let elements = generated_group(jacobian);
+    assert_eq!(BigUint::from(elements.len()), order);
+    let identity = jacobian.identity();
+    for element in &elements {
+        assert!(jacobian.is_element(element));
+        assert!(jacobian.equals(&jacobian.pow(element, &order), &identity));
+        let decoded = jacobian.decode_element(&jacobian.encode_element(element));
+        assert!(jacobian.equals(&decoded.unwrap(), element));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut pick = || &elements[rng.gen_range(0..elements.len())];
+    for _ in 0..100 {
+        let (a, b, c) = (pick(), pick(), pick());
+        let left = jacobian.combine(&jacobian.combine(a, b), c);
+        let right = jacobian.combine(a, &jacobian.combine(b, c));
+        assert!(jacobian.equals(&left, &right));
+        assert!(jacobian.equals(&jacobian.combine(a, b), &jacobian.combine(b, a)));
+        assert!(jacobian.equals(&jacobian.combine(a, &identity), a));
+        let inverse = jacobian.inverse(a).unwrap();
+        assert!(jacobian.equals(&jacobian.combine(a, &inverse), &identity));
+    }
+}
+
+#[test]
+fn group_law_matches_brute_force_order_over_prime_fields() {
+    // (p, h, f) with coefficients in ascending order of degree.
+    // On these curves the divisors P - ∞ generate the whole Jacobian.
+    let curves: [(u32, &[u32], &[u32]); 4] = [
+        (3, &[2, 0, 2], &[2, 2, 2, 1, 1, 1]),
+        (5, &[], &[0, 1, 1, 0, 3, 1]),
+        (7, &[3, 0, 6], &[4, 2, 2, 6, 2, 1]),
+        (11, &[], &[4, 2, 10, 9, 3, 1]),
+    ];
+    for (i, (p, h, f)) in curves.into_iter().enumerate() {
+        let curve =
+            HyperellipticCurve::new(prime_field(p), coefficients(h), coefficients(f)).unwrap();
+        check_jacobian(&Jacobian::new(curve), i as u64);
+    }
+}
+
+#[test]
+fn group_law_matches_brute_force_order_over_binary_fields() {
+    // GF(4) = GF(2)[t] / (t^2 + t + 1) and GF(8) = GF(2)[t] / (t^3 + t + 1), with the
+    // coefficients of h and of f below x^5 given as indices into `elements()`.
+    let curves: [(&[u8], [usize; 3], [usize; 5]); 2] = [
+        (&[1, 1, 1], [0, 3, 0], [0, 1, 3, 0, 2]),
+        (&[1, 0, 1, 1], [1, 2, 4], [4, 0, 0, 2, 7]),
+    ];
+    for (i, (modulus, h, f)) in curves.into_iter().enumerate() {
+        let field = BinaryExtensionField::new(modulus.len() - 1, modulus).unwrap();
+        let elements = field.elements();
+        let h = h.iter().map(|&j| elements[j].clone()).collect();
+        let mut f: Vec<_> = f.iter().map(|&j| elements[j].clone()).collect();
+        f.push(elements[1].clone());
+        let curve = HyperellipticCurve::new(field, h, f).unwrap();
+        check_jacobian(&Jacobian::new(curve), i as u64);
+    }
+}
+
+#[test]
+fn invalid_curves_and_points_are_rejected() {
+    let field = prime_field(7);
+    let new = |h: &[u32], f: &[u32]| {
+        HyperellipticCurve::new(prime_field(7), coefficients(h), coefficients(f)).unwrap_err()
+    };
+    assert_eq!(
+        new(&[], &[1, 0, 0, 0, 0, 9]),
+        HyperellipticError::CoefficientOutOfRange
+    );
+    assert_eq!(
+        new(&[], &[1, 0, 0, 0, 1]),
+        HyperellipticError::InvalidDegree
+    );
+    assert_eq!(
+        new(&[], &[1, 0, 0, 0, 0, 2]),
+        HyperellipticError::InvalidDegree
+    );
+    // x^5 has a repeated root at 0.
+    assert_eq!(
+        new(&[], &[0, 0, 0, 0, 0, 1]),
+        HyperellipticError::SingularCurve
+    );
+
+    let curve = HyperellipticCurve::new(
+        field,
+        coefficients(&[3, 0, 6]),
+        coefficients(&[4, 2, 2, 6, 2, 1]),
+    )
+    .unwrap();
+    let jacobian = Jacobian::new(curve);
+    let x = BigUint::from(2u32);
+    let off_curve = (0..7u32)
+        .map(BigUint::from)
+        .find(|y| !jacobian.curve.is_on_curve(&x, y))
+        .unwrap();
+    for y in [off_curve, BigUint::from(7u32)] {
+        assert_eq!(
+            jacobian.divisor_from_point(&x, &y).unwrap_err(),
+            HyperellipticError::PointNotOnCurve
+        );
+    }
+}
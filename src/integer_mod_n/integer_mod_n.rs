@@ -1,6 +1,7 @@
 // src/integer_mod_n/integer_mod_n.rs
 
 use crate::algebra::traits::{Algebra, Group, Ring};
+use crate::factor::is_probable_prime;
 use num_bigint::{BigInt, BigUint, ToBigInt, ToBigUint};
 use num_integer::Integer; // Import the Integer trait
 use num_traits::{One, Zero};
@@ -12,6 +13,10 @@ use std::fmt;
 #[derive(Debug)]
 pub enum IntegerModNError {
     InversionFailed,
+    FactorizationMismatch,
+    NonPrimeFactor,
+    RepeatedFactor,
+    ZeroExponent,
 }
 
 impl fmt::Display for IntegerModNError {
@@ -20,6 +25,18 @@ impl fmt::Display for IntegerModNError {
             IntegerModNError::InversionFailed => {
                 write!(f, "Multiplicative inverse does not exist.")
             }
+            IntegerModNError::FactorizationMismatch => {
+                write!(f, "The given factorization does not multiply out to `n`.")
+            }
+            IntegerModNError::NonPrimeFactor => {
+                write!(f, "Every base in the factorization must be prime.")
+            }
+            IntegerModNError::RepeatedFactor => {
+                write!(f, "Every prime may appear only once in the factorization.")
+            }
+            IntegerModNError::ZeroExponent => {
+                write!(f, "Every exponent in the factorization must be positive.")
+            }
         }
     }
 }
@@ -27,15 +44,83 @@ impl fmt::Display for IntegerModNError {
 impl std::error::Error for IntegerModNError {}
 
 /// Represents an integer modulo a composite number `n`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct IntegerModN {
-    n: BigUint, // Composite modulus
+    pub(super) n: BigUint,                                 // Composite modulus
+    pub(super) factorization: Option<Vec<(BigUint, u32)>>, // Prime factorization of `n`, if known
 }
 
+/// Two rings are equal when their moduli are, whether or not a factorization is cached.
+impl PartialEq for IntegerModN {
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n
+    }
+}
+
+impl Eq for IntegerModN {}
+
 impl IntegerModN {
     /// Creates a new `IntegerModN` with a given modulus `n`.
     pub fn new(n: BigUint) -> Self {
-        IntegerModN { n }
+        IntegerModN {
+            n,
+            factorization: None,
+        }
+    }
+
+    /// Creates a new `IntegerModN` whose modulus has a known prime factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The modulus.
+    /// * `factors` - Pairs `(p, k)` such that `n` is the product of all `p^k`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IntegerModN)` if the factors are distinct primes that multiply out to `n`.
+    /// * `Err(IntegerModNError::NonPrimeFactor)` if some `p` is not prime.
+    /// * `Err(IntegerModNError::RepeatedFactor)` if some `p` appears twice.
+    /// * `Err(IntegerModNError::ZeroExponent)` if some `k` is zero.
+    /// * `Err(IntegerModNError::FactorizationMismatch)` if the product is not `n`.
+    pub fn with_factorization(
+        n: BigUint,
+        factors: Vec<(BigUint, u32)>,
+    ) -> Result<Self, IntegerModNError> {
+        if !factors.iter().all(|(p, _)| is_probable_prime(p)) {
+            return Err(IntegerModNError::NonPrimeFactor);
+        }
+        let mut primes: Vec<&BigUint> = factors.iter().map(|(p, _)| p).collect();
+        primes.sort();
+        if primes.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(IntegerModNError::RepeatedFactor);
+        }
+        if factors.iter().any(|(_, k)| *k == 0) {
+            return Err(IntegerModNError::ZeroExponent);
+        }
+        let product = factors
+            .iter()
+            .fold(BigUint::one(), |acc, (p, k)| acc * p.pow(*k));
+        if product != n {
+            return Err(IntegerModNError::FactorizationMismatch);
+        }
+        Ok(IntegerModN {
+            n,
+            factorization: Some(factors),
+        })
+    }
+
+    /// Returns a reference to the modulus `n`.
+    pub fn modulus(&self) -> &BigUint {
+        &self.n
+    }
+
+    /// Subtracts `b` from `a` modulo `n`.
+    pub fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        if a >= b {
+            (a - b) % &self.n
+        } else {
+            (&self.n + a - b) % &self.n
+        }
     }
 
     /// Computes the multiplicative inverse using the Extended Euclidean Algorithm.
@@ -184,3 +269,50 @@ impl fmt::Display for IntegerModN {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factors(pairs: &[(u32, u32)]) -> Vec<(BigUint, u32)> {
+        pairs.iter().map(|&(p, k)| (BigUint::from(p), k)).collect()
+    }
+
+    #[test]
+    fn with_factorization_accepts_distinct_primes() {
+        let ring =
+            IntegerModN::with_factorization(360u32.into(), factors(&[(2, 3), (3, 2), (5, 1)]))
+                .unwrap();
+        assert_eq!(ring.euler_totient(), BigUint::from(96u32));
+        assert_eq!(ring.carmichael_lambda(), BigUint::from(12u32));
+    }
+
+    #[test]
+    fn with_factorization_rejects_invalid_factors() {
+        assert!(matches!(
+            IntegerModN::with_factorization(6u32.into(), factors(&[(6, 1)])),
+            Err(IntegerModNError::NonPrimeFactor)
+        ));
+        assert!(matches!(
+            IntegerModN::with_factorization(4u32.into(), factors(&[(2, 1), (2, 1)])),
+            Err(IntegerModNError::RepeatedFactor)
+        ));
+        assert!(matches!(
+            IntegerModN::with_factorization(12u32.into(), factors(&[(2, 1), (3, 1)])),
+            Err(IntegerModNError::FactorizationMismatch)
+        ));
+        // 7^0 does not change the product, but would add the units of Z/7Z.
+        assert!(matches!(
+            IntegerModN::with_factorization(15u32.into(), factors(&[(3, 1), (5, 1), (7, 0)])),
+            Err(IntegerModNError::ZeroExponent)
+        ));
+    }
+
+    #[test]
+    fn equality_ignores_cached_factorization() {
+        let factored =
+            IntegerModN::with_factorization(15u32.into(), factors(&[(3, 1), (5, 1)])).unwrap();
+        assert_eq!(factored, IntegerModN::new(15u32.into()));
+        assert_ne!(factored, IntegerModN::new(21u32.into()));
+    }
+}
@@ -1,5 +1,6 @@
 // src/integer_mod_n/mod.rs
 
 pub mod integer_mod_n;
+pub mod unit_group;
 
 pub use integer_mod_n::{IntegerModN, IntegerModNError};
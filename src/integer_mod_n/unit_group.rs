@@ -0,0 +1,256 @@
+// src/integer_mod_n/unit_group.rs
+
+use super::IntegerModN;
+use crate::factor::factorize;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
+use std::collections::BTreeMap;
+
+impl IntegerModN {
+    /// Returns the prime factorization of `n` as pairs `(p, k)` in ascending order of `p`.
+    ///
+    /// If no factorization was supplied at construction time, it is computed on demand.
+    pub fn factorization(&self) -> Vec<(BigUint, u32)> {
+        match &self.factorization {
+            Some(factors) => {
+                let mut factors = factors.clone();
+                factors.sort();
+                factors
+            }
+            None => factorize(&self.n),
+        }
+    }
+
+    /// Decomposes (Z/nZ)* into cyclic groups of prime-power order.
+    ///
+    /// Each entry `(l, e)` stands for a cyclic factor of order `l^e`. By the Chinese Remainder
+    /// Theorem the unit group is the product of the groups (Z/p^kZ)*, where (Z/p^kZ)* is cyclic
+    /// of order p^(k-1)(p-1) for odd `p`, and (Z/2^kZ)* is C2 x C(2^(k-2)) for k >= 3.
+    fn primary_components(&self) -> Vec<(BigUint, u32)> {
+        let two = BigUint::from(2u32);
+        let mut components = Vec::new();
+
+        for (p, k) in self.factorization() {
+            if p == two {
+                match k {
+                    1 => {}
+                    2 => components.push((two.clone(), 1)),
+                    _ => {
+                        components.push((two.clone(), 1));
+                        components.push((two.clone(), k - 2));
+                    }
+                }
+            } else {
+                components.extend(factorize(&(&p - 1u32)));
+                if k > 1 {
+                    components.push((p, k - 1));
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Groups the primary components by prime, with exponents in descending order.
+    fn components_by_prime(&self) -> BTreeMap<BigUint, Vec<u32>> {
+        let mut by_prime: BTreeMap<BigUint, Vec<u32>> = BTreeMap::new();
+        for (l, e) in self.primary_components() {
+            by_prime.entry(l).or_default().push(e);
+        }
+        for exponents in by_prime.values_mut() {
+            exponents.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        by_prime
+    }
+
+    /// Returns the factorization of the Carmichael function λ(n).
+    fn carmichael_factorization(&self) -> Vec<(BigUint, u32)> {
+        self.components_by_prime()
+            .into_iter()
+            .map(|(l, exponents)| (l, exponents[0]))
+            .collect()
+    }
+
+    /// Computes Euler's totient φ(n), the order of the unit group (Z/nZ)*.
+    pub fn euler_totient(&self) -> BigUint {
+        self.primary_components()
+            .iter()
+            .fold(BigUint::one(), |acc, (l, e)| acc * l.pow(*e))
+    }
+
+    /// Computes the Carmichael function λ(n), the exponent of the unit group (Z/nZ)*.
+    pub fn carmichael_lambda(&self) -> BigUint {
+        self.carmichael_factorization()
+            .iter()
+            .fold(BigUint::one(), |acc, (l, e)| acc * l.pow(*e))
+    }
+
+    /// Computes the multiplicative order of `a` in (Z/nZ)*.
+    ///
+    /// # Returns
+    ///
+    /// `Some(order)` if `a` is a unit modulo `n`, otherwise `None`.
+    pub fn element_order(&self, a: &BigUint) -> Option<BigUint> {
+        let a = a % &self.n;
+        if !a.gcd(&self.n).is_one() {
+            return None;
+        }
+
+        // Start from the group exponent and strip every prime factor that is not needed.
+        let mut order = self.carmichael_lambda();
+        for (l, e) in self.carmichael_factorization() {
+            for _ in 0..e {
+                let candidate = &order / &l;
+                if Self::pow(&a, &candidate, &self.n) == BigUint::one() % &self.n {
+                    order = candidate;
+                } else {
+                    break;
+                }
+            }
+        }
+        Some(order)
+    }
+
+    /// Checks whether the unit group (Z/nZ)* is cyclic.
+    ///
+    /// This holds exactly when `n` is 1, 2, 4, p^k or 2p^k for an odd prime `p`.
+    pub fn is_cyclic(&self) -> bool {
+        self.components_by_prime()
+            .values()
+            .all(|exponents| exponents.len() == 1)
+    }
+
+    /// Finds the smallest primitive root modulo `n`, i.e. a generator of (Z/nZ)*.
+    ///
+    /// # Returns
+    ///
+    /// `Some(g)` if the unit group is cyclic, otherwise `None`.
+    pub fn primitive_root(&self) -> Option<BigUint> {
+        if !self.is_cyclic() {
+            return None;
+        }
+        if self.n <= BigUint::from(2u32) {
+            return Some(BigUint::one() % &self.n);
+        }
+
+        let phi = self.euler_totient();
+        let one = BigUint::one();
+        let cofactors: Vec<BigUint> = self
+            .carmichael_factorization()
+            .iter()
+            .map(|(l, _)| &phi / l)
+            .collect();
+
+        let mut g = BigUint::from(2u32);
+        while g < self.n {
+            if g.gcd(&self.n).is_one()
+                && cofactors
+                    .iter()
+                    .all(|cofactor| Self::pow(&g, cofactor, &self.n) != one)
+            {
+                return Some(g);
+            }
+            g += 1u32;
+        }
+        None
+    }
+
+    /// Returns the invariant factors d1 | d2 | ... | dr of the unit group, so that
+    /// (Z/nZ)* is isomorphic to C(d1) x C(d2) x ... x C(dr).
+    ///
+    /// The trivial group yields an empty vector. The last factor always equals λ(n).
+    pub fn unit_group_invariants(&self) -> Vec<BigUint> {
+        let by_prime = self.components_by_prime();
+        let rank = by_prime.values().map(Vec::len).max().unwrap_or(0);
+
+        // The i-th largest invariant factor collects the i-th largest power of every prime.
+        let mut invariants: Vec<BigUint> = (0..rank)
+            .map(|i| {
+                by_prime
+                    .iter()
+                    .filter_map(|(l, exponents)| exponents.get(i).map(|e| l.pow(*e)))
+                    .fold(BigUint::one(), |acc, q| acc * q)
+            })
+            .collect();
+        invariants.reverse();
+        invariants
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the order of every unit modulo `n`, found by repeated multiplication.
+    fn brute_force_orders(n: u32) -> Vec<(u32, u32)> {
+        (1..n)
+            .filter(|a| a.gcd(&n) == 1)
+            .map(|a| {
+                let (mut x, mut order) = (a, 1);
+                while x != 1 {
+                    x = x * a % n;
+                    order += 1;
+                }
+                (a, order)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unit_group_matches_brute_force() {
+        for n in 2..200u32 {
+            let ring = IntegerModN::new(BigUint::from(n));
+            let orders = brute_force_orders(n);
+            let phi = orders.len() as u32;
+            let lambda = orders.iter().map(|&(_, order)| order).max().unwrap();
+            assert_eq!(ring.euler_totient(), BigUint::from(phi), "n = {}", n);
+            assert_eq!(ring.carmichael_lambda(), BigUint::from(lambda), "n = {}", n);
+            for &(a, order) in &orders {
+                let expected = Some(BigUint::from(order));
+                assert_eq!(ring.element_order(&BigUint::from(a)), expected, "n = {}", n);
+            }
+            let non_unit = (2..n).find(|a| a.gcd(&n) != 1);
+            if let Some(a) = non_unit {
+                assert_eq!(ring.element_order(&BigUint::from(a)), None);
+            }
+
+            let root = orders.iter().find(|&&(_, order)| order == phi);
+            assert_eq!(ring.is_cyclic(), root.is_some(), "n = {}", n);
+            let expected = root.map(|&(a, _)| BigUint::from(a));
+            assert_eq!(ring.primitive_root(), expected, "n = {}", n);
+
+            // C(d1) x ... x C(dr) has prod gcd(d, di) solutions of x^d = 1, which pins down
+            // the invariant factors once it is checked for every d dividing λ(n).
+            let invariants: Vec<u32> = ring
+                .unit_group_invariants()
+                .iter()
+                .map(|d| d.to_string().parse().unwrap())
+                .collect();
+            assert!(invariants.windows(2).all(|pair| pair[1] % pair[0] == 0));
+            assert!(invariants.iter().all(|&d| d > 1));
+            assert_eq!(invariants.iter().product::<u32>(), phi, "n = {}", n);
+            for d in (1..=lambda).filter(|d| lambda % d == 0) {
+                let solutions = orders.iter().filter(|&&(_, order)| d % order == 0).count();
+                let expected: u32 = invariants.iter().map(|di| d.gcd(di)).product();
+                assert_eq!(solutions as u32, expected, "n = {}, d = {}", n, d);
+            }
+        }
+    }
+
+    #[test]
+    fn known_unit_groups() {
+        let invariants =
+            |n: u32| -> Vec<BigUint> { IntegerModN::new(BigUint::from(n)).unit_group_invariants() };
+        let values =
+            |values: &[u32]| -> Vec<BigUint> { values.iter().map(|&v| BigUint::from(v)).collect() };
+        assert_eq!(invariants(1), values(&[]));
+        assert_eq!(invariants(2), values(&[]));
+        assert_eq!(invariants(8), values(&[2, 2]));
+        assert_eq!(invariants(15), values(&[2, 4]));
+        assert_eq!(invariants(360), values(&[2, 2, 2, 12]));
+        let one = IntegerModN::new(BigUint::one());
+        assert_eq!(one.euler_totient(), BigUint::one());
+        assert!(one.is_cyclic());
+    }
+}
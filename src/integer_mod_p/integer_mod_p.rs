@@ -1,6 +1,9 @@
 // src/integer_mod_p/integer_mod_p.rs
 
-use crate::algebra::traits::{Algebra, Field, Group, Ring};
+use super::polynomial::multiply_coefficients;
+use crate::algebra::traits::{
+    Algebra, ElementEncoding, Field, FiniteField, Group, Membership, Ring,
+};
 use num_bigint::{BigInt, BigUint, RandBigInt, ToBigInt, ToBigUint};
 use num_integer::Integer;
 use num_traits::{One, Zero};
@@ -54,8 +57,8 @@ impl IntegerModP {
         &random_num % &self.p
     }
 
-    // Modify the 'witness_loop' to run in parallel
-    fn is_prime_miller_rabin_parallel(n: &BigUint, k: u32) -> bool {
+    /// Probabilistic Miller-Rabin primality test with `k` random witnesses checked in parallel.
+    pub fn is_prime_miller_rabin_parallel(n: &BigUint, k: u32) -> bool {
         // Handle base cases
         if *n < BigUint::from(2u32) {
             return false;
@@ -171,6 +174,83 @@ impl IntegerModP {
         let num = num.into();
         num < self.p
     }
+
+    /// Returns true if `a` is a square modulo p, by Euler's criterion a^((p - 1) / 2) = 1.
+    pub fn is_square(&self, a: &BigUint) -> bool {
+        let a = a % &self.p;
+        a.is_zero() || self.p == BigUint::from(2u32) || {
+            let exponent: BigUint = (&self.p - 1u32) >> 1;
+            a.modpow(&exponent, &self.p).is_one()
+        }
+    }
+
+    /// Computes a square root of `a` modulo p.
+    ///
+    /// Uses a^((p + 1) / 4) when p = 3 mod 4, Atkin's formula when p = 5 mod 8 and the
+    /// Tonelli-Shanks algorithm otherwise. The other root is p minus the one returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(r)` with r^2 = a mod p.
+    /// * `None` if `a` is not a square.
+    pub fn sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.p;
+        let a = a % p;
+        if a.is_zero() || p == &BigUint::from(2u32) {
+            return Some(a);
+        }
+        if !self.is_square(&a) {
+            return None;
+        }
+        let root = if p % 4u32 == BigUint::from(3u32) {
+            a.modpow(&((p + 1u32) >> 2), p)
+        } else if p % 8u32 == BigUint::from(5u32) {
+            // v = (2a)^((p - 5) / 8), i = 2a v^2, r = a v (i - 1)
+            let two_a: BigUint = (&a << 1) % p;
+            let v = two_a.modpow(&((p - 5u32) >> 3), p);
+            let i = (&two_a * &v * &v) % p;
+            (&a * &v * self.sub(&i, &BigUint::one())) % p
+        } else {
+            self.tonelli_shanks(&a)
+        };
+        Some(root)
+    }
+
+    /// Tonelli-Shanks for a nonzero square `a`: write p - 1 = 2^s t with t odd and walk down
+    /// the 2-power part of the order of a^t with a fixed non-square z.
+    fn tonelli_shanks(&self, a: &BigUint) -> BigUint {
+        let p = &self.p;
+        let p_minus_one = p - 1u32;
+        let s = p_minus_one.trailing_zeros().expect("p - 1 is nonzero");
+        let t = &p_minus_one >> s;
+        let mut z = BigUint::from(2u32);
+        while self.is_square(&z) {
+            z += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&t, p);
+        let mut b = a.modpow(&t, p);
+        let mut r = a.modpow(&((&t + 1u32) >> 1), p);
+        while !b.is_one() {
+            // Least i with b^(2^i) = 1; i < m since b lies in the subgroup of order 2^(m - 1).
+            let mut i = 0;
+            let mut power = b.clone();
+            while !power.is_one() {
+                power = (&power * &power) % p;
+                i += 1;
+            }
+            let mut d = c.clone();
+            for _ in 0..m - i - 1 {
+                d = (&d * &d) % p;
+            }
+            m = i;
+            c = (&d * &d) % p;
+            b = (&b * &c) % p;
+            r = (&r * &d) % p;
+        }
+        r
+    }
 }
 
 impl Algebra for IntegerModP {
@@ -219,6 +299,93 @@ impl Group for IntegerModP {
 
 impl Field for IntegerModP {}
 
+impl Membership for IntegerModP {
+    /// Elements are the integers in [0, p - 1].
+    fn is_element(&self, a: &Self::Element) -> bool {
+        a < &self.p
+    }
+}
+
+impl ElementEncoding for IntegerModP {
+    /// Encodes an element as a big-endian integer padded to the byte length of `p`.
+    fn encode_element(&self, a: &Self::Element) -> Vec<u8> {
+        let length = self.p.bits().div_ceil(8) as usize;
+        let bytes = (a % &self.p).to_bytes_be();
+        let bytes: &[u8] = if bytes == [0] { &[] } else { &bytes };
+        let mut out = vec![0u8; length - bytes.len()];
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    /// Accepts exactly the byte length of `p` and values below `p`.
+    fn decode_element(&self, bytes: &[u8]) -> Option<Self::Element> {
+        let a = BigUint::from_bytes_be(bytes);
+        (bytes.len() == self.p.bits().div_ceil(8) as usize && a < self.p).then_some(a)
+    }
+}
+
+impl FiniteField for IntegerModP {
+    fn characteristic(&self) -> BigUint {
+        self.p.clone()
+    }
+
+    fn size(&self) -> BigUint {
+        self.p.clone()
+    }
+
+    fn elements(&self) -> Vec<BigUint> {
+        let mut elements = Vec::new();
+        let mut a = BigUint::zero();
+        while a < self.p {
+            elements.push(a.clone());
+            a += 1u32;
+        }
+        elements
+    }
+
+    fn random(&self) -> BigUint {
+        self.random_element()
+    }
+
+    fn reduce(&self, a: &BigUint) -> BigUint {
+        a % &self.p
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        IntegerModP::sub(self, &BigUint::zero(), a)
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        IntegerModP::sub(self, a, b)
+    }
+
+    fn equal(&self, a: &BigUint, b: &BigUint) -> bool {
+        a % &self.p == b % &self.p
+    }
+
+    fn is_zero(&self, a: &BigUint) -> bool {
+        (a % &self.p).is_zero()
+    }
+
+    fn multiply_coefficients(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        multiply_coefficients(a, b, &self.p)
+    }
+
+    /// Completes the square: y = (-b + sqrt(b^2 + 4c)) / 2. Over GF(2) both candidates are
+    /// tried instead.
+    fn solve_quadratic(&self, b: &BigUint, c: &BigUint) -> Option<BigUint> {
+        if self.p == BigUint::from(2u32) {
+            return [BigUint::zero(), BigUint::one()]
+                .into_iter()
+                .find(|y| self.equal(&self.mul(y, &self.add(y, b)), c));
+        }
+        let discriminant = self.add(&self.mul(b, b), &self.mul(&BigUint::from(4u32), c));
+        let root = self.sqrt(&discriminant)?;
+        let half = (&self.p + 1u32) >> 1;
+        Some(self.mul(&IntegerModP::sub(self, &root, b), &half))
+    }
+}
+
 impl fmt::Display for IntegerModP {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -1,5 +1,7 @@
 // src/integer_mod_p/mod.rs
 
 pub mod integer_mod_p;
+pub mod polynomial;
 
 pub use integer_mod_p::{IntegerModP, IntegerModPError};
+pub use polynomial::{PolynomialModP, QuotientRing};
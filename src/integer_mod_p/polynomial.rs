@@ -0,0 +1,471 @@
+// src/integer_mod_p/polynomial.rs
+
+use super::IntegerModP;
+use crate::algebra::traits::{FiniteField, Group};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// Products where either factor has fewer coefficients than this are computed term by term;
+/// larger ones go through Kronecker substitution.
+const KRONECKER_THRESHOLD: usize = 16;
+
+/// Window width of the fixed-window exponentiation in `QuotientRing::pow`.
+const POW_WINDOW_BITS: u64 = 4;
+
+/// A polynomial over a finite field, with the coefficient of x^i at index i. The coefficients
+/// lie in GF(p) unless another `FiniteField` element type is given, as the hyperelliptic
+/// curves over GF(2^m) do.
+///
+/// Coefficients are always reduced and the leading one is nonzero, so the zero polynomial has
+/// no coefficients at all. Like `Polynomial` over GF(2), which takes the modulus as an argument,
+/// the arithmetic takes the field as an argument instead of storing it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolynomialModP<E = BigUint> {
+    pub coeffs: Vec<E>, // Ascending order of degree
+}
+
+/// The quotient ring GF(p)[x] / (h) for a monic polynomial h.
+///
+/// Reduction uses a precomputed inverse of the reversed modulus, so that reducing a product
+/// costs two multiplications instead of a long division (Newton division).
+#[derive(Debug, Clone)]
+pub struct QuotientRing {
+    pub field: IntegerModP,           // Coefficient field GF(p)
+    pub modulus: PolynomialModP,      // Monic modulus h
+    reversed_inverse: PolynomialModP, // (x^d h(1/x))^(-1) mod x^(d - 1)
+}
+
+impl<E: Clone> PolynomialModP<E> {
+    /// Creates a polynomial from coefficients in ascending order of degree, reducing them
+    /// and dropping leading zeros.
+    pub fn new<F: FiniteField<Element = E>>(coeffs: Vec<E>, field: &F) -> Self {
+        Self::from_reduced(coeffs.iter().map(|c| field.reduce(c)).collect(), field)
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        PolynomialModP { coeffs: Vec::new() }
+    }
+
+    /// Returns the constant polynomial c.
+    pub fn constant<F: FiniteField<Element = E>>(c: &E, field: &F) -> Self {
+        Self::new(vec![c.clone()], field)
+    }
+
+    /// Returns the monic linear polynomial x - a.
+    pub fn linear<F: FiniteField<Element = E>>(a: &E, field: &F) -> Self {
+        PolynomialModP {
+            coeffs: vec![field.neg(&field.reduce(a)), field.one()],
+        }
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Returns true for the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the leading coefficient, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&E> {
+        self.coeffs.last()
+    }
+
+    /// Returns true if both polynomials have the same coefficients, for element types
+    /// without a unique representation.
+    pub fn equals<F: FiniteField<Element = E>>(&self, other: &Self, field: &F) -> bool {
+        self.coeffs.len() == other.coeffs.len()
+            && self
+                .coeffs
+                .iter()
+                .zip(&other.coeffs)
+                .all(|(a, b)| field.equal(a, b))
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's rule.
+    pub fn evaluate<F: FiniteField<Element = E>>(&self, x: &E, field: &F) -> E {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(field.zero(), |acc, c| field.add(&field.mul(&acc, x), c))
+    }
+
+    /// Adds two polynomials.
+    pub fn add<F: FiniteField<Element = E>>(&self, other: &Self, field: &F) -> Self {
+        let (long, short) = if self.coeffs.len() >= other.coeffs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (c, d) in coeffs.iter_mut().zip(&short.coeffs) {
+            *c = field.add(c, d);
+        }
+        Self::from_reduced(coeffs, field)
+    }
+
+    /// Subtracts `other` from `self`.
+    pub fn sub<F: FiniteField<Element = E>>(&self, other: &Self, field: &F) -> Self {
+        self.add(&other.neg(field), field)
+    }
+
+    /// Returns the additive inverse.
+    pub fn neg<F: FiniteField<Element = E>>(&self, field: &F) -> Self {
+        PolynomialModP {
+            coeffs: self.coeffs.iter().map(|c| field.neg(c)).collect(),
+        }
+    }
+
+    /// Multiplies every coefficient by the constant `c`.
+    pub fn scale<F: FiniteField<Element = E>>(&self, c: &E, field: &F) -> Self {
+        Self::from_reduced(self.coeffs.iter().map(|a| field.mul(a, c)).collect(), field)
+    }
+
+    /// Multiplies two polynomials with the field's `multiply_coefficients`, which uses
+    /// Kronecker substitution for large products over GF(p).
+    pub fn mul<F: FiniteField<Element = E>>(&self, other: &Self, field: &F) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        Self::from_reduced(
+            field.multiply_coefficients(&self.coeffs, &other.coeffs),
+            field,
+        )
+    }
+
+    /// Returns the formal derivative.
+    pub fn derivative<F: FiniteField<Element = E>>(&self, field: &F) -> Self {
+        let mut multiple = field.zero();
+        let mut coeffs = Vec::new();
+        for c in self.coeffs.iter().skip(1) {
+            multiple = field.add(&multiple, &field.one());
+            coeffs.push(field.mul(c, &multiple));
+        }
+        Self::from_reduced(coeffs, field)
+    }
+
+    /// Divides `self` by a nonzero `divisor` with schoolbook long division.
+    ///
+    /// # Returns
+    ///
+    /// The quotient and the remainder, whose degree is below that of the divisor.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero.
+    pub fn div_rem<F: FiniteField<Element = E>>(&self, divisor: &Self, field: &F) -> (Self, Self) {
+        let d = divisor.degree().expect("division by the zero polynomial");
+        let lead_inverse =
+            Group::inverse(field, &divisor.coeffs[d]).expect("nonzero coefficients are invertible");
+        let mut remainder = self.coeffs.clone();
+        if remainder.len() <= d {
+            return (Self::zero(), self.clone());
+        }
+        let mut quotient = vec![field.zero(); remainder.len() - d];
+        for i in (0..quotient.len()).rev() {
+            let factor = field.mul(&remainder[i + d], &lead_inverse);
+            if field.is_zero(&factor) {
+                continue;
+            }
+            for (j, c) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] = field.sub(&remainder[i + j], &field.mul(&factor, c));
+            }
+            quotient[i] = factor;
+        }
+        remainder.truncate(d);
+        (
+            Self::from_reduced(quotient, field),
+            Self::from_reduced(remainder, field),
+        )
+    }
+
+    /// Returns the remainder of division by `modulus`.
+    pub fn rem<F: FiniteField<Element = E>>(&self, modulus: &Self, field: &F) -> Self {
+        self.div_rem(modulus, field).1
+    }
+
+    /// Returns the polynomial divided by its leading coefficient.
+    pub fn monic<F: FiniteField<Element = E>>(&self, field: &F) -> Self {
+        match self.leading_coefficient() {
+            None => Self::zero(),
+            Some(lead) => {
+                let inverse =
+                    Group::inverse(field, lead).expect("nonzero coefficients are invertible");
+                self.scale(&inverse, field)
+            }
+        }
+    }
+
+    /// Returns the monic greatest common divisor, computed with Euclid's algorithm.
+    pub fn gcd<F: FiniteField<Element = E>>(&self, other: &Self, field: &F) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b, field);
+            a = b;
+            b = r;
+        }
+        a.monic(field)
+    }
+
+    /// Extended Euclidean algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A tuple (d, s, t) with d = s * self + t * other and d the monic gcd, or zero when both
+    /// inputs are zero.
+    pub fn extended_gcd<F: FiniteField<Element = E>>(
+        &self,
+        other: &Self,
+        field: &F,
+    ) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (Self::constant(&field.one(), field), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::constant(&field.one(), field));
+        while !r.is_zero() {
+            let (quotient, remainder) = old_r.div_rem(&r, field);
+            old_r = std::mem::replace(&mut r, remainder);
+            let next_s = old_s.sub(&quotient.mul(&s, field), field);
+            old_s = std::mem::replace(&mut s, next_s);
+            let next_t = old_t.sub(&quotient.mul(&t, field), field);
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+        match old_r.leading_coefficient() {
+            Some(lead) => {
+                let inverse =
+                    Group::inverse(field, lead).expect("nonzero coefficients are invertible");
+                (
+                    old_r.scale(&inverse, field),
+                    old_s.scale(&inverse, field),
+                    old_t.scale(&inverse, field),
+                )
+            }
+            None => (old_r, old_s, old_t),
+        }
+    }
+
+    fn from_reduced<F: FiniteField<Element = E>>(mut coeffs: Vec<E>, field: &F) -> Self {
+        while coeffs.last().is_some_and(|c| field.is_zero(c)) {
+            coeffs.pop();
+        }
+        PolynomialModP { coeffs }
+    }
+}
+
+impl PolynomialModP {
+    /// Returns the constant polynomial 1.
+    pub fn one() -> Self {
+        PolynomialModP {
+            coeffs: vec![BigUint::one()],
+        }
+    }
+
+    /// Returns the polynomial x.
+    pub fn x() -> Self {
+        PolynomialModP {
+            coeffs: vec![BigUint::zero(), BigUint::one()],
+        }
+    }
+
+    /// Returns true for the constant polynomial 1.
+    pub fn is_one(&self) -> bool {
+        self.coeffs.len() == 1 && self.coeffs[0].is_one()
+    }
+
+    /// Returns the polynomial modulo x^n.
+    fn truncate(&self, n: usize) -> Self {
+        let mut coeffs: Vec<BigUint> = self.coeffs.iter().take(n).cloned().collect();
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        PolynomialModP { coeffs }
+    }
+
+    /// Returns x^(n - 1) a(1/x) for a polynomial of degree below n.
+    fn reverse(&self, n: usize) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.resize(n, BigUint::zero());
+        coeffs.reverse();
+        PolynomialModP { coeffs }.truncate(n)
+    }
+}
+
+/// Multiplies two nonempty coefficient vectors over GF(p).
+///
+/// Large products use Kronecker substitution: both polynomials are packed into integers with
+/// one slot per coefficient, wide enough that the coefficients of the product cannot overflow
+/// into each other, and a single big-integer multiplication does the work.
+pub(crate) fn multiply_coefficients(a: &[BigUint], b: &[BigUint], p: &BigUint) -> Vec<BigUint> {
+    let shortest = a.len().min(b.len());
+    if shortest < KRONECKER_THRESHOLD {
+        let mut coeffs = vec![BigUint::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                coeffs[i + j] += x * y;
+            }
+        }
+        return coeffs.into_iter().map(|c| c % p).collect();
+    }
+
+    // Each product coefficient is a sum of at most `shortest` terms below p^2.
+    let slot_bits = 2 * p.bits() + u64::from(usize::BITS - shortest.leading_zeros());
+    let slot = slot_bits.div_ceil(32) as usize;
+    let product = pack(a, slot) * pack(b, slot);
+    let digits = product.to_u32_digits();
+    (0..a.len() + b.len() - 1)
+        .map(|k| {
+            let start = (k * slot).min(digits.len());
+            let end = ((k + 1) * slot).min(digits.len());
+            BigUint::from_slice(&digits[start..end]) % p
+        })
+        .collect()
+}
+
+/// Packs coefficients into an integer, `slot` 32-bit digits per coefficient.
+fn pack(coeffs: &[BigUint], slot: usize) -> BigUint {
+    let mut digits = vec![0u32; coeffs.len() * slot];
+    for (i, c) in coeffs.iter().enumerate() {
+        for (j, digit) in c.to_u32_digits().into_iter().enumerate() {
+            digits[i * slot + j] = digit;
+        }
+    }
+    BigUint::new(digits)
+}
+
+impl QuotientRing {
+    /// Creates the ring GF(p)[x] / (h). The modulus is made monic first.
+    ///
+    /// # Panics
+    ///
+    /// If h is constant.
+    pub fn new(field: IntegerModP, modulus: &PolynomialModP) -> Self {
+        let modulus = modulus.monic(&field);
+        let d = modulus
+            .degree()
+            .filter(|d| *d > 0)
+            .expect("the modulus must have positive degree");
+
+        // Newton iteration g <- g (2 - f g) doubles the number of correct terms of 1 / f.
+        let target = (d - 1).max(1);
+        let reversed = modulus.reverse(d + 1);
+        let two = PolynomialModP::constant(&BigUint::from(2u32), &field);
+        let mut inverse = PolynomialModP::one();
+        let mut precision = 1;
+        while precision < target {
+            precision = (2 * precision).min(target);
+            let error = reversed
+                .truncate(precision)
+                .mul(&inverse, &field)
+                .truncate(precision);
+            inverse = inverse
+                .mul(&two.sub(&error, &field), &field)
+                .truncate(precision);
+        }
+        QuotientRing {
+            field,
+            modulus,
+            reversed_inverse: inverse,
+        }
+    }
+
+    /// Returns the degree of the modulus.
+    pub fn degree(&self) -> usize {
+        self.modulus.coeffs.len() - 1
+    }
+
+    /// Reduces a polynomial modulo h.
+    pub fn reduce(&self, a: &PolynomialModP) -> PolynomialModP {
+        let d = self.degree();
+        let n = match a.degree() {
+            Some(n) if n >= d => n,
+            _ => return a.clone(),
+        };
+        if n > 2 * d - 2 || d == 1 {
+            return a.div_rem(&self.modulus, &self.field).1;
+        }
+        // The quotient has degree n - d and its reversal is rev(a) / rev(h) mod x^(n - d + 1).
+        let k = n - d + 1;
+        let quotient = a
+            .reverse(n + 1)
+            .truncate(k)
+            .mul(&self.reversed_inverse, &self.field)
+            .truncate(k)
+            .reverse(k);
+        let product = quotient.mul(&self.modulus, &self.field).truncate(d);
+        a.truncate(d).sub(&product, &self.field)
+    }
+
+    /// Adds two reduced elements.
+    pub fn add(&self, a: &PolynomialModP, b: &PolynomialModP) -> PolynomialModP {
+        a.add(b, &self.field)
+    }
+
+    /// Subtracts two reduced elements.
+    pub fn sub(&self, a: &PolynomialModP, b: &PolynomialModP) -> PolynomialModP {
+        a.sub(b, &self.field)
+    }
+
+    /// Multiplies two reduced elements.
+    pub fn mul(&self, a: &PolynomialModP, b: &PolynomialModP) -> PolynomialModP {
+        self.reduce(&a.mul(b, &self.field))
+    }
+
+    /// Squares a reduced element.
+    pub fn square(&self, a: &PolynomialModP) -> PolynomialModP {
+        self.mul(a, a)
+    }
+
+    /// Raises an element to the power `exp` with a fixed 4-bit window.
+    pub fn pow(&self, a: &PolynomialModP, exp: &BigUint) -> PolynomialModP {
+        let base = self.reduce(a);
+        let mut table = vec![PolynomialModP::one(), base.clone()];
+        for i in 2..1usize << POW_WINDOW_BITS {
+            table.push(self.mul(&table[i - 1], &base));
+        }
+        let windows = exp.bits().div_ceil(POW_WINDOW_BITS);
+        let mut result = PolynomialModP::one();
+        for w in (0..windows).rev() {
+            for _ in 0..POW_WINDOW_BITS {
+                result = self.square(&result);
+            }
+            let digit = (0..POW_WINDOW_BITS)
+                .filter(|&b| exp.bit(w * POW_WINDOW_BITS + b))
+                .fold(0, |acc, b| acc | 1 << b);
+            if digit != 0 {
+                result = self.mul(&result, &table[digit]);
+            }
+        }
+        result
+    }
+
+    /// Returns the ring modulo a factor g of h, such as a gcd found while working in this
+    /// ring. Reducing an element of this ring modulo g gives its image in the new one.
+    pub fn restrict(&self, factor: &PolynomialModP) -> Self {
+        QuotientRing::new(self.field.clone(), factor)
+    }
+}
+
+impl fmt::Display for PolynomialModP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let terms: Vec<String> = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| match i {
+                0 => format!("{}", c),
+                1 => format!("{}x", c),
+                _ => format!("{}x^{}", c, i),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
@@ -0,0 +1,124 @@
+// src/kdf/hkdf.rs
+
+use super::KdfError;
+use crate::hash::{HashFunction, Hmac};
+
+/// HKDF-Extract (RFC 5869, section 2.2): PRK = HMAC-Hash(salt, IKM). An empty salt stands
+/// for a string of HashLen zeros.
+pub fn hkdf_extract<H: HashFunction>(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    let default_salt = vec![0u8; H::OUTPUT_SIZE];
+    let salt = if salt.is_empty() { &default_salt } else { salt };
+    Hmac::<H>::mac(salt, ikm)
+}
+
+/// HKDF-Expand (RFC 5869, section 2.3): T(i) = HMAC-Hash(PRK, T(i - 1) || info || i).
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with `length` bytes of output keying material.
+/// * `Err(KdfError::OutputTooLong)` if `length` exceeds 255 * HashLen.
+pub fn hkdf_expand<H: HashFunction>(
+    prk: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, KdfError> {
+    let max = 255 * H::OUTPUT_SIZE;
+    if length > max {
+        return Err(KdfError::OutputTooLong {
+            requested: length,
+            max,
+        });
+    }
+    let mut okm = Vec::with_capacity(length);
+    let mut block = Vec::new();
+    let mut counter = 1u8;
+    while okm.len() < length {
+        let mut hmac = Hmac::<H>::new(prk);
+        hmac.update(&block);
+        hmac.update(info);
+        hmac.update(&[counter]);
+        block = hmac.finalize();
+        okm.extend_from_slice(&block);
+        counter = counter.wrapping_add(1);
+    }
+    okm.truncate(length);
+    Ok(okm)
+}
+
+/// Full HKDF: extract a pseudorandom key from `ikm` with `salt`, then expand it with `info`.
+pub fn hkdf<H: HashFunction>(
+    salt: &[u8],
+    ikm: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, KdfError> {
+    hkdf_expand::<H>(&hkdf_extract::<H>(salt, ikm), info, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Sha256;
+
+    /// Runs one RFC 5869 test case and checks the PRK and the OKM.
+    fn check(ikm: &[u8], salt: &[u8], info: &[u8], prk: &str, okm: &str) {
+        assert_eq!(hex::encode(hkdf_extract::<Sha256>(salt, ikm)), prk);
+        let length = okm.len() / 2;
+        assert_eq!(
+            hex::encode(hkdf::<Sha256>(salt, ikm, info, length).unwrap()),
+            okm
+        );
+    }
+
+    #[test]
+    fn rfc5869_basic_test_case() {
+        check(
+            &[0x0b; 22],
+            &(0x00..=0x0c).collect::<Vec<u8>>(),
+            &(0xf0..=0xf9).collect::<Vec<u8>>(),
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            concat!(
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf",
+                "34007208d5b887185865"
+            ),
+        );
+    }
+
+    #[test]
+    fn rfc5869_longer_inputs_test_case() {
+        check(
+            &(0x00..=0x4f).collect::<Vec<u8>>(),
+            &(0x60..=0xaf).collect::<Vec<u8>>(),
+            &(0xb0..=0xff).collect::<Vec<u8>>(),
+            "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            concat!(
+                "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c",
+                "59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71",
+                "cc30c58179ec3e87c14c01d5c1f3434f1d87"
+            ),
+        );
+    }
+
+    #[test]
+    fn rfc5869_zero_length_salt_and_info_test_case() {
+        check(
+            &[0x0b; 22],
+            &[],
+            &[],
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            concat!(
+                "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d",
+                "9d201395faa4b61a96c8"
+            ),
+        );
+    }
+
+    #[test]
+    fn rejects_overlong_output() {
+        assert!(hkdf_expand::<Sha256>(&[0; 32], &[], 255 * 32).is_ok());
+        assert!(matches!(
+            hkdf_expand::<Sha256>(&[0; 32], &[], 255 * 32 + 1),
+            Err(KdfError::OutputTooLong { .. })
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+// src/kdf/mod.rs
+
+pub mod hkdf;
+pub mod one_step;
+
+pub use hkdf::{hkdf, hkdf_expand, hkdf_extract};
+pub use one_step::{one_step_kdf, one_step_kdf_hmac};
+
+use std::fmt;
+
+/// Errors related to key derivation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum KdfError {
+    OutputTooLong { requested: usize, max: usize },
+}
+
+impl fmt::Display for KdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KdfError::OutputTooLong { requested, max } => write!(
+                f,
+                "Requested {} bytes of key material, but at most {} can be derived.",
+                requested, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KdfError {}
@@ -0,0 +1,75 @@
+// src/kdf/one_step.rs
+
+use super::KdfError;
+use crate::hash::{HashFunction, Hmac};
+
+/// The one-step key derivation function of SP 800-56C Rev. 2, section 4.1, with a hash
+/// function as the auxiliary function (option 1):
+/// K(i) = H(counter || Z || FixedInfo) with a 32-bit big-endian counter starting at 1.
+///
+/// # Arguments
+///
+/// * `z` - The shared secret, e.g. the fixed-length encoding of a Diffie-Hellman secret.
+/// * `fixed_info` - Context shared by both parties, such as identifiers and algorithm IDs.
+/// * `length` - The number of bytes to derive.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with the derived keying material.
+/// * `Err(KdfError::OutputTooLong)` if more than 2^32 - 1 blocks would be needed.
+pub fn one_step_kdf<H: HashFunction>(
+    z: &[u8],
+    fixed_info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, KdfError> {
+    derive(H::OUTPUT_SIZE, length, |counter| {
+        let mut hasher = H::new();
+        hasher.update(&counter.to_be_bytes());
+        hasher.update(z);
+        hasher.update(fixed_info);
+        hasher.finalize()
+    })
+}
+
+/// The one-step key derivation function of SP 800-56C Rev. 2, section 4.1, with HMAC as the
+/// auxiliary function (option 2): K(i) = HMAC-H(salt, counter || Z || FixedInfo). An empty
+/// salt stands for a string of zeros as long as the hash block.
+pub fn one_step_kdf_hmac<H: HashFunction>(
+    salt: &[u8],
+    z: &[u8],
+    fixed_info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, KdfError> {
+    let default_salt = vec![0u8; H::BLOCK_SIZE];
+    let salt = if salt.is_empty() { &default_salt } else { salt };
+    derive(H::OUTPUT_SIZE, length, |counter| {
+        let mut hmac = Hmac::<H>::new(salt);
+        hmac.update(&counter.to_be_bytes());
+        hmac.update(z);
+        hmac.update(fixed_info);
+        hmac.finalize()
+    })
+}
+
+/// Concatenates K(1), K(2), ... until `length` bytes are available.
+fn derive(
+    block_size: usize,
+    length: usize,
+    block: impl Fn(u32) -> Vec<u8>,
+) -> Result<Vec<u8>, KdfError> {
+    let max = (u32::MAX as usize).saturating_mul(block_size);
+    if length > max {
+        return Err(KdfError::OutputTooLong {
+            requested: length,
+            max,
+        });
+    }
+    let mut output = Vec::with_capacity(length);
+    let mut counter = 1u32;
+    while output.len() < length {
+        output.extend(block(counter));
+        counter += 1;
+    }
+    output.truncate(length);
+    Ok(output)
+}
@@ -0,0 +1,27 @@
+// src/lib.rs
+
+pub mod ake;
+pub mod algebra;
+pub mod binary_extension_field;
+pub mod cli;
+pub mod diffie_hellman;
+pub mod dsa;
+pub mod ecdsa;
+pub mod eddsa;
+pub mod elgamal;
+pub mod elliptic_curve;
+pub mod encoding;
+pub mod factor;
+pub mod generic_vector;
+pub mod hash;
+pub mod hash_to_curve;
+pub mod hyperelliptic;
+pub mod integer_mod_n;
+pub mod integer_mod_p;
+pub mod kdf;
+pub mod paillier;
+pub mod pairing;
+pub mod polynomial;
+pub mod rsa;
+pub mod sieve;
+pub mod signature;
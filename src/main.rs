@@ -1,24 +1,25 @@
-mod algebra;
-mod binary_extension_field;
-mod diffie_hellman;
-mod generic_vector;
-mod integer_mod_n;
-mod integer_mod_p;
-mod polynomial;
+use cryptography_toolkit::algebra::traits::{Group, Ring};
+use cryptography_toolkit::binary_extension_field::BinaryExtensionField;
+use cryptography_toolkit::cli;
+use cryptography_toolkit::diffie_hellman::{self, DiffieHellman};
+use cryptography_toolkit::integer_mod_n::IntegerModN;
+use cryptography_toolkit::integer_mod_p::IntegerModP;
 
-use algebra::traits::{Group, Ring};
-use binary_extension_field::BinaryExtensionField;
-use diffie_hellman::DiffieHellman;
-use integer_mod_n::IntegerModN;
-use integer_mod_p::IntegerModP;
-
-use hex::decode;
 use num_bigint::{BigUint, ToBigUint};
 use std::error::Error;
 
 // TODO: - implement an "in" method to check if element belongs in struct
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(err) = cli::run(&args) {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let start = std::time::Instant::now();
 
     //println!("\n================== Testing Binary Fields GF(2^n) ==================");
@@ -116,61 +117,22 @@ fn main() -> Result<(), Box<dyn Error>> {
     //
     println!("\n=========== Testing Diffie-Hellmann ============");
 
-    let p_hex = concat!(
-        "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
-        "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
-        "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
-        "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
-        "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE65381",
-        "FFFFFFFFFFFFFFFF"
-    );
-
-    let p_hex = concat!(
-        "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1",
-        "29024E088A67CC74020BBEA63B139B22514A08798E3404DD",
-        "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245",
-        "E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
-        "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D",
-        "C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
-        "83655D23DCA3AD961C62F356208552BB9ED529077096966D",
-        "670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
-        "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9",
-        "DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
-        "15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64",
-        "ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
-        "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6B",
-        "F12FFA06D98A0864D87602733EC86A64521F2B18177B200C",
-        "BBE117577A615D6C770988C0BAD946E208E24FA074E5AB31",
-        "43DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7",
-        "88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA",
-        "2583E9CA2AD44CE8DBBBC2DB04DE8EF92E8EFC141FBECAA6",
-        "287C59474E6BC05D99B2964FA090C3A2233BA186515BE7ED",
-        "1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9",
-        "93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934063199",
-        "FFFFFFFFFFFFFFFF"
-    );
-
-    // Decode hex to bytes
-    let p_bytes = decode(p_hex)?;
-    // Convert bytes to BigUint
-    let p = BigUint::from_bytes_be(&p_bytes);
-    let g = BigUint::from(2u32); // Generator
+    let dh = DiffieHellman::named(diffie_hellman::NamedGroup::Modp4096);
     println!(
         "Testing Diffie-Hellman with multiplicative group of size {} with generator {}",
-        p, g
+        dh.group.p, dh.g
     );
-    let dh = diffie_hellman::DiffieHellman::new(p, g)?;
     let alice_private = dh.generate_private_key();
-    let alice_public = dh.compute_public_key(&alice_private);
+    let alice_public = alice_private.public_key();
     let bob_private = dh.generate_private_key();
-    let bob_public = dh.compute_public_key(&bob_private);
-    let alice_shared = dh.compute_shared_secret(&bob_public, &alice_private);
-    let bob_shared = dh.compute_shared_secret(&alice_public, &bob_private);
+    let bob_public = bob_private.public_key();
+    let alice_shared = dh.compute_shared_secret(bob_public, &alice_private)?;
+    let bob_shared = dh.compute_shared_secret(alice_public, &bob_private)?;
 
-    println!("Alice's Private Key: {}", alice_private);
-    println!("Alice's Public Key: {}", alice_public);
-    println!("Bob's Private Key: {}", bob_private);
-    println!("Bob's Public Key: {}", bob_public);
+    println!("Alice's Private Key: {}", alice_private.exponent());
+    println!("Alice's Public Key: {}", alice_public.element);
+    println!("Bob's Private Key: {}", bob_private.exponent());
+    println!("Bob's Public Key: {}", bob_public.element);
     println!("Alice's Shared Secret: {}", alice_shared);
     println!("Bob's Shared Secret: {}", bob_shared);
     println!("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
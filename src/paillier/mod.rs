@@ -0,0 +1,333 @@
+// src/paillier/mod.rs
+
+use crate::algebra::traits::{Group, Ring};
+use crate::factor::is_probable_prime;
+use crate::integer_mod_n::IntegerModN;
+use crate::rsa::keys::generate_prime;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
+use std::fmt;
+
+/// Errors related to the Paillier cryptosystem.
+#[derive(Debug)]
+pub enum PaillierError {
+    InvalidKeySize,
+    InvalidPrimes,
+    MessageOutOfRange,
+    InvalidRandomness,
+    InvalidCiphertext,
+}
+
+impl fmt::Display for PaillierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaillierError::InvalidKeySize => write!(f, "The requested key size is too small."),
+            PaillierError::InvalidPrimes => write!(
+                f,
+                "The factors must be distinct primes with gcd(pq, (p - 1)(q - 1)) = 1."
+            ),
+            PaillierError::MessageOutOfRange => {
+                write!(f, "The message must be smaller than the modulus `n`.")
+            }
+            PaillierError::InvalidRandomness => {
+                write!(f, "The randomness must be a unit modulo `n`.")
+            }
+            PaillierError::InvalidCiphertext => {
+                write!(f, "The ciphertext is not a unit modulo `n^2`.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaillierError {}
+
+/// A Paillier public key with generator g = n + 1. Ciphertexts live in (Z/n^2 Z)*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaillierPublicKey {
+    pub n: BigUint,        // Modulus n = pq
+    pub g: BigUint,        // Generator n + 1
+    pub ring: IntegerModN, // Arithmetic modulo n^2
+}
+
+/// A Paillier private key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaillierPrivateKey {
+    pub public_key: PaillierPublicKey,
+    pub lambda: BigUint, // λ = lcm(p - 1, q - 1)
+    pub mu: BigUint,     // μ = L(g^λ mod n^2)^(-1) mod n
+}
+
+impl PaillierPublicKey {
+    /// Creates a public key for the modulus `n`, with g = n + 1.
+    pub fn new(n: BigUint) -> Self {
+        let n_squared = &n * &n;
+        PaillierPublicKey {
+            g: &n + 1u32,
+            ring: IntegerModN::new(n_squared),
+            n,
+        }
+    }
+
+    /// Returns n^2, the modulus of the ciphertext space.
+    pub fn n_squared(&self) -> &BigUint {
+        self.ring.modulus()
+    }
+
+    /// Encrypts `m` with fresh randomness.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with the ciphertext g^m * r^n mod n^2 for a random unit r modulo n.
+    /// * `Err(PaillierError::MessageOutOfRange)` if `m >= n`.
+    pub fn encrypt(&self, m: &BigUint) -> Result<BigUint, PaillierError> {
+        let r = IntegerModN::new(self.n.clone()).random_group_element();
+        self.encrypt_with_randomness(m, &r)
+    }
+
+    /// Encrypts `m` with the given randomness `r`, a unit modulo n.
+    ///
+    /// Since g = n + 1, g^m = 1 + mn mod n^2, which saves one exponentiation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with the ciphertext g^m * r^n mod n^2.
+    /// * `Err(PaillierError::MessageOutOfRange)` if `m >= n`.
+    /// * `Err(PaillierError::InvalidRandomness)` if gcd(r, n) != 1.
+    pub fn encrypt_with_randomness(
+        &self,
+        m: &BigUint,
+        r: &BigUint,
+    ) -> Result<BigUint, PaillierError> {
+        if m >= &self.n {
+            return Err(PaillierError::MessageOutOfRange);
+        }
+        // A non-unit r would leak a factor of n and make the ciphertext undecryptable.
+        if !r.gcd(&self.n).is_one() {
+            return Err(PaillierError::InvalidRandomness);
+        }
+        let g_m = (BigUint::one() + m * &self.n) % self.n_squared();
+        Ok(self.ring.mul(&g_m, &self.ring.pow(r, &self.n)))
+    }
+
+    /// Homomorphic addition: returns an encryption of m1 + m2 mod n.
+    pub fn add(&self, c1: &BigUint, c2: &BigUint) -> BigUint {
+        self.ring.mul(c1, c2)
+    }
+
+    /// Homomorphic addition of a plaintext: returns an encryption of m + k mod n.
+    pub fn add_plaintext(&self, c: &BigUint, k: &BigUint) -> BigUint {
+        let g_k = (BigUint::one() + (k % &self.n) * &self.n) % self.n_squared();
+        self.ring.mul(c, &g_k)
+    }
+
+    /// Homomorphic scalar multiplication: returns an encryption of k * m mod n.
+    pub fn mul_plaintext(&self, c: &BigUint, k: &BigUint) -> BigUint {
+        self.ring.pow(c, k)
+    }
+
+    /// Homomorphic sum of any number of ciphertexts; the empty sum is the trivial encryption
+    /// of 0.
+    pub fn sum(&self, ciphertexts: &[BigUint]) -> BigUint {
+        ciphertexts
+            .iter()
+            .fold(self.ring.one(), |acc, c| self.add(&acc, c))
+    }
+
+    /// Re-randomizes a ciphertext by multiplying it with a fresh encryption of 0. The result
+    /// decrypts to the same message but cannot be linked to the input.
+    pub fn rerandomize(&self, c: &BigUint) -> BigUint {
+        let r = IntegerModN::new(self.n.clone()).random_group_element();
+        self.ring.mul(c, &self.ring.pow(&r, &self.n))
+    }
+}
+
+impl PaillierPrivateKey {
+    /// Generates a key with a modulus of `bits` bits from two primes of equal size, which
+    /// guarantees gcd(n, (p - 1)(q - 1)) = 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PaillierPrivateKey)` on success.
+    /// * `Err(PaillierError::InvalidKeySize)` if `bits` is below 64 or odd.
+    pub fn generate(bits: u64) -> Result<Self, PaillierError> {
+        if bits < 64 || !bits.is_multiple_of(2) {
+            return Err(PaillierError::InvalidKeySize);
+        }
+        loop {
+            let p = generate_prime(bits / 2);
+            let q = generate_prime(bits / 2);
+            if let Ok(key) = Self::from_primes(&p, &q) {
+                return Ok(key);
+            }
+        }
+    }
+
+    /// Builds the key for n = pq, computing λ = lcm(p - 1, q - 1) and μ = λ^(-1) mod n.
+    ///
+    /// With g = n + 1, L(g^λ mod n^2) = λ mod n, so μ is simply the inverse of λ.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PaillierPrivateKey)` on success.
+    /// * `Err(PaillierError::InvalidPrimes)` if p or q is not prime, p = q, or
+    ///   gcd(pq, (p - 1)(q - 1)) != 1.
+    pub fn from_primes(p: &BigUint, q: &BigUint) -> Result<Self, PaillierError> {
+        if p == q || !is_probable_prime(p) || !is_probable_prime(q) {
+            return Err(PaillierError::InvalidPrimes);
+        }
+        let public_key = PaillierPublicKey::new(p * q);
+        let lambda = (p - 1u32).lcm(&(q - 1u32));
+        // gcd(n, λ) = 1 exactly when gcd(pq, (p - 1)(q - 1)) = 1.
+        let mu = IntegerModN::inverse(&(&lambda % &public_key.n), &public_key.n)
+            .ok_or(PaillierError::InvalidPrimes)?;
+        Ok(PaillierPrivateKey {
+            public_key,
+            lambda,
+            mu,
+        })
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &PaillierPublicKey {
+        &self.public_key
+    }
+
+    /// Decrypts `c` as m = L(c^λ mod n^2) * μ mod n, where L(x) = (x - 1) / n.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with the message.
+    /// * `Err(PaillierError::InvalidCiphertext)` if `c` is not a unit modulo n^2.
+    pub fn decrypt(&self, c: &BigUint) -> Result<BigUint, PaillierError> {
+        let public_key = &self.public_key;
+        let n = &public_key.n;
+        if c >= public_key.n_squared() || !c.gcd(n).is_one() {
+            return Err(PaillierError::InvalidCiphertext);
+        }
+        let x = public_key.ring.pow(c, &self.lambda);
+        let l = (x - 1u32) / n;
+        Ok(l * &self.mu % n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> PaillierPrivateKey {
+        let p = BigUint::from(2_147_483_647u32);
+        let q = BigUint::from(4_294_967_291u32);
+        PaillierPrivateKey::from_primes(&p, &q).unwrap()
+    }
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let key = key();
+        let public_key = key.public_key();
+        let n_minus_one = &public_key.n - 1u32;
+        for m in [BigUint::from(0u32), BigUint::from(42u32), n_minus_one] {
+            let c = public_key.encrypt(&m).unwrap();
+            assert_eq!(key.decrypt(&c).unwrap(), m);
+            assert_ne!(c, public_key.encrypt(&m).unwrap());
+            assert_eq!(key.decrypt(&public_key.rerandomize(&c)).unwrap(), m);
+        }
+
+        let m = BigUint::from(7u32);
+        let c = public_key
+            .encrypt_with_randomness(&m, &BigUint::from(12345u32))
+            .unwrap();
+        assert_eq!(key.decrypt(&c).unwrap(), m);
+
+        let generated = PaillierPrivateKey::generate(128).unwrap();
+        let c = generated.public_key().encrypt(&m).unwrap();
+        assert_eq!(generated.decrypt(&c).unwrap(), m);
+    }
+
+    #[test]
+    fn homomorphic_operations() {
+        let key = key();
+        let public_key = key.public_key();
+        let n = &public_key.n;
+        let (a, b) = (BigUint::from(123_456u32), n - 1000u32);
+        let ca = public_key.encrypt(&a).unwrap();
+        let cb = public_key.encrypt(&b).unwrap();
+
+        // Sums wrap around modulo n.
+        let sum = (&a + &b) % n;
+        assert_eq!(key.decrypt(&public_key.add(&ca, &cb)).unwrap(), sum);
+        assert_eq!(
+            key.decrypt(&public_key.sum(&[ca.clone(), cb.clone()]))
+                .unwrap(),
+            sum
+        );
+        assert_eq!(
+            key.decrypt(&public_key.sum(&[])).unwrap(),
+            BigUint::from(0u32)
+        );
+        let k = BigUint::from(99u32);
+        assert_eq!(
+            key.decrypt(&public_key.add_plaintext(&ca, &k)).unwrap(),
+            &a + &k
+        );
+        assert_eq!(
+            key.decrypt(&public_key.mul_plaintext(&cb, &k)).unwrap(),
+            &b * &k % n
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_are_rejected() {
+        let p = BigUint::from(2_147_483_647u32);
+        let composite = BigUint::from(4_294_967_297u64); // 641 * 6700417
+        assert!(matches!(
+            PaillierPrivateKey::from_primes(&p, &p),
+            Err(PaillierError::InvalidPrimes)
+        ));
+        assert!(matches!(
+            PaillierPrivateKey::from_primes(&p, &composite),
+            Err(PaillierError::InvalidPrimes)
+        ));
+        assert!(matches!(
+            PaillierPrivateKey::from_primes(&composite, &p),
+            Err(PaillierError::InvalidPrimes)
+        ));
+        // 3 divides 7 - 1, so gcd(n, λ) != 1.
+        assert!(matches!(
+            PaillierPrivateKey::from_primes(&BigUint::from(3u32), &BigUint::from(7u32)),
+            Err(PaillierError::InvalidPrimes)
+        ));
+        assert!(matches!(
+            PaillierPrivateKey::generate(63),
+            Err(PaillierError::InvalidKeySize)
+        ));
+
+        let key = key();
+        let public_key = key.public_key();
+        let n = &public_key.n;
+        let one = BigUint::from(1u32);
+        assert!(matches!(
+            public_key.encrypt(n),
+            Err(PaillierError::MessageOutOfRange)
+        ));
+        for r in [
+            BigUint::from(0u32),
+            BigUint::from(2_147_483_647u32),
+            n.clone(),
+        ] {
+            assert!(matches!(
+                public_key.encrypt_with_randomness(&one, &r),
+                Err(PaillierError::InvalidRandomness)
+            ));
+        }
+        for c in [
+            BigUint::from(2_147_483_647u32),
+            public_key.n_squared().clone(),
+        ] {
+            assert!(matches!(
+                key.decrypt(&c),
+                Err(PaillierError::InvalidCiphertext)
+            ));
+        }
+    }
+}
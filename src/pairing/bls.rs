@@ -0,0 +1,144 @@
+// src/pairing/bls.rs
+
+use super::bn254::Bn254;
+use super::fp2::Fp2Element;
+use super::{Pairing, PairingError};
+use crate::elliptic_curve::AffinePoint;
+use crate::hash::{HashFunction, Sha256};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+use rand::rngs::OsRng;
+
+/// Domain separation prefix of `Bn254::hash_to_g1`.
+const HASH_TO_G1_DOMAIN: &[u8] = b"BN254G1_SHA-256_TRY_AND_INCREMENT";
+
+/// A BLS public key: the point xG2 in G2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlsPublicKey {
+    pub pairing: Bn254,                 // Curve and pairing
+    pub point: AffinePoint<Fp2Element>, // xG2
+}
+
+/// A BLS private key (Boneh, Lynn and Shacham, 2001) on BN254.
+///
+/// A signature is the single point xH(m) in G1, 33 bytes compressed, and it is checked with
+/// e(xH(m), G2) = e(H(m), xG2). Public keys live in G2 so that signatures use the smaller
+/// group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlsPrivateKey {
+    pub public_key: BlsPublicKey,
+    pub(crate) x: BigUint, // Private scalar in [1, r - 1]
+}
+
+impl Bn254 {
+    /// Hashes a message onto G1 by try-and-increment: for a counter c = 0, 1, ..., take
+    /// x = SHA-256(domain || c || message) mod p until x^3 + 3 is a square, and return the
+    /// point with the even square root as y. E(GF(p)) has prime order, so no cofactor needs
+    /// clearing.
+    ///
+    /// Half of the candidates succeed, so the number of attempts depends on the message and
+    /// the running time leaks it; constant-time encodings avoid this.
+    pub fn hash_to_g1(&self, message: &[u8]) -> AffinePoint {
+        let field = &self.curve.field;
+        for counter in 0u32.. {
+            let mut hasher = Sha256::new();
+            hasher.update(HASH_TO_G1_DOMAIN);
+            hasher.update(&counter.to_be_bytes());
+            hasher.update(message);
+            let x = BigUint::from_bytes_be(&hasher.finalize()) % &field.p;
+            if let Some(y) = field.sqrt(&self.curve.right_hand_side(&x)) {
+                let y = if y.bit(0) { &field.p - y } else { y };
+                return AffinePoint::new(x, y);
+            }
+        }
+        unreachable!("half of all x-coordinates lie on the curve")
+    }
+}
+
+impl BlsPublicKey {
+    /// Creates a public key after checking that the point is in G2 and not the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BlsPublicKey)` if `point` is a nonzero element of G2.
+    /// * `Err(PairingError::PointNotInGroup)` otherwise.
+    pub fn new(pairing: Bn254, point: AffinePoint<Fp2Element>) -> Result<Self, PairingError> {
+        if point.is_infinity() || !pairing.is_in_g2(&point) {
+            return Err(PairingError::PointNotInGroup);
+        }
+        Ok(BlsPublicKey { pairing, point })
+    }
+
+    /// Decodes the output of `VerifyingKey::to_bytes` and validates the point.
+    pub fn from_bytes(pairing: Bn254, bytes: &[u8]) -> Result<Self, PairingError> {
+        let point = pairing.twist.decode_point(bytes)?;
+        Self::new(pairing, point)
+    }
+}
+
+impl BlsPrivateKey {
+    /// Generates a key pair with a uniformly random x in [1, r - 1].
+    pub fn generate(pairing: &Bn254) -> Self {
+        let x = OsRng.gen_biguint_range(&BigUint::from(1u32), &pairing.r);
+        Self::from_private_scalar(pairing.clone(), x).expect("x is in [1, r - 1]")
+    }
+
+    /// Creates the key pair for a given private scalar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BlsPrivateKey)` if 1 <= x <= r - 1.
+    /// * `Err(PairingError::InvalidPrivateKey)` otherwise.
+    pub fn from_private_scalar(pairing: Bn254, x: BigUint) -> Result<Self, PairingError> {
+        if x.is_zero() || x >= pairing.r {
+            return Err(PairingError::InvalidPrivateKey);
+        }
+        let point = pairing.g2_mul(&x, &pairing.g2);
+        Ok(BlsPrivateKey {
+            public_key: BlsPublicKey { pairing, point },
+            x,
+        })
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &BlsPublicKey {
+        &self.public_key
+    }
+}
+
+impl SigningKey for BlsPrivateKey {
+    type VerifyingKey = BlsPublicKey;
+
+    /// Returns xH(m) as a compressed SEC 1 point.
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        let pairing = &self.public_key.pairing;
+        let signature = pairing.g1_mul(&self.x, &pairing.hash_to_g1(message));
+        pairing.curve.encode_point(&signature, true)
+    }
+
+    fn verifying_key(&self) -> BlsPublicKey {
+        self.public_key.clone()
+    }
+}
+
+impl VerifyingKey for BlsPublicKey {
+    /// Encodes the point uncompressed, as in `TwistCurve::encode_point`.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.pairing.twist.encode_point(&self.point)
+    }
+
+    /// Checks e(signature, G2) = e(H(m), xG2), rejecting encodings that are not points of
+    /// G1 or that encode the identity.
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        let pairing = &self.pairing;
+        let Ok(signature) = pairing.curve.decode_point(signature) else {
+            return false;
+        };
+        if signature.is_infinity() || !pairing.is_in_g1(&signature) {
+            return false;
+        }
+        let hashed = pairing.hash_to_g1(message);
+        pairing.pair(&signature, &pairing.g2) == pairing.pair(&hashed, &self.point)
+    }
+}
@@ -0,0 +1,265 @@
+// src/pairing/bn254.rs
+
+use super::fp12::{Fp12, Fp12Element};
+use super::fp2::{Fp2, Fp2Element};
+use super::fp6::Fp6;
+use super::twist::TwistCurve;
+use super::{miller_loop, Pairing};
+use crate::algebra::traits::{Group, Ring};
+use crate::elliptic_curve::{AffinePoint, Curve};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use num_traits::Num;
+
+/// Field prime p = 36u^4 + 36u^3 + 24u^2 + 6u + 1.
+const P: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+/// Group order r = 36u^4 + 36u^3 + 18u^2 + 6u + 1.
+const R: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+/// The BN parameter u.
+const U: u64 = 4965661367192848881;
+
+/// Coordinates (c0, c1) of the generator of G2, as in EIP-197.
+const G2_X: [&str; 2] = [
+    "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+    "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+];
+const G2_Y: [&str; 2] = [
+    "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+    "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+];
+
+/// The pairing that `Pairing::pair` computes on BN254.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingVariant {
+    Tate,
+    OptimalAte,
+}
+
+/// The Barreto-Naehrig curve BN254 (alt_bn128) used by Ethereum's precompiles.
+///
+/// E: y^2 = x^3 + 3 over GF(p) has prime order r and embedding degree 12. G1 = E(GF(p)) and
+/// G2 is the subgroup of order r of the sextic twist E': y^2 = x^3 + 3 / xi over GF(p^2),
+/// xi = 9 + u, which maps into E(GF(p^12)) by (x, y) -> (x w^2, y w^3). GT is the subgroup of
+/// r-th roots of unity in GF(p^12) = GF(p^2)[v, w] / (v^3 - xi, w^2 - v).
+///
+/// Both the reduced Tate pairing, with a Miller loop of length log r over G1, and the optimal
+/// ate pairing, with a loop of length log(6u + 2) over G2, are available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bn254 {
+    pub curve: Curve,                // E over GF(p)
+    pub twist: TwistCurve,           // E' over GF(p^2)
+    pub fp12: Fp12,                  // GF(p^12)
+    pub r: BigUint,                  // Order of G1, G2 and GT
+    pub g1: AffinePoint,             // Generator (1, 2) of G1
+    pub g2: AffinePoint<Fp2Element>, // Generator of G2
+    pub variant: PairingVariant,     // Pairing computed by `pair`
+    ate_loop: BigUint,               // 6u + 2
+    hard_exponent: BigUint,          // (p^4 - p^2 + 1) / r
+}
+
+impl Bn254 {
+    /// Sets up BN254 with `pair` computing the given variant.
+    pub fn new(variant: PairingVariant) -> Self {
+        let parse = |hex: &str| BigUint::from_str_radix(hex, 16).expect("valid constant");
+        let decimal = |text: &str| BigUint::from_str_radix(text, 10).expect("valid constant");
+        let (p, r) = (parse(P), parse(R));
+        let field = IntegerModP::new_valid_prime(p.clone());
+        let curve = Curve {
+            field: field.clone(),
+            a: BigUint::from(0u32),
+            b: BigUint::from(3u32),
+        };
+
+        let fp2 = Fp2::with_minus_one(field);
+        let xi = fp2.element(BigUint::from(9u32), BigUint::from(1u32));
+        let xi_inverse = fp2.inverse(&xi).expect("xi is nonzero");
+        let twist = TwistCurve::new(fp2.clone(), fp2.scale(&xi_inverse, &BigUint::from(3u32)));
+        let fp12 = Fp12::new(Fp6::new(fp2.clone(), xi));
+
+        let g2 = AffinePoint::new(
+            fp2.element(decimal(G2_X[0]), decimal(G2_X[1])),
+            fp2.element(decimal(G2_Y[0]), decimal(G2_Y[1])),
+        );
+        let p2 = &p * &p;
+        Bn254 {
+            curve,
+            twist,
+            fp12,
+            g1: AffinePoint::new(BigUint::from(1u32), BigUint::from(2u32)),
+            g2,
+            variant,
+            ate_loop: BigUint::from(U) * 6u32 + 2u32,
+            hard_exponent: (&p2 * &p2 - &p2 + 1u32) / &r,
+            r,
+        }
+    }
+
+    /// Returns GF(p^2).
+    pub fn fp2(&self) -> &Fp2 {
+        &self.twist.fp2
+    }
+
+    /// Computes the reduced Tate pairing f_(r, P)(psi(Q))^((p^12 - 1) / r) for P in G1 and Q
+    /// in G2, where psi(Q) = (x_Q w^2, y_Q w^3) is Q moved onto E.
+    ///
+    /// The line through T with slope lambda, evaluated at psi(Q), is
+    /// (lambda x_T - y_T) - lambda x_Q w^2 + y_Q w^3. Vertical lines lie in GF(p^6), which the
+    /// final exponentiation sends to 1.
+    pub fn tate(&self, p: &AffinePoint, q: &AffinePoint<Fp2Element>) -> Fp12Element {
+        let (AffinePoint::Finite { .. }, AffinePoint::Finite { x: xq, y: yq }) = (p, q) else {
+            return self.fp12.one();
+        };
+        let (f, fp2) = (&self.curve.field, self.fp2());
+        let (f_value, _) = miller_loop(&self.fp12, p, &self.r, |t, s| {
+            let AffinePoint::Finite { x: xt, y: yt } = t else {
+                return (AffinePoint::Infinity, self.fp12.one());
+            };
+            match self.curve.tangent_or_chord(t, s) {
+                Some((sum, lambda)) => {
+                    let constant = f.sub(&f.mul(&lambda, xt), yt);
+                    let line = self.fp12.element_in_w_basis([
+                        fp2.embed(constant),
+                        fp2.zero(),
+                        fp2.neg(&fp2.scale(xq, &lambda)),
+                        yq.clone(),
+                        fp2.zero(),
+                        fp2.zero(),
+                    ]);
+                    (sum, line)
+                }
+                None => (AffinePoint::Infinity, self.fp12.one()),
+            }
+        });
+        self.final_exponentiation(&f_value)
+    }
+
+    /// Computes the optimal ate pairing for P in G1 and Q in G2:
+    /// (f_(6u + 2, Q)(P) l_(T, pi(Q))(P) l_(T + pi(Q), -pi^2(Q))(P))^((p^12 - 1) / r) with
+    /// T = [6u + 2]Q, where pi is the Frobenius endomorphism carried over to the twist.
+    ///
+    /// The Miller loop runs over the twist. A line through T with slope lambda on E' becomes
+    /// a line with slope lambda w on E, whose value at P is y_P - lambda x_P w +
+    /// (lambda x_T - y_T) w^3.
+    pub fn optimal_ate(&self, p: &AffinePoint, q: &AffinePoint<Fp2Element>) -> Fp12Element {
+        let (AffinePoint::Finite { x: xp, y: yp }, AffinePoint::Finite { .. }) = (p, q) else {
+            return self.fp12.one();
+        };
+        let fp2 = self.fp2();
+        let mut step = |t: &AffinePoint<Fp2Element>, s: &AffinePoint<Fp2Element>| {
+            let AffinePoint::Finite { x: xt, y: yt } = t else {
+                return (AffinePoint::Infinity, self.fp12.one());
+            };
+            match self.twist.tangent_or_chord(t, s) {
+                Some((sum, lambda)) => {
+                    let line = self.fp12.element_in_w_basis([
+                        fp2.embed(yp.clone()),
+                        fp2.neg(&fp2.scale(&lambda, xp)),
+                        fp2.zero(),
+                        fp2.sub(&fp2.mul(&lambda, xt), yt),
+                        fp2.zero(),
+                        fp2.zero(),
+                    ]);
+                    (sum, line)
+                }
+                None => (AffinePoint::Infinity, self.fp12.one()),
+            }
+        };
+        let (f_value, t) = miller_loop(&self.fp12, q, &self.ate_loop, &mut step);
+
+        let q1 = self.twist_frobenius(q);
+        let q2 = self.twist.negate(&self.twist_frobenius(&q1));
+        let (t, line1) = step(&t, &q1);
+        let (_, line2) = step(&t, &q2);
+        let f_value = self.fp12.mul(&self.fp12.mul(&f_value, &line1), &line2);
+        self.final_exponentiation(&f_value)
+    }
+
+    /// Raises to (p^12 - 1) / r = (p^6 - 1)(p^2 + 1)(p^4 - p^2 + 1) / r. The first two factors
+    /// cost a conjugation, an inversion and a Frobenius map; the last is a plain
+    /// exponentiation.
+    pub fn final_exponentiation(&self, f: &Fp12Element) -> Fp12Element {
+        let fp12 = &self.fp12;
+        let inverse = fp12.inverse(f).expect("Miller's loop never returns zero");
+        let easy = fp12.mul(&fp12.conjugate(f), &inverse);
+        let easy = fp12.mul(&fp12.frobenius(&easy, 2), &easy);
+        fp12.pow(&easy, &self.hard_exponent)
+    }
+
+    /// Applies the p-power Frobenius of E(GF(p^12)) to a point of the twist:
+    /// (x, y) -> (conj(x) xi^((p - 1) / 3), conj(y) xi^((p - 1) / 2)).
+    pub fn twist_frobenius(&self, q: &AffinePoint<Fp2Element>) -> AffinePoint<Fp2Element> {
+        let AffinePoint::Finite { x, y } = q else {
+            return AffinePoint::Infinity;
+        };
+        let fp2 = self.fp2();
+        let gamma = &self.fp12.frobenius_coefficients;
+        AffinePoint::new(
+            fp2.mul(&fp2.conjugate(x), &gamma[2]),
+            fp2.mul(&fp2.conjugate(y), &gamma[3]),
+        )
+    }
+}
+
+impl Pairing for Bn254 {
+    type G1 = AffinePoint;
+    type G2 = AffinePoint<Fp2Element>;
+    type Gt = Fp12Element;
+
+    fn order(&self) -> &BigUint {
+        &self.r
+    }
+
+    fn g1_generator(&self) -> AffinePoint {
+        self.g1.clone()
+    }
+
+    fn g2_generator(&self) -> AffinePoint<Fp2Element> {
+        self.g2.clone()
+    }
+
+    fn g1_add(&self, a: &AffinePoint, b: &AffinePoint) -> AffinePoint {
+        self.curve.add(a, b)
+    }
+
+    fn g1_mul(&self, k: &BigUint, a: &AffinePoint) -> AffinePoint {
+        self.curve.scalar_mul(k, a)
+    }
+
+    fn g2_add(&self, a: &AffinePoint<Fp2Element>, b: &AffinePoint<Fp2Element>) -> Self::G2 {
+        self.twist.add(a, b)
+    }
+
+    fn g2_mul(&self, k: &BigUint, a: &AffinePoint<Fp2Element>) -> Self::G2 {
+        self.twist.scalar_mul(k, a)
+    }
+
+    /// E(GF(p)) has prime order, so every point on the curve is in G1.
+    fn is_in_g1(&self, a: &AffinePoint) -> bool {
+        self.curve.is_on_curve(a)
+    }
+
+    fn is_in_g2(&self, a: &AffinePoint<Fp2Element>) -> bool {
+        self.twist.is_on_curve(a) && self.twist.scalar_mul(&self.r, a).is_infinity()
+    }
+
+    fn gt_mul(&self, a: &Fp12Element, b: &Fp12Element) -> Fp12Element {
+        self.fp12.mul(a, b)
+    }
+
+    fn gt_pow(&self, a: &Fp12Element, k: &BigUint) -> Fp12Element {
+        self.fp12.pow(a, k)
+    }
+
+    fn gt_one(&self) -> Fp12Element {
+        self.fp12.one()
+    }
+
+    fn pair(&self, p: &AffinePoint, q: &AffinePoint<Fp2Element>) -> Fp12Element {
+        match self.variant {
+            PairingVariant::Tate => self.tate(p, q),
+            PairingVariant::OptimalAte => self.optimal_ate(p, q),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// src/pairing/fp12.rs
+
+use super::fp2::Fp2Element;
+use super::fp6::{Fp6, Fp6Element};
+use crate::algebra::traits::{Algebra, Field, Group, Membership, Ring};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// The quadratic extension GF(p^12) = GF(p^6)[w] / (w^2 - v), the target field of pairings
+/// with embedding degree 12.
+///
+/// As w^6 = xi, an element is also a polynomial of degree 5 in w over GF(p^2), which is how
+/// the Frobenius map acts on it: the coefficient of w^i is conjugated and multiplied by
+/// xi^(i (p - 1) / 6).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fp12 {
+    pub fp6: Fp6,                                // GF(p^6)
+    pub frobenius_coefficients: Vec<Fp2Element>, // xi^(i (p - 1) / 6) for i = 0, ..., 5
+}
+
+/// An element c0 + c1 w of GF(p^12).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fp12Element {
+    pub c0: Fp6Element,
+    pub c1: Fp6Element,
+}
+
+impl Fp12 {
+    /// Creates GF(p^12) over `fp6`.
+    ///
+    /// # Panics
+    ///
+    /// If p != 1 mod 6, where the Frobenius coefficients are not powers of xi.
+    pub fn new(fp6: Fp6) -> Self {
+        let fp2 = &fp6.fp2;
+        let p = &fp2.base.p;
+        assert!(
+            (p - 1u32) % 6u32 == BigUint::zero(),
+            "the tower needs p = 1 mod 6"
+        );
+        let gamma = fp2.pow(&fp6.non_residue, &((p - 1u32) / 6u32));
+        let mut frobenius_coefficients = vec![fp2.one()];
+        for i in 1..6 {
+            let next = fp2.mul(&frobenius_coefficients[i - 1], &gamma);
+            frobenius_coefficients.push(next);
+        }
+        Fp12 {
+            fp6,
+            frobenius_coefficients,
+        }
+    }
+
+    /// Embeds an element of GF(p^6).
+    pub fn embed(&self, c0: Fp6Element) -> Fp12Element {
+        Fp12Element {
+            c0,
+            c1: self.fp6.zero(),
+        }
+    }
+
+    /// Builds the element sum a_i w^i from its coefficients over GF(p^2). Lines in Miller's
+    /// loop are sparse in this basis.
+    pub fn element_in_w_basis(&self, a: [Fp2Element; 6]) -> Fp12Element {
+        let [a0, a1, a2, a3, a4, a5] = a;
+        Fp12Element {
+            c0: Fp6Element {
+                c0: a0,
+                c1: a2,
+                c2: a4,
+            },
+            c1: Fp6Element {
+                c0: a1,
+                c1: a3,
+                c2: a5,
+            },
+        }
+    }
+
+    /// Subtracts `b` from `a`.
+    pub fn sub(&self, a: &Fp12Element, b: &Fp12Element) -> Fp12Element {
+        Fp12Element {
+            c0: self.fp6.sub(&a.c0, &b.c0),
+            c1: self.fp6.sub(&a.c1, &b.c1),
+        }
+    }
+
+    /// Returns a^2 with two GF(p^6) multiplications.
+    pub fn square(&self, a: &Fp12Element) -> Fp12Element {
+        let f = &self.fp6;
+        // (c0 + c1 w)^2 = (c0^2 + v c1^2) + 2 c0 c1 w, with c0^2 + v c1^2 =
+        // (c0 + c1)(c0 + v c1) - (1 + v) c0 c1.
+        let c0c1 = f.mul(&a.c0, &a.c1);
+        let product = f.mul(&f.add(&a.c0, &a.c1), &f.add(&a.c0, &f.mul_by_v(&a.c1)));
+        let c0 = f.sub(&product, &f.add(&c0c1, &f.mul_by_v(&c0c1)));
+        Fp12Element {
+            c0,
+            c1: f.add(&c0c1, &c0c1),
+        }
+    }
+
+    /// Returns the conjugate c0 - c1 w, which is a^(p^6). On elements of norm one, such as
+    /// pairing values, it is the inverse.
+    pub fn conjugate(&self, a: &Fp12Element) -> Fp12Element {
+        Fp12Element {
+            c0: a.c0.clone(),
+            c1: self.fp6.neg(&a.c1),
+        }
+    }
+
+    /// Returns a^(p^k) by applying the Frobenius map k times.
+    pub fn frobenius(&self, a: &Fp12Element, k: usize) -> Fp12Element {
+        let fp2 = &self.fp6.fp2;
+        let gamma = &self.frobenius_coefficients;
+        let map = |c: &Fp2Element, i: usize| fp2.mul(&fp2.conjugate(c), &gamma[i]);
+        let mut result = a.clone();
+        for _ in 0..k {
+            // c0 holds the coefficients of w^0, w^2, w^4 and c1 those of w^1, w^3, w^5.
+            result = Fp12Element {
+                c0: Fp6Element {
+                    c0: map(&result.c0.c0, 0),
+                    c1: map(&result.c0.c1, 2),
+                    c2: map(&result.c0.c2, 4),
+                },
+                c1: Fp6Element {
+                    c0: map(&result.c1.c0, 1),
+                    c1: map(&result.c1.c1, 3),
+                    c2: map(&result.c1.c2, 5),
+                },
+            };
+        }
+        result
+    }
+}
+
+impl Algebra for Fp12 {
+    type Element = Fp12Element;
+}
+
+impl Ring for Fp12 {
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        Fp12Element {
+            c0: self.fp6.add(&a.c0, &b.c0),
+            c1: self.fp6.add(&a.c1, &b.c1),
+        }
+    }
+
+    /// Karatsuba multiplication with three GF(p^6) products.
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let f = &self.fp6;
+        let v0 = f.mul(&a.c0, &b.c0);
+        let v1 = f.mul(&a.c1, &b.c1);
+        let cross = f.mul(&f.add(&a.c0, &a.c1), &f.add(&b.c0, &b.c1));
+        Fp12Element {
+            c0: f.add(&v0, &f.mul_by_v(&v1)),
+            c1: f.sub(&cross, &f.add(&v0, &v1)),
+        }
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.embed(self.fp6.zero())
+    }
+
+    fn one(&self) -> Self::Element {
+        self.embed(self.fp6.one())
+    }
+}
+
+impl Group for Fp12 {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.mul(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        self.one()
+    }
+
+    /// Returns conj(a) / (c0^2 - v c1^2).
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        let f = &self.fp6;
+        let norm = f.sub(&f.mul(&a.c0, &a.c0), &f.mul_by_v(&f.mul(&a.c1, &a.c1)));
+        let norm_inverse = Group::inverse(f, &norm)?;
+        Some(Fp12Element {
+            c0: f.mul(&a.c0, &norm_inverse),
+            c1: f.neg(&f.mul(&a.c1, &norm_inverse)),
+        })
+    }
+
+    /// Square-and-multiply with the dedicated squaring, as the final exponentiation of a
+    /// pairing spends most of its time here.
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        let mut result = self.one();
+        for i in (0..exp.bits()).rev() {
+            result = self.square(&result);
+            if exp.bit(i) {
+                result = self.mul(&result, a);
+            }
+        }
+        result
+    }
+}
+
+impl Field for Fp12 {}
+
+impl Membership for Fp12 {
+    /// Elements have two coefficients in GF(p^6).
+    fn is_element(&self, a: &Self::Element) -> bool {
+        self.fp6.is_element(&a.c0) && self.fp6.is_element(&a.c1)
+    }
+}
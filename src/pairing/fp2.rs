@@ -0,0 +1,204 @@
+// src/pairing/fp2.rs
+
+use super::pow_by_squaring;
+use crate::algebra::traits::{Algebra, Field, Group, Membership, Ring};
+use crate::integer_mod_p::IntegerModP;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::fmt;
+
+/// The quadratic extension GF(p^2) = GF(p)[u] / (u^2 - beta) for a non-square beta.
+///
+/// It holds the target group of pairings with embedding degree 2 and is the bottom layer of
+/// the tower for GF(p^12).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fp2 {
+    pub base: IntegerModP,    // GF(p)
+    pub non_residue: BigUint, // beta = u^2
+}
+
+/// An element c0 + c1 u of GF(p^2).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fp2Element {
+    pub c0: BigUint,
+    pub c1: BigUint,
+}
+
+impl Fp2 {
+    /// Creates GF(p^2) with u^2 = `non_residue`.
+    ///
+    /// # Panics
+    ///
+    /// If `non_residue` is a square modulo p, so that u^2 - beta is reducible.
+    pub fn new(base: IntegerModP, non_residue: BigUint) -> Self {
+        assert!(
+            !base.is_square(&non_residue),
+            "u^2 - beta must be irreducible"
+        );
+        let non_residue = non_residue % &base.p;
+        Fp2 { base, non_residue }
+    }
+
+    /// Creates GF(p^2) with u^2 = -1, which needs p = 3 mod 4.
+    pub fn with_minus_one(base: IntegerModP) -> Self {
+        let minus_one = &base.p - 1u32;
+        Self::new(base, minus_one)
+    }
+
+    /// Returns the element c0 + c1 u, reducing both coefficients.
+    pub fn element(&self, c0: BigUint, c1: BigUint) -> Fp2Element {
+        let p = &self.base.p;
+        Fp2Element {
+            c0: c0 % p,
+            c1: c1 % p,
+        }
+    }
+
+    /// Embeds an element of GF(p).
+    pub fn embed(&self, c0: BigUint) -> Fp2Element {
+        self.element(c0, BigUint::zero())
+    }
+
+    /// Returns a uniformly random element.
+    pub fn random_element(&self) -> Fp2Element {
+        Fp2Element {
+            c0: self.base.random_element(),
+            c1: self.base.random_element(),
+        }
+    }
+
+    /// Subtracts `b` from `a`.
+    pub fn sub(&self, a: &Fp2Element, b: &Fp2Element) -> Fp2Element {
+        let f = &self.base;
+        Fp2Element {
+            c0: f.sub(&a.c0, &b.c0),
+            c1: f.sub(&a.c1, &b.c1),
+        }
+    }
+
+    /// Returns -a.
+    pub fn neg(&self, a: &Fp2Element) -> Fp2Element {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Returns 2a.
+    pub fn double(&self, a: &Fp2Element) -> Fp2Element {
+        self.add(a, a)
+    }
+
+    /// Returns a^2 with two base field multiplications: (c0 + c1)(c0 + beta c1) gives
+    /// c0^2 + beta c1^2 once the cross terms (1 + beta) c0 c1 are removed.
+    pub fn square(&self, a: &Fp2Element) -> Fp2Element {
+        let f = &self.base;
+        let c0c1 = f.mul(&a.c0, &a.c1);
+        let product = f.mul(
+            &f.add(&a.c0, &a.c1),
+            &f.add(&a.c0, &f.mul(&self.non_residue, &a.c1)),
+        );
+        let cross = f.mul(&f.add(&BigUint::from(1u32), &self.non_residue), &c0c1);
+        Fp2Element {
+            c0: f.sub(&product, &cross),
+            c1: f.add(&c0c1, &c0c1),
+        }
+    }
+
+    /// Multiplies by an element of GF(p).
+    pub fn scale(&self, a: &Fp2Element, k: &BigUint) -> Fp2Element {
+        let f = &self.base;
+        Fp2Element {
+            c0: f.mul(&a.c0, k),
+            c1: f.mul(&a.c1, k),
+        }
+    }
+
+    /// Returns the conjugate c0 - c1 u, which is also the Frobenius image a^p.
+    pub fn conjugate(&self, a: &Fp2Element) -> Fp2Element {
+        Fp2Element {
+            c0: a.c0.clone(),
+            c1: self.base.sub(&BigUint::zero(), &a.c1),
+        }
+    }
+
+    /// Returns the norm a * conj(a) = c0^2 - beta c1^2, an element of GF(p).
+    pub fn norm(&self, a: &Fp2Element) -> BigUint {
+        let f = &self.base;
+        let c1_squared = f.mul(&a.c1, &a.c1);
+        f.sub(&f.mul(&a.c0, &a.c0), &f.mul(&self.non_residue, &c1_squared))
+    }
+}
+
+impl Fp2Element {
+    /// Returns true for the zero element.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl Algebra for Fp2 {
+    type Element = Fp2Element;
+}
+
+impl Ring for Fp2 {
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let f = &self.base;
+        Fp2Element {
+            c0: f.add(&a.c0, &b.c0),
+            c1: f.add(&a.c1, &b.c1),
+        }
+    }
+
+    /// Karatsuba multiplication with three base field products.
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let f = &self.base;
+        let v0 = f.mul(&a.c0, &b.c0);
+        let v1 = f.mul(&a.c1, &b.c1);
+        let cross = f.mul(&f.add(&a.c0, &a.c1), &f.add(&b.c0, &b.c1));
+        Fp2Element {
+            c0: f.add(&v0, &f.mul(&self.non_residue, &v1)),
+            c1: f.sub(&cross, &f.add(&v0, &v1)),
+        }
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.embed(BigUint::zero())
+    }
+
+    fn one(&self) -> Self::Element {
+        self.embed(BigUint::from(1u32))
+    }
+}
+
+impl Group for Fp2 {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.mul(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        self.one()
+    }
+
+    /// Returns conj(a) / N(a).
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        let norm_inverse = Group::inverse(&self.base, &self.norm(a))?;
+        Some(self.scale(&self.conjugate(a), &norm_inverse))
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        pow_by_squaring(self, a, exp)
+    }
+}
+
+impl Field for Fp2 {}
+
+impl Membership for Fp2 {
+    /// Elements have both coefficients in [0, p - 1].
+    fn is_element(&self, a: &Self::Element) -> bool {
+        a.c0 < self.base.p && a.c1 < self.base.p
+    }
+}
+
+impl fmt::Display for Fp2Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}u", self.c0, self.c1)
+    }
+}
@@ -0,0 +1,165 @@
+// src/pairing/fp6.rs
+
+use super::fp2::{Fp2, Fp2Element};
+use super::pow_by_squaring;
+use crate::algebra::traits::{Algebra, Field, Group, Membership, Ring};
+use num_bigint::BigUint;
+
+/// The cubic extension GF(p^6) = GF(p^2)[v] / (v^3 - xi) for an element xi of GF(p^2) that
+/// is neither a square nor a cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fp6 {
+    pub fp2: Fp2,                // GF(p^2)
+    pub non_residue: Fp2Element, // xi = v^3
+}
+
+/// An element c0 + c1 v + c2 v^2 of GF(p^6).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fp6Element {
+    pub c0: Fp2Element,
+    pub c1: Fp2Element,
+    pub c2: Fp2Element,
+}
+
+impl Fp6 {
+    /// Creates GF(p^6) with v^3 = `non_residue`. The caller vouches that xi is not a cube,
+    /// which is what makes v^3 - xi irreducible.
+    pub fn new(fp2: Fp2, non_residue: Fp2Element) -> Self {
+        Fp6 { fp2, non_residue }
+    }
+
+    /// Embeds an element of GF(p^2).
+    pub fn embed(&self, c0: Fp2Element) -> Fp6Element {
+        Fp6Element {
+            c0,
+            c1: self.fp2.zero(),
+            c2: self.fp2.zero(),
+        }
+    }
+
+    /// Subtracts `b` from `a`.
+    pub fn sub(&self, a: &Fp6Element, b: &Fp6Element) -> Fp6Element {
+        let f = &self.fp2;
+        Fp6Element {
+            c0: f.sub(&a.c0, &b.c0),
+            c1: f.sub(&a.c1, &b.c1),
+            c2: f.sub(&a.c2, &b.c2),
+        }
+    }
+
+    /// Returns -a.
+    pub fn neg(&self, a: &Fp6Element) -> Fp6Element {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Multiplies by v: (c0, c1, c2) v = (xi c2, c0, c1).
+    pub fn mul_by_v(&self, a: &Fp6Element) -> Fp6Element {
+        Fp6Element {
+            c0: self.fp2.mul(&a.c2, &self.non_residue),
+            c1: a.c0.clone(),
+            c2: a.c1.clone(),
+        }
+    }
+
+    /// Multiplies every coefficient by an element of GF(p^2).
+    pub fn scale(&self, a: &Fp6Element, k: &Fp2Element) -> Fp6Element {
+        let f = &self.fp2;
+        Fp6Element {
+            c0: f.mul(&a.c0, k),
+            c1: f.mul(&a.c1, k),
+            c2: f.mul(&a.c2, k),
+        }
+    }
+}
+
+impl Fp6Element {
+    /// Returns true for the zero element.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl Algebra for Fp6 {
+    type Element = Fp6Element;
+}
+
+impl Ring for Fp6 {
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let f = &self.fp2;
+        Fp6Element {
+            c0: f.add(&a.c0, &b.c0),
+            c1: f.add(&a.c1, &b.c1),
+            c2: f.add(&a.c2, &b.c2),
+        }
+    }
+
+    /// Karatsuba multiplication with six GF(p^2) products.
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let f = &self.fp2;
+        let xi = &self.non_residue;
+        let v0 = f.mul(&a.c0, &b.c0);
+        let v1 = f.mul(&a.c1, &b.c1);
+        let v2 = f.mul(&a.c2, &b.c2);
+        let cross = |x0: &Fp2Element, x1: &Fp2Element, y0: &Fp2Element, y1: &Fp2Element| {
+            f.mul(&f.add(x0, x1), &f.add(y0, y1))
+        };
+        // c0 = v0 + xi (a1 b2 + a2 b1), c1 = a0 b1 + a1 b0 + xi v2, c2 = a0 b2 + a2 b0 + v1.
+        let t0 = f.sub(&cross(&a.c1, &a.c2, &b.c1, &b.c2), &f.add(&v1, &v2));
+        let t1 = f.sub(&cross(&a.c0, &a.c1, &b.c0, &b.c1), &f.add(&v0, &v1));
+        let t2 = f.sub(&cross(&a.c0, &a.c2, &b.c0, &b.c2), &f.add(&v0, &v2));
+        Fp6Element {
+            c0: f.add(&v0, &f.mul(xi, &t0)),
+            c1: f.add(&t1, &f.mul(xi, &v2)),
+            c2: f.add(&t2, &v1),
+        }
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.embed(self.fp2.zero())
+    }
+
+    fn one(&self) -> Self::Element {
+        self.embed(self.fp2.one())
+    }
+}
+
+impl Group for Fp6 {
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        self.mul(a, b)
+    }
+
+    fn identity(&self) -> Self::Element {
+        self.one()
+    }
+
+    /// Inverts through the adjugate (A, B, C) with a (A + Bv + Cv^2) in GF(p^2), so that only
+    /// one GF(p^2) inversion is needed.
+    fn inverse(&self, a: &Self::Element) -> Option<Self::Element> {
+        let f = &self.fp2;
+        let xi = &self.non_residue;
+        let big_a = f.sub(&f.square(&a.c0), &f.mul(xi, &f.mul(&a.c1, &a.c2)));
+        let big_b = f.sub(&f.mul(xi, &f.square(&a.c2)), &f.mul(&a.c0, &a.c1));
+        let big_c = f.sub(&f.square(&a.c1), &f.mul(&a.c0, &a.c2));
+        let rest = f.add(&f.mul(&a.c2, &big_b), &f.mul(&a.c1, &big_c));
+        let norm = f.add(&f.mul(&a.c0, &big_a), &f.mul(xi, &rest));
+        let norm_inverse = Group::inverse(f, &norm)?;
+        Some(Fp6Element {
+            c0: f.mul(&big_a, &norm_inverse),
+            c1: f.mul(&big_b, &norm_inverse),
+            c2: f.mul(&big_c, &norm_inverse),
+        })
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &BigUint) -> Self::Element {
+        pow_by_squaring(self, a, exp)
+    }
+}
+
+impl Field for Fp6 {}
+
+impl Membership for Fp6 {
+    /// Elements have three coefficients in GF(p^2).
+    fn is_element(&self, a: &Self::Element) -> bool {
+        [&a.c0, &a.c1, &a.c2].iter().all(|c| self.fp2.is_element(c))
+    }
+}
@@ -0,0 +1,91 @@
+// src/pairing/joux.rs
+
+use super::{Pairing, PairingError};
+use num_bigint::{BigUint, RandBigInt};
+use rand::rngs::OsRng;
+
+/// Joux's one-round key agreement for three parties (2000), generic over `Pairing`.
+///
+/// Each party picks a secret a and broadcasts (aG1, aG2). With the shares bG and cG of the
+/// other two, it computes e(bG1, cG2)^a = e(G1, G2)^(abc), so all three agree after a single
+/// round, which plain Diffie-Hellman cannot do for more than two parties.
+#[derive(Debug, Clone)]
+pub struct Joux<P: Pairing> {
+    pub pairing: P,
+}
+
+/// A party's broadcast share (aG1, aG2). On a symmetric pairing the two halves coincide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JouxPublicKey<P: Pairing> {
+    pub g1: P::G1, // aG1
+    pub g2: P::G2, // aG2
+}
+
+/// A party's secret exponent together with its share.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JouxPrivateKey<P: Pairing> {
+    pub(super) exponent: BigUint, // Secret a in [1, r - 1]
+    pub public_key: JouxPublicKey<P>,
+}
+
+impl<P: Pairing> JouxPrivateKey<P> {
+    /// Returns the party's share.
+    pub fn public_key(&self) -> &JouxPublicKey<P> {
+        &self.public_key
+    }
+}
+
+impl<P: Pairing> Joux<P> {
+    /// Runs the key agreement over `pairing`.
+    pub fn new(pairing: P) -> Self {
+        Joux { pairing }
+    }
+
+    /// Generates a secret a uniformly in [1, r - 1] and its share.
+    pub fn generate_private_key(&self) -> JouxPrivateKey<P> {
+        let pairing = &self.pairing;
+        let exponent = OsRng.gen_biguint_range(&BigUint::from(1u32), pairing.order());
+        JouxPrivateKey {
+            public_key: JouxPublicKey {
+                g1: pairing.g1_mul(&exponent, &pairing.g1_generator()),
+                g2: pairing.g2_mul(&exponent, &pairing.g2_generator()),
+            },
+            exponent,
+        }
+    }
+
+    /// Checks a received share: both halves lie in their groups and carry the same nonzero
+    /// exponent, which holds exactly when e(aG1, G2) = e(G1, aG2) != 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` for a valid share.
+    /// * `Err(PairingError::PointNotInGroup)` or `Err(PairingError::InconsistentShare)`.
+    pub fn validate_public_key(&self, key: &JouxPublicKey<P>) -> Result<(), PairingError> {
+        let pairing = &self.pairing;
+        if !pairing.is_in_g1(&key.g1) || !pairing.is_in_g2(&key.g2) {
+            return Err(PairingError::PointNotInGroup);
+        }
+        let left = pairing.pair(&key.g1, &pairing.g2_generator());
+        let right = pairing.pair(&pairing.g1_generator(), &key.g2);
+        if left != right || left == pairing.gt_one() {
+            return Err(PairingError::InconsistentShare);
+        }
+        Ok(())
+    }
+
+    /// Computes e(bG1, cG2)^a from the shares of the other two parties, after validating
+    /// them.
+    pub fn compute_shared_secret(
+        &self,
+        private_key: &JouxPrivateKey<P>,
+        first: &JouxPublicKey<P>,
+        second: &JouxPublicKey<P>,
+    ) -> Result<P::Gt, PairingError> {
+        self.validate_public_key(first)?;
+        self.validate_public_key(second)?;
+        let pairing = &self.pairing;
+        let base = pairing.pair(&first.g1, &second.g2);
+        Ok(pairing.gt_pow(&base, &private_key.exponent))
+    }
+}
@@ -0,0 +1,233 @@
+// src/pairing/mod.rs
+
+pub mod bls;
+pub mod bn254;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod joux;
+pub mod supersingular;
+pub mod twist;
+
+pub use bls::{BlsPrivateKey, BlsPublicKey};
+pub use bn254::{Bn254, PairingVariant};
+pub use fp12::{Fp12, Fp12Element};
+pub use fp2::{Fp2, Fp2Element};
+pub use fp6::{Fp6, Fp6Element};
+pub use joux::Joux;
+pub use supersingular::SupersingularPairing;
+pub use twist::TwistCurve;
+
+use crate::algebra::traits::{Group, Ring};
+use crate::elliptic_curve::{AffinePoint, Curve};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+use rand::rngs::OsRng;
+use std::fmt;
+
+/// Errors related to pairings and the protocols built on them.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PairingError {
+    InvalidParameters,
+    PointNotInGroup,
+    InvalidEncoding,
+    InvalidPrivateKey,
+    InconsistentShare,
+    Degenerate,
+    NotBilinear,
+}
+
+impl fmt::Display for PairingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairingError::InvalidParameters => write!(
+                f,
+                "The curve parameters do not define a pairing-friendly subgroup."
+            ),
+            PairingError::PointNotInGroup => {
+                write!(f, "The point is not in the prime-order subgroup.")
+            }
+            PairingError::InvalidEncoding => write!(f, "The encoded point is malformed."),
+            PairingError::InvalidPrivateKey => {
+                write!(f, "The private scalar must lie in [1, r - 1].")
+            }
+            PairingError::InconsistentShare => write!(
+                f,
+                "The two halves of the key share do not carry the same nonzero exponent."
+            ),
+            PairingError::Degenerate => {
+                write!(f, "The pairing of the generators is not of order r.")
+            }
+            PairingError::NotBilinear => write!(f, "The pairing is not bilinear."),
+        }
+    }
+}
+
+impl std::error::Error for PairingError {}
+
+/// A bilinear pairing e: G1 x G2 -> GT between groups of prime order r.
+///
+/// G1 and G2 are written additively and GT multiplicatively, so bilinearity reads
+/// e(aP, bQ) = e(P, Q)^(ab). Protocols such as BLS signatures and Joux's key agreement are
+/// written against this trait.
+pub trait Pairing {
+    type G1: Clone + PartialEq + fmt::Debug;
+    type G2: Clone + PartialEq + fmt::Debug;
+    type Gt: Clone + PartialEq + fmt::Debug;
+
+    /// Returns the prime order r of the three groups.
+    fn order(&self) -> &BigUint;
+
+    /// Returns the fixed generator of G1.
+    fn g1_generator(&self) -> Self::G1;
+
+    /// Returns the fixed generator of G2.
+    fn g2_generator(&self) -> Self::G2;
+
+    /// Adds two elements of G1.
+    fn g1_add(&self, a: &Self::G1, b: &Self::G1) -> Self::G1;
+
+    /// Computes kA in G1.
+    fn g1_mul(&self, k: &BigUint, a: &Self::G1) -> Self::G1;
+
+    /// Adds two elements of G2.
+    fn g2_add(&self, a: &Self::G2, b: &Self::G2) -> Self::G2;
+
+    /// Computes kA in G2.
+    fn g2_mul(&self, k: &BigUint, a: &Self::G2) -> Self::G2;
+
+    /// Returns true if `a` lies in G1, the subgroup of order r.
+    fn is_in_g1(&self, a: &Self::G1) -> bool;
+
+    /// Returns true if `a` lies in G2, the subgroup of order r.
+    fn is_in_g2(&self, a: &Self::G2) -> bool;
+
+    /// Multiplies two elements of GT.
+    fn gt_mul(&self, a: &Self::Gt, b: &Self::Gt) -> Self::Gt;
+
+    /// Computes a^k in GT.
+    fn gt_pow(&self, a: &Self::Gt, k: &BigUint) -> Self::Gt;
+
+    /// Returns the identity of GT.
+    fn gt_one(&self) -> Self::Gt;
+
+    /// Evaluates the pairing.
+    fn pair(&self, p: &Self::G1, q: &Self::G2) -> Self::Gt;
+}
+
+/// Checks a pairing on `rounds` random inputs: for random P1, P2 in G1 and Q1, Q2 in G2 it
+/// tests e(P1 + P2, Q1) = e(P1, Q1) e(P2, Q1), e(P1, Q1 + Q2) = e(P1, Q1) e(P1, Q2) and, with
+/// P1 = aG1 and Q1 = bG2, e(P1, Q1) = e(G1, G2)^(ab). Non-degeneracy is checked once:
+/// e(G1, G2) has to be a nontrivial r-th root of unity.
+///
+/// # Returns
+///
+/// * `Ok(())` if every check passes.
+/// * `Err(PairingError::Degenerate)` or `Err(PairingError::NotBilinear)` at the first failure.
+pub fn check_pairing<P: Pairing>(pairing: &P, rounds: usize) -> Result<(), PairingError> {
+    let r = pairing.order();
+    let (g1, g2) = (pairing.g1_generator(), pairing.g2_generator());
+    let base = pairing.pair(&g1, &g2);
+    if base == pairing.gt_one() || pairing.gt_pow(&base, r) != pairing.gt_one() {
+        return Err(PairingError::Degenerate);
+    }
+
+    let mut rng = OsRng;
+    let mut scalar = || rng.gen_biguint_range(&BigUint::from(1u32), r);
+    for _ in 0..rounds {
+        let (a, b, c, d) = (scalar(), scalar(), scalar(), scalar());
+        let (p1, p2) = (pairing.g1_mul(&a, &g1), pairing.g1_mul(&c, &g1));
+        let (q1, q2) = (pairing.g2_mul(&b, &g2), pairing.g2_mul(&d, &g2));
+        let e11 = pairing.pair(&p1, &q1);
+        let first = pairing.pair(&pairing.g1_add(&p1, &p2), &q1);
+        let second = pairing.pair(&p1, &pairing.g2_add(&q1, &q2));
+        if e11 != pairing.gt_pow(&base, &(a * b % r))
+            || first != pairing.gt_mul(&e11, &pairing.pair(&p2, &q1))
+            || second != pairing.gt_mul(&e11, &pairing.pair(&p1, &q2))
+        {
+            return Err(PairingError::NotBilinear);
+        }
+    }
+    Ok(())
+}
+
+/// Runs Miller's loop for the function f_(n, S) with divisor n(S) - ([n]S) - (n - 1)(O).
+///
+/// Reading n from its second most significant bit down, the accumulator is squared and
+/// multiplied by the tangent line at T, and on a one bit also by the line through T and S.
+/// `step(T, U)` returns T + U and the line through T and U evaluated at the second pairing
+/// argument; vertical lines are left out, as the final exponentiation removes them.
+///
+/// # Returns
+///
+/// The value f_(n, S) and the point [n]S.
+pub fn miller_loop<F, P>(
+    field: &F,
+    base: &P,
+    n: &BigUint,
+    mut step: impl FnMut(&P, &P) -> (P, F::Element),
+) -> (F::Element, P)
+where
+    F: Ring,
+    P: Clone,
+{
+    let mut f = field.one();
+    let mut t = base.clone();
+    for i in (0..n.bits() - 1).rev() {
+        let (doubled, line) = step(&t, &t);
+        f = field.mul(&field.mul(&f, &f), &line);
+        t = doubled;
+        if n.bit(i) {
+            let (sum, line) = step(&t, base);
+            f = field.mul(&f, &line);
+            t = sum;
+        }
+    }
+    (f, t)
+}
+
+/// Raises `a` to the power `exp` by left-to-right square-and-multiply.
+pub(crate) fn pow_by_squaring<R: Ring>(ring: &R, a: &R::Element, exp: &BigUint) -> R::Element {
+    let mut result = ring.one();
+    for i in (0..exp.bits()).rev() {
+        result = ring.mul(&result, &result);
+        if exp.bit(i) {
+            result = ring.mul(&result, a);
+        }
+    }
+    result
+}
+
+impl Curve {
+    /// For finite points T and S, returns T + S together with the slope of the line through
+    /// them (the tangent when T = S), or `None` when that line is vertical and T + S = O.
+    ///
+    /// # Panics
+    ///
+    /// If T or S is the point at infinity.
+    pub fn tangent_or_chord(
+        &self,
+        t: &AffinePoint,
+        s: &AffinePoint,
+    ) -> Option<(AffinePoint, BigUint)> {
+        let f = &self.field;
+        let (AffinePoint::Finite { x: x1, y: y1 }, AffinePoint::Finite { x: x2, y: y2 }) = (t, s)
+        else {
+            panic!("the line is only defined through finite points");
+        };
+        let lambda = if x1 != x2 {
+            f.mul(&f.sub(y2, y1), &Group::inverse(f, &f.sub(x2, x1))?)
+        } else if y1 == y2 && !y1.is_zero() {
+            let numerator = f.add(&f.mul(&BigUint::from(3u32), &f.mul(x1, x1)), &self.a);
+            f.mul(&numerator, &Group::inverse(f, &f.add(y1, y1))?)
+        } else {
+            return None;
+        };
+        let x3 = f.sub(&f.sub(&f.mul(&lambda, &lambda), x1), x2);
+        let y3 = f.sub(&f.mul(&lambda, &f.sub(x1, &x3)), y1);
+        Some((AffinePoint::new(x3, y3), lambda))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,181 @@
+// src/pairing/supersingular.rs
+
+use super::fp2::{Fp2, Fp2Element};
+use super::{miller_loop, Pairing, PairingError};
+use crate::algebra::traits::{Group, Ring};
+use crate::elliptic_curve::{AffinePoint, Curve};
+use crate::factor::is_probable_prime;
+use num_bigint::BigUint;
+use num_traits::{Num, One, Zero};
+
+/// Prime of the toy curve, p = 4 * 18446744073709551650 * r - 1 = 3 mod 4 (128 bits).
+const TOY_P: &str = "800000000000000cffffffffffffff77";
+
+/// Subgroup order of the toy curve, the Mersenne prime 2^61 - 1.
+const TOY_R: &str = "1fffffffffffffff";
+
+/// The reduced Tate pairing on the supersingular curve y^2 = x^3 + x over GF(p), p = 3 mod 4.
+///
+/// The curve has p + 1 points and embedding degree 2, so the pairing maps into the subgroup of
+/// order r of GF(p^2)^*. G1 and G2 are the same subgroup G of E(GF(p)): the distortion map
+/// (x, y) -> (-x, iy) sends the second argument to a point of E(GF(p^2)) independent of it,
+/// which makes the pairing symmetric and e(G, G) nontrivial.
+///
+/// With a 128-bit p the target group is only 256 bits, where discrete logarithms are easy, so
+/// the toy parameters demonstrate the arithmetic rather than offering security.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupersingularPairing {
+    pub curve: Curve,        // y^2 = x^3 + x over GF(p)
+    pub fp2: Fp2,            // GF(p^2) with i^2 = -1
+    pub r: BigUint,          // Prime order of G
+    pub g: AffinePoint,      // Generator of G
+    final_exponent: BigUint, // (p + 1) / r
+}
+
+impl SupersingularPairing {
+    /// Sets up the pairing on y^2 = x^3 + x over GF(p) for a subgroup of prime order r. The
+    /// generator is h(x, y) for the smallest x with a point above it and h = (p + 1) / r.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SupersingularPairing)` if p is a prime with p = 3 mod 4 and r is an odd prime
+    ///   dividing p + 1.
+    /// * `Err(PairingError::InvalidParameters)` otherwise.
+    pub fn new(p: BigUint, r: BigUint) -> Result<Self, PairingError> {
+        let curve = Curve::new(p.clone(), BigUint::one(), BigUint::zero())
+            .map_err(|_| PairingError::InvalidParameters)?;
+        if &p % 4u32 != BigUint::from(3u32)
+            || r <= BigUint::from(2u32)
+            || !is_probable_prime(&r)
+            || !((&p + 1u32) % &r).is_zero()
+        {
+            return Err(PairingError::InvalidParameters);
+        }
+        let cofactor = (&p + 1u32) / &r;
+
+        let mut x = BigUint::one();
+        let g = loop {
+            if let Some(y) = curve.field.sqrt(&curve.right_hand_side(&x)) {
+                let g = curve.scalar_mul(&cofactor, &AffinePoint::new(x.clone(), y));
+                if !g.is_infinity() {
+                    break g;
+                }
+            }
+            x += 1u32;
+        };
+        Ok(SupersingularPairing {
+            fp2: Fp2::with_minus_one(curve.field.clone()),
+            curve,
+            r,
+            g,
+            final_exponent: cofactor,
+        })
+    }
+
+    /// Returns the pairing on the toy curve with a 128-bit p and r = 2^61 - 1.
+    pub fn toy() -> Self {
+        let parse = |hex: &str| BigUint::from_str_radix(hex, 16).expect("valid constant");
+        Self::new(parse(TOY_P), parse(TOY_R)).expect("the toy parameters are valid")
+    }
+
+    /// Applies the distortion map (x, y) -> (-x, iy), returning the coordinates in GF(p^2).
+    pub fn distort(&self, point: &AffinePoint) -> Option<(Fp2Element, Fp2Element)> {
+        let (x, y) = (point.x()?, point.y()?);
+        let f = &self.curve.field;
+        Some((
+            self.fp2.embed(f.sub(&BigUint::zero(), x)),
+            self.fp2.element(BigUint::zero(), y.clone()),
+        ))
+    }
+
+    /// Computes the reduced Tate pairing f_(r, P)(-x_Q, i y_Q)^((p^2 - 1) / r).
+    ///
+    /// The line through T with slope lambda, evaluated at the distorted Q, is
+    /// (lambda (x_Q + x_T) - y_T) + y_Q i. Vertical lines take values in GF(p), which the
+    /// factor p - 1 of the final exponent sends to 1, so they are skipped.
+    pub fn tate(&self, p: &AffinePoint, q: &AffinePoint) -> Fp2Element {
+        let (AffinePoint::Finite { .. }, AffinePoint::Finite { x: xq, y: yq }) = (p, q) else {
+            return self.fp2.one();
+        };
+        let f = &self.curve.field;
+        let (f_value, _) = miller_loop(&self.fp2, p, &self.r, |t, s| {
+            let AffinePoint::Finite { x: xt, y: yt } = t else {
+                return (AffinePoint::Infinity, self.fp2.one());
+            };
+            match self.curve.tangent_or_chord(t, s) {
+                Some((sum, lambda)) => {
+                    let c0 = f.sub(&f.mul(&lambda, &f.add(xq, xt)), yt);
+                    (sum, self.fp2.element(c0, yq.clone()))
+                }
+                None => (AffinePoint::Infinity, self.fp2.one()),
+            }
+        });
+        self.final_exponentiation(&f_value)
+    }
+
+    /// Raises to (p^2 - 1) / r = (p - 1) (p + 1) / r, where the power p - 1 is conj(f) / f.
+    fn final_exponentiation(&self, f: &Fp2Element) -> Fp2Element {
+        let fp2 = &self.fp2;
+        let inverse = fp2.inverse(f).expect("Miller's loop never returns zero");
+        let unitary = fp2.mul(&fp2.conjugate(f), &inverse);
+        fp2.pow(&unitary, &self.final_exponent)
+    }
+}
+
+impl Pairing for SupersingularPairing {
+    type G1 = AffinePoint;
+    type G2 = AffinePoint;
+    type Gt = Fp2Element;
+
+    fn order(&self) -> &BigUint {
+        &self.r
+    }
+
+    fn g1_generator(&self) -> AffinePoint {
+        self.g.clone()
+    }
+
+    fn g2_generator(&self) -> AffinePoint {
+        self.g.clone()
+    }
+
+    fn g1_add(&self, a: &AffinePoint, b: &AffinePoint) -> AffinePoint {
+        self.curve.add(a, b)
+    }
+
+    fn g1_mul(&self, k: &BigUint, a: &AffinePoint) -> AffinePoint {
+        self.curve.scalar_mul(k, a)
+    }
+
+    fn g2_add(&self, a: &AffinePoint, b: &AffinePoint) -> AffinePoint {
+        self.curve.add(a, b)
+    }
+
+    fn g2_mul(&self, k: &BigUint, a: &AffinePoint) -> AffinePoint {
+        self.curve.scalar_mul(k, a)
+    }
+
+    fn is_in_g1(&self, a: &AffinePoint) -> bool {
+        self.curve.is_on_curve(a) && self.curve.scalar_mul(&self.r, a).is_infinity()
+    }
+
+    fn is_in_g2(&self, a: &AffinePoint) -> bool {
+        self.is_in_g1(a)
+    }
+
+    fn gt_mul(&self, a: &Fp2Element, b: &Fp2Element) -> Fp2Element {
+        self.fp2.mul(a, b)
+    }
+
+    fn gt_pow(&self, a: &Fp2Element, k: &BigUint) -> Fp2Element {
+        self.fp2.pow(a, k)
+    }
+
+    fn gt_one(&self) -> Fp2Element {
+        self.fp2.one()
+    }
+
+    fn pair(&self, p: &AffinePoint, q: &AffinePoint) -> Fp2Element {
+        self.tate(p, q)
+    }
+}
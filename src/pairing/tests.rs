@@ -0,0 +1,102 @@
+// src/pairing/tests.rs
+
+// Randomized bilinearity and non-degeneracy checks of the supersingular and BN254 pairings,
+// and round trips of BLS signatures and Joux's three-party key agreement built on them.
+
+use super::joux::JouxPublicKey;
+use super::{
+    check_pairing, BlsPrivateKey, BlsPublicKey, Bn254, Joux, Pairing, PairingError, PairingVariant,
+    SupersingularPairing,
+};
+use crate::signature::{SigningKey, VerifyingKey};
+use num_bigint::BigUint;
+
+#[test]
+fn supersingular_pairing_is_bilinear_and_non_degenerate() {
+    // p = 1019 = 3 mod 4 and r = 17 divides p + 1 = 1020.
+    let small = SupersingularPairing::new(BigUint::from(1019u32), BigUint::from(17u32)).unwrap();
+    assert_eq!(check_pairing(&small, 10), Ok(()));
+    assert_eq!(check_pairing(&SupersingularPairing::toy(), 3), Ok(()));
+}
+
+#[test]
+fn supersingular_parameters_are_validated() {
+    let new = |p: u32, r: u32| SupersingularPairing::new(BigUint::from(p), BigUint::from(r));
+    assert!(new(1019, 17).is_ok());
+    assert_eq!(new(1021, 7).unwrap_err(), PairingError::InvalidParameters); // p = 1 mod 4
+    assert_eq!(new(1019, 7).unwrap_err(), PairingError::InvalidParameters); // 7 does not divide p + 1
+    assert_eq!(new(1019, 15).unwrap_err(), PairingError::InvalidParameters); // r not prime
+    assert_eq!(new(1023, 11).unwrap_err(), PairingError::InvalidParameters); // p not prime
+}
+
+#[test]
+fn bn254_tate_pairing_is_bilinear_and_non_degenerate() {
+    assert_eq!(check_pairing(&Bn254::new(PairingVariant::Tate), 1), Ok(()));
+}
+
+#[test]
+fn bn254_optimal_ate_pairing_is_bilinear_and_non_degenerate() {
+    assert_eq!(
+        check_pairing(&Bn254::new(PairingVariant::OptimalAte), 1),
+        Ok(())
+    );
+}
+
+#[test]
+fn bls_signatures_round_trip() {
+    let pairing = Bn254::new(PairingVariant::OptimalAte);
+    let key = BlsPrivateKey::generate(&pairing);
+    let public_key = key.verifying_key();
+    let signature = key.sign(b"message");
+    assert!(public_key.verify(b"message", &signature));
+    assert!(!public_key.verify(b"massage", &signature));
+
+    let decoded = BlsPublicKey::from_bytes(pairing.clone(), &public_key.to_bytes()).unwrap();
+    assert!(decoded.verify(b"message", &signature));
+
+    let other = BlsPrivateKey::generate(&pairing).verifying_key();
+    assert!(!other.verify(b"message", &signature));
+    let mut tampered = signature.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(!public_key.verify(b"message", &tampered));
+    assert!(!public_key.verify(b"message", &signature[1..]));
+}
+
+/// Runs Joux's key agreement between three parties and checks that they agree.
+fn joux_agreement<P: Pairing>(pairing: P) {
+    let joux = Joux::new(pairing);
+    let alice = joux.generate_private_key();
+    let bob = joux.generate_private_key();
+    let carol = joux.generate_private_key();
+    let key_a = joux
+        .compute_shared_secret(&alice, bob.public_key(), carol.public_key())
+        .unwrap();
+    let key_b = joux
+        .compute_shared_secret(&bob, carol.public_key(), alice.public_key())
+        .unwrap();
+    let key_c = joux
+        .compute_shared_secret(&carol, alice.public_key(), bob.public_key())
+        .unwrap();
+    assert!(key_a == key_b && key_b == key_c);
+    assert!(key_a != joux.pairing.gt_one());
+
+    // Halves taken from two different exponents are rejected.
+    let mixed = JouxPublicKey::<P> {
+        g1: bob.public_key().g1.clone(),
+        g2: carol.public_key().g2.clone(),
+    };
+    assert_eq!(
+        joux.validate_public_key(&mixed),
+        Err(PairingError::InconsistentShare)
+    );
+}
+
+#[test]
+fn joux_three_party_agreement_on_the_toy_curve() {
+    joux_agreement(SupersingularPairing::toy());
+}
+
+#[test]
+fn joux_three_party_agreement_on_bn254() {
+    joux_agreement(Bn254::new(PairingVariant::OptimalAte));
+}
@@ -0,0 +1,169 @@
+// src/pairing/twist.rs
+
+use super::fp2::{Fp2, Fp2Element};
+use super::PairingError;
+use crate::algebra::traits::{Group, Membership, Ring};
+use crate::elliptic_curve::AffinePoint;
+use num_bigint::BigUint;
+
+/// Tag of an uncompressed point, as in SEC 1.
+const TAG_UNCOMPRESSED: u8 = 0x04;
+
+/// A curve y^2 = x^3 + b over GF(p^2), used as the sextic twist that holds G2 of a BN curve.
+///
+/// Points are `AffinePoint<Fp2Element>`, with the same group law as `Curve` specialised to
+/// a = 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwistCurve {
+    pub fp2: Fp2,      // GF(p^2)
+    pub b: Fp2Element, // Constant coefficient
+}
+
+impl TwistCurve {
+    /// Creates the curve y^2 = x^3 + b over `fp2`.
+    pub fn new(fp2: Fp2, b: Fp2Element) -> Self {
+        TwistCurve { fp2, b }
+    }
+
+    /// Returns true if the point is at infinity or satisfies y^2 = x^3 + b.
+    pub fn is_on_curve(&self, point: &AffinePoint<Fp2Element>) -> bool {
+        match point {
+            AffinePoint::Infinity => true,
+            AffinePoint::Finite { x, y } => {
+                let f = &self.fp2;
+                f.is_element(x)
+                    && f.is_element(y)
+                    && f.square(y) == f.add(&f.mul(&f.square(x), x), &self.b)
+            }
+        }
+    }
+
+    /// Returns -P = (x, -y).
+    pub fn negate(&self, point: &AffinePoint<Fp2Element>) -> AffinePoint<Fp2Element> {
+        match point {
+            AffinePoint::Infinity => AffinePoint::Infinity,
+            AffinePoint::Finite { x, y } => AffinePoint::new(x.clone(), self.fp2.neg(y)),
+        }
+    }
+
+    /// Adds two points.
+    pub fn add(
+        &self,
+        p: &AffinePoint<Fp2Element>,
+        q: &AffinePoint<Fp2Element>,
+    ) -> AffinePoint<Fp2Element> {
+        match (p, q) {
+            (AffinePoint::Infinity, _) => q.clone(),
+            (_, AffinePoint::Infinity) => p.clone(),
+            _ => match self.tangent_or_chord(p, q) {
+                Some((sum, _)) => sum,
+                None => AffinePoint::Infinity,
+            },
+        }
+    }
+
+    /// Computes kP by double-and-add.
+    pub fn scalar_mul(
+        &self,
+        k: &BigUint,
+        point: &AffinePoint<Fp2Element>,
+    ) -> AffinePoint<Fp2Element> {
+        let mut result = AffinePoint::Infinity;
+        for i in (0..k.bits()).rev() {
+            result = self.add(&result, &result);
+            if k.bit(i) {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+
+    /// For finite points T and S, returns T + S together with the slope of the line through
+    /// them (the tangent when T = S), or `None` when that line is vertical and T + S = O.
+    ///
+    /// # Panics
+    ///
+    /// If T or S is the point at infinity.
+    pub fn tangent_or_chord(
+        &self,
+        t: &AffinePoint<Fp2Element>,
+        s: &AffinePoint<Fp2Element>,
+    ) -> Option<(AffinePoint<Fp2Element>, Fp2Element)> {
+        let f = &self.fp2;
+        let (AffinePoint::Finite { x: x1, y: y1 }, AffinePoint::Finite { x: x2, y: y2 }) = (t, s)
+        else {
+            panic!("the line is only defined through finite points");
+        };
+        let lambda = if x1 != x2 {
+            f.mul(&f.sub(y2, y1), &Group::inverse(f, &f.sub(x2, x1))?)
+        } else if y1 == y2 && !y1.is_zero() {
+            let three_x2 = f.scale(&f.square(x1), &BigUint::from(3u32));
+            f.mul(&three_x2, &Group::inverse(f, &f.double(y1))?)
+        } else {
+            return None;
+        };
+        let x3 = f.sub(&f.sub(&f.square(&lambda), x1), x2);
+        let y3 = f.sub(&f.mul(&lambda, &f.sub(x1, &x3)), y1);
+        Some((AffinePoint::new(x3, y3), lambda))
+    }
+
+    /// Encodes a point as 0x04 || x1 || x0 || y1 || y0, each coefficient padded to the byte
+    /// length of p, with the coefficient of u first as in EIP-197. The point at infinity is
+    /// the single byte 0x00.
+    pub fn encode_point(&self, point: &AffinePoint<Fp2Element>) -> Vec<u8> {
+        let AffinePoint::Finite { x, y } = point else {
+            return vec![0x00];
+        };
+        let mut out = vec![TAG_UNCOMPRESSED];
+        for c in [&x.c1, &x.c0, &y.c1, &y.c0] {
+            out.extend(self.pad(c));
+        }
+        out
+    }
+
+    /// Decodes the output of `encode_point`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AffinePoint)` for a well-formed encoding of a point on the curve.
+    /// * `Err(PairingError::InvalidEncoding)` otherwise.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<AffinePoint<Fp2Element>, PairingError> {
+        if bytes == [0x00] {
+            return Ok(AffinePoint::Infinity);
+        }
+        let length = self.field_length();
+        if bytes.len() != 1 + 4 * length || bytes[0] != TAG_UNCOMPRESSED {
+            return Err(PairingError::InvalidEncoding);
+        }
+        let c: Vec<BigUint> = bytes[1..]
+            .chunks(length)
+            .map(BigUint::from_bytes_be)
+            .collect();
+        let point = AffinePoint::new(
+            Fp2Element {
+                c0: c[1].clone(),
+                c1: c[0].clone(),
+            },
+            Fp2Element {
+                c0: c[3].clone(),
+                c1: c[2].clone(),
+            },
+        );
+        if !self.is_on_curve(&point) {
+            return Err(PairingError::InvalidEncoding);
+        }
+        Ok(point)
+    }
+
+    /// Returns the byte length of an element of GF(p).
+    fn field_length(&self) -> usize {
+        self.fp2.base.p.bits().div_ceil(8) as usize
+    }
+
+    fn pad(&self, c: &BigUint) -> Vec<u8> {
+        let bytes = c.to_bytes_be();
+        let mut out = vec![0u8; self.field_length() - bytes.len()];
+        out.extend(bytes);
+        out
+    }
+}
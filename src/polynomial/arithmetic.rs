@@ -85,27 +85,17 @@ impl Polynomial {
 
     // multiplies itself with another polynomial and returns the resultant
     pub fn multiply(&self, other: &Polynomial) -> Polynomial {
-        let result_len = self.bits.len() + other.bits.len() - 1;
-        let mut result_bits = BitVec::<u8, Msb0>::repeat(false, result_len);
-
-        for i in 0..self.bits.len() {
-            if self.bits[i] {
-                for j in 0..other.bits.len() {
-                    if other.bits[j] {
-                        let idx = i + j;
-                        let bit = result_bits[idx];
-                        result_bits.set(idx, bit ^ true);
-                    }
+        let a = self.to_words();
+        let b = other.to_words();
+        let mut result = vec![0u64; a.len() + b.len() + 1];
+        for (i, &word) in a.iter().enumerate() {
+            for k in 0..64 {
+                if (word >> k) & 1 == 1 {
+                    xor_shifted(&mut result, &b, 64 * i + k);
                 }
             }
         }
-
-        // Trim leading zeros
-        while result_bits.len() > 1 && !result_bits.first().unwrap() {
-            result_bits.remove(0);
-        }
-
-        Polynomial { bits: result_bits }
+        Polynomial::from_words(&result)
     }
 
     /// Multiplies `self` with another polynomial in place.
@@ -160,22 +150,19 @@ impl Polynomial {
     }
 
     pub fn modulo(&self, modulus: &Polynomial) -> Polynomial {
-        let mut remainder = self.clone();
-
-        while remainder.degree() >= modulus.degree() && !remainder.is_zero() {
-            let degree_diff = remainder.degree() - modulus.degree();
-            let shifted_modulus = modulus.shift_left(degree_diff);
-
-            // Subtract (XOR in GF(2))
-            remainder = remainder.add(&shifted_modulus);
-
-            // Trim leading zeros
-            while remainder.bits.len() > 1 && !remainder.bits.first().unwrap() {
-                remainder.bits.remove(0);
+        let modulus_degree = modulus.degree();
+        let divisor = modulus.to_words();
+        let mut remainder = self.to_words();
+        remainder.push(0);
+
+        // Cancel the leading terms from the top down, a whole word of coefficients at a time
+        for power in (modulus_degree..=self.degree()).rev() {
+            if (remainder[power / 64] >> (power % 64)) & 1 == 1 {
+                xor_shifted(&mut remainder, &divisor, power - modulus_degree);
             }
         }
 
-        remainder
+        Polynomial::from_words(&remainder)
     }
 
     /// Raises the polynomial to the power `exp` modulo `modulus`.
@@ -194,3 +181,130 @@ impl Polynomial {
         result
     }
 }
+
+impl Polynomial {
+    /// Packs the coefficients into 64-bit words, with the coefficient of x^i in bit i % 64 of
+    /// word i / 64.
+    fn to_words(&self) -> Vec<u64> {
+        let len = self.bits.len();
+        let mut words = vec![0u64; len.div_ceil(64).max(1)];
+        for index in self.bits.iter_ones() {
+            let power = len - 1 - index;
+            words[power / 64] |= 1 << (power % 64);
+        }
+        words
+    }
+
+    /// Unpacks words produced by `to_words`, trimming leading zeros.
+    fn from_words(words: &[u64]) -> Polynomial {
+        let Some(top) = words.iter().rposition(|&word| word != 0) else {
+            return Polynomial::new(&[0]);
+        };
+        let degree = 64 * top + 63 - words[top].leading_zeros() as usize;
+        let mut bits = BitVec::<u8, Msb0>::with_capacity(degree + 1);
+        for power in (0..=degree).rev() {
+            bits.push((words[power / 64] >> (power % 64)) & 1 == 1);
+        }
+        Polynomial { bits }
+    }
+}
+
+/// XORs `source` shifted left by `shift` bits into `target`, which must be long enough.
+fn xor_shifted(target: &mut [u64], source: &[u64], shift: usize) {
+    let (word_shift, bit_shift) = (shift / 64, shift % 64);
+    for (i, &word) in source.iter().enumerate() {
+        if word == 0 {
+            continue;
+        }
+        target[i + word_shift] ^= word << bit_shift;
+        if bit_shift != 0 {
+            target[i + word_shift + 1] ^= word >> (64 - bit_shift);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// The bit-by-bit product that `multiply` replaced, kept as a reference.
+    fn reference_multiply(a: &Polynomial, b: &Polynomial) -> Polynomial {
+        let result_len = a.bits.len() + b.bits.len() - 1;
+        let mut result_bits = BitVec::<u8, Msb0>::repeat(false, result_len);
+        for i in 0..a.bits.len() {
+            if a.bits[i] {
+                for j in 0..b.bits.len() {
+                    if b.bits[j] {
+                        let bit = result_bits[i + j];
+                        result_bits.set(i + j, !bit);
+                    }
+                }
+            }
+        }
+        while result_bits.len() > 1 && !result_bits.first().unwrap() {
+            result_bits.remove(0);
+        }
+        Polynomial { bits: result_bits }
+    }
+
+    /// The shift-and-subtract remainder that `modulo` replaced, kept as a reference.
+    fn reference_modulo(a: &Polynomial, modulus: &Polynomial) -> Polynomial {
+        let mut remainder = a.clone();
+        while remainder.degree() >= modulus.degree() && !remainder.is_zero() {
+            let shifted_modulus = modulus.shift_left(remainder.degree() - modulus.degree());
+            remainder = remainder.add(&shifted_modulus);
+            while remainder.bits.len() > 1 && !remainder.bits.first().unwrap() {
+                remainder.bits.remove(0);
+            }
+        }
+        remainder
+    }
+
+    fn random_polynomial(rng: &mut StdRng, max_len: usize) -> Polynomial {
+        let len = rng.gen_range(1..=max_len);
+        let coeffs: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=1)).collect();
+        Polynomial::new(&coeffs)
+    }
+
+    #[test]
+    fn word_packed_multiply_matches_reference() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..300 {
+            let a = random_polynomial(&mut rng, 200);
+            let b = random_polynomial(&mut rng, 200);
+            assert_eq!(a.multiply(&b), reference_multiply(&a, &b), "{} * {}", a, b);
+        }
+        let zero = Polynomial::new(&[0]);
+        let one = Polynomial::new(&[1]);
+        let x64 = Polynomial::new(&[1, 0, 0, 0, 0, 0, 0, 0]).shift_left(57);
+        assert_eq!(x64.multiply(&zero), reference_multiply(&x64, &zero));
+        assert_eq!(x64.multiply(&one), x64);
+        assert_eq!(x64.multiply(&x64), reference_multiply(&x64, &x64));
+    }
+
+    #[test]
+    fn word_packed_modulo_matches_reference() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..300 {
+            let a = random_polynomial(&mut rng, 400);
+            let mut modulus = random_polynomial(&mut rng, 200);
+            if modulus.is_zero() {
+                modulus = Polynomial::new(&[1]);
+            }
+            assert_eq!(
+                a.modulo(&modulus),
+                reference_modulo(&a, &modulus),
+                "{} mod {}",
+                a,
+                modulus
+            );
+        }
+        let modulus = Polynomial::new(&[1, 0, 0, 0, 1, 1, 0, 1, 1]);
+        let small = Polynomial::new(&[1, 0, 1]);
+        assert_eq!(small.modulo(&modulus), small);
+        assert_eq!(modulus.modulo(&modulus), Polynomial::new(&[0]));
+        assert_eq!(small.modulo(&Polynomial::new(&[1])), Polynomial::new(&[0]));
+    }
+}
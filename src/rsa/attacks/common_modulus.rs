@@ -0,0 +1,62 @@
+// src/rsa/attacks/common_modulus.rs
+
+use super::AttackError;
+use crate::integer_mod_n::IntegerModN;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed};
+
+/// The outcome of a successful common-modulus attack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommonModulusResult {
+    /// The recovered message.
+    pub message: BigUint,
+    /// Bézout coefficients with a * e1 + b * e2 = 1.
+    pub a: BigInt,
+    pub b: BigInt,
+}
+
+/// Raises `c` to a possibly negative power modulo `n`.
+fn signed_pow(c: &BigUint, exponent: &BigInt, n: &BigUint) -> Result<BigUint, AttackError> {
+    let magnitude = exponent.magnitude();
+    if exponent.is_negative() {
+        let inverse = IntegerModN::inverse(c, n).ok_or_else(|| {
+            AttackError::InvalidInput("a ciphertext shares a factor with the modulus".to_string())
+        })?;
+        Ok(IntegerModN::pow(&inverse, magnitude, n))
+    } else {
+        Ok(IntegerModN::pow(c, magnitude, n))
+    }
+}
+
+/// The common-modulus attack on one message encrypted under two exponents of the same modulus.
+///
+/// If c1 = m^e1 and c2 = m^e2 modulo n with gcd(e1, e2) = 1, then a * e1 + b * e2 = 1 for
+/// Bézout coefficients a and b, and m = c1^a * c2^b mod n. One of a, b is negative, so the
+/// corresponding ciphertext is inverted first.
+///
+/// # Returns
+///
+/// * `Ok(CommonModulusResult)` with the message.
+/// * `Err(AttackError::ExponentsNotCoprime)` if gcd(e1, e2) != 1.
+pub fn common_modulus_attack(
+    n: &BigUint,
+    e1: &BigUint,
+    c1: &BigUint,
+    e2: &BigUint,
+    c2: &BigUint,
+) -> Result<CommonModulusResult, AttackError> {
+    let e1_signed = BigInt::from_biguint(Sign::Plus, e1.clone());
+    let e2_signed = BigInt::from_biguint(Sign::Plus, e2.clone());
+    let gcd = e1_signed.extended_gcd(&e2_signed);
+    if !gcd.gcd.is_one() {
+        return Err(AttackError::ExponentsNotCoprime);
+    }
+
+    let message = signed_pow(c1, &gcd.x, n)? * signed_pow(c2, &gcd.y, n)? % n;
+    Ok(CommonModulusResult {
+        message,
+        a: gcd.x,
+        b: gcd.y,
+    })
+}
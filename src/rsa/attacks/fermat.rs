@@ -0,0 +1,59 @@
+// src/rsa/attacks/fermat.rs
+
+use super::{AttackError, FactoredModulus};
+use num_bigint::BigUint;
+use num_integer::Integer;
+
+/// The outcome of a successful Fermat factorization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FermatResult {
+    /// The factorization of the modulus.
+    pub factors: FactoredModulus,
+    /// The number of values of a tried, including the successful one.
+    pub iterations: u64,
+}
+
+/// Fermat's factorization method, which is fast when the two factors are close.
+///
+/// Searches for a with a^2 - n = b^2, starting from a = ceil(sqrt(n)); then n = (a - b)(a + b).
+/// If |p - q| is small compared to n^(1/4), the very first a already works.
+///
+/// # Arguments
+///
+/// * `n` - An odd composite modulus.
+/// * `max_iterations` - The number of values of a to try.
+///
+/// # Returns
+///
+/// * `Ok(FermatResult)` with the factors.
+/// * `Err(AttackError::IterationLimit)` if no factorization was found in time.
+/// * `Err(AttackError::InvalidInput)` if `n` is even.
+pub fn fermat_factor(n: &BigUint, max_iterations: u64) -> Result<FermatResult, AttackError> {
+    if n.is_even() {
+        return Err(AttackError::InvalidInput(
+            "the modulus must be odd".to_string(),
+        ));
+    }
+
+    let mut a = n.sqrt();
+    if &a * &a < *n {
+        a += 1u32;
+    }
+    // Track b^2 = a^2 - n incrementally: (a + 1)^2 - a^2 = 2a + 1.
+    let mut b_squared = &a * &a - n;
+    for iteration in 1..=max_iterations {
+        let b = b_squared.sqrt();
+        if &b * &b == b_squared {
+            let p = &a - &b;
+            if p > BigUint::from(1u32) {
+                return Ok(FermatResult {
+                    factors: FactoredModulus::new(p, &a + &b),
+                    iterations: iteration,
+                });
+            }
+        }
+        b_squared += &a * 2u32 + 1u32;
+        a += 1u32;
+    }
+    Err(AttackError::IterationLimit(max_iterations))
+}
@@ -0,0 +1,95 @@
+// src/rsa/attacks/hastad.rs
+
+use super::AttackError;
+use crate::integer_mod_n::IntegerModN;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// The outcome of a successful broadcast attack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HastadResult {
+    /// The recovered message.
+    pub message: BigUint,
+    /// m^e as an integer, recovered with the Chinese Remainder Theorem.
+    pub message_power: BigUint,
+    /// The number of ciphertexts combined.
+    pub ciphertexts_used: usize,
+}
+
+/// Håstad's broadcast attack on unpadded RSA with a small public exponent.
+///
+/// When the same message m is encrypted with exponent e under e or more pairwise coprime
+/// moduli, the Chinese Remainder Theorem gives m^e modulo their product. Since m is smaller
+/// than every modulus, m^e is smaller than that product, so m^e is known as an integer and m
+/// is its integer e-th root.
+///
+/// # Arguments
+///
+/// * `e` - The common public exponent.
+/// * `ciphertexts` - The ciphertexts c_i = m^e mod n_i.
+/// * `moduli` - The moduli n_i, in the same order.
+///
+/// # Returns
+///
+/// * `Ok(HastadResult)` with the message.
+/// * `Err(AttackError)` if too few ciphertexts are given, the moduli share a factor, or the
+///   combined value is not a perfect e-th power (the message was padded or differs).
+pub fn hastad_broadcast_attack(
+    e: &BigUint,
+    ciphertexts: &[BigUint],
+    moduli: &[BigUint],
+) -> Result<HastadResult, AttackError> {
+    if ciphertexts.len() != moduli.len() {
+        return Err(AttackError::InvalidInput(
+            "there must be one modulus per ciphertext".to_string(),
+        ));
+    }
+    let exponent = e
+        .to_u32()
+        .filter(|&x| x >= 2)
+        .ok_or_else(|| AttackError::InvalidInput("the exponent must be small".to_string()))?;
+    if ciphertexts.len() < exponent as usize {
+        return Err(AttackError::NotEnoughCiphertexts {
+            needed: exponent as usize,
+            given: ciphertexts.len(),
+        });
+    }
+    for i in 0..moduli.len() {
+        if ciphertexts[i] >= moduli[i] {
+            return Err(AttackError::InvalidInput(format!(
+                "ciphertext {} is not smaller than its modulus",
+                i
+            )));
+        }
+        for j in i + 1..moduli.len() {
+            if !moduli[i].gcd(&moduli[j]).is_one() {
+                return Err(AttackError::ModuliNotCoprime {
+                    first: i,
+                    second: j,
+                });
+            }
+        }
+    }
+
+    // CRT: x = sum c_i * N_i * (N_i^(-1) mod n_i) mod N with N_i = N / n_i.
+    let product: BigUint = moduli.iter().product();
+    let mut combined = BigUint::zero();
+    for (c, n) in ciphertexts.iter().zip(moduli) {
+        let partial = &product / n;
+        let inverse = IntegerModN::inverse(&(&partial % n), n)
+            .ok_or_else(|| AttackError::InvalidInput("moduli must be coprime".to_string()))?;
+        combined += c * &partial * inverse;
+    }
+    combined %= &product;
+
+    let message = combined.nth_root(exponent);
+    if message.pow(exponent) != combined {
+        return Err(AttackError::NotVulnerable);
+    }
+    Ok(HastadResult {
+        message,
+        message_power: combined,
+        ciphertexts_used: ciphertexts.len(),
+    })
+}
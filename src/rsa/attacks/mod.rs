@@ -0,0 +1,85 @@
+// src/rsa/attacks/mod.rs
+
+pub mod common_modulus;
+pub mod fermat;
+pub mod hastad;
+pub mod recover;
+pub mod weak_keys;
+pub mod wiener;
+
+pub use common_modulus::{common_modulus_attack, CommonModulusResult};
+pub use fermat::{fermat_factor, FermatResult};
+pub use hastad::{hastad_broadcast_attack, HastadResult};
+pub use recover::recover_prime_factors;
+pub use wiener::{wiener_attack, WienerResult};
+
+use num_bigint::BigUint;
+use std::fmt;
+
+/// The two prime factors of an RSA modulus, with `p <= q`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactoredModulus {
+    pub p: BigUint,
+    pub q: BigUint,
+}
+
+impl FactoredModulus {
+    /// Orders the two factors so that `p <= q`.
+    fn new(a: BigUint, b: BigUint) -> Self {
+        if a <= b {
+            FactoredModulus { p: a, q: b }
+        } else {
+            FactoredModulus { p: b, q: a }
+        }
+    }
+}
+
+/// Reasons an attack can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttackError {
+    /// The inputs do not have the weakness the attack relies on.
+    NotVulnerable,
+    /// The attack gave up after the given number of iterations.
+    IterationLimit(u64),
+    /// Fewer ciphertexts were supplied than the public exponent requires.
+    NotEnoughCiphertexts { needed: usize, given: usize },
+    /// Two moduli share a factor, so the broadcast attack does not apply (but a gcd
+    /// factors both of them).
+    ModuliNotCoprime { first: usize, second: usize },
+    /// The public exponents share a factor.
+    ExponentsNotCoprime,
+    /// The inputs are malformed, e.g. mismatched lengths or a ciphertext not below its modulus.
+    InvalidInput(String),
+}
+
+impl fmt::Display for AttackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttackError::NotVulnerable => {
+                write!(f, "The inputs do not have the weakness this attack needs.")
+            }
+            AttackError::IterationLimit(limit) => {
+                write!(f, "No result within {} iterations.", limit)
+            }
+            AttackError::NotEnoughCiphertexts { needed, given } => write!(
+                f,
+                "The attack needs {} ciphertexts but only {} were given.",
+                needed, given
+            ),
+            AttackError::ModuliNotCoprime { first, second } => write!(
+                f,
+                "Moduli {} and {} share a factor; a gcd factors both of them.",
+                first, second
+            ),
+            AttackError::ExponentsNotCoprime => {
+                write!(f, "The public exponents are not coprime.")
+            }
+            AttackError::InvalidInput(reason) => write!(f, "Invalid input: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for AttackError {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+// src/rsa/attacks/recover.rs
+
+use super::{AttackError, FactoredModulus};
+use crate::integer_mod_n::IntegerModN;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
+
+/// The number of bases tried before giving up. Each base succeeds with probability at least
+/// 1/2, so failures only happen when (e, d) is not a key pair for n.
+const MAX_BASES: u32 = 100;
+
+/// Recovers p and q from a modulus and a matching pair of exponents.
+///
+/// With ed - 1 = 2^t * r and r odd, ed - 1 is a multiple of λ(n), so g^(ed - 1) = 1 for every
+/// unit g. Squaring g^r repeatedly therefore reaches 1, and the value just before it is a
+/// square root of 1. For at least half of all g that root is not -1, and then gcd(x - 1, n) is
+/// a proper factor. This is the procedure of NIST SP 800-56B, Appendix C.
+///
+/// # Returns
+///
+/// * `Ok(FactoredModulus)` with the factors.
+/// * `Err(AttackError::InvalidInput)` if ed - 1 is odd or zero, which no RSA key allows.
+/// * `Err(AttackError::NotVulnerable)` if no base revealed a factor.
+pub fn recover_prime_factors(
+    n: &BigUint,
+    e: &BigUint,
+    d: &BigUint,
+) -> Result<FactoredModulus, AttackError> {
+    let k = e * d - 1u32;
+    if k.bits() == 0 || k.is_odd() {
+        return Err(AttackError::InvalidInput(
+            "ed - 1 must be even and positive".to_string(),
+        ));
+    }
+    let t = k.trailing_zeros().unwrap();
+    let r = &k >> t;
+    let n_minus_1 = n - 1u32;
+
+    for g in 2..MAX_BASES + 2 {
+        let g = BigUint::from(g);
+        let common = g.gcd(n);
+        if !common.is_one() {
+            // A small base that shares a factor with n gives the answer directly.
+            return Ok(FactoredModulus::new(common.clone(), n / common));
+        }
+
+        let mut x = IntegerModN::pow(&g, &r, n);
+        for _ in 0..t {
+            if x.is_one() || x == n_minus_1 {
+                break;
+            }
+            let y = &x * &x % n;
+            if y.is_one() {
+                let p = (&x - 1u32).gcd(n);
+                return Ok(FactoredModulus::new(p.clone(), n / p));
+            }
+            x = y;
+        }
+    }
+    Err(AttackError::NotVulnerable)
+}
@@ -0,0 +1,121 @@
+// src/rsa/attacks/tests.rs
+
+// Each attack recovers the key or message it targets: Wiener's attack and Fermat's method on
+// keys from `weak_keys`, the common-modulus attack on one message under two exponents, and
+// Håstad's attack on one message sent to three e = 3 keys. Generated keys resist the first two.
+
+use super::weak_keys::{close_primes_key, small_private_exponent_key};
+use super::*;
+use crate::rsa::{RsaPrivateKey, RsaPublicKey};
+use num_bigint::{BigInt, BigUint, RandBigInt};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn factors_of(key: &RsaPrivateKey) -> FactoredModulus {
+    FactoredModulus::new(key.p.clone(), key.q.clone())
+}
+
+#[test]
+fn wiener_recovers_a_small_private_exponent() {
+    let key = small_private_exponent_key(512).unwrap();
+    let public_key = key.public_key();
+    let result = wiener_attack(&public_key.n, &public_key.e).unwrap();
+    assert_eq!(result.d, key.d);
+    assert_eq!(result.factors, factors_of(&key));
+    assert!(result.convergents_tried > 0);
+
+    let strong = RsaPrivateKey::generate(512).unwrap();
+    let public_key = strong.public_key();
+    assert_eq!(
+        wiener_attack(&public_key.n, &public_key.e),
+        Err(AttackError::NotVulnerable)
+    );
+}
+
+#[test]
+fn fermat_factors_close_primes() {
+    let key = close_primes_key(512, 32).unwrap();
+    let result = fermat_factor(&key.public_key().n, 10).unwrap();
+    assert_eq!(result.factors, factors_of(&key));
+    // |p - q| < 2^33 is far below n^(1/4) = 2^128, so the first a already works.
+    assert_eq!(result.iterations, 1);
+
+    let strong = RsaPrivateKey::generate(512).unwrap();
+    assert_eq!(
+        fermat_factor(&strong.public_key().n, 1000),
+        Err(AttackError::IterationLimit(1000))
+    );
+}
+
+#[test]
+fn common_modulus_recovers_the_message() {
+    let mut rng = StdRng::seed_from_u64(32);
+    let key = RsaPrivateKey::generate(512).unwrap();
+    let n = key.public_key().n.clone();
+    let m = rng.gen_biguint_below(&n);
+    let (e1, e2) = (BigUint::from(65537u32), BigUint::from(3u32));
+    let c1 = RsaPublicKey::new(n.clone(), e1.clone())
+        .unwrap()
+        .encrypt_raw(&m)
+        .unwrap();
+    let c2 = RsaPublicKey::new(n.clone(), e2.clone())
+        .unwrap()
+        .encrypt_raw(&m)
+        .unwrap();
+
+    let result = common_modulus_attack(&n, &e1, &c1, &e2, &c2).unwrap();
+    assert_eq!(result.message, m);
+    assert_eq!(
+        result.a * BigInt::from(e1.clone()) + result.b * BigInt::from(e2),
+        BigInt::from(1)
+    );
+
+    let e3 = BigUint::from(65537u32 * 3);
+    let c3 = RsaPublicKey::new(n.clone(), e3.clone())
+        .unwrap()
+        .encrypt_raw(&m)
+        .unwrap();
+    assert_eq!(
+        common_modulus_attack(&n, &e1, &c1, &e3, &c3),
+        Err(AttackError::ExponentsNotCoprime)
+    );
+}
+
+#[test]
+fn hastad_recovers_a_broadcast_message() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let e = BigUint::from(3u32);
+    let keys: Vec<RsaPrivateKey> = (0..3)
+        .map(|_| RsaPrivateKey::generate_with_exponent(512, &e).unwrap())
+        .collect();
+    let moduli: Vec<BigUint> = keys.iter().map(|key| key.public_key().n.clone()).collect();
+    let m = rng.gen_biguint(500);
+    let ciphertexts: Vec<BigUint> = keys
+        .iter()
+        .map(|key| key.public_key().encrypt_raw(&m).unwrap())
+        .collect();
+
+    let result = hastad_broadcast_attack(&e, &ciphertexts, &moduli).unwrap();
+    assert_eq!(result.message, m);
+    assert_eq!(result.message_power, m.pow(3));
+    assert_eq!(result.ciphertexts_used, 3);
+
+    assert_eq!(
+        hastad_broadcast_attack(&e, &ciphertexts[..2], &moduli[..2]),
+        Err(AttackError::NotEnoughCiphertexts {
+            needed: 3,
+            given: 2
+        })
+    );
+}
+
+#[test]
+fn recovered_exponents_factor_the_modulus() {
+    let key = small_private_exponent_key(256).unwrap();
+    let public_key = key.public_key();
+    let d = wiener_attack(&public_key.n, &public_key.e).unwrap().d;
+    assert_eq!(
+        recover_prime_factors(&public_key.n, &public_key.e, &d).unwrap(),
+        factors_of(&key)
+    );
+}
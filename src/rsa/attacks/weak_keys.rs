@@ -0,0 +1,77 @@
+// src/rsa/attacks/weak_keys.rs
+
+use crate::factor::is_probable_prime;
+use crate::integer_mod_n::IntegerModN;
+use crate::rsa::keys::{generate_prime, DEFAULT_PUBLIC_EXPONENT};
+use crate::rsa::{RsaError, RsaPrivateKey};
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::One;
+use rand::rngs::OsRng;
+
+/// Generates a key whose private exponent is small enough for Wiener's attack.
+///
+/// The primes are balanced (q < p < 2q), d is a random odd number below n^(1/4) / 3, and
+/// e = d^(-1) mod φ(n), so e is about as large as n.
+///
+/// # Returns
+///
+/// * `Ok(RsaPrivateKey)` with the vulnerable key.
+/// * `Err(RsaError::InvalidKeySize)` if `bits` is below 64.
+pub fn small_private_exponent_key(bits: u64) -> Result<RsaPrivateKey, RsaError> {
+    if bits < 64 {
+        return Err(RsaError::InvalidKeySize);
+    }
+    let mut rng = OsRng;
+    // n^(1/4) / 3 > 2^(bits / 4 - 3) for an n of `bits` bits.
+    let d_bits = bits / 4 - 3;
+
+    loop {
+        let p = generate_prime(bits / 2);
+        let q = generate_prime(bits - bits / 2);
+        if p == q {
+            continue;
+        }
+        let phi = (&p - 1u32) * (&q - 1u32);
+        let d = rng.gen_biguint(d_bits) | BigUint::one();
+        let Some(e) = IntegerModN::inverse(&d, &phi) else {
+            continue;
+        };
+        if e.is_even() || e < BigUint::from(3u32) {
+            continue;
+        }
+        if let Ok(key) = RsaPrivateKey::from_components(&p * &q, e, d, p, q) {
+            return Ok(key);
+        }
+    }
+}
+
+/// Generates a key whose primes are so close that Fermat's method factors n at once.
+///
+/// q is the first prime after p + r for a random r below 2^`max_gap_bits`, and e = 65537.
+///
+/// # Returns
+///
+/// * `Ok(RsaPrivateKey)` with the vulnerable key.
+/// * `Err(RsaError::InvalidKeySize)` if `bits` is below 64.
+pub fn close_primes_key(bits: u64, max_gap_bits: u64) -> Result<RsaPrivateKey, RsaError> {
+    if bits < 64 {
+        return Err(RsaError::InvalidKeySize);
+    }
+    let mut rng = OsRng;
+    let e = BigUint::from(DEFAULT_PUBLIC_EXPONENT);
+
+    loop {
+        let p = generate_prime(bits / 2);
+        let mut q = &p + rng.gen_biguint(max_gap_bits) + 1u32;
+        if q.is_even() {
+            q += 1u32;
+        }
+        while !is_probable_prime(&q) {
+            q += 2u32;
+        }
+        if let Ok(key) = RsaPrivateKey::from_primes(p, q, e.clone()) {
+            return Ok(key);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// src/rsa/attacks/wiener.rs
+
+use super::{AttackError, FactoredModulus};
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// The outcome of a successful Wiener attack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WienerResult {
+    /// The private exponent.
+    pub d: BigUint,
+    /// The factorization of the modulus.
+    pub factors: FactoredModulus,
+    /// The number of convergents of e/n examined, including the successful one.
+    pub convergents_tried: usize,
+}
+
+/// Computes the partial quotients of the continued fraction of `numerator / denominator`.
+pub fn continued_fraction(numerator: &BigUint, denominator: &BigUint) -> Vec<BigUint> {
+    let mut quotients = Vec::new();
+    let (mut a, mut b) = (numerator.clone(), denominator.clone());
+    while !b.is_zero() {
+        let (q, r) = a.div_rem(&b);
+        quotients.push(q);
+        a = b;
+        b = r;
+    }
+    quotients
+}
+
+/// Computes the convergents h_i / k_i of a continued fraction, as `(h_i, k_i)` pairs.
+pub fn convergents(quotients: &[BigUint]) -> Vec<(BigUint, BigUint)> {
+    // h_i = a_i h_(i-1) + h_(i-2) and k_i = a_i k_(i-1) + k_(i-2), from h = (0, 1), k = (1, 0).
+    let (mut h_prev, mut h) = (BigUint::zero(), BigUint::one());
+    let (mut k_prev, mut k) = (BigUint::one(), BigUint::zero());
+    let mut result = Vec::with_capacity(quotients.len());
+    for a in quotients {
+        let h_next = a * &h + &h_prev;
+        let k_next = a * &k + &k_prev;
+        h_prev = std::mem::replace(&mut h, h_next);
+        k_prev = std::mem::replace(&mut k, k_next);
+        result.push((h.clone(), k.clone()));
+    }
+    result
+}
+
+/// Wiener's attack on a small private exponent.
+///
+/// If d < n^(1/4) / 3, then k/d is a convergent of the continued fraction of e/n, where
+/// ed - 1 = kφ(n). Each convergent gives a candidate φ(n) = (ed - 1) / k, and a candidate is
+/// right when p and q, the roots of x^2 - (n - φ(n) + 1)x + n, are integers.
+///
+/// # Arguments
+///
+/// * `n` - The modulus.
+/// * `e` - The public exponent.
+///
+/// # Returns
+///
+/// * `Ok(WienerResult)` with d and the factors of n.
+/// * `Err(AttackError::NotVulnerable)` if no convergent works.
+pub fn wiener_attack(n: &BigUint, e: &BigUint) -> Result<WienerResult, AttackError> {
+    let four = BigUint::from(4u32);
+    let all = convergents(&continued_fraction(e, n));
+
+    for (index, (k, d)) in all.iter().enumerate() {
+        if k.is_zero() {
+            continue;
+        }
+        let ed_minus_1 = e * d - 1u32;
+        if !(&ed_minus_1 % k).is_zero() {
+            continue;
+        }
+        let phi = ed_minus_1 / k;
+        if &phi >= n {
+            continue;
+        }
+
+        // p + q = n - φ(n) + 1 and (p - q)^2 = (p + q)^2 - 4n.
+        let sum = n - &phi + 1u32;
+        let square = &sum * &sum;
+        if square < &four * n {
+            continue;
+        }
+        let discriminant = square - &four * n;
+        let root = discriminant.sqrt();
+        if &root * &root != discriminant || (&sum + &root).is_odd() {
+            continue;
+        }
+
+        let p = (&sum - &root) / 2u32;
+        let q = (&sum + &root) / 2u32;
+        if &p * &q == *n && !p.is_one() {
+            return Ok(WienerResult {
+                d: d.clone(),
+                factors: FactoredModulus::new(p, q),
+                convergents_tried: index + 1,
+            });
+        }
+    }
+    Err(AttackError::NotVulnerable)
+}
@@ -0,0 +1,49 @@
+// src/rsa/errors.rs
+
+use crate::encoding::EncodingError;
+use std::fmt;
+
+/// Errors related to RSA keys, primitives and padding schemes.
+#[derive(Debug)]
+pub enum RsaError {
+    InvalidKeySize,
+    InvalidKey,
+    MessageTooLong,
+    MessageRepresentativeOutOfRange,
+    DecryptionError,
+    InvalidSignature,
+    FaultDetected,
+    Encoding(EncodingError),
+}
+
+impl fmt::Display for RsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RsaError::InvalidKeySize => write!(f, "The requested key size is too small."),
+            RsaError::InvalidKey => write!(f, "The key components are inconsistent."),
+            RsaError::MessageTooLong => {
+                write!(f, "The message is too long for the key and padding scheme.")
+            }
+            RsaError::MessageRepresentativeOutOfRange => {
+                write!(
+                    f,
+                    "The message representative is not smaller than the modulus."
+                )
+            }
+            RsaError::DecryptionError => write!(f, "Decryption error."),
+            RsaError::InvalidSignature => write!(f, "The signature is invalid."),
+            RsaError::FaultDetected => {
+                write!(f, "The private-key operation failed its consistency check.")
+            }
+            RsaError::Encoding(err) => write!(f, "Invalid key encoding: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RsaError {}
+
+impl From<EncodingError> for RsaError {
+    fn from(err: EncodingError) -> Self {
+        RsaError::Encoding(err)
+    }
+}
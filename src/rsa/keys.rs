@@ -0,0 +1,263 @@
+// src/rsa/keys.rs
+
+use super::RsaError;
+use crate::factor::is_probable_prime;
+use crate::integer_mod_n::IntegerModN;
+use crate::sieve::sieve_of_eratosthenes_bitset;
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::rngs::OsRng;
+
+/// The public exponent used by key generation.
+pub const DEFAULT_PUBLIC_EXPONENT: u32 = 65537;
+
+/// The smallest modulus size accepted by key generation. Such small keys are only useful for
+/// experiments; real keys should have at least 2048 bits.
+pub const MIN_KEY_BITS: u64 = 64;
+
+/// Candidates divisible by a prime below this bound are rejected before the primality test.
+const SMALL_PRIME_BOUND: usize = 2_000;
+
+/// An RSA public key `(n, e)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    pub n: BigUint, // Modulus
+    pub e: BigUint, // Public exponent
+}
+
+/// An RSA private key with the CRT components of PKCS#1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    pub public_key: RsaPublicKey,
+    pub d: BigUint,    // Private exponent, reduced modulo λ(n)
+    pub p: BigUint,    // First prime factor
+    pub q: BigUint,    // Second prime factor
+    pub dp: BigUint,   // d mod (p - 1)
+    pub dq: BigUint,   // d mod (q - 1)
+    pub qinv: BigUint, // q^(-1) mod p
+}
+
+impl RsaPublicKey {
+    /// Creates a public key from its modulus and exponent.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPublicKey)` if `n` is odd and `e` is an odd number with 3 <= e < n.
+    /// * `Err(RsaError::InvalidKey)` otherwise.
+    pub fn new(n: BigUint, e: BigUint) -> Result<Self, RsaError> {
+        if n.is_even() || e.is_even() || e < BigUint::from(3u32) || e >= n {
+            return Err(RsaError::InvalidKey);
+        }
+        Ok(RsaPublicKey { n, e })
+    }
+
+    /// Returns the length of the modulus in bytes.
+    pub fn size(&self) -> usize {
+        self.n.bits().div_ceil(8) as usize
+    }
+
+    /// The RSA encryption primitive (RSAEP), which is also the verification primitive
+    /// (RSAVP1): computes m^e mod n.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with the result.
+    /// * `Err(RsaError::MessageRepresentativeOutOfRange)` if `m >= n`.
+    pub fn encrypt_raw(&self, m: &BigUint) -> Result<BigUint, RsaError> {
+        if m >= &self.n {
+            return Err(RsaError::MessageRepresentativeOutOfRange);
+        }
+        Ok(IntegerModN::pow(m, &self.e, &self.n))
+    }
+}
+
+impl RsaPrivateKey {
+    /// Generates a key with a modulus of exactly `bits` bits and e = 65537.
+    pub fn generate(bits: u64) -> Result<Self, RsaError> {
+        Self::generate_with_exponent(bits, &BigUint::from(DEFAULT_PUBLIC_EXPONENT))
+    }
+
+    /// Generates a key with a modulus of exactly `bits` bits and the given public exponent.
+    ///
+    /// Both primes have their top two bits set so that their product has the full size, and
+    /// they are rejected if p - 1 or q - 1 shares a factor with `e`. For moduli above 200 bits
+    /// the primes must also differ in their top 100 bits, which rules out Fermat factorization,
+    /// and d must exceed 2^(bits / 2), which rules out Wiener's attack.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPrivateKey)` on success.
+    /// * `Err(RsaError::InvalidKeySize)` if `bits` is below `MIN_KEY_BITS`.
+    /// * `Err(RsaError::InvalidKey)` if `e` is even or smaller than 3.
+    pub fn generate_with_exponent(bits: u64, e: &BigUint) -> Result<Self, RsaError> {
+        if bits < MIN_KEY_BITS {
+            return Err(RsaError::InvalidKeySize);
+        }
+        if e.is_even() || e < &BigUint::from(3u32) {
+            return Err(RsaError::InvalidKey);
+        }
+
+        let p_bits = bits / 2;
+        let q_bits = bits - p_bits;
+        let min_distance = if bits > 200 {
+            BigUint::one() << (p_bits - 100)
+        } else {
+            BigUint::one()
+        };
+        let min_d = BigUint::one() << p_bits;
+
+        let generate_factor = |bits| loop {
+            let candidate = generate_prime(bits);
+            if (&candidate - 1u32).gcd(e).is_one() {
+                return candidate;
+            }
+        };
+        loop {
+            let p = generate_factor(p_bits);
+            let q = generate_factor(q_bits);
+            let distance = if p > q { &p - &q } else { &q - &p };
+            if distance < min_distance {
+                continue;
+            }
+            let key = Self::from_primes(p, q, e.clone())?;
+            if key.d > min_d {
+                return Ok(key);
+            }
+        }
+    }
+
+    /// Builds a key from its two primes and public exponent, computing d = e^(-1) mod λ(n)
+    /// and the CRT components.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPrivateKey)` on success.
+    /// * `Err(RsaError::InvalidKey)` if the primes are equal or `e` is not invertible
+    ///   modulo λ(n).
+    pub fn from_primes(p: BigUint, q: BigUint, e: BigUint) -> Result<Self, RsaError> {
+        if p == q || p < BigUint::from(3u32) || q < BigUint::from(3u32) {
+            return Err(RsaError::InvalidKey);
+        }
+        let p_minus_1 = &p - 1u32;
+        let q_minus_1 = &q - 1u32;
+        let lambda = p_minus_1.lcm(&q_minus_1);
+        let d = IntegerModN::inverse(&e, &lambda).ok_or(RsaError::InvalidKey)?;
+
+        let public_key = RsaPublicKey::new(&p * &q, e)?;
+        let qinv = IntegerModN::inverse(&q, &p).ok_or(RsaError::InvalidKey)?;
+        Ok(RsaPrivateKey {
+            public_key,
+            dp: &d % &p_minus_1,
+            dq: &d % &q_minus_1,
+            d,
+            p,
+            q,
+            qinv,
+        })
+    }
+
+    /// Builds a key from all of its components, checking that they fit together.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPrivateKey)` if n = pq and ed = 1 modulo λ(n).
+    /// * `Err(RsaError::InvalidKey)` otherwise.
+    pub fn from_components(
+        n: BigUint,
+        e: BigUint,
+        d: BigUint,
+        p: BigUint,
+        q: BigUint,
+    ) -> Result<Self, RsaError> {
+        if &p * &q != n {
+            return Err(RsaError::InvalidKey);
+        }
+        let mut key = Self::from_primes(p, q, e)?;
+        let lambda = (&key.p - 1u32).lcm(&(&key.q - 1u32));
+        if (&key.public_key.e * &d) % &lambda != BigUint::one() {
+            return Err(RsaError::InvalidKey);
+        }
+        // Keep the caller's exponent; it may be any representative of e^(-1) mod λ(n).
+        key.dp = &d % (&key.p - 1u32);
+        key.dq = &d % (&key.q - 1u32);
+        key.d = d;
+        Ok(key)
+    }
+
+    /// Returns the public half of the key.
+    pub fn public_key(&self) -> &RsaPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the length of the modulus in bytes.
+    pub fn size(&self) -> usize {
+        self.public_key.size()
+    }
+
+    /// The RSA decryption primitive (RSADP), which is also the signature primitive (RSASP1):
+    /// computes c^d mod n.
+    ///
+    /// The input is blinded with a random unit r as c * r^e, so the value that goes through
+    /// the private exponentiation is unrelated to `c`, and r is divided out of the result.
+    /// This is not constant time: `BigUint::modpow` takes time that depends on its operands.
+    /// The exponentiation uses the Chinese Remainder Theorem, and its result is checked by
+    /// raising it to e again, since a fault in one of the two half-size exponentiations would
+    /// otherwise give a value that reveals a factor of n.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BigUint)` with the result.
+    /// * `Err(RsaError::MessageRepresentativeOutOfRange)` if `c >= n`.
+    /// * `Err(RsaError::FaultDetected)` if the CRT result fails the check.
+    pub fn decrypt_raw(&self, c: &BigUint) -> Result<BigUint, RsaError> {
+        let n = &self.public_key.n;
+        if c >= n {
+            return Err(RsaError::MessageRepresentativeOutOfRange);
+        }
+
+        let ring = IntegerModN::new(n.clone());
+        let r = ring.random_group_element();
+        let r_inv = IntegerModN::inverse(&r, n).ok_or(RsaError::InvalidKey)?;
+        let blinded = c * IntegerModN::pow(&r, &self.public_key.e, n) % n;
+
+        let m = self.decrypt_crt(&blinded);
+        if IntegerModN::pow(&m, &self.public_key.e, n) != blinded {
+            return Err(RsaError::FaultDetected);
+        }
+        Ok(m * r_inv % n)
+    }
+
+    /// Computes c^d mod n through the exponents dP and dQ (Garner's recombination).
+    fn decrypt_crt(&self, c: &BigUint) -> BigUint {
+        let m1 = IntegerModN::pow(&(c % &self.p), &self.dp, &self.p);
+        let m2 = IntegerModN::pow(&(c % &self.q), &self.dq, &self.q);
+        // h = qInv * (m1 - m2) mod p
+        let difference = (&self.p + &m1 - &m2 % &self.p) % &self.p;
+        let h = &self.qinv * difference % &self.p;
+        m2 + h * &self.q
+    }
+}
+
+/// Generates a random prime of exactly `bits` bits with its top two bits set, so that the
+/// product of two such primes has exactly twice as many bits.
+pub fn generate_prime(bits: u64) -> BigUint {
+    let small_primes = sieve_of_eratosthenes_bitset(SMALL_PRIME_BOUND);
+    let top = (BigUint::one() << (bits - 1)) | (BigUint::one() << (bits - 2));
+    let bound = BigUint::from(SMALL_PRIME_BOUND);
+    let mut rng = OsRng;
+
+    loop {
+        let candidate = rng.gen_biguint(bits) | &top | BigUint::one();
+        if candidate > bound
+            && small_primes
+                .iter()
+                .any(|&s| (&candidate % s as u64).is_zero())
+        {
+            continue;
+        }
+        if is_probable_prime(&candidate) {
+            return candidate;
+        }
+    }
+}
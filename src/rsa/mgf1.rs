@@ -0,0 +1,35 @@
+// src/rsa/mgf1.rs
+
+use crate::hash::HashFunction;
+
+/// The mask generation function MGF1 of PKCS#1 (RFC 8017, Appendix B.2.1).
+///
+/// # Arguments
+///
+/// * `seed` - The seed the mask is derived from.
+/// * `length` - The length of the mask in bytes.
+///
+/// # Returns
+///
+/// The concatenation of H(seed || counter) for counter = 0, 1, ..., truncated to `length`
+/// bytes.
+pub fn mgf1<H: HashFunction>(seed: &[u8], length: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(length + H::OUTPUT_SIZE);
+    let mut counter = 0u32;
+    while mask.len() < length {
+        let mut hasher = H::new();
+        hasher.update(seed);
+        hasher.update(&counter.to_be_bytes());
+        mask.extend(hasher.finalize());
+        counter += 1;
+    }
+    mask.truncate(length);
+    mask
+}
+
+/// XORs `mask` into `data` in place.
+pub(super) fn xor_in_place(data: &mut [u8], mask: &[u8]) {
+    for (byte, m) in data.iter_mut().zip(mask) {
+        *byte ^= m;
+    }
+}
@@ -0,0 +1,41 @@
+// src/rsa/mod.rs
+
+pub mod attacks;
+mod errors;
+pub mod keys;
+pub mod mgf1;
+pub mod oaep;
+pub mod pkcs1;
+pub mod pkcs1v15;
+pub mod pss;
+
+pub use errors::RsaError;
+pub use keys::{RsaPrivateKey, RsaPublicKey, DEFAULT_PUBLIC_EXPONENT};
+
+use num_bigint::BigUint;
+
+/// Converts a non-negative integer to a big-endian byte string of exactly `length` bytes
+/// (I2OSP in RFC 8017).
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` with the encoding.
+/// * `Err(RsaError::MessageTooLong)` if `x` does not fit in `length` bytes.
+pub fn i2osp(x: &BigUint, length: usize) -> Result<Vec<u8>, RsaError> {
+    let bytes = x.to_bytes_be();
+    let bytes: &[u8] = if bytes == [0] { &[] } else { &bytes };
+    if bytes.len() > length {
+        return Err(RsaError::MessageTooLong);
+    }
+    let mut out = vec![0u8; length - bytes.len()];
+    out.extend_from_slice(bytes);
+    Ok(out)
+}
+
+/// Converts a big-endian byte string to a non-negative integer (OS2IP in RFC 8017).
+pub fn os2ip(bytes: &[u8]) -> BigUint {
+    BigUint::from_bytes_be(bytes)
+}
+
+#[cfg(test)]
+mod tests;
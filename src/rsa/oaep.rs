@@ -0,0 +1,118 @@
+// src/rsa/oaep.rs
+
+use super::mgf1::{mgf1, xor_in_place};
+use super::{i2osp, os2ip, RsaError, RsaPrivateKey, RsaPublicKey};
+use crate::hash::HashFunction;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+impl RsaPublicKey {
+    /// Encrypts `message` with RSAES-OAEP (RFC 8017, Section 7.1.1), using `H` for both the
+    /// label hash and MGF1.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - At most k - 2 * hLen - 2 bytes, where k is the modulus length.
+    /// * `label` - Data bound to the ciphertext; usually empty.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with a ciphertext of k bytes.
+    /// * `Err(RsaError::MessageTooLong)` if the message does not fit.
+    pub fn encrypt_oaep<H: HashFunction>(
+        &self,
+        message: &[u8],
+        label: &[u8],
+    ) -> Result<Vec<u8>, RsaError> {
+        let k = self.size();
+        let h_len = H::OUTPUT_SIZE;
+        if k < 2 * h_len + 2 || message.len() > k - 2 * h_len - 2 {
+            return Err(RsaError::MessageTooLong);
+        }
+
+        // DB = lHash || PS || 0x01 || M
+        let mut db = H::digest(label);
+        db.resize(k - h_len - message.len() - 2, 0);
+        db.push(0x01);
+        db.extend_from_slice(message);
+
+        let mut seed = vec![0u8; h_len];
+        OsRng.fill_bytes(&mut seed);
+
+        xor_in_place(&mut db, &mgf1::<H>(&seed, k - h_len - 1));
+        xor_in_place(&mut seed, &mgf1::<H>(&db, h_len));
+
+        let mut em = vec![0x00];
+        em.extend_from_slice(&seed);
+        em.extend_from_slice(&db);
+
+        let c = self.encrypt_raw(&os2ip(&em))?;
+        i2osp(&c, k)
+    }
+}
+
+impl RsaPrivateKey {
+    /// Decrypts an RSAES-OAEP ciphertext (RFC 8017, Section 7.1.2).
+    ///
+    /// All padding checks are combined before the result is inspected, and every failure is
+    /// reported as the same `RsaError::DecryptionError`, so that the error does not reveal
+    /// which check failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ciphertext` - A ciphertext of exactly k bytes.
+    /// * `label` - The label used for encryption.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with the message.
+    /// * `Err(RsaError::DecryptionError)` if the ciphertext is invalid.
+    pub fn decrypt_oaep<H: HashFunction>(
+        &self,
+        ciphertext: &[u8],
+        label: &[u8],
+    ) -> Result<Vec<u8>, RsaError> {
+        let k = self.size();
+        let h_len = H::OUTPUT_SIZE;
+        if ciphertext.len() != k || k < 2 * h_len + 2 {
+            return Err(RsaError::DecryptionError);
+        }
+
+        let m = self
+            .decrypt_raw(&os2ip(ciphertext))
+            .map_err(|_| RsaError::DecryptionError)?;
+        let em = i2osp(&m, k).map_err(|_| RsaError::DecryptionError)?;
+
+        let (y, rest) = em.split_first().unwrap();
+        let (masked_seed, masked_db) = rest.split_at(h_len);
+        let mut seed = masked_seed.to_vec();
+        xor_in_place(&mut seed, &mgf1::<H>(masked_db, h_len));
+        let mut db = masked_db.to_vec();
+        xor_in_place(&mut db, &mgf1::<H>(&seed, k - h_len - 1));
+
+        // Accumulate every check into `bad` and locate the 0x01 separator without
+        // branching on secret data.
+        let l_hash = H::digest(label);
+        let mut bad = *y;
+        for (a, b) in db[..h_len].iter().zip(&l_hash) {
+            bad |= a ^ b;
+        }
+        let mut separator = 0usize;
+        let mut found = 0u8;
+        for (i, &byte) in db.iter().enumerate().skip(h_len) {
+            let is_one = (byte == 0x01) as u8;
+            let is_zero = (byte == 0x00) as u8;
+            let first = is_one & !found & 1;
+            separator |= i * first as usize;
+            // Any byte other than 0x00 before the separator is invalid.
+            bad |= !found & 1 & !is_zero & !is_one;
+            found |= is_one;
+        }
+        bad |= !found & 1;
+
+        if bad != 0 {
+            return Err(RsaError::DecryptionError);
+        }
+        Ok(db[separator + 1..].to_vec())
+    }
+}
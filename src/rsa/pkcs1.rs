@@ -0,0 +1,111 @@
+// src/rsa/pkcs1.rs
+
+use super::{RsaError, RsaPrivateKey, RsaPublicKey};
+use crate::encoding::der::{self, DerReader};
+use crate::encoding::pem;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// PEM label of a PKCS#1 `RSAPublicKey`.
+pub const PUBLIC_KEY_PEM_LABEL: &str = "RSA PUBLIC KEY";
+
+/// PEM label of a PKCS#1 `RSAPrivateKey`.
+pub const PRIVATE_KEY_PEM_LABEL: &str = "RSA PRIVATE KEY";
+
+impl RsaPublicKey {
+    /// Encodes the key as a PKCS#1 `RSAPublicKey` structure in DER:
+    /// `SEQUENCE { modulus INTEGER, publicExponent INTEGER }`.
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        der::encode_sequence(&[der::encode_integer(&self.n), der::encode_integer(&self.e)])
+    }
+
+    /// Decodes a PKCS#1 `RSAPublicKey` structure from DER.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPublicKey)` on success.
+    /// * `Err(RsaError::Encoding)` if the DER is malformed.
+    /// * `Err(RsaError::InvalidKey)` if the values do not form a valid key.
+    pub fn from_pkcs1_der(bytes: &[u8]) -> Result<Self, RsaError> {
+        let mut outer = DerReader::new(bytes);
+        let mut fields = outer.read_sequence()?;
+        outer.finish()?;
+        let n = fields.read_integer()?;
+        let e = fields.read_integer()?;
+        fields.finish()?;
+        RsaPublicKey::new(n, e)
+    }
+
+    /// Encodes the key as a PEM block labelled `RSA PUBLIC KEY`.
+    pub fn to_pkcs1_pem(&self) -> String {
+        pem::encode(PUBLIC_KEY_PEM_LABEL, &self.to_pkcs1_der())
+    }
+
+    /// Decodes a key from a PEM block labelled `RSA PUBLIC KEY`.
+    pub fn from_pkcs1_pem(text: &str) -> Result<Self, RsaError> {
+        Self::from_pkcs1_der(&pem::decode(PUBLIC_KEY_PEM_LABEL, text)?)
+    }
+}
+
+impl RsaPrivateKey {
+    /// Encodes the key as a two-prime PKCS#1 `RSAPrivateKey` structure in DER:
+    /// `SEQUENCE { version, n, e, d, p, q, dP, dQ, qInv }` with version 0.
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        let fields = [
+            &BigUint::zero(),
+            &self.public_key.n,
+            &self.public_key.e,
+            &self.d,
+            &self.p,
+            &self.q,
+            &self.dp,
+            &self.dq,
+            &self.qinv,
+        ];
+        let encoded: Vec<Vec<u8>> = fields.iter().map(|x| der::encode_integer(x)).collect();
+        der::encode_sequence(&encoded)
+    }
+
+    /// Decodes a two-prime PKCS#1 `RSAPrivateKey` structure from DER.
+    ///
+    /// The CRT components are checked against the ones recomputed from p, q and d.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RsaPrivateKey)` on success.
+    /// * `Err(RsaError::Encoding)` if the DER is malformed.
+    /// * `Err(RsaError::InvalidKey)` if the key is multi-prime or its components are
+    ///   inconsistent.
+    pub fn from_pkcs1_der(bytes: &[u8]) -> Result<Self, RsaError> {
+        let mut outer = DerReader::new(bytes);
+        let mut fields = outer.read_sequence()?;
+        outer.finish()?;
+
+        let version = fields.read_integer()?;
+        if !version.is_zero() {
+            return Err(RsaError::InvalidKey);
+        }
+        let mut values = Vec::with_capacity(8);
+        for _ in 0..8 {
+            values.push(fields.read_integer()?);
+        }
+        fields.finish()?;
+
+        let [n, e, d, p, q, dp, dq, qinv]: [BigUint; 8] = values.try_into().unwrap();
+        let key = RsaPrivateKey::from_components(n, e, d, p, q)?;
+        if key.dp != dp || key.dq != dq || key.qinv != qinv {
+            return Err(RsaError::InvalidKey);
+        }
+        Ok(key)
+    }
+
+    /// Encodes the key as a PEM block labelled `RSA PRIVATE KEY`.
+    pub fn to_pkcs1_pem(&self) -> String {
+        pem::encode(PRIVATE_KEY_PEM_LABEL, &self.to_pkcs1_der())
+    }
+
+    /// Decodes a key from a PEM block labelled `RSA PRIVATE KEY`.
+    pub fn from_pkcs1_pem(text: &str) -> Result<Self, RsaError> {
+        Self::from_pkcs1_der(&pem::decode(PRIVATE_KEY_PEM_LABEL, text)?)
+    }
+}
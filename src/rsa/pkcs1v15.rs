@@ -0,0 +1,132 @@
+// src/rsa/pkcs1v15.rs
+
+use super::{i2osp, os2ip, RsaError, RsaPrivateKey, RsaPublicKey};
+use crate::encoding::der;
+use crate::hash::HashFunction;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// The minimum number of padding bytes in an encryption or signature block.
+const MIN_PADDING: usize = 8;
+
+/// Builds the EMSA-PKCS1-v1_5 encoding 0x00 || 0x01 || 0xff... || 0x00 || DigestInfo of
+/// `message` for a modulus of `k` bytes.
+fn emsa_pkcs1v15_encode<H: HashFunction>(message: &[u8], k: usize) -> Result<Vec<u8>, RsaError> {
+    let algorithm = der::encode_sequence(&[der::encode_oid(H::OID), der::encode_null()]);
+    let digest_info =
+        der::encode_sequence(&[algorithm, der::encode_octet_string(&H::digest(message))]);
+    if k < digest_info.len() + MIN_PADDING + 3 {
+        return Err(RsaError::MessageTooLong);
+    }
+
+    let mut em = vec![0x00, 0x01];
+    em.resize(k - digest_info.len() - 1, 0xff);
+    em.push(0x00);
+    em.extend_from_slice(&digest_info);
+    Ok(em)
+}
+
+impl RsaPublicKey {
+    /// Encrypts `message` with RSAES-PKCS1-v1_5 (RFC 8017, Section 7.2.1).
+    ///
+    /// This scheme is only provided for interoperability; new designs should use OAEP.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with a ciphertext of k bytes.
+    /// * `Err(RsaError::MessageTooLong)` if the message exceeds k - 11 bytes.
+    pub fn encrypt_pkcs1v15(&self, message: &[u8]) -> Result<Vec<u8>, RsaError> {
+        let k = self.size();
+        if k < message.len() + MIN_PADDING + 3 {
+            return Err(RsaError::MessageTooLong);
+        }
+
+        // EM = 0x00 || 0x02 || PS || 0x00 || M with PS made of non-zero random bytes.
+        let mut em = vec![0x00, 0x02];
+        let mut byte = [0u8; 1];
+        while em.len() < k - message.len() - 1 {
+            OsRng.fill_bytes(&mut byte);
+            if byte[0] != 0 {
+                em.push(byte[0]);
+            }
+        }
+        em.push(0x00);
+        em.extend_from_slice(message);
+
+        let c = self.encrypt_raw(&os2ip(&em))?;
+        i2osp(&c, k)
+    }
+
+    /// Verifies an RSASSA-PKCS1-v1_5 signature (RFC 8017, Section 8.2.2) by re-encoding the
+    /// message and comparing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(RsaError::InvalidSignature)` otherwise.
+    pub fn verify_pkcs1v15<H: HashFunction>(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), RsaError> {
+        let k = self.size();
+        if signature.len() != k {
+            return Err(RsaError::InvalidSignature);
+        }
+        let m = self
+            .encrypt_raw(&os2ip(signature))
+            .map_err(|_| RsaError::InvalidSignature)?;
+        let em = i2osp(&m, k).map_err(|_| RsaError::InvalidSignature)?;
+        let expected =
+            emsa_pkcs1v15_encode::<H>(message, k).map_err(|_| RsaError::InvalidSignature)?;
+
+        if em != expected {
+            return Err(RsaError::InvalidSignature);
+        }
+        Ok(())
+    }
+}
+
+impl RsaPrivateKey {
+    /// Decrypts an RSAES-PKCS1-v1_5 ciphertext (RFC 8017, Section 7.2.2).
+    ///
+    /// Every failure is reported as the same `RsaError::DecryptionError`. Callers must still
+    /// avoid revealing whether decryption failed, or they expose a padding oracle
+    /// (Bleichenbacher's attack).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with the message.
+    /// * `Err(RsaError::DecryptionError)` if the ciphertext is invalid.
+    pub fn decrypt_pkcs1v15(&self, ciphertext: &[u8]) -> Result<Vec<u8>, RsaError> {
+        let k = self.size();
+        if ciphertext.len() != k || k < MIN_PADDING + 3 {
+            return Err(RsaError::DecryptionError);
+        }
+        let m = self
+            .decrypt_raw(&os2ip(ciphertext))
+            .map_err(|_| RsaError::DecryptionError)?;
+        let em = i2osp(&m, k).map_err(|_| RsaError::DecryptionError)?;
+
+        let separator = em[2..].iter().position(|&b| b == 0).map(|i| i + 2);
+        match separator {
+            Some(index) if em[0] == 0x00 && em[1] == 0x02 && index >= MIN_PADDING + 2 => {
+                Ok(em[index + 1..].to_vec())
+            }
+            _ => Err(RsaError::DecryptionError),
+        }
+    }
+
+    /// Signs `message` with RSASSA-PKCS1-v1_5 (RFC 8017, Section 8.2.1).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with a signature of k bytes.
+    /// * `Err(RsaError::MessageTooLong)` if the modulus is too small for the DigestInfo.
+    pub fn sign_pkcs1v15<H: HashFunction>(&self, message: &[u8]) -> Result<Vec<u8>, RsaError> {
+        let k = self.size();
+        let em = emsa_pkcs1v15_encode::<H>(message, k)?;
+        let s = self.decrypt_raw(&os2ip(&em))?;
+        i2osp(&s, k)
+    }
+}
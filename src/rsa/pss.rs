@@ -0,0 +1,118 @@
+// src/rsa/pss.rs
+
+use super::mgf1::{mgf1, xor_in_place};
+use super::{i2osp, os2ip, RsaError, RsaPrivateKey, RsaPublicKey};
+use crate::hash::HashFunction;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Computes H(0x00 * 8 || mHash || salt), the value embedded in a PSS encoding.
+fn salted_hash<H: HashFunction>(message_hash: &[u8], salt: &[u8]) -> Vec<u8> {
+    let mut hasher = H::new();
+    hasher.update(&[0u8; 8]);
+    hasher.update(message_hash);
+    hasher.update(salt);
+    hasher.finalize()
+}
+
+impl RsaPrivateKey {
+    /// Signs `message` with RSASSA-PSS (RFC 8017, Section 8.1.1), using `H` for both the
+    /// message hash and MGF1.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to sign.
+    /// * `salt_len` - The length of the random salt in bytes; hLen is the usual choice.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` with a signature of k bytes.
+    /// * `Err(RsaError::MessageTooLong)` if the key is too small for the hash and salt.
+    pub fn sign_pss<H: HashFunction>(
+        &self,
+        message: &[u8],
+        salt_len: usize,
+    ) -> Result<Vec<u8>, RsaError> {
+        let h_len = H::OUTPUT_SIZE;
+        let em_bits = self.public_key.n.bits() as usize - 1;
+        let em_len = em_bits.div_ceil(8);
+        if em_len < h_len + salt_len + 2 {
+            return Err(RsaError::MessageTooLong);
+        }
+
+        let mut salt = vec![0u8; salt_len];
+        OsRng.fill_bytes(&mut salt);
+        let h = salted_hash::<H>(&H::digest(message), &salt);
+
+        // DB = PS || 0x01 || salt
+        let mut db = vec![0u8; em_len - salt_len - h_len - 2];
+        db.push(0x01);
+        db.extend_from_slice(&salt);
+        xor_in_place(&mut db, &mgf1::<H>(&h, em_len - h_len - 1));
+        db[0] &= 0xffu8 >> (8 * em_len - em_bits);
+
+        let mut em = db;
+        em.extend_from_slice(&h);
+        em.push(0xbc);
+
+        let s = self.decrypt_raw(&os2ip(&em))?;
+        i2osp(&s, self.size())
+    }
+}
+
+impl RsaPublicKey {
+    /// Verifies an RSASSA-PSS signature (RFC 8017, Section 8.1.2).
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The signed message.
+    /// * `signature` - A signature of exactly k bytes.
+    /// * `salt_len` - The salt length used by the signer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the signature is valid.
+    /// * `Err(RsaError::InvalidSignature)` otherwise.
+    pub fn verify_pss<H: HashFunction>(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        salt_len: usize,
+    ) -> Result<(), RsaError> {
+        let h_len = H::OUTPUT_SIZE;
+        let em_bits = self.n.bits() as usize - 1;
+        let em_len = em_bits.div_ceil(8);
+        if signature.len() != self.size() || em_len < h_len + salt_len + 2 {
+            return Err(RsaError::InvalidSignature);
+        }
+
+        let m = self
+            .encrypt_raw(&os2ip(signature))
+            .map_err(|_| RsaError::InvalidSignature)?;
+        let em = i2osp(&m, em_len).map_err(|_| RsaError::InvalidSignature)?;
+
+        if em[em_len - 1] != 0xbc {
+            return Err(RsaError::InvalidSignature);
+        }
+        let (masked_db, h) = em[..em_len - 1].split_at(em_len - h_len - 1);
+        let unused_bits = 8 * em_len - em_bits;
+        if masked_db[0] & !(0xffu8 >> unused_bits) != 0 {
+            return Err(RsaError::InvalidSignature);
+        }
+
+        let mut db = masked_db.to_vec();
+        xor_in_place(&mut db, &mgf1::<H>(h, em_len - h_len - 1));
+        db[0] &= 0xffu8 >> unused_bits;
+
+        let padding_len = em_len - h_len - salt_len - 2;
+        if db[..padding_len].iter().any(|&b| b != 0) || db[padding_len] != 0x01 {
+            return Err(RsaError::InvalidSignature);
+        }
+        let salt = &db[padding_len + 1..];
+
+        if salted_hash::<H>(&H::digest(message), salt) != h {
+            return Err(RsaError::InvalidSignature);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// src/rsa/tests.rs
+
+// Interoperability tests against a key, ciphertexts and signatures produced by Python's
+// `cryptography` package (test-data/rsa).
+
+use super::{RsaError, RsaPrivateKey, RsaPublicKey};
+use crate::hash::{Sha256, Sha512};
+use num_bigint::BigUint;
+
+const PRIVATE_KEY_PEM: &str = include_str!("../../test-data/rsa/private_key.pem");
+const PUBLIC_KEY_PEM: &str = include_str!("../../test-data/rsa/public_key.pem");
+const VECTORS: &str = include_str!("../../test-data/rsa/vectors.txt");
+
+fn vector(name: &str) -> Vec<u8> {
+    VECTORS
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| hex::decode(value).unwrap())
+        .unwrap_or_else(|| panic!("missing vector {}", name))
+}
+
+fn private_key() -> RsaPrivateKey {
+    RsaPrivateKey::from_pkcs1_pem(PRIVATE_KEY_PEM).unwrap()
+}
+
+#[test]
+fn pem_keys_round_trip() {
+    let key = private_key();
+    let public_key = RsaPublicKey::from_pkcs1_pem(PUBLIC_KEY_PEM).unwrap();
+    assert_eq!(key.public_key(), &public_key);
+    assert_eq!(key.public_key.n.bits(), 2048);
+    assert_eq!(key.to_pkcs1_pem(), PRIVATE_KEY_PEM);
+    assert_eq!(public_key.to_pkcs1_pem(), PUBLIC_KEY_PEM);
+}
+
+#[test]
+fn decrypts_oaep_ciphertexts() {
+    let key = private_key();
+    let message = vector("message");
+    let plaintext = key
+        .decrypt_oaep::<Sha256>(&vector("oaep_sha256"), b"")
+        .unwrap();
+    assert_eq!(plaintext, message);
+    let plaintext = key
+        .decrypt_oaep::<Sha256>(&vector("oaep_sha256_label"), b"label")
+        .unwrap();
+    assert_eq!(plaintext, message);
+    assert!(matches!(
+        key.decrypt_oaep::<Sha256>(&vector("oaep_sha256_label"), b""),
+        Err(RsaError::DecryptionError)
+    ));
+}
+
+#[test]
+fn decrypts_pkcs1v15_ciphertext() {
+    let key = private_key();
+    let plaintext = key
+        .decrypt_pkcs1v15(&vector("pkcs1v15_encryption"))
+        .unwrap();
+    assert_eq!(plaintext, vector("message"));
+}
+
+#[test]
+fn verifies_pss_signature() {
+    let public_key = RsaPublicKey::from_pkcs1_pem(PUBLIC_KEY_PEM).unwrap();
+    let message = vector("message");
+    let signature = vector("pss_sha256");
+    assert!(public_key
+        .verify_pss::<Sha256>(&message, &signature, 32)
+        .is_ok());
+    assert!(public_key
+        .verify_pss::<Sha256>(b"another message", &signature, 32)
+        .is_err());
+}
+
+#[test]
+fn pkcs1v15_signatures_match() {
+    let key = private_key();
+    let message = vector("message");
+    let signature = key.sign_pkcs1v15::<Sha256>(&message).unwrap();
+    assert_eq!(signature, vector("pkcs1v15_sha256"));
+    assert!(key
+        .public_key
+        .verify_pkcs1v15::<Sha256>(&message, &signature)
+        .is_ok());
+    let signature = key.sign_pkcs1v15::<Sha512>(&message).unwrap();
+    assert_eq!(signature, vector("pkcs1v15_sha512"));
+}
+
+#[test]
+fn own_ciphertexts_and_signatures_round_trip() {
+    let key = private_key();
+    let message = vector("message");
+    let ciphertext = key
+        .public_key
+        .encrypt_oaep::<Sha256>(&message, b"")
+        .unwrap();
+    assert_eq!(
+        key.decrypt_oaep::<Sha256>(&ciphertext, b"").unwrap(),
+        message
+    );
+    let ciphertext = key.public_key.encrypt_pkcs1v15(&message).unwrap();
+    assert_eq!(key.decrypt_pkcs1v15(&ciphertext).unwrap(), message);
+    let signature = key.sign_pss::<Sha256>(&message, 32).unwrap();
+    assert!(key
+        .public_key
+        .verify_pss::<Sha256>(&message, &signature, 32)
+        .is_ok());
+}
+
+#[test]
+fn faulty_crt_exponent_is_detected() {
+    let mut key = private_key();
+    key.dp += 1u32;
+    assert!(matches!(
+        key.decrypt_raw(&BigUint::from(12345u32)),
+        Err(RsaError::FaultDetected)
+    ));
+}
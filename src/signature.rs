@@ -0,0 +1,24 @@
+// src/signature.rs
+
+/// A private key that produces signatures on byte strings, so that protocols can be written
+/// independently of the signature scheme.
+pub trait SigningKey {
+    /// The matching public key type.
+    type VerifyingKey: VerifyingKey;
+
+    /// Signs `message` and returns the encoded signature.
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+
+    /// Returns the public key that verifies this key's signatures.
+    fn verifying_key(&self) -> Self::VerifyingKey;
+}
+
+/// A public key that verifies encoded signatures.
+pub trait VerifyingKey: Clone {
+    /// Encodes the key; the encoding doubles as the signer's identity in key exchange
+    /// protocols.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Returns true if `signature` is a valid signature on `message`.
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool;
+}